@@ -7,8 +7,7 @@ fn run_llvm_config() -> String {
         .output()
         .expect("failed to execute process");
 
-    let output = String::from_utf8(output.stdout).unwrap();
-    output
+    String::from_utf8(output.stdout).unwrap()
 }
 
 const BINDING_FILE_PATH: &str = "./src/llvm/bindings.rs";
@@ -29,7 +28,7 @@ fn main() {
     // 라이브러리 링크
     //println!("cargo:rustc-link-lib=foo");
 
-    let current_dir = PathBuf::from(std::env::current_dir().unwrap());
+    let current_dir = std::env::current_dir().unwrap();
 
     // 바인딩 파일이 존재하면 생성하지 않음
     if exists_binding_file() {
@@ -60,7 +59,7 @@ fn main() {
         bindings_builder = bindings_builder.header(header_base_path.join(header).to_str().unwrap());
     }
 
-    let llvm_config = run_llvm_config();
+    let _llvm_config = run_llvm_config();
 
     //    -fno-rtti  -D_DEBUG -D_GLIBCXX_ASSERTIONS
 
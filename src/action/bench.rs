@@ -0,0 +1,12 @@
+use foolang::{
+    bench::{synthetic::SyntheticProgram, BenchReport, Benchmark},
+    AllError,
+};
+
+use crate::command::action::bench;
+
+pub(crate) async fn execute_bench(action: bench::Action) -> Result<BenchReport, AllError> {
+    let text = SyntheticProgram::new().generate(action.value.statements);
+
+    Benchmark::new(text, action.value.iterations).run()
+}
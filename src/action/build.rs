@@ -1,28 +1,81 @@
-use crate::{
-    builder::Builder, codegen::CodeGenerator, command::action::build, error::all_error::AllError,
-    lexer::tokenizer::Tokenizer, parser::Parser,
+use std::path::Path;
+
+use foolang::{
+    builder::{emit::Emit, Builder},
+    dump_ast, dump_tokens, lower_modules_with_warnings, parse, AllError, Diagnostic, MessageFormat,
 };
 
-pub(crate) async fn execute_build(action: build::Action) -> Result<String, AllError> {
-    let text = if let Ok(text) = tokio::fs::read_to_string(&action.value.filename).await {
-        text
-    } else {
-        return Err(AllError::FileNotFound(action.value.filename));
-    };
+use crate::{command::action::build, print_diagnostic};
+
+pub(crate) async fn execute_build(
+    action: build::Action,
+    message_format: MessageFormat,
+) -> Result<String, AllError> {
+    let mut texts = vec![];
+
+    for filename in action.value.filenames.iter() {
+        let text = if let Ok(text) = tokio::fs::read_to_string(filename).await {
+            text
+        } else {
+            return Err(AllError::FileNotFound(filename.clone()));
+        };
 
-    let tokens = Tokenizer::string_to_tokens(text)?;
+        texts.push((filename.clone(), text));
+    }
 
-    let mut parser = Parser::new();
-    parser.set_tokens(tokens);
-    let statements = parser.parse()?;
+    if action.value.emit.is_frontend() {
+        return emit_frontend(action, texts).await;
+    }
 
-    let mut codegen = CodeGenerator::new();
-    codegen.set_statements(statements);
-    let codes = codegen.generate()?;
+    let mut sources = vec![];
+    for (filename, text) in texts {
+        let statements = parse(&text).map_err(|error| error.with_file(&filename))?;
+        sources.push((filename, statements));
+    }
+
+    let (mut modules, warnings) = lower_modules_with_warnings(sources, action.value.no_std)?;
+
+    for warning in warnings.iter() {
+        print_diagnostic(&Diagnostic::warning(warning), message_format);
+    }
+    let entry = modules.remove(0);
 
     let mut builder = Builder::new();
-    builder.set_filenames(codes);
+    builder.set_filenames(entry);
+    builder.set_modules(modules);
+    builder.set_no_std(action.value.no_std);
+    builder.set_emit(action.value.emit);
+    builder.set_via_assembly(action.value.via_asm);
+    builder.set_assembly_syntax(action.value.asm_syntax);
+    builder.set_target(action.value.target);
+    builder.set_source_path(&action.value.filenames[0]);
     let output = builder.build()?;
 
     Ok(output)
 }
+
+// 토큰 목록이나 AST를 소스 파일 옆에 기록하고, 생성한 파일 목록을 반환합니다.
+async fn emit_frontend(
+    action: build::Action,
+    texts: Vec<(String, String)>,
+) -> Result<String, AllError> {
+    let mut outputs = vec![];
+
+    for (filename, text) in texts {
+        let dump = if action.value.emit == Emit::Tokens {
+            dump_tokens(&text)
+        } else {
+            dump_ast(&text)
+        }
+        .map_err(|error| error.with_file(&filename))?;
+
+        let output_path = Path::new(&filename).with_extension(action.value.emit.extension());
+        tokio::fs::write(&output_path, dump)
+            .await
+            .map_err(|error| AllError::IOError(format!("{}: {}", output_path.display(), error)))?;
+
+        outputs.push(output_path.to_string_lossy().into_owned());
+    }
+
+    Ok(outputs.join(", "))
+}
@@ -0,0 +1,32 @@
+use foolang::{check, parse, AllError, Diagnostic, MessageFormat};
+
+use crate::{command::action::check, print_diagnostic};
+
+// 파일을 생성하지 않고 오류만 검사합니다. 검사한 파일 목록을 반환합니다.
+pub(crate) async fn execute_check(
+    action: check::Action,
+    message_format: MessageFormat,
+) -> Result<Vec<String>, AllError> {
+    let mut sources = vec![];
+
+    for filename in action.value.filenames.iter() {
+        let text = if let Ok(text) = tokio::fs::read_to_string(filename).await {
+            text
+        } else {
+            return Err(AllError::FileNotFound(filename.clone()));
+        };
+
+        sources.push((
+            filename.clone(),
+            parse(&text).map_err(|error| error.with_file(filename))?,
+        ));
+    }
+
+    let warnings = check(sources, action.value.no_std)?;
+
+    for warning in warnings.iter() {
+        print_diagnostic(&Diagnostic::warning(warning), message_format);
+    }
+
+    Ok(action.value.filenames)
+}
@@ -0,0 +1,39 @@
+use foolang::{format_source, AllError, SourceError};
+
+use crate::command::action::fmt;
+
+// 소스 파일을 정해진 형식으로 고치고, 내용이 바뀐 파일 목록을 반환합니다.
+// --check이면 파일을 고치지 않고, 바뀔 파일이 있으면 그 목록을 오류로 반환합니다.
+pub(crate) async fn execute_fmt(action: fmt::Action) -> Result<Vec<String>, AllError> {
+    let mut changed = vec![];
+
+    for filename in action.value.filenames.iter() {
+        let text = if let Ok(text) = tokio::fs::read_to_string(filename).await {
+            text
+        } else {
+            return Err(AllError::FileNotFound(filename.clone()));
+        };
+
+        let formatted = format_source(&text).map_err(|error| error.with_file(filename))?;
+        if formatted == text {
+            continue;
+        }
+
+        if !action.value.check {
+            tokio::fs::write(filename, formatted)
+                .await
+                .map_err(|error| AllError::IOError(format!("{}: {}", filename, error)))?;
+        }
+
+        changed.push(filename.clone());
+    }
+
+    if action.value.check && !changed.is_empty() {
+        return Err(AllError::FormatterError(SourceError::new(
+            1,
+            format!("not formatted: {}", changed.join(", ")),
+        )));
+    }
+
+    Ok(changed)
+}
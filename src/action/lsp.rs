@@ -0,0 +1,15 @@
+use foolang::{lsp::Server, AllError};
+
+use crate::command::action::lsp;
+
+// 편집기가 exit 알림을 보내거나 표준 입력이 닫힐 때까지 실행합니다.
+pub(crate) async fn execute_lsp(action: lsp::Action) -> Result<(), AllError> {
+    let mut server = Server::new();
+    server.set_no_std(action.value.no_std);
+
+    tokio::task::spawn_blocking(move || {
+        server.run(std::io::stdin().lock(), std::io::stdout().lock())
+    })
+    .await
+    .map_err(|error| AllError::IOError(error.to_string()))?
+}
@@ -1 +1,7 @@
+#[cfg(feature = "bench")]
+pub mod bench;
 pub mod build;
+pub mod check;
+pub mod fmt;
+pub mod lsp;
+pub mod repl;
@@ -0,0 +1,76 @@
+use std::io::{BufRead, Write};
+
+use foolang::{
+    repl::{is_incomplete, Session},
+    AllError, Diagnostic, MessageFormat,
+};
+
+use crate::command::action::repl;
+
+const PROMPT: &str = ">> ";
+// 괄호가 닫히지 않아 다음 줄을 이어서 읽을 때의 프롬프트
+const CONTINUATION: &str = ".. ";
+
+// :quit을 입력하거나 표준 입력이 닫힐 때까지 실행합니다.
+// 입력의 오류는 세션을 끝내지 않고 출력만 합니다.
+pub(crate) async fn execute_repl(
+    action: repl::Action,
+    message_format: MessageFormat,
+) -> Result<(), AllError> {
+    let mut session = Session::new();
+    session.set_no_std(action.value.no_std);
+
+    tokio::task::spawn_blocking(move || {
+        let stdin = std::io::stdin();
+        let mut input = stdin.lock();
+        let io_error = |error: std::io::Error| AllError::IOError(error.to_string());
+
+        loop {
+            let mut source = String::new();
+
+            loop {
+                print!(
+                    "{}",
+                    if source.is_empty() {
+                        PROMPT
+                    } else {
+                        CONTINUATION
+                    }
+                );
+                std::io::stdout().flush().map_err(io_error)?;
+
+                if input.read_line(&mut source).map_err(io_error)? == 0 {
+                    println!();
+                    return Ok(());
+                }
+
+                if !is_incomplete(&source) {
+                    break;
+                }
+            }
+
+            match source.trim() {
+                "" => continue,
+                ":quit" | ":q" => return Ok(()),
+                _ => {}
+            }
+
+            match session.evaluate(&source) {
+                Ok(evaluation) => {
+                    print!("{}", evaluation.stdout);
+                    eprint!("{}", evaluation.stderr);
+
+                    if let Some(code) = evaluation.failure {
+                        eprintln!("exit status: {}", code);
+                    }
+                }
+                Err(error) => eprint!(
+                    "{}",
+                    Diagnostic::from(&error).format(message_format, Some(&source))
+                ),
+            }
+        }
+    })
+    .await
+    .map_err(|error| AllError::IOError(error.to_string()))?
+}
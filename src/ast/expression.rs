@@ -1,14 +1,20 @@
-use crate::lexer::primary::PrimaryToken;
+use crate::{lexer::primary::PrimaryToken, utils::span::Span};
 
 use self::{
-    binary::BinaryExpression, call::CallExpression, literal::LiteralExpression,
-    parentheses::ParenthesesExpression, unary::UnaryExpression, variable::VariableExpression,
+    binary::BinaryExpression, call::CallExpression, cast::CastExpression, field::FieldExpression,
+    literal::LiteralExpression, match_expression::MatchExpression,
+    parentheses::ParenthesesExpression, struct_literal::StructLiteralExpression,
+    unary::UnaryExpression, variable::VariableExpression,
 };
 
 pub(crate) mod binary;
 pub(crate) mod call;
+pub(crate) mod cast;
+pub(crate) mod field;
 pub(crate) mod literal;
+pub(crate) mod match_expression;
 pub(crate) mod parentheses;
+pub(crate) mod struct_literal;
 pub(crate) mod unary;
 pub(crate) mod variable;
 
@@ -17,22 +23,28 @@ pub(crate) mod variable;
 pub enum Expression {
     Binary(BinaryExpression),
     Unary(UnaryExpression),
-    Literal(LiteralExpression),
+    // 리터럴 토큰의 위치. 음수 리터럴의 -는 Unary로 따로 있습니다.
+    Literal(LiteralExpression, Span),
     Variable(VariableExpression),
     Call(CallExpression),
     Parentheses(ParenthesesExpression),
-    Comment(String),
+    Match(MatchExpression),
+    StructLiteral(StructLiteralExpression),
+    Field(FieldExpression),
+    Cast(CastExpression),
+    // 주석의 내용과 위치
+    Comment(String, Span),
 }
 
 impl From<LiteralExpression> for Expression {
     fn from(literal: LiteralExpression) -> Self {
-        Expression::Literal(literal)
+        Expression::Literal(literal, Span::default())
     }
 }
 
 impl From<PrimaryToken> for Expression {
     fn from(token: PrimaryToken) -> Self {
-        Expression::Literal(token.into())
+        Expression::Literal(token.into(), Span::default())
     }
 }
 
@@ -60,6 +72,30 @@ impl From<VariableExpression> for Expression {
     }
 }
 
+impl From<MatchExpression> for Expression {
+    fn from(match_expression: MatchExpression) -> Self {
+        Expression::Match(match_expression)
+    }
+}
+
+impl From<StructLiteralExpression> for Expression {
+    fn from(struct_literal: StructLiteralExpression) -> Self {
+        Expression::StructLiteral(struct_literal)
+    }
+}
+
+impl From<FieldExpression> for Expression {
+    fn from(field: FieldExpression) -> Self {
+        Expression::Field(field)
+    }
+}
+
+impl From<CastExpression> for Expression {
+    fn from(cast: CastExpression) -> Self {
+        Expression::Cast(cast)
+    }
+}
+
 impl From<CallExpression> for Expression {
     fn from(call: CallExpression) -> Self {
         Expression::Call(call)
@@ -89,3 +125,67 @@ impl Expression {
         }
     }
 }
+
+impl Expression {
+    // 식이 시작하는 토큰의 위치입니다.
+    pub fn span(&self) -> Span {
+        match self {
+            Expression::Binary(binary) => binary.lhs.span(),
+            Expression::Unary(unary) => unary.span,
+            Expression::Literal(_, span) | Expression::Comment(_, span) => *span,
+            Expression::Variable(variable) => variable.span,
+            Expression::Call(call) => call.span,
+            Expression::Parentheses(parentheses) => parentheses.expression.span(),
+            Expression::Match(expression) => expression.span,
+            Expression::StructLiteral(literal) => literal.span,
+            Expression::Field(field) => field.object.span(),
+            Expression::Cast(cast) => cast.value.span(),
+        }
+    }
+
+    // 모든 위치를 지웁니다. (Statement::clear_spans)
+    pub fn clear_spans(&mut self) {
+        match self {
+            Expression::Binary(binary) => {
+                binary.span = Span::default();
+                binary.lhs.clear_spans();
+                binary.rhs.clear_spans();
+            }
+            Expression::Unary(unary) => {
+                unary.span = Span::default();
+                unary.operand.clear_spans();
+            }
+            Expression::Literal(_, span) | Expression::Comment(_, span) => *span = Span::default(),
+            Expression::Variable(variable) => variable.span = Span::default(),
+            Expression::Call(call) => {
+                call.span = Span::default();
+                for argument in &mut call.arguments {
+                    argument.clear_spans();
+                }
+            }
+            Expression::Parentheses(parentheses) => parentheses.expression.clear_spans(),
+            Expression::Match(match_expression) => {
+                match_expression.span = Span::default();
+                match_expression.subject.clear_spans();
+                for arm in &mut match_expression.arms {
+                    arm.span = Span::default();
+                    arm.body.clear_spans();
+                }
+            }
+            Expression::StructLiteral(struct_literal) => {
+                struct_literal.span = Span::default();
+                for (_, value) in &mut struct_literal.fields {
+                    value.clear_spans();
+                }
+            }
+            Expression::Field(field) => {
+                field.span = Span::default();
+                field.object.clear_spans();
+            }
+            Expression::Cast(cast) => {
+                cast.span = Span::default();
+                cast.value.clear_spans();
+            }
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use crate::ast::operator::binary::BinaryOperator;
+use crate::{ast::operator::binary::BinaryOperator, utils::span::Span};
 
 use super::Expression;
 
@@ -7,4 +7,6 @@ pub struct BinaryExpression {
     pub operator: BinaryOperator,
     pub lhs: Box<Expression>,
     pub rhs: Box<Expression>,
+    // 연산자의 위치
+    pub span: Span,
 }
@@ -1,7 +1,11 @@
+use crate::utils::{interner::Symbol, span::Span};
+
 use super::Expression;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct CallExpression {
-    pub function_name: String,
+    pub function_name: Symbol,
     pub arguments: Vec<Expression>,
+    // 함수 이름의 위치
+    pub span: Span,
 }
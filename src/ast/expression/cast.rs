@@ -0,0 +1,12 @@
+use crate::utils::{interner::Symbol, span::Span};
+
+use super::Expression;
+
+// 값 as 타입
+#[derive(Debug, Clone, PartialEq)]
+pub struct CastExpression {
+    pub value: Box<Expression>,
+    pub type_name: Symbol,
+    // as의 위치
+    pub span: Span,
+}
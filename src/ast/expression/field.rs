@@ -0,0 +1,12 @@
+use crate::utils::{interner::Symbol, span::Span};
+
+use super::Expression;
+
+// 구조체 값.필드
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldExpression {
+    pub object: Box<Expression>,
+    pub field: Symbol,
+    // 필드 이름의 위치
+    pub span: Span,
+}
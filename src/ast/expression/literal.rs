@@ -4,17 +4,40 @@ use crate::lexer::primary::PrimaryToken;
 pub enum LiteralExpression {
     String(String),
     Integer(i64),
+    // 타입 추론에서 i32로 정해진 정수 리터럴. 파서는 만들지 않고 의미 분석의 annotate가 만듭니다.
+    Int32(i32),
+    // 접미사로 i64를 정한 정수 리터럴. 다른 정수 타입으로 추론되지 않습니다.
+    Int64(i64),
     Float(f64),
+    Float32(f32),
     Boolean(bool),
+    Char(char),
 }
 
+impl LiteralExpression {
+    // 앞에 -를 붙인 숫자 리터럴입니다. 숫자가 아니면 None입니다.
+    // 정수는 -(-9223372036854775808)처럼 범위를 넘으면 같은 값으로 돌아옵니다.
+    pub fn negated(&self) -> Option<Self> {
+        match self {
+            Self::Integer(integer) => Some(Self::Integer(integer.wrapping_neg())),
+            Self::Int32(integer) => Some(Self::Int32(integer.wrapping_neg())),
+            Self::Int64(integer) => Some(Self::Int64(integer.wrapping_neg())),
+            Self::Float(float) => Some(Self::Float(-float)),
+            Self::Float32(float) => Some(Self::Float32(-float)),
+            _ => None,
+        }
+    }
+}
+
+// 정수 토큰은 앞의 -에 따라 범위가 달라지므로 Parser::parse_literal에서 변환합니다.
 impl From<PrimaryToken> for LiteralExpression {
     fn from(token: PrimaryToken) -> Self {
         match token {
             PrimaryToken::String(string) => Self::String(string),
-            PrimaryToken::Integer(integer) => Self::Integer(integer),
             PrimaryToken::Float(float) => Self::Float(float),
+            PrimaryToken::Float32(float) => Self::Float32(float),
             PrimaryToken::Boolean(boolean) => Self::Boolean(boolean),
+            PrimaryToken::Char(character) => Self::Char(character),
             _ => panic!("Cannot convert {:?} to LiteralExpression", token),
         }
     }
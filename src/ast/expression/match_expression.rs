@@ -0,0 +1,42 @@
+use crate::utils::span::Span;
+
+use super::{literal::LiteralExpression, Expression};
+
+// match 대상 { 패턴 => 식, ... }
+// 위에서부터 처음으로 일치하는 갈래의 식이 match 전체의 값이 됩니다.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MatchExpression {
+    pub subject: Box<Expression>,
+    pub arms: Vec<MatchArm>,
+    // match 키워드의 위치
+    pub span: Span,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MatchArm {
+    pub pattern: Pattern,
+    pub body: Expression,
+    // 패턴 전체의 위치 (-1이면 -부터)
+    pub span: Span,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Pattern {
+    Literal(LiteralExpression),
+    Wildcard, // _
+}
+
+impl MatchExpression {
+    // 앞의 갈래와 패턴이 같거나 _ 뒤에 오는 갈래는 선택될 수 없습니다.
+    pub fn is_reachable(&self, index: usize) -> bool {
+        let pattern = &self.arms[index].pattern;
+
+        !self.arms[..index]
+            .iter()
+            .any(|arm| arm.pattern == Pattern::Wildcard || &arm.pattern == pattern)
+    }
+
+    pub fn has_wildcard(&self) -> bool {
+        self.arms.iter().any(|arm| arm.pattern == Pattern::Wildcard)
+    }
+}
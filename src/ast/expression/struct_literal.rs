@@ -0,0 +1,13 @@
+use crate::utils::{interner::Symbol, span::Span};
+
+use super::Expression;
+
+// 구조체 이름 { 필드: 식, ... }
+// 필드의 식은 적은 순서대로 계산합니다.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StructLiteralExpression {
+    pub name: Symbol,
+    pub fields: Vec<(Symbol, Expression)>,
+    // 구조체 이름의 위치
+    pub span: Span,
+}
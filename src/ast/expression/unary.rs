@@ -1,7 +1,11 @@
+use crate::utils::span::Span;
+
 use super::{super::operator::unary::UnaryOperator, Expression};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct UnaryExpression {
     pub operator: UnaryOperator,
     pub operand: Box<Expression>,
+    // 연산자의 위치
+    pub span: Span,
 }
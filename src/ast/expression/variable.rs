@@ -1,4 +1,7 @@
+use crate::utils::{interner::Symbol, span::Span};
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct VariableExpression {
-    pub name: String,
+    pub name: Symbol,
+    pub span: Span,
 }
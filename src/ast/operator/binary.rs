@@ -15,6 +15,11 @@ pub enum BinaryOperator {
     GreaterThanOrEqual, // >=
     And,                // &&
     Or,                 // ||
+    BitwiseAnd,         // &
+    BitwiseOr,          // |
+    BitwiseXor,         // ^
+    LeftShift,          // <<
+    RightShift,         // >>
 }
 
 impl From<OperatorToken> for BinaryOperator {
@@ -33,28 +38,102 @@ impl From<OperatorToken> for BinaryOperator {
             OperatorToken::GreaterThanOrEqual => Self::GreaterThanOrEqual,
             OperatorToken::And => Self::And,
             OperatorToken::Or => Self::Or,
+            OperatorToken::Ampersand => Self::BitwiseAnd,
+            OperatorToken::BitwiseOr => Self::BitwiseOr,
+            OperatorToken::BitwiseXor => Self::BitwiseXor,
+            OperatorToken::LeftShift => Self::LeftShift,
+            OperatorToken::RightShift => Self::RightShift,
             _ => panic!("Cannot convert {:?} to BinaryOperator", token),
         }
     }
 }
 
+// 우선순위 표. 아래 줄일수록 우선순위가 높아서 먼저 묶입니다.
+// 같은 줄의 연산자는 우선순위가 같고, 모든 이항 연산자는 왼쪽부터 묶습니다.
+// 대입 연산자(=, += 등)는 식이 아니라 statement이므로 표에 없고, 어떤 이항 연산자보다도 나중에 묶입니다.
+const PRECEDENCE_TABLE: &[&[BinaryOperator]] = &[
+    &[BinaryOperator::Or],
+    &[BinaryOperator::And],
+    &[
+        BinaryOperator::Equal,
+        BinaryOperator::NotEqual,
+        BinaryOperator::LessThan,
+        BinaryOperator::LessThanOrEqual,
+        BinaryOperator::GreaterThan,
+        BinaryOperator::GreaterThanOrEqual,
+    ],
+    &[BinaryOperator::BitwiseOr],
+    &[BinaryOperator::BitwiseXor],
+    &[BinaryOperator::BitwiseAnd],
+    &[BinaryOperator::LeftShift, BinaryOperator::RightShift],
+    &[BinaryOperator::Add, BinaryOperator::Subtract],
+    &[
+        BinaryOperator::Multiply,
+        BinaryOperator::Divide,
+        BinaryOperator::Modulo,
+    ],
+];
+
 impl BinaryOperator {
-    // 연산자 우선순위
-    pub fn get_precedence(&self) -> u8 {
+    // 소스코드에 적는 연산자 기호입니다.
+    pub fn as_str(&self) -> &'static str {
         match self {
-            Self::Add => 1,
-            Self::Subtract => 1,
-            Self::Multiply => 2,
-            Self::Divide => 2,
-            Self::Modulo => 2,
-            Self::Equal => 3,
-            Self::NotEqual => 3,
-            Self::LessThan => 3,
-            Self::LessThanOrEqual => 3,
-            Self::GreaterThan => 3,
-            Self::GreaterThanOrEqual => 3,
-            Self::And => 4,
-            Self::Or => 4,
+            Self::Add => "+",
+            Self::Subtract => "-",
+            Self::Multiply => "*",
+            Self::Divide => "/",
+            Self::Modulo => "%",
+            Self::Equal => "==",
+            Self::NotEqual => "!=",
+            Self::LessThan => "<",
+            Self::LessThanOrEqual => "<=",
+            Self::GreaterThan => ">",
+            Self::GreaterThanOrEqual => ">=",
+            Self::And => "&&",
+            Self::Or => "||",
+            Self::BitwiseAnd => "&",
+            Self::BitwiseOr => "|",
+            Self::BitwiseXor => "^",
+            Self::LeftShift => "<<",
+            Self::RightShift => ">>",
+        }
+    }
+
+    // 연산자 우선순위. 값이 클수록 먼저 묶이며, 가장 낮은 값은 1입니다.
+    pub fn get_precedence(&self) -> u8 {
+        PRECEDENCE_TABLE
+            .iter()
+            .position(|operators| operators.contains(self))
+            .map(|index| index as u8 + 1)
+            .expect("every binary operator has a precedence")
+    }
+
+    // 비트 연산과 시프트입니다. 실수에는 쓸 수 없습니다.
+    pub fn is_bitwise(&self) -> bool {
+        matches!(
+            self,
+            Self::BitwiseAnd
+                | Self::BitwiseOr
+                | Self::BitwiseXor
+                | Self::LeftShift
+                | Self::RightShift
+        )
+    }
+
+    // 복합 대입 연산자(+=, <<= 등)가 계산하는 이항 연산자입니다. 단순 대입(=)이면 None입니다.
+    pub fn from_compound_assignment(token: OperatorToken) -> Option<Self> {
+        match token {
+            OperatorToken::PlusAssign => Some(Self::Add),
+            OperatorToken::MinusAssign => Some(Self::Subtract),
+            OperatorToken::StarAssign => Some(Self::Multiply),
+            OperatorToken::SlashAssign => Some(Self::Divide),
+            OperatorToken::ModuloAssign => Some(Self::Modulo),
+            OperatorToken::AndAssign => Some(Self::BitwiseAnd),
+            OperatorToken::OrAssign => Some(Self::BitwiseOr),
+            OperatorToken::XorAssign => Some(Self::BitwiseXor),
+            OperatorToken::LeftShiftAssign => Some(Self::LeftShift),
+            OperatorToken::RightShiftAssign => Some(Self::RightShift),
+            _ => None,
         }
     }
 }
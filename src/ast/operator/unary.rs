@@ -23,3 +23,17 @@ impl From<OperatorToken> for UnaryOperator {
         }
     }
 }
+
+impl UnaryOperator {
+    // 소스코드에 적는 연산자 기호입니다.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Not => "!",
+            Self::Plus => "+",
+            Self::Minus => "-",
+            Self::Dereference => "*",
+            Self::Reference => "&",
+            Self::BitwiseNot => "~",
+        }
+    }
+}
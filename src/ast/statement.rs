@@ -1,10 +1,20 @@
 use self::{
-    define_function::FunctionDefinitionStatement, define_variable::VariableDefinitionStatement,
+    assign::AssignStatement, define_function::FunctionDefinitionStatement,
+    define_struct::StructDefinitionStatement, define_variable::VariableDefinitionStatement,
+    for_statement::ForStatement, if_statement::IfStatement, loop_statement::LoopStatement,
+    while_statement::WhileStatement,
 };
 
 use super::expression::Expression;
+use crate::utils::span::Span;
+pub mod assign;
 pub mod define_function;
+pub mod define_struct;
 pub mod define_variable;
+pub mod for_statement;
+pub mod if_statement;
+pub mod loop_statement;
+pub mod while_statement;
 
 #[allow(dead_code)]
 #[derive(Debug, Clone, PartialEq)]
@@ -12,7 +22,17 @@ pub enum Statement {
     Expression(Expression),
     DefineVariable(VariableDefinitionStatement),
     DefineFunction(FunctionDefinitionStatement),
-    Return(Expression),
+    DefineStruct(StructDefinitionStatement),
+    // 반환할 값과 return 키워드의 위치
+    Return(Expression, Span),
+    If(IfStatement),
+    While(WhileStatement),
+    Loop(LoopStatement),
+    For(ForStatement),
+    // break, continue 키워드의 위치
+    Break(Span),
+    Continue(Span),
+    Assign(AssignStatement),
 }
 
 impl From<Expression> for Statement {
@@ -26,3 +46,127 @@ impl From<VariableDefinitionStatement> for Statement {
         Statement::DefineVariable(statement)
     }
 }
+
+impl From<IfStatement> for Statement {
+    fn from(statement: IfStatement) -> Self {
+        Statement::If(statement)
+    }
+}
+
+impl From<WhileStatement> for Statement {
+    fn from(statement: WhileStatement) -> Self {
+        Statement::While(statement)
+    }
+}
+
+impl From<LoopStatement> for Statement {
+    fn from(statement: LoopStatement) -> Self {
+        Statement::Loop(statement)
+    }
+}
+
+impl From<AssignStatement> for Statement {
+    fn from(statement: AssignStatement) -> Self {
+        Statement::Assign(statement)
+    }
+}
+
+impl From<ForStatement> for Statement {
+    fn from(statement: ForStatement) -> Self {
+        Statement::For(statement)
+    }
+}
+
+impl From<FunctionDefinitionStatement> for Statement {
+    fn from(statement: FunctionDefinitionStatement) -> Self {
+        Statement::DefineFunction(statement)
+    }
+}
+
+impl From<StructDefinitionStatement> for Statement {
+    fn from(statement: StructDefinitionStatement) -> Self {
+        Statement::DefineStruct(statement)
+    }
+}
+
+impl Statement {
+    // statement가 시작하는 위치입니다. if, while은 조건식의 위치입니다.
+    pub fn span(&self) -> Span {
+        match self {
+            Statement::Expression(expression) => expression.span(),
+            Statement::DefineVariable(variable) => variable.span,
+            Statement::DefineFunction(function) => function.span,
+            Statement::DefineStruct(definition) => definition.span,
+            Statement::If(statement) => statement.condition.span(),
+            Statement::While(statement) => statement.condition.span(),
+            Statement::Loop(statement) => statement.span,
+            Statement::For(statement) => statement.span,
+            Statement::Return(_, span) | Statement::Break(span) | Statement::Continue(span) => {
+                *span
+            }
+            Statement::Assign(statement) => statement.span,
+        }
+    }
+
+    // 모든 위치를 지웁니다. 위치와 상관없이 두 AST가 같은지 비교할 때 씁니다. (포매터 검사, 테스트)
+    pub fn clear_spans(&mut self) {
+        match self {
+            Statement::Expression(expression) => expression.clear_spans(),
+            Statement::Return(expression, span) => {
+                *span = Span::default();
+                expression.clear_spans();
+            }
+            Statement::DefineVariable(statement) => {
+                statement.span = Span::default();
+                if let Some(value) = &mut statement.value {
+                    value.clear_spans();
+                }
+            }
+            Statement::DefineFunction(statement) => {
+                statement.span = Span::default();
+                for parameter in &mut statement.parameters {
+                    parameter.span = Span::default();
+                }
+                clear_spans(&mut statement.body);
+            }
+            Statement::DefineStruct(statement) => {
+                statement.span = Span::default();
+                for field in &mut statement.fields {
+                    field.span = Span::default();
+                }
+            }
+            Statement::If(statement) => {
+                statement.condition.clear_spans();
+                clear_spans(&mut statement.then_body);
+                if let Some(else_body) = &mut statement.else_body {
+                    clear_spans(else_body);
+                }
+            }
+            Statement::While(statement) => {
+                statement.condition.clear_spans();
+                clear_spans(&mut statement.body);
+            }
+            Statement::Loop(statement) => {
+                statement.span = Span::default();
+                clear_spans(&mut statement.body);
+            }
+            Statement::For(statement) => {
+                statement.span = Span::default();
+                statement.start.clear_spans();
+                statement.end.clear_spans();
+                clear_spans(&mut statement.body);
+            }
+            Statement::Break(span) | Statement::Continue(span) => *span = Span::default(),
+            Statement::Assign(statement) => {
+                statement.span = Span::default();
+                statement.value.clear_spans();
+            }
+        }
+    }
+}
+
+pub fn clear_spans(statements: &mut [Statement]) {
+    for statement in statements {
+        statement.clear_spans();
+    }
+}
@@ -0,0 +1,38 @@
+use crate::{
+    ast::{
+        expression::{binary::BinaryExpression, variable::VariableExpression, Expression},
+        operator::binary::BinaryOperator,
+    },
+    utils::{interner::Symbol, span::Span},
+};
+
+// 이미 선언한 mut 변수에 새 값을 저장합니다.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AssignStatement {
+    pub name: Symbol,
+    pub operator: Option<BinaryOperator>, // 복합 대입(+= 등)의 연산자. =이면 None
+    pub value: Expression,
+    pub span: Span, // 변수 이름의 위치
+}
+
+impl AssignStatement {
+    // 변수에 저장할 값입니다. 복합 대입 x += 1은 x + 1을 계산해서 저장합니다.
+    pub fn stored_value(&self) -> Expression {
+        match &self.operator {
+            Some(operator) => BinaryExpression {
+                lhs: Box::new(
+                    VariableExpression {
+                        name: self.name,
+                        span: self.span,
+                    }
+                    .into(),
+                ),
+                rhs: Box::new(self.value.clone()),
+                operator: operator.clone(),
+                span: self.span,
+            }
+            .into(),
+            None => self.value.clone(),
+        }
+    }
+}
@@ -1,9 +1,35 @@
+use crate::utils::{interner::Symbol, span::Span};
+
 use super::Statement;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct FunctionDefinitionStatement {
-    pub name: String,
-    pub parameters: Vec<String>, // TODO: add type
-    // pub return_type: Type,
+    pub name: Symbol,
+    pub parameters: Vec<Parameter>,
+    pub return_type: Option<Symbol>, // -> 뒤에 적은 타입 이름. 생략하면 None
     pub body: Vec<Statement>,
+    pub span: Span, // 함수 이름의 위치
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Parameter {
+    pub name: Symbol,
+    pub type_name: Option<Symbol>, // : 뒤에 적은 타입 이름. 생략하면 i64
+    pub span: Span,                // 매개변수 이름의 위치
+}
+
+impl From<Symbol> for Parameter {
+    fn from(name: Symbol) -> Self {
+        Self {
+            name,
+            type_name: None,
+            span: Span::default(),
+        }
+    }
+}
+
+impl From<&str> for Parameter {
+    fn from(name: &str) -> Self {
+        Symbol::intern(name).into()
+    }
 }
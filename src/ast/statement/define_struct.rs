@@ -0,0 +1,17 @@
+use crate::utils::{interner::Symbol, span::Span};
+
+// struct 이름 { 필드: 타입, ... }
+// 필드는 선언한 순서대로 메모리에 놓입니다.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StructDefinitionStatement {
+    pub name: Symbol,
+    pub fields: Vec<StructField>,
+    pub span: Span, // 구조체 이름의 위치
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct StructField {
+    pub name: Symbol,
+    pub type_name: Symbol,
+    pub span: Span, // 필드 이름의 위치
+}
@@ -1,9 +1,14 @@
-use crate::ast::expression::Expression;
+use crate::{
+    ast::expression::Expression,
+    utils::{interner::Symbol, span::Span},
+};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct VariableDefinitionStatement {
     pub mutable: bool,
-    pub name: String,
+    pub name: Symbol,
     pub value: Option<Expression>,
-    // pub type: Type,
+    // : 뒤에 적은 타입 이름. 생략하면 의미 분석에서 추론합니다.
+    pub type_name: Option<Symbol>,
+    pub span: Span, // 변수 이름의 위치
 }
@@ -0,0 +1,17 @@
+use crate::{
+    ast::expression::Expression,
+    utils::{interner::Symbol, span::Span},
+};
+
+use super::Statement;
+
+// for 변수 in start..end { ... }
+// 범위는 end를 포함하지 않고, start와 end는 반복을 시작하기 전에 한 번만 계산합니다.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ForStatement {
+    pub variable: Symbol,
+    pub start: Expression,
+    pub end: Expression,
+    pub body: Vec<Statement>,
+    pub span: Span, // 반복 변수 이름의 위치
+}
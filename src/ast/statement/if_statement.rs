@@ -0,0 +1,11 @@
+use crate::ast::expression::Expression;
+
+use super::Statement;
+
+// else if는 else 블록 안의 if 문 하나로 표현합니다.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IfStatement {
+    pub condition: Expression,
+    pub then_body: Vec<Statement>,
+    pub else_body: Option<Vec<Statement>>,
+}
@@ -0,0 +1,11 @@
+use crate::utils::span::Span;
+
+use super::Statement;
+
+// 조건 없이 break를 만날 때까지 반복합니다.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LoopStatement {
+    pub body: Vec<Statement>,
+    // loop 키워드의 위치
+    pub span: Span,
+}
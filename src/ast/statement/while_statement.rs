@@ -0,0 +1,9 @@
+use crate::ast::expression::Expression;
+
+use super::Statement;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct WhileStatement {
+    pub condition: Expression,
+    pub body: Vec<Statement>,
+}
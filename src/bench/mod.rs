@@ -0,0 +1,139 @@
+pub mod synthetic;
+
+pub(crate) mod test;
+
+use std::time::{Duration, Instant};
+
+use crate::{
+    codegen::CodeGenerator, error::all_error::AllError, lexer::tokenizer::Tokenizer, parser::Parser,
+};
+
+// 파이프라인 한 단계의 측정 결과입니다.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StageMeasurement {
+    pub name: &'static str,
+    pub unit: &'static str,
+    pub count: usize,
+    pub elapsed: Duration, // 반복 측정 중 가장 빠른 시간
+}
+
+impl StageMeasurement {
+    pub fn per_second(&self) -> f64 {
+        let seconds = self.elapsed.as_secs_f64();
+
+        if seconds == 0.0 {
+            return 0.0;
+        }
+
+        self.count as f64 / seconds
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BenchReport {
+    pub source_bytes: usize,
+    pub iterations: usize,
+    pub stages: Vec<StageMeasurement>,
+}
+
+impl std::fmt::Display for BenchReport {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        writeln!(
+            formatter,
+            "source: {} bytes, best of {} iterations",
+            self.source_bytes, self.iterations
+        )?;
+
+        for stage in &self.stages {
+            writeln!(
+                formatter,
+                "{:<8} {:>10} {:<10} {:>12.3?} {:>16.0} {}/sec",
+                stage.name,
+                stage.count,
+                stage.unit,
+                stage.elapsed,
+                stage.per_second(),
+                stage.unit
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Benchmark {
+    source: String,
+    iterations: usize,
+}
+
+impl Benchmark {
+    pub fn new(source: String, iterations: usize) -> Self {
+        Self {
+            source,
+            iterations: iterations.max(1),
+        }
+    }
+
+    // 각 단계를 iterations만큼 반복 실행하고, 단계별로 가장 빠른 시간을 기록합니다.
+    pub fn run(&self) -> Result<BenchReport, AllError> {
+        let mut lex = StageMeasurement {
+            name: "lex",
+            unit: "tokens",
+            count: 0,
+            elapsed: Duration::MAX,
+        };
+        let mut parse = StageMeasurement {
+            name: "parse",
+            unit: "statements",
+            count: 0,
+            elapsed: Duration::MAX,
+        };
+        let mut codegen = StageMeasurement {
+            name: "codegen",
+            unit: "bytes",
+            count: 0,
+            elapsed: Duration::MAX,
+        };
+
+        for _ in 0..self.iterations {
+            let (tokens, elapsed) = measure(|| Tokenizer::string_to_tokens(&self.source))?;
+            lex.count = tokens.len();
+            lex.elapsed = lex.elapsed.min(elapsed);
+
+            let (statements, elapsed) = measure(|| {
+                let mut parser = Parser::new();
+                parser.set_tokens(tokens);
+                parser.parse()
+            })?;
+            parse.count = statements.len();
+            parse.elapsed = parse.elapsed.min(elapsed);
+
+            // 런타임 코드는 소스와 상관없이 같으므로 생성된 바이트 수에 포함하지 않습니다.
+            let (codes, elapsed) = measure(|| {
+                let mut generator = CodeGenerator::new();
+                generator.set_statements(statements);
+                generator.generate()
+            })?;
+            codegen.count = codes
+                .iter()
+                .take_while(|line| !line.starts_with("define i32 @main("))
+                .map(|line| line.len() + 1)
+                .sum();
+            codegen.elapsed = codegen.elapsed.min(elapsed);
+        }
+
+        Ok(BenchReport {
+            source_bytes: self.source.len(),
+            iterations: self.iterations,
+            stages: vec![lex, parse, codegen],
+        })
+    }
+}
+
+fn measure<T>(stage: impl FnOnce() -> Result<T, AllError>) -> Result<(T, Duration), AllError> {
+    let started = Instant::now();
+    let output = stage()?;
+
+    Ok((output, started.elapsed()))
+}
@@ -0,0 +1,56 @@
+// 벤치마크용 합성 프로그램 생성기입니다.
+// 같은 statement 수에 대해 항상 같은 소스를 생성하도록 고정된 시드의 LCG를 사용합니다.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SyntheticProgram {
+    seed: u64,
+}
+
+impl SyntheticProgram {
+    pub fn new() -> Self {
+        Self {
+            seed: 0x5eed_f001_a4c0_ffee,
+        }
+    }
+
+    fn next_number(&mut self) -> u64 {
+        self.seed = self
+            .seed
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+
+        (self.seed >> 33) % 10000
+    }
+
+    // statement_count개의 최상위 statement로 이루어진 소스코드를 생성합니다.
+    pub fn generate(&mut self, statement_count: usize) -> String {
+        let mut source = String::with_capacity(statement_count * 32);
+
+        for index in 0..statement_count {
+            let a = self.next_number();
+            let b = self.next_number();
+            let c = self.next_number();
+            let d = self.next_number();
+
+            let statement = match index % 5 {
+                0 => format!("// synthetic statement {}", index),
+                1 => format!("let v{} = {} + {} * {}", index, a, b, c),
+                2 => format!("let v{} = ({} - {}) * {} / {}", index, a, b, c, d + 1),
+                3 => format!("let v{} = {}.{} * {}.5 % {}.0", index, a, b, c, d + 1),
+                _ => format!("let v{} = \"synthetic string {}\"", index, a),
+            };
+
+            if index > 0 {
+                source.push('\n');
+            }
+            source.push_str(&statement);
+        }
+
+        source
+    }
+}
+
+impl Default for SyntheticProgram {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,44 @@
+#![cfg(test)]
+
+use crate::{
+    bench::{synthetic::SyntheticProgram, Benchmark},
+    lexer::tokenizer::Tokenizer,
+    parser::Parser,
+};
+
+#[test]
+pub fn synthetic_program_is_deterministic() {
+    let first = SyntheticProgram::new().generate(100);
+    let second = SyntheticProgram::new().generate(100);
+
+    assert_eq!(first, second);
+}
+
+#[test]
+pub fn synthetic_program_parses() {
+    let text = SyntheticProgram::new().generate(100);
+
+    let tokens = Tokenizer::string_to_tokens(&text).unwrap();
+
+    let mut parser = Parser::new();
+    parser.set_tokens(tokens);
+
+    let statements = parser.parse().unwrap();
+
+    assert_eq!(statements.len(), 100);
+}
+
+#[test]
+pub fn benchmark_report() {
+    let text = SyntheticProgram::new().generate(50);
+
+    let report = Benchmark::new(text, 2).run().unwrap();
+
+    assert_eq!(report.iterations, 2);
+    assert_eq!(report.stages[0].name, "lex");
+    assert!(report.stages[0].count > 50);
+    assert_eq!(report.stages[1].name, "parse");
+    assert_eq!(report.stages[1].count, 50);
+    assert_eq!(report.stages[2].name, "codegen");
+    assert!(report.stages[2].count > 0);
+}
@@ -0,0 +1,63 @@
+use clap::ValueEnum;
+use serde::Deserialize;
+
+// 빌드 결과물의 종류입니다. (--emit)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, ValueEnum)]
+#[serde(rename_all = "kebab-case")]
+pub enum Emit {
+    // lexer가 만든 토큰 목록 (.tokens)
+    Tokens,
+    // parser가 만든 AST (.ast)
+    Ast,
+    // 텍스트 LLVM-IR (.ll)
+    #[clap(alias = "ir")]
+    #[serde(alias = "ir")]
+    LlvmIr,
+    // 어셈블리 (.s)
+    #[clap(name = "asm")]
+    #[serde(rename = "asm")]
+    Assembly,
+    // 링크하지 않은 오브젝트 파일 (.o)
+    #[clap(name = "obj")]
+    #[serde(rename = "obj")]
+    Object,
+    // 실행 파일
+    #[default]
+    #[clap(name = "exe")]
+    #[serde(rename = "exe")]
+    Executable,
+}
+
+impl Emit {
+    // 소스 파일 이름에서 확장자만 바꿔서 결과물 경로를 만듭니다.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Tokens => "tokens",
+            Self::Ast => "ast",
+            Self::LlvmIr => "ll",
+            Self::Assembly => "s",
+            Self::Object => "o",
+            Self::Executable => "",
+        }
+    }
+
+    // codegen 이전 단계의 결과물인지 여부입니다. 이 경우 Builder를 거치지 않습니다.
+    pub fn is_frontend(&self) -> bool {
+        match self {
+            Self::Tokens | Self::Ast => true,
+            _ => false,
+        }
+    }
+}
+
+// 어셈블리의 문법입니다. (--asm-syntax)
+// 어느 쪽이든 GNU as로 어셈블할 수 있습니다.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, ValueEnum)]
+#[serde(rename_all = "kebab-case")]
+pub enum AssemblySyntax {
+    // AT&T 문법 (movq $1, %rdi)
+    #[default]
+    Att,
+    // Intel 문법 (mov rdi, 1). x86-64에서만 사용할 수 있습니다.
+    Intel,
+}
@@ -1,18 +1,95 @@
-use crate::error::all_error::AllError;
+pub mod emit;
+pub mod target;
+pub(crate) mod toolchain;
+
+pub(crate) mod test;
+
+use std::{
+    path::{Path, PathBuf},
+    process::Output,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use crate::{
+    error::all_error::{source_error::SourceError, AllError},
+    utils::logger::{Level, Logger},
+};
+
+use self::{
+    emit::{AssemblySyntax, Emit},
+    target::Target,
+    toolchain::Toolchain,
+};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Builder {
     filenames: Vec<String>,
+    // 진입점 모듈(filenames) 외에 함께 링크할 모듈들의 LLVM-IR
+    modules: Vec<Vec<String>>,
+    no_std: bool,
+    emit: Emit,
+    via_assembly: bool,
+    source_path: PathBuf,
+    // execute_jit에서 기계어로 컴파일하지 않고 IR을 해석해서 실행합니다.
+    interpreter: bool,
+    toolchain: Toolchain,
 }
 
 impl Builder {
     pub fn new() -> Self {
-        Self { filenames: vec![] }
+        Self {
+            filenames: vec![],
+            modules: vec![],
+            no_std: false,
+            emit: Emit::Executable,
+            via_assembly: false,
+            source_path: PathBuf::from("main.foo"),
+            interpreter: false,
+            toolchain: Toolchain::new(),
+        }
     }
 
     pub fn set_filenames(&mut self, filenames: Vec<String>) {
         self.filenames = filenames;
     }
+
+    // 각 모듈은 별도의 오브젝트 파일로 컴파일한 뒤 진입점 모듈과 함께 링크합니다.
+    pub fn set_modules(&mut self, modules: Vec<Vec<String>>) {
+        self.modules = modules;
+    }
+
+    // libc 없이 ld로 직접 링크합니다.
+    pub fn set_no_std(&mut self, no_std: bool) {
+        self.no_std = no_std;
+    }
+
+    pub fn set_emit(&mut self, emit: Emit) {
+        self.emit = emit;
+    }
+
+    // 오브젝트 파일을 llc로 직접 만들지 않고, 어셈블리를 거쳐 as로 만듭니다.
+    pub fn set_via_assembly(&mut self, via_assembly: bool) {
+        self.via_assembly = via_assembly;
+    }
+
+    pub fn set_target(&mut self, target: Target) {
+        self.toolchain.set_target(target);
+    }
+
+    // --emit asm과 --via-asm에서 생성하는 어셈블리의 문법입니다.
+    pub fn set_assembly_syntax(&mut self, assembly_syntax: AssemblySyntax) {
+        self.toolchain.set_assembly_syntax(assembly_syntax);
+    }
+
+    // 결과물은 소스 파일과 같은 위치에 확장자만 바꿔서 생성합니다.
+    pub fn set_source_path(&mut self, source_path: impl AsRef<Path>) {
+        self.source_path = source_path.as_ref().to_path_buf();
+    }
+
+    // llc를 거치지 않는 기준 실행 결과입니다. 백엔드가 잘못 컴파일했는지 비교할 때 사용합니다.
+    pub fn set_interpreter(&mut self, interpreter: bool) {
+        self.interpreter = interpreter;
+    }
 }
 
 impl Default for Builder {
@@ -22,7 +99,204 @@ impl Default for Builder {
 }
 
 impl Builder {
+    // 생성한 결과물의 경로를 반환합니다.
     pub fn build(&mut self) -> Result<String, AllError> {
-        todo!()
+        let output_path = self.output_path();
+
+        if self.emit.is_frontend() {
+            return Err(AllError::BuilderError(SourceError::new(
+                1,
+                format!("{:?} is emitted before code generation", self.emit),
+            )));
+        }
+
+        if self.emit != Emit::LlvmIr && !self.toolchain.is_available() {
+            return Err(AllError::BuilderError(SourceError::new(
+                2,
+                "llc not found. install LLVM or set FOOLANG_LLC".into(),
+            )));
+        }
+
+        if self.emit != Emit::Executable && !self.modules.is_empty() {
+            return Err(AllError::BuilderError(SourceError::new(
+                3,
+                "multiple source files can only be built as an executable".into(),
+            )));
+        }
+
+        let target = self.toolchain.target();
+        if self.no_std && !target.supports_no_std() {
+            return Err(AllError::BuilderError(SourceError::new(
+                4,
+                format!("--no-std is not supported for {}", target.triple()),
+            )));
+        }
+
+        if self.toolchain.assembly_syntax() == AssemblySyntax::Intel && target != Target::LinuxAmd64
+        {
+            return Err(AllError::BuilderError(SourceError::new(
+                5,
+                format!(
+                    "intel assembly syntax is not supported for {}",
+                    target.triple()
+                ),
+            )));
+        }
+
+        match self.emit {
+            Emit::Tokens | Emit::Ast => unreachable!(),
+            Emit::LlvmIr => {
+                Self::write_llvm_ir(&self.filenames, &output_path)?;
+            }
+            Emit::Assembly => {
+                let directory = IntermediateDirectory::new()?;
+                let ir_path = directory.path().join("main.ll");
+                Self::write_llvm_ir(&self.filenames, &ir_path)?;
+                self.toolchain.compile_assembly(&ir_path, &output_path)?;
+            }
+            Emit::Object => {
+                let directory = IntermediateDirectory::new()?;
+                self.compile_object(&self.filenames, directory.path(), &output_path)?;
+            }
+            Emit::Executable => {
+                let directory = IntermediateDirectory::new()?;
+
+                let mut object_paths = vec![];
+                for (index, codes) in std::iter::once(&self.filenames)
+                    .chain(self.modules.iter())
+                    .enumerate()
+                {
+                    let module_directory = directory.path().join(index.to_string());
+                    std::fs::create_dir_all(&module_directory).map_err(|error| {
+                        AllError::IOError(format!("{}: {}", module_directory.display(), error))
+                    })?;
+
+                    let object_path = module_directory.join("main.o");
+                    self.compile_object(codes, &module_directory, &object_path)?;
+                    object_paths.push(object_path);
+                }
+
+                self.toolchain
+                    .link_executable(&object_paths, &output_path, self.no_std)?;
+            }
+        }
+
+        if Logger::enabled(Level::Info) {
+            let size = std::fs::metadata(&output_path)
+                .map(|metadata| metadata.len())
+                .unwrap_or_default();
+            Logger::info(
+                "build",
+                format!("wrote {} ({} bytes)", output_path.display(), size),
+            );
+        }
+
+        Ok(output_path.to_string_lossy().into_owned())
+    }
+
+    // 실행 파일을 만들지 않고 lli로 바로 실행합니다. (repl, 테스트)
+    // 프로그램이 실패해도 오류가 아니므로, 종료 코드는 반환한 Output에서 확인합니다.
+    pub fn execute_jit(&self) -> Result<Output, AllError> {
+        if !self.toolchain.is_jit_available() {
+            return Err(AllError::BuilderError(SourceError::new(
+                6,
+                "lli not found. install LLVM or set FOOLANG_LLI".into(),
+            )));
+        }
+
+        // no_std의 진입점은 main이 아니라 _start이므로 lli로 호출할 수 없습니다.
+        if self.no_std {
+            return Err(AllError::BuilderError(SourceError::new(
+                7,
+                "--no-std programs can not be executed with lli".into(),
+            )));
+        }
+
+        if self.toolchain.target() != Target::default() {
+            return Err(AllError::BuilderError(SourceError::new(
+                8,
+                format!(
+                    "{} can not be executed on this host",
+                    self.toolchain.target().triple()
+                ),
+            )));
+        }
+
+        let directory = IntermediateDirectory::new()?;
+
+        let ir_path = directory.path().join("main.ll");
+        Self::write_llvm_ir(&self.filenames, &ir_path)?;
+
+        let mut module_paths = vec![];
+        for (index, codes) in self.modules.iter().enumerate() {
+            let module_path = directory.path().join(format!("{}.ll", index));
+            Self::write_llvm_ir(codes, &module_path)?;
+            module_paths.push(module_path);
+        }
+
+        self.toolchain
+            .execute_jit(&ir_path, &module_paths, self.interpreter)
+    }
+
+    pub fn output_path(&self) -> PathBuf {
+        self.source_path.with_extension(self.emit.extension())
+    }
+
+    // 중간 파일은 directory에 만들고 object_path에 오브젝트 파일을 생성합니다.
+    fn compile_object(
+        &self,
+        codes: &[String],
+        directory: &Path,
+        object_path: &Path,
+    ) -> Result<(), AllError> {
+        let ir_path = directory.join("main.ll");
+        Self::write_llvm_ir(codes, &ir_path)?;
+
+        if self.via_assembly {
+            let assembly_path = directory.join("main.s");
+            self.toolchain.compile_assembly(&ir_path, &assembly_path)?;
+            self.toolchain.assemble(&assembly_path, object_path)
+        } else {
+            self.toolchain.compile_object(&ir_path, object_path)
+        }
+    }
+
+    fn write_llvm_ir(codes: &[String], path: &Path) -> Result<(), AllError> {
+        let mut text = codes.join("\n");
+        text.push('\n');
+
+        std::fs::write(path, text)
+            .map_err(|error| AllError::IOError(format!("{}: {}", path.display(), error)))
+    }
+}
+
+// 빌드 중에 생기는 중간 파일을 담는 임시 디렉터리입니다. drop될 때 삭제됩니다.
+pub(crate) struct IntermediateDirectory {
+    path: PathBuf,
+}
+
+impl IntermediateDirectory {
+    pub(crate) fn new() -> Result<Self, AllError> {
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+        let path = std::env::temp_dir().join(format!(
+            "foolang-build-{}-{}",
+            std::process::id(),
+            COUNTER.fetch_add(1, Ordering::SeqCst)
+        ));
+        std::fs::create_dir_all(&path)
+            .map_err(|error| AllError::IOError(format!("{}: {}", path.display(), error)))?;
+
+        Ok(Self { path })
+    }
+
+    pub(crate) fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Drop for IntermediateDirectory {
+    fn drop(&mut self) {
+        std::fs::remove_dir_all(&self.path).ok();
     }
 }
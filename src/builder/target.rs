@@ -0,0 +1,53 @@
+use clap::ValueEnum;
+use serde::Deserialize;
+
+// 오브젝트 파일을 생성할 대상 플랫폼입니다. (--target)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, ValueEnum)]
+#[serde(rename_all = "kebab-case")]
+pub enum Target {
+    #[default]
+    LinuxAmd64,
+    LinuxAarch64,
+}
+
+impl Target {
+    // 컴파일러가 실행 중인 플랫폼입니다. 지원하지 않는 플랫폼이면 None입니다.
+    // 이 target으로 만든 실행 파일만 그 자리에서 실행해 볼 수 있습니다.
+    pub fn host() -> Option<Self> {
+        if !cfg!(target_os = "linux") {
+            return None;
+        }
+
+        if cfg!(target_arch = "x86_64") {
+            Some(Self::LinuxAmd64)
+        } else if cfg!(target_arch = "aarch64") {
+            Some(Self::LinuxAarch64)
+        } else {
+            None
+        }
+    }
+
+    // llc의 -mtriple 값
+    pub fn triple(&self) -> &'static str {
+        match self {
+            Self::LinuxAmd64 => "x86_64-unknown-linux-gnu",
+            Self::LinuxAarch64 => "aarch64-unknown-linux-gnu",
+        }
+    }
+
+    // ELF 헤더의 e_machine 값
+    pub fn elf_machine(&self) -> u16 {
+        match self {
+            Self::LinuxAmd64 => 62,
+            Self::LinuxAarch64 => 183,
+        }
+    }
+
+    // no_std 런타임의 _start와 시스템 콜은 x86_64 어셈블리로 작성되어 있습니다.
+    pub fn supports_no_std(&self) -> bool {
+        match self {
+            Self::LinuxAmd64 => true,
+            Self::LinuxAarch64 => false,
+        }
+    }
+}
@@ -0,0 +1,255 @@
+#![cfg(test)]
+
+use std::path::Path;
+
+use crate::{
+    builder::{target::Target, toolchain::Toolchain},
+    utils::testing::{objdump, with_object},
+};
+
+const SOURCE: &str = concat!(
+    "fn add(a: i64, b: i64) -> i64 {\n",
+    "    return a + b\n",
+    "}\n",
+    "fn sub(a: i64, b: i64) -> i64 {\n",
+    "    return a - b\n",
+    "}\n",
+    "fn mul(a: i64, b: i64) -> i64 {\n",
+    "    return a * b\n",
+    "}\n",
+    "fn div(a: i64, b: i64) -> i64 {\n",
+    "    return a / b\n",
+    "}\n",
+    "fn rem(a: i64, b: i64) -> i64 {\n",
+    "    return a % b\n",
+    "}\n",
+    "fn less(a: i64, b: i64) -> bool {\n",
+    "    return a < b\n",
+    "}\n",
+    "fn bits(a: i64, b: i64) -> i64 {\n",
+    "    return (a & b) | (a ^ b)\n",
+    "}\n",
+    "fn shift(a: i64, b: i64) -> i64 {\n",
+    "    return a << b\n",
+    "}\n",
+    "fn narrow(a: i32, b: i32) -> i32 {\n",
+    "    return a * b\n",
+    "}\n",
+    "fn widen(a: i32) -> i64 {\n",
+    "    return a as i64\n",
+    "}\n",
+    "fn fadd(a: f64, b: f64) -> f64 {\n",
+    "    return a + b\n",
+    "}\n",
+    "fn fdiv(a: f64, b: f64) -> f64 {\n",
+    "    return a / b\n",
+    "}\n",
+    "fn fmul32(a: f32, b: f32) -> f32 {\n",
+    "    return a * b\n",
+    "}\n",
+    "fn fless(a: f64, b: f64) -> bool {\n",
+    "    return a < b\n",
+    "}\n",
+    "fn to_float(a: i64) -> f64 {\n",
+    "    return a as f64\n",
+    "}\n",
+    "fn to_integer(a: f64) -> i64 {\n",
+    "    return a as i64\n",
+    "}\n",
+    "fn twice(a: i64) -> i64 {\n",
+    "    return add(a, a)\n",
+    "}\n",
+    "println(twice(1))\n",
+);
+
+// IR 명령어 종류마다 함수 안에 순서대로 나와야 하는 opcode입니다.
+// 피연산자와 레지스터 배정은 llc 버전마다 달라지므로 opcode만 비교하고, |로 나눈 것 중 하나면 됩니다.
+const EXPECTED: &[(&str, &[&str])] = &[
+    ("add", &["add|lea", "ret"]),
+    ("sub", &["sub", "ret"]),
+    ("mul", &["imul", "ret"]),
+    // 0과 -1로 나누는지 먼저 검사하고, 부호를 확장해서 나눕니다.
+    ("div", &["test|cmp", "cmp", "cqo", "idiv"]),
+    ("rem", &["test|cmp", "cmp", "cqo", "idiv"]),
+    ("less", &["cmp", "setl|setg"]),
+    ("bits", &["and", "xor", "or"]),
+    ("shift", &["shl|sal|shlx", "ret"]),
+    ("narrow", &["imul", "ret"]),
+    ("widen", &["movsxd|movsx", "ret"]),
+    ("fadd", &["addsd", "ret"]),
+    ("fdiv", &["divsd", "ret"]),
+    ("fmul32", &["mulss", "ret"]),
+    ("fless", &["ucomisd|comisd", "seta|setb"]),
+    ("to_float", &["cvtsi2sd", "ret"]),
+    ("to_integer", &["cvttsd2si", "ret"]),
+    ("twice", &["call|jmp"]),
+];
+
+// 소스코드를 오브젝트 파일로 컴파일하고 objdump로 읽습니다.
+// objdump가 없으면 None을 반환합니다.
+fn object(no_std: bool) -> Option<Object> {
+    with_object(SOURCE, no_std, Object::read).flatten()
+}
+
+struct Object {
+    // objdump -f의 헤더 (파일 형식과 아키텍처)
+    header: String,
+    // 함수 이름별 명령어 목록. 재배치도 명령어처럼 한 줄로 들어갑니다.
+    functions: Vec<(String, Vec<String>)>,
+}
+
+impl Object {
+    fn read(path: &Path) -> Option<Self> {
+        let header = objdump(path, &["-f"])?;
+        let disassembly = objdump(path, &["-d", "-r", "--no-show-raw-insn", "-M", "intel"])?;
+        let mut functions: Vec<(String, Vec<String>)> = vec![];
+
+        for line in disassembly.lines() {
+            // 0000000000000020 <foolang.add>:
+            if let Some(name) = line
+                .strip_suffix(">:")
+                .and_then(|line| line.split_once(" <"))
+            {
+                functions.push((name.1.to_owned(), vec![]));
+                continue;
+            }
+
+            // "  2a:\tlea    rax,[rdi+rsi*1]" 또는 재배치 "\t\t\t2b: R_X86_64_PLT32\texit-0x4"
+            let instruction = line.trim_start().split_once(':').filter(|(address, _)| {
+                !address.is_empty() && address.chars().all(|c| c.is_ascii_hexdigit())
+            });
+
+            if let (Some((_, instruction)), Some((_, instructions))) =
+                (instruction, functions.last_mut())
+            {
+                instructions.push(instruction.split_whitespace().collect::<Vec<_>>().join(" "));
+            }
+        }
+
+        Some(Self { header, functions })
+    }
+
+    fn function(&self, name: &str) -> &[String] {
+        &self
+            .functions
+            .iter()
+            .find(|(function, _)| function == name)
+            .unwrap_or_else(|| panic!("{} not found in {:?}", name, self.symbols()))
+            .1
+    }
+
+    fn symbols(&self) -> Vec<&str> {
+        self.functions
+            .iter()
+            .map(|(function, _)| function.as_str())
+            .collect()
+    }
+
+    // 어떤 함수의 명령어나 재배치 중에 pattern을 포함하는 것이 있는지 확인합니다.
+    fn contains(&self, pattern: &str) -> bool {
+        self.functions
+            .iter()
+            .flat_map(|(_, instructions)| instructions)
+            .any(|instruction| instruction.contains(pattern))
+    }
+}
+
+// "imul rax,rsi"의 imul
+fn opcode(instruction: &str) -> &str {
+    instruction.split(' ').next().unwrap_or_default()
+}
+
+// 오브젝트 파일이 x86-64 ELF인지, 진입점 심볼과 종료 경로가 있는지 확인합니다.
+#[test]
+pub fn object_structure() {
+    if !Toolchain::new().is_available() || Target::host() != Some(Target::LinuxAmd64) {
+        return;
+    }
+
+    for no_std in [false, true] {
+        let object = if let Some(object) = object(no_std) {
+            object
+        } else {
+            return;
+        };
+
+        assert!(object.header.contains("elf64-x86-64"), "{}", object.header);
+        assert!(object.header.contains("i386:x86-64"), "{}", object.header);
+
+        // 사용자 함수는 foolang. 접두사를 붙여서 정의하고, 서로를 심볼로 참조합니다.
+        object.function("foolang.add");
+        // 0으로 나누면 런타임의 panic을 호출합니다.
+        assert!(object
+            .function("foolang.div")
+            .iter()
+            .any(|instruction| instruction.contains("__foolang_panic")));
+        assert!(
+            object
+                .function("foolang.twice")
+                .iter()
+                .any(|instruction| instruction.contains("foolang.add")),
+            "{:?}",
+            object.function("foolang.twice")
+        );
+
+        // main은 언제나 있고, 진입점 함수를 호출합니다.
+        assert!(
+            object
+                .function("main")
+                .iter()
+                .any(|instruction| instruction.contains("__foolang_main")),
+            "{:?}",
+            object.function("main")
+        );
+
+        if no_std {
+            // _start가 main을 거쳐 exit 시스템 콜로 끝납니다.
+            object.function("_start");
+            assert!(object.contains("syscall"), "{:?}", object.symbols());
+        } else {
+            // crt1이 _start를 제공하고, 종료는 libc의 exit를 호출합니다.
+            assert!(!object.symbols().contains(&"_start"));
+            let exit = object.function("__foolang_exit");
+            assert!(
+                exit.iter()
+                    .any(|instruction| instruction.starts_with("call"))
+                    && exit.iter().any(|instruction| instruction.contains(" exit")),
+                "{:?}",
+                exit
+            );
+        }
+    }
+}
+
+// 백엔드가 IR 명령어 종류마다 의도한 명령어를 골랐는지 디스어셈블러로 확인합니다.
+// 잘못된 ModRM처럼 실행은 되지만 다른 명령어로 인코딩되는 오류를 잡기 위한 것입니다.
+#[test]
+pub fn instruction_selection() {
+    if !Toolchain::new().is_available() || Target::host() != Some(Target::LinuxAmd64) {
+        return;
+    }
+
+    let object = if let Some(object) = object(false) {
+        object
+    } else {
+        return;
+    };
+
+    for (name, expected) in EXPECTED {
+        let symbol = format!("foolang.{}", name);
+        let instructions = object.function(&symbol);
+
+        let mut rest = instructions.iter();
+        for pattern in expected.iter() {
+            assert!(
+                rest.any(|instruction| pattern
+                    .split('|')
+                    .any(|alternative| opcode(instruction) == alternative)),
+                "{}: {:?} not found in order\n{}",
+                symbol,
+                pattern,
+                instructions.join("\n")
+            );
+        }
+    }
+}
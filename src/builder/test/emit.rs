@@ -0,0 +1,200 @@
+#![cfg(test)]
+
+use std::{path::PathBuf, process::Command};
+
+use clap::ValueEnum;
+
+use crate::{
+    builder::{
+        emit::{AssemblySyntax, Emit},
+        target::Target,
+        toolchain::Toolchain,
+        Builder,
+    },
+    codegen::CodeGenerator,
+    error::all_error::AllError,
+    utils::testing::temporary_directory,
+};
+
+#[test]
+pub fn emit_from_argument() {
+    assert_eq!(Emit::from_str("llvm-ir", false), Ok(Emit::LlvmIr));
+    assert_eq!(Emit::from_str("exe", false), Ok(Emit::Executable));
+    assert_eq!(Emit::from_str("asm", false), Ok(Emit::Assembly));
+    assert_eq!(Emit::from_str("obj", false), Ok(Emit::Object));
+    assert_eq!(Emit::from_str("tokens", false), Ok(Emit::Tokens));
+    assert_eq!(Emit::from_str("ast", false), Ok(Emit::Ast));
+    assert_eq!(Emit::from_str("ir", false), Ok(Emit::LlvmIr));
+    assert!(Emit::from_str("bin", false).is_err());
+    assert_eq!(Emit::default(), Emit::Executable);
+}
+
+#[test]
+pub fn output_path() {
+    let mut builder = Builder::new();
+    builder.set_source_path("examples/hello.foo");
+
+    builder.set_emit(Emit::LlvmIr);
+    assert_eq!(builder.output_path().to_str(), Some("examples/hello.ll"));
+
+    builder.set_emit(Emit::Executable);
+    assert_eq!(builder.output_path().to_str(), Some("examples/hello"));
+}
+
+#[test]
+pub fn write_llvm_ir() {
+    let directory = temporary_directory();
+    let codes = CodeGenerator::new().generate().unwrap();
+
+    let mut builder = Builder::new();
+    builder.set_filenames(codes.clone());
+    builder.set_emit(Emit::LlvmIr);
+    builder.set_source_path(directory.join("main.foo"));
+
+    let output = builder.build().unwrap();
+    let written = std::fs::read_to_string(&output).unwrap();
+
+    assert_eq!(output, directory.join("main.ll").to_string_lossy());
+    assert_eq!(written.lines().collect::<Vec<_>>(), codes);
+
+    std::fs::remove_dir_all(&directory).ok();
+}
+
+#[test]
+pub fn write_assembly() {
+    if !Toolchain::new().is_available() {
+        return;
+    }
+
+    let directory = temporary_directory();
+
+    let mut builder = Builder::new();
+    builder.set_filenames(CodeGenerator::new().generate().unwrap());
+    builder.set_emit(Emit::Assembly);
+    builder.set_source_path(directory.join("main.foo"));
+
+    let output = builder.build().unwrap();
+    let written = std::fs::read_to_string(&output).unwrap();
+
+    assert!(output.ends_with("main.s"));
+    assert!(written.contains("__foolang_main:"));
+
+    std::fs::remove_dir_all(&directory).ok();
+}
+
+// llc가 직접 만든 오브젝트와 as를 거친 오브젝트 모두 링크해서 실행할 수 있어야 합니다.
+#[test]
+pub fn object_via_assembly() {
+    let toolchain = Toolchain::new();
+
+    if !toolchain.is_available() {
+        return;
+    }
+
+    for via_assembly in [false, true] {
+        let directory = temporary_directory();
+
+        let mut builder = Builder::new();
+        builder.set_filenames(CodeGenerator::new().generate().unwrap());
+        builder.set_emit(Emit::Object);
+        builder.set_via_assembly(via_assembly);
+        builder.set_source_path(directory.join("main.foo"));
+
+        let object_path = PathBuf::from(builder.build().unwrap());
+        let executable_path = directory.join("main");
+        toolchain
+            .link_executable(&[object_path], &executable_path, false)
+            .unwrap();
+
+        let status = Command::new(&executable_path).status().unwrap();
+        assert_eq!(status.code(), Some(0));
+
+        std::fs::remove_dir_all(&directory).ok();
+    }
+}
+
+// Intel 문법의 어셈블리도 as로 어셈블해서 실행할 수 있어야 합니다.
+#[test]
+pub fn intel_assembly() {
+    let toolchain = Toolchain::new();
+
+    if !toolchain.is_available() {
+        return;
+    }
+
+    let directory = temporary_directory();
+
+    let mut builder = Builder::new();
+    builder.set_filenames(CodeGenerator::new().generate().unwrap());
+    builder.set_emit(Emit::Assembly);
+    builder.set_assembly_syntax(AssemblySyntax::Intel);
+    builder.set_source_path(directory.join("main.foo"));
+
+    let written = std::fs::read_to_string(builder.build().unwrap()).unwrap();
+    assert!(written.contains(".intel_syntax noprefix"));
+    assert!(!written.contains("%rsp"));
+
+    builder.set_emit(Emit::Executable);
+    builder.set_via_assembly(true);
+
+    let status = Command::new(builder.build().unwrap()).status().unwrap();
+    assert_eq!(status.code(), Some(0));
+
+    builder.set_target(Target::LinuxAarch64);
+    builder.set_emit(Emit::Assembly);
+    assert!(matches!(builder.build(), Err(AllError::BuilderError(_))));
+
+    std::fs::remove_dir_all(&directory).ok();
+}
+
+#[test]
+pub fn target_from_argument() {
+    assert_eq!(
+        Target::from_str("linux-amd64", false),
+        Ok(Target::LinuxAmd64)
+    );
+    assert_eq!(
+        Target::from_str("linux-aarch64", false),
+        Ok(Target::LinuxAarch64)
+    );
+    assert_eq!(Target::default(), Target::LinuxAmd64);
+}
+
+#[test]
+pub fn host_target() {
+    if cfg!(all(target_os = "linux", target_arch = "x86_64")) {
+        assert_eq!(Target::host(), Some(Target::LinuxAmd64));
+    }
+
+    assert_eq!(Target::LinuxAmd64.elf_machine(), 62);
+    assert_eq!(Target::LinuxAarch64.elf_machine(), 183);
+}
+
+#[test]
+pub fn no_std_requires_amd64() {
+    if !Toolchain::new().is_available() {
+        return;
+    }
+
+    let directory = temporary_directory();
+
+    let mut builder = Builder::new();
+    builder.set_filenames(CodeGenerator::new().generate().unwrap());
+    builder.set_no_std(true);
+    builder.set_target(Target::LinuxAarch64);
+    builder.set_source_path(directory.join("main.foo"));
+
+    assert!(builder.build().is_err());
+
+    std::fs::remove_dir_all(&directory).ok();
+}
+
+#[test]
+pub fn frontend_emit_is_rejected() {
+    let mut builder = Builder::new();
+    builder.set_emit(Emit::Tokens);
+
+    assert!(Emit::Tokens.is_frontend());
+    assert!(!Emit::LlvmIr.is_frontend());
+    assert!(builder.build().is_err());
+}
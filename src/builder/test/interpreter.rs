@@ -0,0 +1,96 @@
+#![cfg(test)]
+
+use crate::{
+    builder::{toolchain::Toolchain, Builder},
+    compiler::{lower_to_ir, parse},
+    utils::testing::run_llvm_ir,
+};
+
+// llc로 컴파일한 실행 파일과 LLVM 인터프리터가 같은 결과를 내는 프로그램들입니다.
+const PROGRAMS: &[&str] = &[
+    concat!(
+        "let a = 7 * 6\n",
+        "println(a / 4 - a % 5)\n",
+        "println(-a + (3 << 2))\n",
+        "println(1.5 * 3.0)\n",
+        "println(\"hello, \" + \"world\")\n",
+    ),
+    concat!(
+        "fn fib(n) -> i64 {\n",
+        "    if n < 2 {\n",
+        "        return n\n",
+        "    }\n",
+        "    return fib(n - 1) + fib(n - 2)\n",
+        "}\n",
+        "mut total = 0\n",
+        "for i in 0..10 {\n",
+        "    total += fib(i)\n",
+        "}\n",
+        "println(total)\n",
+        "mut i = 0\n",
+        "loop {\n",
+        "    i += 1\n",
+        "    if i == 3 { continue }\n",
+        "    if i > 5 { break }\n",
+        "    println(i)\n",
+        "}\n",
+    ),
+    concat!(
+        "let max: i32 = 2147483647\n",
+        "println(max + 1)\n",
+        "println(4294967297 as i32)\n",
+        "println(-2.75 as i64)\n",
+        "println(2.5f32 * 3.0f32)\n",
+        "println(match 7 { 1 => \"one\", 7 => \"seven\", _ => \"many\" })\n",
+    ),
+    concat!(
+        "struct Pair { a: i64, b: i64 }\n",
+        "struct Box { min: Pair, size: i64, name: String }\n",
+        "fn swap(p: Pair) -> Pair {\n",
+        "    return Pair { a: p.b, b: p.a }\n",
+        "}\n",
+        "fn grow(b: Box, by) -> Box {\n",
+        "    return Box { size: b.size + by, min: swap(b.min), name: b.name + \"!\" }\n",
+        "}\n",
+        "let b = grow(Box { min: Pair { a: 1, b: 2 }, size: 3, name: \"box\" }, 2)\n",
+        "println(b.min.a * 10 + b.min.b)\n",
+        "println(b.name)\n",
+    ),
+    "panic(\"boom\")\n",
+];
+
+fn output_text(output: &std::process::Output) -> (String, Option<i32>) {
+    (
+        String::from_utf8_lossy(&output.stdout).into_owned(),
+        output.status.code(),
+    )
+}
+
+#[test]
+pub fn interpreter_matches_backend() {
+    if !Toolchain::new().is_jit_available() {
+        return;
+    }
+
+    for source in PROGRAMS {
+        let codes = lower_to_ir(parse(source).unwrap(), false).unwrap();
+
+        let mut builder = Builder::new();
+        builder.set_filenames(codes.clone());
+        builder.set_interpreter(true);
+        let interpreted = builder.execute_jit().unwrap();
+
+        let compiled = if let Some(output) = run_llvm_ir(&codes, false, &[]) {
+            output
+        } else {
+            return;
+        };
+
+        assert_eq!(
+            output_text(&interpreted),
+            output_text(&compiled),
+            "{}",
+            source
+        );
+    }
+}
@@ -0,0 +1,91 @@
+#![cfg(test)]
+
+use crate::{
+    builder::{target::Target, toolchain::Toolchain, Builder},
+    compiler::{lower_modules, lower_to_ir, parse},
+    error::all_error::AllError,
+};
+
+#[test]
+pub fn execute_without_linking() {
+    if !Toolchain::new().is_jit_available() {
+        return;
+    }
+
+    let source = "let a = 7 * 6\nprintln(a)\nprintln(\"hello, \" + \"world\")\n";
+
+    let mut builder = Builder::new();
+    builder.set_filenames(lower_to_ir(parse(source).unwrap(), false).unwrap());
+
+    let output = builder.execute_jit().unwrap();
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        "42\nhello, world\n"
+    );
+}
+
+#[test]
+pub fn execute_failure_is_not_error() {
+    if !Toolchain::new().is_jit_available() {
+        return;
+    }
+
+    let mut builder = Builder::new();
+    builder.set_filenames(lower_to_ir(parse("panic(\"boom\")").unwrap(), false).unwrap());
+
+    let output = builder.execute_jit().unwrap();
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("boom"));
+}
+
+#[test]
+pub fn execute_modules() {
+    if !Toolchain::new().is_jit_available() {
+        return;
+    }
+
+    let mut modules = lower_modules(
+        vec![
+            ("main.foo".into(), parse("println(add(40, 2))").unwrap()),
+            (
+                "math.foo".into(),
+                parse("fn add(a: i64, b: i64) -> i64 {\n    return a + b\n}").unwrap(),
+            ),
+        ],
+        false,
+    )
+    .unwrap();
+
+    let mut builder = Builder::new();
+    builder.set_filenames(modules.remove(0));
+    builder.set_modules(modules);
+
+    let output = builder.execute_jit().unwrap();
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "42\n");
+}
+
+#[test]
+pub fn unsupported_jit() {
+    if !Toolchain::new().is_jit_available() {
+        return;
+    }
+
+    let codes = lower_to_ir(parse("println(1)").unwrap(), true).unwrap();
+
+    let mut builder = Builder::new();
+    builder.set_filenames(codes.clone());
+    builder.set_no_std(true);
+    assert!(matches!(
+        builder.execute_jit(),
+        Err(AllError::BuilderError(_))
+    ));
+
+    let mut builder = Builder::new();
+    builder.set_filenames(codes);
+    builder.set_target(Target::LinuxAarch64);
+    assert!(matches!(
+        builder.execute_jit(),
+        Err(AllError::BuilderError(_))
+    ));
+}
@@ -0,0 +1,5 @@
+pub(crate) mod disassemble;
+pub(crate) mod emit;
+pub(crate) mod interpreter;
+pub(crate) mod jit;
+pub(crate) mod pipeline;
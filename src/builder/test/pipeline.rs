@@ -0,0 +1,70 @@
+#![cfg(test)]
+
+use std::process::Command;
+
+use crate::{
+    builder::{emit::Emit, toolchain::Toolchain, Builder},
+    compiler::{lower_to_ir, parse},
+    error::all_error::AllError,
+    utils::testing::temporary_directory,
+};
+
+const SOURCE: &str = r#"let a = 7 * 6;
+let b = a - 2;
+println(a);
+println(b / 4);
+println("hello, " + "world");
+println(-a);
+"#;
+
+// 소스코드에서 실행 파일까지 만들고 실행합니다.
+#[test]
+pub fn build_executable() {
+    if !Toolchain::new().is_available() {
+        return;
+    }
+
+    for no_std in [false, true] {
+        let directory = temporary_directory();
+        let source_path = directory.join("main.foo");
+
+        let mut builder = Builder::new();
+        builder.set_filenames(lower_to_ir(parse(SOURCE).unwrap(), no_std).unwrap());
+        builder.set_no_std(no_std);
+        builder.set_source_path(&source_path);
+
+        let executable = builder.build().unwrap();
+        assert_eq!(executable, directory.join("main").to_string_lossy());
+
+        let output = Command::new(&executable).output().unwrap();
+        assert_eq!(
+            String::from_utf8_lossy(&output.stdout),
+            "42\n10\nhello, world\n-42\n"
+        );
+
+        std::fs::remove_dir_all(&directory).ok();
+    }
+}
+
+#[test]
+pub fn stage_errors() {
+    assert!(matches!(parse("let = 1"), Err(AllError::ParserError(_))));
+    assert!(matches!(
+        lower_to_ir(parse("println(undefined)").unwrap(), false),
+        Err(AllError::SemanticError(_))
+    ));
+
+    // 잘못된 IR은 llc 단계에서 BuilderError가 됩니다.
+    if Toolchain::new().is_available() {
+        let directory = temporary_directory();
+
+        let mut builder = Builder::new();
+        builder.set_filenames(vec!["not llvm ir".into()]);
+        builder.set_emit(Emit::Object);
+        builder.set_source_path(directory.join("main.foo"));
+
+        assert!(matches!(builder.build(), Err(AllError::BuilderError(_))));
+
+        std::fs::remove_dir_all(&directory).ok();
+    }
+}
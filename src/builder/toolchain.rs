@@ -0,0 +1,201 @@
+use std::{
+    path::{Path, PathBuf},
+    process::{Command, Output},
+};
+
+use crate::{
+    error::all_error::{source_error::SourceError, AllError},
+    utils::{logger::Logger, timings::Timings},
+};
+
+use super::{emit::AssemblySyntax, target::Target};
+
+// LLVM-IR을 기계어로 바꾸고 링크하기 위해 호출하는 외부 도구 목록입니다.
+// 환경변수 FOOLANG_LLC, FOOLANG_AS, FOOLANG_CC, FOOLANG_LD, FOOLANG_LLI로 경로를 바꿀 수 있습니다.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Toolchain {
+    llc: String,
+    assembler: String,
+    cc: String,
+    ld: String,
+    // 오브젝트 파일 없이 LLVM-IR을 바로 실행하는 JIT
+    lli: String,
+    target: Target,
+    assembly_syntax: AssemblySyntax,
+}
+
+impl Toolchain {
+    pub fn new() -> Self {
+        Self {
+            llc: std::env::var("FOOLANG_LLC").unwrap_or_else(|_| "llc".into()),
+            assembler: std::env::var("FOOLANG_AS").unwrap_or_else(|_| "as".into()),
+            cc: std::env::var("FOOLANG_CC").unwrap_or_else(|_| "cc".into()),
+            ld: std::env::var("FOOLANG_LD").unwrap_or_else(|_| "ld".into()),
+            lli: std::env::var("FOOLANG_LLI").unwrap_or_else(|_| "lli".into()),
+            target: Target::default(),
+            assembly_syntax: AssemblySyntax::default(),
+        }
+    }
+
+    pub fn set_target(&mut self, target: Target) {
+        self.target = target;
+    }
+
+    pub fn target(&self) -> Target {
+        self.target
+    }
+
+    pub fn set_assembly_syntax(&mut self, assembly_syntax: AssemblySyntax) {
+        self.assembly_syntax = assembly_syntax;
+    }
+
+    pub fn assembly_syntax(&self) -> AssemblySyntax {
+        self.assembly_syntax
+    }
+}
+
+impl Default for Toolchain {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Toolchain {
+    // llc를 실행할 수 있는 환경인지 확인합니다.
+    pub fn is_available(&self) -> bool {
+        Command::new(&self.llc)
+            .arg("--version")
+            .output()
+            .map(|output| output.status.success())
+            .unwrap_or(false)
+    }
+
+    // lli를 실행할 수 있는 환경인지 확인합니다.
+    pub fn is_jit_available(&self) -> bool {
+        Command::new(&self.lli)
+            .arg("--version")
+            .output()
+            .map(|output| output.status.success())
+            .unwrap_or(false)
+    }
+
+    // LLVM-IR 파일을 오브젝트 파일로 컴파일합니다.
+    pub fn compile_object(&self, ir_path: &Path, object_path: &Path) -> Result<(), AllError> {
+        let mut command = Command::new(&self.llc);
+        command
+            .arg("-filetype=obj")
+            .arg(format!("-mtriple={}", self.target.triple()))
+            .arg("-relocation-model=pic")
+            .arg("-o")
+            .arg(object_path)
+            .arg(ir_path);
+
+        run("llc", command)
+    }
+
+    // 결과물을 만들지 않고 LLVM-IR 파일이 올바른지만 검사합니다.
+    pub fn verify(&self, ir_path: &Path) -> Result<(), AllError> {
+        let mut command = Command::new(&self.llc);
+        command
+            .arg("-filetype=null")
+            .arg(format!("-mtriple={}", self.target.triple()))
+            .arg(ir_path);
+
+        run("llc", command)
+    }
+
+    // LLVM-IR 파일을 어셈블리 파일로 컴파일합니다.
+    pub fn compile_assembly(&self, ir_path: &Path, assembly_path: &Path) -> Result<(), AllError> {
+        let mut command = Command::new(&self.llc);
+        command
+            .arg("-filetype=asm")
+            .arg(format!("-mtriple={}", self.target.triple()))
+            .arg("-relocation-model=pic");
+
+        // llc는 파일 앞에 .intel_syntax noprefix를 적으므로 as로 그대로 어셈블할 수 있습니다.
+        if self.assembly_syntax == AssemblySyntax::Intel {
+            command.arg("--x86-asm-syntax=intel");
+        }
+
+        command.arg("-o").arg(assembly_path).arg(ir_path);
+
+        run("llc", command)
+    }
+
+    // 어셈블리 파일을 GNU as로 오브젝트 파일로 만듭니다.
+    pub fn assemble(&self, assembly_path: &Path, object_path: &Path) -> Result<(), AllError> {
+        let mut command = Command::new(&self.assembler);
+        command.arg("-o").arg(object_path).arg(assembly_path);
+
+        run("as", command)
+    }
+
+    // 오브젝트 파일들을 실행 파일로 링크합니다.
+    // no_std일 경우 libc 없이 ld로 정적 링크합니다.
+    pub fn link_executable(
+        &self,
+        object_paths: &[PathBuf],
+        output_path: &Path,
+        no_std: bool,
+    ) -> Result<(), AllError> {
+        let mut command = if no_std {
+            let mut command = Command::new(&self.ld);
+            command.arg("-static").arg("-nostdlib");
+            command
+        } else {
+            Command::new(&self.cc)
+        };
+
+        command.arg("-o").arg(output_path).args(object_paths);
+
+        run("link", command)
+    }
+
+    // LLVM-IR을 메모리에서 컴파일해서 main을 바로 호출합니다.
+    // libc 함수는 lli가 현재 프로세스에서 찾아 연결합니다.
+    // interpreter일 경우 기계어로 컴파일하지 않고 LLVM 인터프리터로 IR을 그대로 실행합니다.
+    // 프로그램의 실패는 오류가 아니므로 종료 코드와 출력을 그대로 반환합니다.
+    pub fn execute_jit(
+        &self,
+        ir_path: &Path,
+        extra_modules: &[PathBuf],
+        interpreter: bool,
+    ) -> Result<Output, AllError> {
+        let mut command = Command::new(&self.lli);
+        if interpreter {
+            command.arg("-force-interpreter");
+        }
+        for module_path in extra_modules {
+            command.arg(format!("-extra-module={}", module_path.display()));
+        }
+        command.arg(ir_path);
+
+        Logger::debug("jit", format!("{:?}", command));
+
+        Timings::measure("jit", || command.output()).map_err(|error| {
+            AllError::BuilderError(SourceError::new(9, format!("{:?}: {}", command, error)))
+        })
+    }
+}
+
+// stage는 --timings에 표시할 단계 이름입니다.
+fn run(stage: &str, mut command: Command) -> Result<(), AllError> {
+    Logger::debug("build", format!("{:?}", command));
+
+    let output = Timings::measure(stage, || command.output()).map_err(|error| {
+        AllError::BuilderError(SourceError::new(9, format!("{:?}: {}", command, error)))
+    })?;
+
+    if output.status.success() {
+        Ok(())
+    } else {
+        Err(AllError::BuilderError(SourceError::new(
+            10,
+            format!(
+                "{:?} failed: {}",
+                command,
+                String::from_utf8_lossy(&output.stderr)
+            ),
+        )))
+    }
+}
@@ -1,32 +1,987 @@
-use crate::{ast::expression::Expression, error::all_error::AllError};
+use crate::{
+    ast::{
+        expression::{
+            binary::BinaryExpression,
+            call::CallExpression,
+            cast::CastExpression,
+            field::FieldExpression,
+            literal::LiteralExpression,
+            match_expression::{MatchExpression, Pattern},
+            struct_literal::StructLiteralExpression,
+            unary::UnaryExpression,
+            Expression,
+        },
+        operator::{binary::BinaryOperator, unary::UnaryOperator},
+    },
+    error::all_error::AllError,
+    runtime::{builtin::Builtin, string::STRING_TYPE},
+    utils::{interner::Symbol, span::Span},
+};
 
-use super::CodeGenerator;
+use super::{
+    value::{Value, ValueType},
+    CodeGenerator,
+};
 
 impl CodeGenerator {
-    pub fn generate_expression(&mut self, expression: Expression) -> Result<Vec<String>, AllError> {
-        let _codes = vec![];
-
+    // 표현식을 계산하는 코드를 현재 함수에 추가하고, 결과 값을 반환합니다.
+    pub fn generate_expression(&mut self, expression: Expression) -> Result<Value, AllError> {
         match expression {
-            Expression::Call(_call_expression) => {
-                unimplemented!();
+            Expression::Call(call_expression) => self.generate_call(call_expression),
+            Expression::Literal(literal_expression, _) => {
+                Ok(self.generate_literal(literal_expression))
+            }
+            Expression::Variable(variable_expression) => {
+                // 지역 변수가 같은 이름의 전역 변수를 가립니다.
+                let variable = if let Some(variable) = self
+                    .current
+                    .get_variable(variable_expression.name)
+                    .or_else(|| self.global_variables.get(&variable_expression.name))
+                {
+                    variable.clone()
+                } else {
+                    return Err(AllError::CodegenError(
+                        self.source_error(
+                            210,
+                            variable_expression.span,
+                            format!("undefined variable {}", variable_expression.name),
+                        )
+                        .with_help(format!(
+                            "declare it with `let {} = ...` before using it",
+                            variable_expression.name
+                        )),
+                    ));
+                };
+
+                let register = self.current.new_temporary();
+                let llvm_type = variable.value_type.llvm_type();
+                self.current.push(format!(
+                    "  {} = load {}, {}* {}",
+                    register, llvm_type, llvm_type, variable.pointer
+                ));
+
+                Ok(Value::new(variable.value_type, register))
+            }
+            Expression::Binary(binary_expression) => self.generate_binary(binary_expression),
+            Expression::Unary(unary_expression) => self.generate_unary(unary_expression),
+            Expression::Parentheses(parentheses_expression) => {
+                self.generate_expression(*parentheses_expression.expression)
+            }
+            Expression::Match(match_expression) => self.generate_match(match_expression),
+            Expression::StructLiteral(struct_literal) => {
+                self.generate_struct_literal(struct_literal)
+            }
+            Expression::Field(field_expression) => self.generate_field(field_expression),
+            Expression::Cast(cast_expression) => self.generate_cast(cast_expression),
+            Expression::Comment(..) => Ok(Value::void()),
+        }
+    }
+
+    // 패턴이 모두 리터럴이므로 switch 하나로 갈래를 고르고, 갈래의 값은 phi로 합칩니다.
+    // 선택될 수 없는 갈래는 생성하지 않습니다. (switch에 같은 값이 두 번 나오면 안 됩니다.)
+    fn generate_match(&mut self, expression: MatchExpression) -> Result<Value, AllError> {
+        let reachable = (0..expression.arms.len())
+            .map(|index| expression.is_reachable(index))
+            .collect::<Vec<_>>();
+        let span = expression.span;
+
+        let subject = self.generate_expression(*expression.subject)?;
+
+        let end_label = self.current.new_label("match_end");
+        let mut default_label = None;
+        let mut cases = vec![];
+        let mut arms = vec![];
+
+        for (arm, reachable) in expression.arms.into_iter().zip(reachable) {
+            if !reachable {
+                continue;
+            }
+
+            let label = self.current.new_label("match_arm");
+
+            match &arm.pattern {
+                Pattern::Wildcard => default_label = Some(label.clone()),
+                Pattern::Literal(literal) => {
+                    let pattern = self.generate_literal(literal.clone());
+                    cases.push(format!("{}, label %{}", pattern.typed(), label));
+                }
+            }
+
+            arms.push((label, arm.body));
+        }
+
+        // _가 없으면 일치하는 갈래가 없을 때 match_default로 갑니다.
+        let has_wildcard = default_label.is_some();
+        let default_label =
+            default_label.unwrap_or_else(|| self.current.new_label("match_default"));
+
+        self.current.push(format!(
+            "  switch {}, label %{} [ {} ]",
+            subject.typed(),
+            default_label,
+            cases.join(" ")
+        ));
+
+        let mut result_type: Option<ValueType> = None;
+        let mut incoming = vec![];
+
+        for (label, body) in arms {
+            self.current.start_block(&label);
+            let value = self.generate_expression(body)?;
+
+            match result_type {
+                Some(result_type) if result_type != value.value_type => {
+                    return Err(self.error_at(
+                        101,
+                        span,
+                        format!(
+                            "match arms have different types: {} and {}",
+                            result_type.name(),
+                            value.value_type.name()
+                        ),
+                    ));
+                }
+                _ => result_type = Some(value.value_type),
+            }
+
+            incoming.push(format!(
+                "[ {}, %{} ]",
+                value.operand,
+                self.current.current_block()
+            ));
+            self.current.push(format!("  br label %{}", end_label));
+        }
+
+        let result_type = result_type.unwrap_or(ValueType::Void);
+
+        // 값이 있는 match는 의미 분석에서 모든 경우를 다루는지 확인했으므로 여기에 올 수 없습니다.
+        if !has_wildcard {
+            self.current.start_block(&default_label);
+
+            if result_type == ValueType::Void {
+                self.current.push(format!("  br label %{}", end_label));
+            } else {
+                self.current.push("  unreachable");
+            }
+        }
+
+        self.current.start_block(&end_label);
+
+        if result_type == ValueType::Void {
+            return Ok(Value::void());
+        }
+
+        let register = self.current.new_temporary();
+        self.current.push(format!(
+            "  {} = phi {} {}",
+            register,
+            result_type.llvm_type(),
+            incoming.join(", ")
+        ));
+
+        Ok(Value::new(result_type, register))
+    }
+
+    fn generate_literal(&mut self, literal: LiteralExpression) -> Value {
+        match literal {
+            LiteralExpression::Integer(integer) => {
+                Value::new(ValueType::Integer, integer.to_string())
+            }
+            LiteralExpression::Int32(integer) => Value::new(ValueType::Int32, integer.to_string()),
+            LiteralExpression::Int64(integer) => {
+                Value::new(ValueType::Integer, integer.to_string())
+            }
+            // 10진수 표기로는 정확하게 표현할 수 없는 값이 있으므로 16진수 비트 표기를 사용합니다.
+            LiteralExpression::Float(float) => {
+                Value::new(ValueType::Float, format!("0x{:016X}", float.to_bits()))
+            }
+            // float 상수도 double의 16진수 비트로 적습니다. (f32 값은 f64로 정확하게 바뀝니다.)
+            LiteralExpression::Float32(float) => Value::new(
+                ValueType::Float32,
+                format!("0x{:016X}", f64::from(float).to_bits()),
+            ),
+            LiteralExpression::Boolean(boolean) => {
+                Value::new(ValueType::Boolean, boolean.to_string())
+            }
+            LiteralExpression::String(string) => {
+                Value::new(ValueType::String, self.string_constant(&string))
+            }
+            LiteralExpression::Char(character) => {
+                Value::new(ValueType::Char, (character as u32).to_string())
+            }
+        }
+    }
+
+    fn generate_binary(&mut self, binary: BinaryExpression) -> Result<Value, AllError> {
+        if matches!(binary.operator, BinaryOperator::And | BinaryOperator::Or) {
+            return self.generate_logical(binary);
+        }
+
+        let lhs = self.generate_expression(*binary.lhs)?;
+        let mut rhs = self.generate_expression(*binary.rhs)?;
+
+        if lhs.value_type != rhs.value_type {
+            return Err(AllError::CodegenError(
+                self.source_error(
+                    103,
+                    binary.span,
+                    format!(
+                        "mismatched operand types for {:?}: {} and {}",
+                        binary.operator,
+                        lhs.value_type.name(),
+                        rhs.value_type.name()
+                    ),
+                )
+                .with_help(lhs.value_type.mismatch_help(rhs.value_type)),
+            ));
+        }
+
+        let value_type = lhs.value_type;
+        // i32와 i64, f32와 f64는 같은 명령어를 씁니다. 피연산자의 타입은 lhs.typed()에 들어갑니다.
+        let class = if value_type.is_integer() {
+            ValueType::Integer
+        } else if value_type.is_float() {
+            ValueType::Float
+        } else {
+            value_type
+        };
+
+        use BinaryOperator::*;
+        let (instruction, result_type) = match (class, &binary.operator) {
+            (ValueType::Integer, Add) => ("add", value_type),
+            (ValueType::Integer, Subtract) => ("sub", value_type),
+            (ValueType::Integer, Multiply) => ("mul", value_type),
+            (ValueType::Integer, Divide) => {
+                self.generate_division_guard(
+                    &lhs,
+                    &rhs,
+                    "attempt to divide with overflow",
+                    binary.span,
+                );
+                ("sdiv", value_type)
+            }
+            (ValueType::Integer, Modulo) => {
+                self.generate_division_guard(
+                    &lhs,
+                    &rhs,
+                    "attempt to calculate the remainder with overflow",
+                    binary.span,
+                );
+                ("srem", value_type)
+            }
+            (ValueType::Integer, Equal) => ("icmp eq", ValueType::Boolean),
+            (ValueType::Integer, NotEqual) => ("icmp ne", ValueType::Boolean),
+            (ValueType::Integer, LessThan) => ("icmp slt", ValueType::Boolean),
+            (ValueType::Integer, LessThanOrEqual) => ("icmp sle", ValueType::Boolean),
+            (ValueType::Integer, GreaterThan) => ("icmp sgt", ValueType::Boolean),
+            (ValueType::Integer, GreaterThanOrEqual) => ("icmp sge", ValueType::Boolean),
+            (ValueType::Integer, BitwiseAnd) => ("and", value_type),
+            (ValueType::Integer, BitwiseOr) => ("or", value_type),
+            (ValueType::Integer, BitwiseXor) => ("xor", value_type),
+            (ValueType::Integer, LeftShift) => {
+                rhs = self.generate_shift_mask(rhs);
+                ("shl", value_type)
+            }
+            // 정수는 부호가 있으므로 산술 시프트입니다.
+            (ValueType::Integer, RightShift) => {
+                rhs = self.generate_shift_mask(rhs);
+                ("ashr", value_type)
+            }
+            // char는 코드 포인트끼리 더하고 빼고, 부호 없이 비교합니다.
+            (ValueType::Char, Add) => ("add", value_type),
+            (ValueType::Char, Subtract) => ("sub", value_type),
+            (ValueType::Char, Equal) => ("icmp eq", ValueType::Boolean),
+            (ValueType::Char, NotEqual) => ("icmp ne", ValueType::Boolean),
+            (ValueType::Char, LessThan) => ("icmp ult", ValueType::Boolean),
+            (ValueType::Char, LessThanOrEqual) => ("icmp ule", ValueType::Boolean),
+            (ValueType::Char, GreaterThan) => ("icmp ugt", ValueType::Boolean),
+            (ValueType::Char, GreaterThanOrEqual) => ("icmp uge", ValueType::Boolean),
+            (ValueType::Float, Add) => ("fadd", value_type),
+            (ValueType::Float, Subtract) => ("fsub", value_type),
+            (ValueType::Float, Multiply) => ("fmul", value_type),
+            (ValueType::Float, Divide) => ("fdiv", value_type),
+            (ValueType::Float, Modulo) => ("frem", value_type),
+            (ValueType::Float, Equal) => ("fcmp oeq", ValueType::Boolean),
+            (ValueType::Float, NotEqual) => ("fcmp une", ValueType::Boolean),
+            (ValueType::Float, LessThan) => ("fcmp olt", ValueType::Boolean),
+            (ValueType::Float, LessThanOrEqual) => ("fcmp ole", ValueType::Boolean),
+            (ValueType::Float, GreaterThan) => ("fcmp ogt", ValueType::Boolean),
+            (ValueType::Float, GreaterThanOrEqual) => ("fcmp oge", ValueType::Boolean),
+            // &, |, ^는 &&, ||와 달리 양쪽을 모두 계산한 뒤에 연산합니다.
+            (ValueType::Boolean, BitwiseAnd) => ("and", value_type),
+            (ValueType::Boolean, BitwiseOr) => ("or", value_type),
+            (ValueType::Boolean, BitwiseXor) => ("xor", value_type),
+            (ValueType::Boolean, Equal) => ("icmp eq", value_type),
+            (ValueType::Boolean, NotEqual) => ("icmp ne", value_type),
+            (ValueType::String, _) => {
+                return self.generate_string_binary(binary.operator, binary.span, lhs, rhs);
+            }
+            (_, operator) => {
+                return Err(self.error_at(
+                    102,
+                    binary.span,
+                    format!(
+                        "unsupported operator {:?} for {}",
+                        operator,
+                        value_type.name()
+                    ),
+                ));
+            }
+        };
+
+        let register = self.current.new_temporary();
+        self.current.push(format!(
+            "  {} = {} {}, {}",
+            register,
+            instruction,
+            lhs.typed(),
+            rhs.operand
+        ));
+
+        Ok(Value::new(result_type, register))
+    }
+
+    // &&는 왼쪽이 false면, ||는 왼쪽이 true면 오른쪽을 계산하지 않고 왼쪽 값이 결과가 됩니다.
+    // 오른쪽은 별도 블록에서 계산하고, 두 경로의 값을 phi로 합칩니다.
+    fn generate_logical(&mut self, binary: BinaryExpression) -> Result<Value, AllError> {
+        let lhs = self.generate_expression(*binary.lhs)?;
+
+        if lhs.value_type != ValueType::Boolean {
+            return Err(self.error_at(
+                102,
+                binary.span,
+                format!(
+                    "unsupported operator {:?} for {}",
+                    binary.operator,
+                    lhs.value_type.name()
+                ),
+            ));
+        }
+
+        let rhs_label = self.current.new_label("logical_rhs");
+        let end_label = self.current.new_label("logical_end");
+        let lhs_block = self.current.current_block().to_owned();
+
+        let (short_circuit, true_label, false_label) = if binary.operator == BinaryOperator::And {
+            ("false", &rhs_label, &end_label)
+        } else {
+            ("true", &end_label, &rhs_label)
+        };
+
+        self.current.push(format!(
+            "  br {}, label %{}, label %{}",
+            lhs.typed(),
+            true_label,
+            false_label
+        ));
+
+        self.current.start_block(&rhs_label);
+        let rhs = self.generate_expression(*binary.rhs)?;
+
+        if rhs.value_type != ValueType::Boolean {
+            return Err(self.error_at(
+                103,
+                binary.span,
+                format!(
+                    "mismatched operand types for {:?}: {} and {}",
+                    binary.operator,
+                    lhs.value_type.name(),
+                    rhs.value_type.name()
+                ),
+            ));
+        }
+
+        // 오른쪽 식 안에서 블록이 나뉘었을 수 있으므로 계산이 끝난 블록을 다시 확인합니다.
+        let rhs_block = self.current.current_block().to_owned();
+        self.current.push(format!("  br label %{}", end_label));
+
+        self.current.start_block(&end_label);
+        let register = self.current.new_temporary();
+        self.current.push(format!(
+            "  {} = phi i1 [ {}, %{} ], [ {}, %{} ]",
+            register, short_circuit, lhs_block, rhs.operand, rhs_block
+        ));
+
+        Ok(Value::new(ValueType::Boolean, register))
+    }
+
+    // 시프트 양이 비트 수 이상이면 LLVM에서는 poison이므로, x86_64의 shl/sar처럼 하위 비트만 사용합니다.
+    // (i64는 하위 6비트, i32는 하위 5비트)
+    fn generate_shift_mask(&mut self, amount: Value) -> Value {
+        let register = self.current.new_temporary();
+        self.current.push(format!(
+            "  {} = and {}, {}",
+            register,
+            amount.typed(),
+            amount.value_type.bits() - 1
+        ));
+
+        Value::new(amount.value_type, register)
+    }
+
+    // 문자열의 +는 연결, 비교 연산자는 바이트 단위 사전순 비교입니다.
+    fn generate_string_binary(
+        &mut self,
+        operator: BinaryOperator,
+        span: Span,
+        lhs: Value,
+        rhs: Value,
+    ) -> Result<Value, AllError> {
+        let register = self.current.new_temporary();
+
+        if operator == BinaryOperator::Add {
+            self.current.push(format!(
+                "  {} = call {} @__foolang_string_concat({}, {})",
+                register,
+                STRING_TYPE,
+                lhs.typed(),
+                rhs.typed()
+            ));
+
+            return Ok(Value::new(ValueType::String, register));
+        }
+
+        let predicate = match operator {
+            BinaryOperator::Equal => "eq",
+            BinaryOperator::NotEqual => "ne",
+            BinaryOperator::LessThan => "slt",
+            BinaryOperator::LessThanOrEqual => "sle",
+            BinaryOperator::GreaterThan => "sgt",
+            BinaryOperator::GreaterThanOrEqual => "sge",
+            operator => {
+                return Err(self.error_at(
+                    102,
+                    span,
+                    format!(
+                        "unsupported operator {:?} for {}",
+                        operator,
+                        ValueType::String.name()
+                    ),
+                ));
+            }
+        };
+
+        self.current.push(format!(
+            "  {} = call i64 @__foolang_string_compare({}, {})",
+            register,
+            lhs.typed(),
+            rhs.typed()
+        ));
+
+        let result = self.current.new_temporary();
+        self.current.push(format!(
+            "  {} = icmp {} i64 {}, 0",
+            result, predicate, register
+        ));
+
+        Ok(Value::new(ValueType::Boolean, result))
+    }
+
+    // 0으로 나누거나 가장 작은 정수를 -1로 나누면 하드웨어 예외 대신 panic으로 종료합니다.
+    // 가장 작은 정수를 -1로 나눈 몫은 범위를 넘고, sdiv와 srem 모두 이 경우의 결과가 정의되지 않습니다.
+    fn generate_division_guard(
+        &mut self,
+        dividend: &Value,
+        divisor: &Value,
+        overflow_message: &str,
+        span: Span,
+    ) {
+        let is_zero = self.current.new_temporary();
+        self.current
+            .push(format!("  {} = icmp eq {}, 0", is_zero, divisor.typed()));
+        self.generate_division_panic(
+            &is_zero,
+            "division_by_zero",
+            "attempt to divide by zero",
+            span,
+        );
+
+        let minimum = if dividend.value_type == ValueType::Int32 {
+            i32::MIN as i64
+        } else {
+            i64::MIN
+        };
+
+        let is_minus_one = self.current.new_temporary();
+        let is_minimum = self.current.new_temporary();
+        let overflow = self.current.new_temporary();
+        self.current.push(format!(
+            "  {} = icmp eq {}, -1",
+            is_minus_one,
+            divisor.typed()
+        ));
+        self.current.push(format!(
+            "  {} = icmp eq {}, {}",
+            is_minimum,
+            dividend.typed(),
+            minimum
+        ));
+        self.current.push(format!(
+            "  {} = and i1 {}, {}",
+            overflow, is_minus_one, is_minimum
+        ));
+        self.generate_division_panic(&overflow, "division_overflow", overflow_message, span);
+    }
+
+    // condition이 참이면 message와 위치를 출력하고 종료하고, 거짓이면 나눗셈을 계속합니다.
+    fn generate_division_panic(&mut self, condition: &str, label: &str, message: &str, span: Span) {
+        let message = self.string_constant(message);
+        let location = self.location_arguments(span);
+
+        let panic_label = self.current.new_label(label);
+        let continue_label = self.current.new_label("division");
+
+        self.current.push(format!(
+            "  br i1 {}, label %{}, label %{}",
+            condition, panic_label, continue_label
+        ));
+        self.current.start_block(&panic_label);
+        self.current.push(format!(
+            "  call void @__foolang_panic({} {}, {})",
+            STRING_TYPE, message, location
+        ));
+        self.current.push("  unreachable");
+        self.current.start_block(&continue_label);
+    }
+
+    // panic 메시지에 표시할 파일 이름, 줄, 열 인자입니다. 위치를 모르면 줄, 열은 0입니다.
+    fn location_arguments(&mut self, span: Span) -> String {
+        let file = self.string_constant(&self.source_name.clone());
+
+        format!(
+            "{} {}, i64 {}, i64 {}",
+            STRING_TYPE, file, span.line, span.column
+        )
+    }
+
+    fn generate_unary(&mut self, unary: UnaryExpression) -> Result<Value, AllError> {
+        // 음수 리터럴은 명령어 없이 상수로 만듭니다. (전역 변수의 초기값으로 쓸 수 있도록)
+        if unary.operator == UnaryOperator::Minus {
+            if let Expression::Literal(literal, _) = unary.operand.as_ref() {
+                if let Some(negative) = literal.negated() {
+                    return Ok(self.generate_literal(negative));
+                }
             }
-            Expression::Literal(_literal_expression) => {
-                unimplemented!();
+        }
+
+        let operand = self.generate_expression(*unary.operand)?;
+
+        let value_type = operand.value_type;
+        let instruction = match (&unary.operator, value_type) {
+            (UnaryOperator::Plus, _) if value_type.is_float() => {
+                return Ok(operand);
             }
-            Expression::Variable(_variable_expression) => {
-                unimplemented!();
+            (UnaryOperator::Plus, _) if value_type.is_integer() => {
+                return Ok(operand);
             }
-            Expression::Binary(_binary_expression) => {
-                unimplemented!();
+            (UnaryOperator::Minus, _) if value_type.is_integer() => {
+                format!("sub {} 0, {}", value_type.llvm_type(), operand.operand)
             }
-            Expression::Unary(_unary_expression) => {
-                unimplemented!();
+            (UnaryOperator::Minus, _) if value_type.is_float() => {
+                format!("fneg {}", operand.typed())
             }
-            _ => {
-                unimplemented!();
+            (UnaryOperator::Not, ValueType::Boolean) => format!("xor {}, true", operand.typed()),
+            (UnaryOperator::BitwiseNot, _) if value_type.is_integer() => {
+                format!("xor {}, -1", operand.typed())
             }
+            (operator, value_type) => {
+                return Err(self.error_at(
+                    104,
+                    unary.span,
+                    format!(
+                        "unsupported unary operator {:?} for {}",
+                        operator,
+                        value_type.name()
+                    ),
+                ));
+            }
+        };
+
+        let register = self.current.new_temporary();
+        self.current
+            .push(format!("  {} = {}", register, instruction));
+
+        Ok(Value::new(operand.value_type, register))
+    }
+
+    fn generate_call(&mut self, call: CallExpression) -> Result<Value, AllError> {
+        let mut arguments = vec![];
+        for argument in call.arguments {
+            arguments.push(self.generate_expression(argument)?);
         }
 
-        Ok(_codes)
+        if let Some(builtin) = Builtin::from_name(&call.function_name.text()) {
+            return self.generate_builtin_call(call.function_name, call.span, builtin, arguments);
+        }
+
+        let signature = if let Some(signature) = self.function_signatures.get(&call.function_name) {
+            signature.clone()
+        } else {
+            return Err(self.error_at(
+                105,
+                call.span,
+                format!("undefined function {}", call.function_name),
+            ));
+        };
+
+        if signature.parameters.len() != arguments.len() {
+            return Err(self.error_at(
+                106,
+                call.span,
+                format!(
+                    "function {} takes {} arguments but {} were given",
+                    call.function_name,
+                    signature.parameters.len(),
+                    arguments.len()
+                ),
+            ));
+        }
+
+        if let Some((parameter, argument)) = signature
+            .parameters
+            .iter()
+            .zip(&arguments)
+            .find(|(parameter, argument)| **parameter != argument.value_type)
+        {
+            return Err(self.error_at(
+                107,
+                call.span,
+                format!(
+                    "function {} takes {} arguments, found {}",
+                    call.function_name,
+                    parameter.name(),
+                    argument.value_type.name()
+                ),
+            ));
+        }
+
+        // 큰 구조체는 호출하는 쪽에서 복사본과 반환값을 담을 슬롯을 잡고 그 포인터를 넘깁니다.
+        let return_type = signature.return_type;
+        let return_slot = if self.structs.is_passed_by_pointer(return_type) {
+            Some(self.current.new_slot(return_type))
+        } else {
+            None
+        };
+
+        let mut operands = vec![];
+        if let Some(slot) = &return_slot {
+            operands.push(self.pointer_argument("sret", return_type, slot));
+        }
+
+        for argument in &arguments {
+            if self.structs.is_passed_by_pointer(argument.value_type) {
+                let slot = self.current.new_slot(argument.value_type);
+                self.current.push(format!(
+                    "  store {}, {}* {}",
+                    argument.typed(),
+                    argument.value_type.llvm_type(),
+                    slot
+                ));
+                operands.push(self.pointer_argument("byval", argument.value_type, &slot));
+            } else {
+                operands.push(argument.typed());
+            }
+        }
+
+        let function = Self::function_symbol(&call.function_name.text());
+
+        if let Some(slot) = return_slot {
+            self.current
+                .push(format!("  call void {}({})", function, operands.join(", ")));
+
+            let register = self.current.new_temporary();
+            let llvm_type = return_type.llvm_type();
+            self.current.push(format!(
+                "  {} = load {}, {}* {}",
+                register, llvm_type, llvm_type, slot
+            ));
+
+            return Ok(Value::new(return_type, register));
+        }
+
+        let register = self.current.new_temporary();
+        self.current.push(format!(
+            "  {} = call {} {}({})",
+            register,
+            return_type.llvm_type(),
+            function,
+            operands.join(", ")
+        ));
+
+        Ok(Value::new(return_type, register))
+    }
+
+    // byval, sret 속성을 붙인 포인터 인자입니다. (예: %foolang.Big* byval(%foolang.Big) align 8 %slot.3)
+    pub(crate) fn pointer_argument(
+        &self,
+        attribute: &str,
+        value_type: ValueType,
+        pointer: &str,
+    ) -> String {
+        format!("{} {}", self.pointer_type(attribute, value_type), pointer)
+    }
+
+    // 선언에서는 이름 없이 타입과 속성만 적습니다. (예: %foolang.Big* byval(%foolang.Big) align 8)
+    pub(crate) fn pointer_type(&self, attribute: &str, value_type: ValueType) -> String {
+        let llvm_type = value_type.llvm_type();
+
+        format!(
+            "{}* {}({}) align {}",
+            llvm_type,
+            attribute,
+            llvm_type,
+            self.structs.align_of(value_type)
+        )
+    }
+
+    // 필드를 선언한 순서대로 insertvalue로 채워서 구조체 값을 만듭니다.
+    fn generate_struct_literal(
+        &mut self,
+        literal: StructLiteralExpression,
+    ) -> Result<Value, AllError> {
+        let struct_type = if let Some(struct_type) = self.structs.get(literal.name) {
+            struct_type.clone()
+        } else {
+            return Err(self.error_at(
+                108,
+                literal.span,
+                format!("undefined struct {}", literal.name),
+            ));
+        };
+
+        let mut values = vec![];
+        for (name, expression) in literal.fields {
+            values.push((name, self.generate_expression(expression)?));
+        }
+
+        let value_type = ValueType::Struct(literal.name);
+        let mut aggregate = "zeroinitializer".to_owned();
+
+        for (index, (field, field_type)) in struct_type.fields.iter().enumerate() {
+            let value = if let Some((_, value)) = values.iter().find(|(name, _)| name == field) {
+                value
+            } else {
+                return Err(self.error_at(
+                    109,
+                    literal.span,
+                    format!(
+                        "missing field {} in initializer of struct {}",
+                        field, literal.name
+                    ),
+                ));
+            };
+
+            if value.value_type != *field_type {
+                return Err(self.error_at(
+                    110,
+                    literal.span,
+                    format!(
+                        "mismatched type for field {} of struct {}: expected {}, found {}",
+                        field,
+                        literal.name,
+                        field_type.name(),
+                        value.value_type.name()
+                    ),
+                ));
+            }
+
+            let register = self.current.new_temporary();
+            self.current.push(format!(
+                "  {} = insertvalue {} {}, {}, {}",
+                register,
+                value_type.llvm_type(),
+                if index == 0 { "undef" } else { &aggregate },
+                value.typed(),
+                index
+            ));
+            aggregate = register;
+        }
+
+        Ok(Value::new(value_type, aggregate))
+    }
+
+    fn generate_field(&mut self, field: FieldExpression) -> Result<Value, AllError> {
+        let object = self.generate_expression(*field.object)?;
+
+        let found = match object.value_type {
+            ValueType::Struct(name) => self
+                .structs
+                .get(name)
+                .and_then(|struct_type| struct_type.field(field.field)),
+            _ => None,
+        };
+
+        let (index, field_type) = found.ok_or_else(|| {
+            self.error_at(
+                111,
+                field.span,
+                format!(
+                    "no field {} on type {}",
+                    field.field,
+                    object.value_type.name()
+                ),
+            )
+        })?;
+
+        let register = self.current.new_temporary();
+        self.current.push(format!(
+            "  {} = extractvalue {}, {}",
+            register,
+            object.typed(),
+            index
+        ));
+
+        Ok(Value::new(field_type, register))
+    }
+
+    fn generate_widening(&mut self, value: Value) -> Value {
+        let (instruction, target) = match value.value_type {
+            ValueType::Int32 => ("sext", ValueType::Integer),
+            ValueType::Float32 => ("fpext", ValueType::Float),
+            _ => return value,
+        };
+
+        let register = self.current.new_temporary();
+        self.current.push(format!(
+            "  {} = {} {} to {}",
+            register,
+            instruction,
+            value.typed(),
+            target.llvm_type()
+        ));
+
+        Value::new(target, register)
+    }
+
+    fn generate_cast(&mut self, cast: CastExpression) -> Result<Value, AllError> {
+        let value = self.generate_expression(*cast.value)?;
+
+        let instruction = self
+            .structs
+            .resolve_type(cast.type_name)
+            .and_then(|target| {
+                value
+                    .value_type
+                    .cast_instruction(target)
+                    .map(|instruction| (target, instruction))
+            });
+
+        let (target, instruction) = instruction.ok_or_else(|| {
+            self.error_at(
+                112,
+                cast.span,
+                format!(
+                    "cannot cast {} as {}",
+                    value.value_type.name(),
+                    cast.type_name
+                ),
+            )
+        })?;
+
+        // 같은 비트의 타입(char와 i32 등)은 타입만 바꿉니다.
+        if instruction.is_empty() {
+            return Ok(Value::new(target, value.operand));
+        }
+
+        let register = self.current.new_temporary();
+        self.current.push(format!(
+            "  {} = {} {} to {}",
+            register,
+            instruction,
+            value.typed(),
+            target.llvm_type()
+        ));
+
+        Ok(Value::new(target, register))
+    }
+
+    // 내장 함수는 첫 번째 인자의 타입으로 런타임 함수를 고릅니다.
+    fn generate_builtin_call(
+        &mut self,
+        name: Symbol,
+        span: Span,
+        builtin: Builtin,
+        arguments: Vec<Value>,
+    ) -> Result<Value, AllError> {
+        // 런타임 함수는 i64와 double만 받으므로 i32, f32 인자는 넓혀서 넘깁니다.
+        let arguments = arguments
+            .into_iter()
+            .map(|argument| self.generate_widening(argument))
+            .collect::<Vec<_>>();
+
+        let argument_type = arguments
+            .first()
+            .map(|argument| argument.value_type.llvm_type())
+            .unwrap_or_default();
+
+        let function = builtin.runtime_function(&argument_type).ok_or_else(|| {
+            self.error_at(
+                113,
+                span,
+                format!(
+                    "{} does not accept arguments of type ({})",
+                    name,
+                    arguments
+                        .iter()
+                        .map(|argument| argument.value_type.name())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+            )
+        })?;
+
+        let parameter_types = builtin.parameter_types(&argument_type);
+        let argument_types = arguments
+            .iter()
+            .map(|argument| argument.value_type.llvm_type())
+            .collect::<Vec<_>>();
+
+        if parameter_types != argument_types {
+            return Err(self.error_at(
+                114,
+                span,
+                format!(
+                    "{} expects arguments ({}), found ({})",
+                    name,
+                    parameter_types.join(", "),
+                    argument_types.join(", ")
+                ),
+            ));
+        }
+
+        let mut operands = arguments.iter().map(Value::typed).collect::<Vec<_>>();
+        if builtin.takes_location() {
+            operands.push(self.location_arguments(span));
+        }
+
+        let return_type = builtin.return_type();
+        let value_type = ValueType::from_llvm_type(return_type).unwrap_or(ValueType::Void);
+
+        if value_type == ValueType::Void {
+            self.current.push(format!(
+                "  call void @{}({})",
+                function,
+                operands.join(", ")
+            ));
+
+            if builtin == Builtin::Panic {
+                self.current.push("  unreachable");
+                let label = self.current.new_label("after_panic");
+                self.current.start_block(&label);
+            }
+
+            Ok(Value::void())
+        } else {
+            let register = self.current.new_temporary();
+            self.current.push(format!(
+                "  {} = call {} @{}({})",
+                register,
+                return_type,
+                function,
+                operands.join(", ")
+            ));
+
+            Ok(Value::new(value_type, register))
+        }
     }
 }
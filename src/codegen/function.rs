@@ -0,0 +1,153 @@
+use std::collections::HashMap;
+
+use crate::utils::interner::Symbol;
+
+use super::value::ValueType;
+
+// 지역 변수는 alloca로 잡은 스택 슬롯에 저장합니다.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Variable {
+    pub value_type: ValueType,
+    pub pointer: String,
+}
+
+// 사용자 정의 함수의 매개변수와 반환값 타입입니다.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FunctionSignature {
+    pub parameters: Vec<ValueType>,
+    pub return_type: ValueType,
+}
+
+// break, continue가 이동할 블록 이름입니다.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LoopLabels {
+    pub continue_label: String,
+    pub break_label: String,
+}
+
+// 생성 중인 함수 하나의 상태입니다.
+// 레지스터, 변수 슬롯, 블록 이름이 겹치지 않도록 하나의 카운터로 번호를 붙입니다.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct FunctionContext {
+    pub codes: Vec<String>,
+    allocas: Vec<String>,
+    variables: HashMap<Symbol, Variable>,
+    // 안쪽 반복문이 뒤에 옵니다.
+    loops: Vec<LoopLabels>,
+    // 지금 코드를 쌓고 있는 블록의 이름입니다. phi에서 어느 블록에서 왔는지 적을 때 씁니다.
+    block: String,
+    next_id: usize,
+}
+
+impl FunctionContext {
+    pub fn new() -> Self {
+        Self {
+            block: "entry".into(),
+            ..Self::default()
+        }
+    }
+
+    fn next_id(&mut self) -> usize {
+        let id = self.next_id;
+        self.next_id += 1;
+        id
+    }
+
+    pub fn new_temporary(&mut self) -> String {
+        format!("%t{}", self.next_id())
+    }
+
+    pub fn new_label(&mut self, prefix: &str) -> String {
+        format!("{}.{}", prefix, self.next_id())
+    }
+
+    // 이름 없는 스택 슬롯입니다. 구조체를 포인터로 주고받을 때 씁니다.
+    pub fn new_slot(&mut self, value_type: ValueType) -> String {
+        let pointer = format!("%slot.{}", self.next_id());
+
+        self.allocas
+            .push(format!("  {} = alloca {}", pointer, value_type.llvm_type()));
+
+        pointer
+    }
+
+    // 같은 이름으로 다시 선언하면 새 슬롯을 만들어서 이전 변수를 가립니다.
+    pub fn define_variable(&mut self, name: Symbol, value_type: ValueType) -> String {
+        let pointer = identifier('%', &format!("{}.{}", name, self.next_id()));
+
+        self.allocas
+            .push(format!("  {} = alloca {}", pointer, value_type.llvm_type()));
+        self.variables.insert(
+            name,
+            Variable {
+                value_type,
+                pointer: pointer.clone(),
+            },
+        );
+
+        pointer
+    }
+
+    pub fn get_variable(&self, name: Symbol) -> Option<&Variable> {
+        self.variables.get(&name)
+    }
+
+    // 블록이 끝나면 블록 안에서 선언한 변수가 보이지 않도록 변수 목록을 저장했다가 되돌립니다.
+    pub fn save_variables(&self) -> HashMap<Symbol, Variable> {
+        self.variables.clone()
+    }
+
+    pub fn restore_variables(&mut self, variables: HashMap<Symbol, Variable>) {
+        self.variables = variables;
+    }
+
+    pub fn push_loop(&mut self, labels: LoopLabels) {
+        self.loops.push(labels);
+    }
+
+    pub fn pop_loop(&mut self) {
+        self.loops.pop();
+    }
+
+    pub fn current_loop(&self) -> Option<&LoopLabels> {
+        self.loops.last()
+    }
+
+    pub fn push(&mut self, code: impl Into<String>) {
+        self.codes.push(code.into());
+    }
+
+    // 블록을 끝내는 명령(ret, br) 뒤에 오는 코드가 들어갈 새 블록을 엽니다.
+    pub fn start_block(&mut self, label: &str) {
+        self.codes.push(format!("{}:", label));
+        self.block = label.to_owned();
+    }
+
+    pub fn current_block(&self) -> &str {
+        &self.block
+    }
+
+    // alloca는 모두 entry 블록 맨 앞에 모아서 함수 전체를 완성합니다.
+    pub fn finish(self, header: String, terminator: &str) -> Vec<String> {
+        let mut codes = vec![header, "entry:".into()];
+        codes.extend(self.allocas);
+        codes.extend(self.codes);
+        codes.push(format!("  {}", terminator));
+        codes.push("}".into());
+
+        codes
+    }
+}
+
+// LLVM 식별자로 그대로 쓸 수 없는 문자(한글 등)가 있으면 따옴표로 감쌉니다.
+pub fn identifier(sigil: char, name: &str) -> String {
+    let plain = name
+        .chars()
+        .all(|character| character.is_ascii_alphanumeric() || "._$-".contains(character));
+
+    if plain {
+        format!("{}{}", sigil, name)
+    } else {
+        format!("{}\"{}\"", sigil, name)
+    }
+}
@@ -1,20 +1,105 @@
 pub(crate) mod expression;
+pub(crate) mod function;
+pub(crate) mod statement;
+pub(crate) mod structure;
+pub(crate) mod value;
 
-use crate::{ast::statement::Statement, error::all_error::AllError};
+pub(crate) mod test;
+
+use std::collections::HashMap;
+
+use crate::{
+    ast::statement::Statement,
+    error::all_error::{source_error::SourceError, AllError},
+    runtime::{
+        startup,
+        string::{escape, literal_constant, shared_literal_global, shared_literal_name},
+        Runtime,
+    },
+    utils::{interner::Symbol, span::Span},
+};
+
+use self::{
+    function::{FunctionContext, FunctionSignature, Variable},
+    structure::StructTable,
+    value::ValueType,
+};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct CodeGenerator {
     statements: Vec<Statement>,
+    runtime: Runtime,
+    source_name: String,
+    // 같은 내용의 문자열 리터럴은 하나의 전역 상수를 공유합니다. (다른 모듈과도 공유합니다.)
+    string_constants: HashMap<String, String>,
+    globals: Vec<String>,
+    // 최상위에서 선언한 변수는 전역 변수가 되어 함수 안에서도 읽을 수 있습니다.
+    global_variables: HashMap<Symbol, Variable>,
+    // 생성 중인 함수의 중첩 깊이입니다. 0이면 최상위(__foolang_main)입니다.
+    function_depth: usize,
+    // if 등 블록의 중첩 깊이입니다.
+    block_depth: usize,
+    // 사용자 정의 함수 이름과 시그니처
+    function_signatures: HashMap<Symbol, FunctionSignature>,
+    // 다른 모듈에 정의된 함수 이름과 시그니처
+    external_functions: HashMap<Symbol, FunctionSignature>,
+    // 이 모듈에서 선언한 구조체
+    structs: StructTable,
+    // 생성 중인 함수의 반환 타입
+    return_type: ValueType,
+    entry: bool,
+    functions: Vec<String>,
+    current: FunctionContext,
 }
 
 impl CodeGenerator {
     pub fn new() -> Self {
-        Self { statements: vec![] }
+        Self {
+            statements: vec![],
+            runtime: Runtime::new(),
+            source_name: "main.foo".to_owned(),
+            string_constants: HashMap::new(),
+            globals: vec![],
+            global_variables: HashMap::new(),
+            function_depth: 0,
+            block_depth: 0,
+            function_signatures: HashMap::new(),
+            external_functions: HashMap::new(),
+            structs: StructTable::new(),
+            return_type: ValueType::Integer,
+            entry: true,
+            functions: vec![],
+            current: FunctionContext::new(),
+        }
     }
 
     pub fn set_statements(&mut self, statements: Vec<Statement>) {
         self.statements = statements;
     }
+
+    pub fn set_no_std(&mut self, no_std: bool) {
+        self.runtime.set_no_std(no_std);
+    }
+
+    // panic 메시지에 표시할 소스 파일 이름입니다.
+    pub fn set_source_name(&mut self, source_name: &str) {
+        self.source_name = source_name.to_owned();
+    }
+
+    // 다른 모듈에 정의되어 있어서 declare만 하고 호출할 함수 목록입니다.
+    pub fn set_external_functions(
+        &mut self,
+        external_functions: HashMap<Symbol, FunctionSignature>,
+    ) {
+        self.external_functions = external_functions;
+    }
+
+    // 프로그램 진입점(main)을 포함하는 모듈인지 여부입니다.
+    // 진입점이 아닌 모듈에는 함수 정의만 올 수 있습니다.
+    pub fn set_entry(&mut self, entry: bool) {
+        self.entry = entry;
+        self.runtime.set_entry(entry);
+    }
 }
 
 impl Default for CodeGenerator {
@@ -24,34 +109,105 @@ impl Default for CodeGenerator {
 }
 
 impl CodeGenerator {
+    // 최상위 statement는 프로그램 진입점(__foolang_main)의 본문이 됩니다.
     pub fn generate(&mut self) -> Result<Vec<String>, AllError> {
-        let mut codes = vec![
-            "declare i32 @printf(i8* nocapture, ...) nounwind".to_owned(),
-            "define i32 @main()".into(),
-            "{".into(),
-        ];
+        let statements = std::mem::take(&mut self.statements);
 
-        for statement in self.statements.clone().into_iter() {
+        self.structs = StructTable::from_statements(&statements)
+            .map_err(|error| AllError::CodegenError(error.with_file(self.source_name.as_str())))?;
+
+        // 선언보다 앞에서 호출할 수 있도록 함수 목록을 먼저 수집합니다.
+        for statement in &statements {
             match statement {
-                Statement::Expression(expression) => {
-                    let mut result = self.generate_expression(expression.to_owned())?;
-                    codes.append(&mut result);
-                }
-                Statement::DefineVariable(_variable_declaration) => {
-                    unimplemented!();
-                }
-                Statement::DefineFunction(_function_declaration) => {
-                    unimplemented!();
-                }
-                Statement::Return(_return_statement) => {
-                    unimplemented!();
+                Statement::DefineFunction(function) => self.define_function_signature(function)?,
+                Statement::DefineStruct(_) => {}
+                statement if !self.entry => {
+                    return Err(self.error_at(
+                        1,
+                        statement.span(),
+                        "only function and struct definitions are allowed outside the main file"
+                            .to_owned(),
+                    ));
                 }
+                _ => {}
+            }
+        }
+
+        let mut declarations = vec![];
+        for (name, signature) in &self.external_functions {
+            // 이 모듈에 없는 구조체를 주고받는 함수는 호출할 수 없으므로 선언하지 않습니다.
+            if self.function_signatures.contains_key(name)
+                || self.structs.foreign_struct(signature).is_some()
+            {
+                continue;
             }
+
+            declarations.push(self.function_declaration(*name, signature));
+            self.function_signatures.insert(*name, signature.clone());
+        }
+        declarations.sort();
+
+        self.current = FunctionContext::new();
+
+        for statement in statements.iter().cloned() {
+            self.generate_statement(statement)?;
+        }
+
+        self.statements = statements;
+
+        let main = std::mem::take(&mut self.current);
+        // 모듈 이름을 정하지 않으면 llc가 중간 파일의 이름을 오브젝트에 기록하므로,
+        // 같은 소스는 어디서 빌드해도 같은 오브젝트가 되도록 소스 파일 이름을 적습니다.
+        let mut codes = vec![format!(
+            "source_filename = \"{}\"",
+            escape(self.source_name.as_str())
+        )];
+        codes.append(&mut self.structs.definitions());
+        if self.entry {
+            codes.append(&mut main.finish(
+                format!("define i64 @{}() {{", startup::ENTRY_FUNCTION),
+                "ret i64 0",
+            ));
         }
 
-        codes.push("ret i32 0".into());
-        codes.push("}".into());
+        codes.append(&mut self.functions);
+        codes.append(&mut declarations);
+        codes.append(&mut self.globals);
+        if self.entry {
+            codes.append(&mut startup::generate_entry());
+        }
+        codes.append(&mut self.runtime.generate());
+
+        self.string_constants.clear();
+        self.global_variables.clear();
+        self.function_signatures.clear();
 
         Ok(codes)
     }
+
+    // 오류에 파일 이름과 위치를 붙입니다. 코드로 만든 AST라면 위치는 알 수 없습니다.
+    // uid는 오류 코드(C0101 등)의 번호입니다. 같은 종류의 오류는 같은 번호를 씁니다.
+    pub(crate) fn error_at(&self, uid: i32, span: Span, message: String) -> AllError {
+        AllError::CodegenError(self.source_error(uid, span, message))
+    }
+
+    pub(crate) fn source_error(&self, uid: i32, span: Span, message: String) -> SourceError {
+        SourceError::new(uid, message)
+            .with_file(self.source_name.as_str())
+            .with_span(span)
+    }
+
+    // 문자열 리터럴의 전역 상수를 만들거나 재사용하고, 그 상수 표현식을 반환합니다.
+    pub(crate) fn string_constant(&mut self, text: &str) -> String {
+        let name = if let Some(name) = self.string_constants.get(text) {
+            name.clone()
+        } else {
+            let name = shared_literal_name(text);
+            self.globals.append(&mut shared_literal_global(&name, text));
+            self.string_constants.insert(text.to_owned(), name.clone());
+            name
+        };
+
+        literal_constant(&name, text)
+    }
 }
@@ -0,0 +1,578 @@
+use crate::{
+    ast::statement::{
+        assign::AssignStatement, define_function::FunctionDefinitionStatement,
+        for_statement::ForStatement, if_statement::IfStatement, loop_statement::LoopStatement,
+        while_statement::WhileStatement, Statement,
+    },
+    error::all_error::{source_error::SourceError, AllError},
+    utils::interner::Symbol,
+};
+
+use super::{
+    function::{identifier, FunctionContext, FunctionSignature, LoopLabels, Variable},
+    value::{Value, ValueType},
+    CodeGenerator,
+};
+
+// 큰 구조체를 반환하는 함수에서 반환값을 저장할 곳을 가리키는 sret 매개변수입니다.
+const RETURN_SLOT: &str = "%return.slot";
+
+impl CodeGenerator {
+    pub fn generate_statement(&mut self, statement: Statement) -> Result<(), AllError> {
+        match statement {
+            Statement::Expression(expression) => {
+                self.generate_expression(expression)?;
+            }
+            Statement::DefineVariable(variable) => {
+                let value = if let Some(expression) = variable.value {
+                    self.generate_expression(expression)?
+                } else {
+                    return Err(AllError::CodegenError(SourceError::new(
+                        201,
+                        format!("variable {} must be initialized", variable.name),
+                    )));
+                };
+
+                if value.value_type == ValueType::Void {
+                    return Err(AllError::CodegenError(SourceError::new(
+                        202,
+                        format!("cannot assign void to variable {}", variable.name),
+                    )));
+                }
+
+                // 정수 리터럴의 타입은 의미 분석의 annotate에서 변수의 타입에 맞춰 두었습니다.
+                if let Some(type_name) = variable.type_name {
+                    let annotated = self.structs.resolve_type(type_name);
+
+                    if annotated != Some(value.value_type) {
+                        return Err(self.error_at(
+                            203,
+                            variable.span,
+                            format!(
+                                "cannot assign {} to variable {} of type {}",
+                                value.value_type.name(),
+                                variable.name,
+                                type_name
+                            ),
+                        ));
+                    }
+                }
+
+                if self.function_depth == 0 {
+                    self.define_global_variable(variable.name, value);
+                } else {
+                    let pointer = self
+                        .current
+                        .define_variable(variable.name, value.value_type);
+                    self.current.push(format!(
+                        "  store {}, {}* {}",
+                        value.typed(),
+                        value.value_type.llvm_type(),
+                        pointer
+                    ));
+                }
+            }
+            Statement::DefineFunction(function) => {
+                self.generate_function(function)?;
+            }
+            // 구조체 타입은 generate를 시작할 때 모듈 맨 앞에 정의했습니다.
+            Statement::DefineStruct(definition) => {
+                if self.function_depth > 0 || self.block_depth > 0 {
+                    return Err(self.error_at(
+                        204,
+                        definition.span,
+                        format!(
+                            "struct {} must be declared at the top level",
+                            definition.name
+                        ),
+                    ));
+                }
+            }
+            Statement::Return(expression, span) => {
+                let span = span.to(expression.span());
+                let value = self.generate_expression(expression)?;
+
+                if value.value_type != self.return_type {
+                    return Err(self.error_at(
+                        205,
+                        span,
+                        format!(
+                            "return value must be {}, found {}",
+                            self.return_type.name(),
+                            value.value_type.name()
+                        ),
+                    ));
+                }
+
+                // 큰 구조체는 호출하는 쪽이 넘겨준 sret 슬롯에 저장합니다.
+                if self.structs.is_passed_by_pointer(value.value_type) {
+                    self.current.push(format!(
+                        "  store {}, {}* {}",
+                        value.typed(),
+                        value.value_type.llvm_type(),
+                        RETURN_SLOT
+                    ));
+                    self.current.push("  ret void");
+                } else {
+                    self.current.push(format!("  ret {}", value.typed()));
+                }
+                let label = self.current.new_label("after_return");
+                self.current.start_block(&label);
+            }
+            Statement::If(statement) => {
+                self.generate_if(statement)?;
+            }
+            Statement::While(statement) => {
+                self.generate_while(statement)?;
+            }
+            Statement::Loop(statement) => {
+                self.generate_loop(statement)?;
+            }
+            Statement::For(statement) => {
+                self.generate_for(statement)?;
+            }
+            Statement::Break(_) => {
+                let target = self.loop_labels("break")?.break_label;
+                self.generate_jump(&target);
+            }
+            Statement::Continue(_) => {
+                let target = self.loop_labels("continue")?.continue_label;
+                self.generate_jump(&target);
+            }
+            Statement::Assign(statement) => {
+                self.generate_assign(statement)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    // 상수로 초기화하면 초기값을 가진 전역 변수(.data)가 되고,
+    // 실행 중에 계산한 값이면 0으로 초기화(.bss)한 뒤 최상위 코드에서 저장합니다.
+    // 블록 안에서 선언한 변수는 블록을 실행할 때마다 초기화해야 하므로 상수여도 저장합니다.
+    fn define_global_variable(&mut self, name: Symbol, value: Value) {
+        let pointer = identifier('@', &format!("{}.{}", name, self.globals.len()));
+        let llvm_type = value.value_type.llvm_type();
+
+        if value.operand.starts_with('%') || self.block_depth > 0 {
+            self.globals.push(format!(
+                "{} = internal global {} zeroinitializer",
+                pointer, llvm_type
+            ));
+            self.current.push(format!(
+                "  store {}, {}* {}",
+                value.typed(),
+                llvm_type,
+                pointer
+            ));
+        } else {
+            self.globals
+                .push(format!("{} = internal global {}", pointer, value.typed()));
+        }
+
+        self.global_variables.insert(
+            name,
+            Variable {
+                value_type: value.value_type,
+                pointer,
+            },
+        );
+    }
+
+    // 조건이 참이면 then 블록, 거짓이면 else 블록을 실행한 뒤 if_end 블록에서 합쳐집니다.
+    fn generate_if(&mut self, statement: IfStatement) -> Result<(), AllError> {
+        let condition = self.generate_expression(statement.condition)?;
+
+        if condition.value_type != ValueType::Boolean {
+            return Err(AllError::CodegenError(SourceError::new(
+                206,
+                format!(
+                    "if condition must be a boolean, found {}",
+                    condition.value_type.name()
+                ),
+            )));
+        }
+
+        let then_label = self.current.new_label("if_then");
+        let else_label = self.current.new_label("if_else");
+        let end_label = self.current.new_label("if_end");
+
+        self.current.push(format!(
+            "  br {}, label %{}, label %{}",
+            condition.typed(),
+            then_label,
+            else_label
+        ));
+
+        self.current.start_block(&then_label);
+        self.generate_block(statement.then_body)?;
+        self.current.push(format!("  br label %{}", end_label));
+
+        self.current.start_block(&else_label);
+        if let Some(else_body) = statement.else_body {
+            self.generate_block(else_body)?;
+        }
+        self.current.push(format!("  br label %{}", end_label));
+
+        self.current.start_block(&end_label);
+
+        Ok(())
+    }
+
+    // 조건을 검사하는 while_cond 블록에서 시작해서, 본문을 실행할 때마다 다시 조건으로 돌아갑니다.
+    fn generate_while(&mut self, statement: WhileStatement) -> Result<(), AllError> {
+        let condition_label = self.current.new_label("while_cond");
+        let body_label = self.current.new_label("while_body");
+        let end_label = self.current.new_label("while_end");
+
+        self.current
+            .push(format!("  br label %{}", condition_label));
+        self.current.start_block(&condition_label);
+
+        let condition = self.generate_expression(statement.condition)?;
+
+        if condition.value_type != ValueType::Boolean {
+            return Err(AllError::CodegenError(SourceError::new(
+                207,
+                format!(
+                    "while condition must be a boolean, found {}",
+                    condition.value_type.name()
+                ),
+            )));
+        }
+
+        self.current.push(format!(
+            "  br {}, label %{}, label %{}",
+            condition.typed(),
+            body_label,
+            end_label
+        ));
+
+        self.current.start_block(&body_label);
+        self.generate_loop_body(statement.body, condition_label.clone(), end_label.clone())?;
+        self.current
+            .push(format!("  br label %{}", condition_label));
+
+        self.current.start_block(&end_label);
+
+        Ok(())
+    }
+
+    fn generate_loop(&mut self, statement: LoopStatement) -> Result<(), AllError> {
+        let body_label = self.current.new_label("loop_body");
+        let end_label = self.current.new_label("loop_end");
+
+        self.current.push(format!("  br label %{}", body_label));
+        self.current.start_block(&body_label);
+        self.generate_loop_body(statement.body, body_label.clone(), end_label.clone())?;
+        self.current.push(format!("  br label %{}", body_label));
+
+        self.current.start_block(&end_label);
+
+        Ok(())
+    }
+
+    // 반복 변수는 스택 슬롯에 두고, 본문이 끝나거나 continue하면 for_step에서 1 증가시킵니다.
+    // 반복 변수의 타입은 경계의 정수 타입(i64 또는 i32)이나 char입니다.
+    fn generate_for(&mut self, statement: ForStatement) -> Result<(), AllError> {
+        let start = self.generate_expression(statement.start)?;
+        let end = self.generate_expression(statement.end)?;
+
+        if !start.value_type.is_integral() || start.value_type != end.value_type {
+            return Err(self.error_at(
+                208,
+                statement.span,
+                format!(
+                    "range bounds must be integers, found {} and {}",
+                    start.value_type.name(),
+                    end.value_type.name()
+                ),
+            ));
+        }
+
+        // 반복 변수는 반복문이 끝나면 보이지 않습니다.
+        let variables = self.current.save_variables();
+        let value_type = start.value_type;
+        let llvm_type = value_type.llvm_type();
+        let pointer = self.current.define_variable(statement.variable, value_type);
+
+        let condition_label = self.current.new_label("for_cond");
+        let body_label = self.current.new_label("for_body");
+        let step_label = self.current.new_label("for_step");
+        let end_label = self.current.new_label("for_end");
+
+        self.current.push(format!(
+            "  store {}, {}* {}",
+            start.typed(),
+            llvm_type,
+            pointer
+        ));
+        self.current
+            .push(format!("  br label %{}", condition_label));
+
+        self.current.start_block(&condition_label);
+        let index = self.current.new_temporary();
+        let condition = self.current.new_temporary();
+        self.current.push(format!(
+            "  {} = load {}, {}* {}",
+            index, llvm_type, llvm_type, pointer
+        ));
+        self.current.push(format!(
+            "  {} = icmp slt {} {}, {}",
+            condition, llvm_type, index, end.operand
+        ));
+        self.current.push(format!(
+            "  br i1 {}, label %{}, label %{}",
+            condition, body_label, end_label
+        ));
+
+        self.current.start_block(&body_label);
+        let result = self.generate_loop_body(statement.body, step_label.clone(), end_label.clone());
+        self.current.push(format!("  br label %{}", step_label));
+
+        self.current.start_block(&step_label);
+        let index = self.current.new_temporary();
+        let next = self.current.new_temporary();
+        self.current.push(format!(
+            "  {} = load {}, {}* {}",
+            index, llvm_type, llvm_type, pointer
+        ));
+        self.current
+            .push(format!("  {} = add {} {}, 1", next, llvm_type, index));
+        self.current.push(format!(
+            "  store {} {}, {}* {}",
+            llvm_type, next, llvm_type, pointer
+        ));
+        self.current
+            .push(format!("  br label %{}", condition_label));
+
+        self.current.start_block(&end_label);
+
+        self.current.restore_variables(variables);
+
+        result
+    }
+
+    // 본문 안의 break는 break_label로, continue는 continue_label로 이동합니다.
+    fn generate_loop_body(
+        &mut self,
+        body: Vec<Statement>,
+        continue_label: String,
+        break_label: String,
+    ) -> Result<(), AllError> {
+        self.current.push_loop(LoopLabels {
+            continue_label,
+            break_label,
+        });
+        let result = self.generate_block(body);
+        self.current.pop_loop();
+
+        result
+    }
+
+    fn loop_labels(&self, keyword: &str) -> Result<LoopLabels, AllError> {
+        self.current.current_loop().cloned().ok_or_else(|| {
+            AllError::CodegenError(SourceError::new(
+                209,
+                format!("{} outside of a loop", keyword),
+            ))
+        })
+    }
+
+    // 이동한 뒤의 코드는 실행되지 않지만, 블록을 새로 열어야 올바른 IR이 됩니다.
+    fn generate_jump(&mut self, target: &str) {
+        self.current.push(format!("  br label %{}", target));
+        let label = self.current.new_label("after_jump");
+        self.current.start_block(&label);
+    }
+
+    fn generate_assign(&mut self, statement: AssignStatement) -> Result<(), AllError> {
+        let value = self.generate_expression(statement.stored_value())?;
+
+        let variable = if let Some(variable) = self
+            .current
+            .get_variable(statement.name)
+            .or_else(|| self.global_variables.get(&statement.name))
+        {
+            variable.clone()
+        } else {
+            return Err(self.error_at(
+                210,
+                statement.span,
+                format!("undefined variable {}", statement.name),
+            ));
+        };
+
+        if variable.value_type != value.value_type {
+            return Err(self.error_at(
+                203,
+                statement.span,
+                format!(
+                    "cannot assign {} to variable {} of type {}",
+                    value.value_type.name(),
+                    statement.name,
+                    variable.value_type.name()
+                ),
+            ));
+        }
+
+        self.current.push(format!(
+            "  store {}, {}* {}",
+            value.typed(),
+            variable.value_type.llvm_type(),
+            variable.pointer
+        ));
+
+        Ok(())
+    }
+
+    // 블록 안에서 선언한 변수는 블록이 끝나면 보이지 않습니다.
+    fn generate_block(&mut self, statements: Vec<Statement>) -> Result<(), AllError> {
+        let variables = self.current.save_variables();
+        let global_variables = self.global_variables.clone();
+        self.block_depth += 1;
+
+        let mut result = Ok(());
+        for statement in statements {
+            result = self.generate_statement(statement);
+
+            if result.is_err() {
+                break;
+            }
+        }
+
+        self.block_depth -= 1;
+        self.current.restore_variables(variables);
+        self.global_variables = global_variables;
+
+        result
+    }
+
+    // 사용자 정의 함수는 libc 심볼과 겹치지 않도록 foolang. 접두사를 붙입니다.
+    pub(crate) fn function_symbol(name: &str) -> String {
+        identifier('@', &format!("foolang.{}", name))
+    }
+
+    // 다른 모듈에 정의된 함수의 선언입니다. 큰 구조체는 generate_function과 같이 byval, sret 포인터로 주고받습니다.
+    pub(crate) fn function_declaration(
+        &self,
+        name: Symbol,
+        signature: &FunctionSignature,
+    ) -> String {
+        let mut parameters = vec![];
+
+        let return_type = if self.structs.is_passed_by_pointer(signature.return_type) {
+            parameters.push(self.pointer_type("sret", signature.return_type));
+            "void".into()
+        } else {
+            signature.return_type.llvm_type()
+        };
+
+        for value_type in &signature.parameters {
+            if self.structs.is_passed_by_pointer(*value_type) {
+                parameters.push(self.pointer_type("byval", *value_type));
+            } else {
+                parameters.push(value_type.llvm_type().into_owned());
+            }
+        }
+
+        format!(
+            "declare {} {}({})",
+            return_type,
+            Self::function_symbol(&name.text()),
+            parameters.join(", ")
+        )
+    }
+
+    pub(crate) fn define_function_signature(
+        &mut self,
+        function: &FunctionDefinitionStatement,
+    ) -> Result<(), AllError> {
+        let signature = self
+            .structs
+            .signature(function)
+            .map_err(|error| AllError::CodegenError(error.with_file(self.source_name.as_str())))?;
+        self.function_signatures.insert(function.name, signature);
+
+        Ok(())
+    }
+
+    // 타입을 적지 않은 매개변수와 반환값은 i64입니다.
+    // 16바이트 이하의 구조체는 값 그대로 레지스터로 주고받고, 더 큰 구조체는 byval, sret 포인터로 주고받습니다.
+    fn generate_function(&mut self, function: FunctionDefinitionStatement) -> Result<(), AllError> {
+        // 함수 안에서 정의한 함수도 최상위 함수와 같이 취급합니다.
+        self.define_function_signature(&function)?;
+        let signature = self.function_signatures[&function.name].clone();
+
+        let outer = std::mem::replace(&mut self.current, FunctionContext::new());
+        let outer_return_type = std::mem::replace(&mut self.return_type, signature.return_type);
+        self.function_depth += 1;
+
+        let by_pointer = self.structs.is_passed_by_pointer(signature.return_type);
+
+        let mut parameters = vec![];
+        if by_pointer {
+            parameters.push(self.pointer_argument("sret", signature.return_type, RETURN_SLOT));
+        }
+
+        for (parameter, value_type) in function.parameters.iter().zip(signature.parameters) {
+            let argument = identifier('%', &format!("param.{}", parameter.name));
+            let pointer = self.current.define_variable(parameter.name, value_type);
+            let llvm_type = value_type.llvm_type();
+
+            // byval로 받은 구조체는 호출하는 쪽이 만든 복사본이므로 지역 변수 슬롯으로 옮겨 담습니다.
+            let value = if self.structs.is_passed_by_pointer(value_type) {
+                parameters.push(self.pointer_argument("byval", value_type, &argument));
+
+                let register = self.current.new_temporary();
+                self.current.push(format!(
+                    "  {} = load {}, {}* {}",
+                    register, llvm_type, llvm_type, argument
+                ));
+                register
+            } else {
+                parameters.push(format!("{} {}", llvm_type, argument));
+                argument
+            };
+
+            self.current.push(format!(
+                "  store {} {}, {}* {}",
+                llvm_type, value, llvm_type, pointer
+            ));
+        }
+
+        let mut result = Ok(());
+        for statement in function.body {
+            result = self.generate_statement(statement);
+
+            if result.is_err() {
+                break;
+            }
+        }
+
+        let context = std::mem::replace(&mut self.current, outer);
+        self.return_type = outer_return_type;
+        self.function_depth -= 1;
+        result?;
+
+        // 모든 경로가 return으로 끝나는지는 의미 분석에서 검사했으므로, 마지막 블록에는 도달하지 않습니다.
+        let return_type = if by_pointer {
+            "void".into()
+        } else {
+            signature.return_type.llvm_type()
+        };
+
+        let mut codes = context.finish(
+            format!(
+                "define {} {}({}) {{",
+                return_type,
+                Self::function_symbol(&function.name.text()),
+                parameters.join(", ")
+            ),
+            "unreachable",
+        );
+        self.functions.append(&mut codes);
+
+        Ok(())
+    }
+}
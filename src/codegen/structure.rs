@@ -0,0 +1,280 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    ast::statement::{
+        define_function::FunctionDefinitionStatement, define_struct::StructDefinitionStatement,
+        Statement,
+    },
+    error::all_error::source_error::SourceError,
+    utils::interner::Symbol,
+};
+
+use super::{function::FunctionSignature, value::ValueType};
+
+// x86-64 System V ABI에서 레지스터로 전달할 수 있는 구조체의 최대 크기(바이트)입니다.
+pub const REGISTER_STRUCT_SIZE: usize = 16;
+
+// 구조체 하나의 필드 목록입니다. 필드는 선언한 순서대로 메모리에 놓입니다.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StructType {
+    pub name: Symbol,
+    pub fields: Vec<(Symbol, ValueType)>,
+}
+
+impl StructType {
+    // 필드의 순서(extractvalue, insertvalue의 인덱스)와 타입입니다.
+    pub fn field(&self, name: Symbol) -> Option<(usize, ValueType)> {
+        self.fields
+            .iter()
+            .position(|(field, _)| *field == name)
+            .map(|index| (index, self.fields[index].1))
+    }
+
+    // %foolang.이름 = type { ... }
+    pub fn definition(&self) -> String {
+        format!(
+            "{} = type {{ {} }}",
+            ValueType::Struct(self.name).llvm_type(),
+            self.fields
+                .iter()
+                .map(|(_, value_type)| value_type.llvm_type())
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    }
+}
+
+// 한 모듈에서 선언한 구조체 목록입니다. 의미 분석과 코드 생성이 같은 규칙으로 타입 이름을 해석합니다.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct StructTable {
+    structs: HashMap<Symbol, StructType>,
+    // 선언한 순서
+    names: Vec<Symbol>,
+}
+
+impl StructTable {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // 최상위의 struct 선언으로 표를 만듭니다. 선언보다 앞에서도 구조체 이름을 쓸 수 있습니다.
+    pub fn from_statements(statements: &[Statement]) -> Result<Self, SourceError> {
+        let definitions = statements
+            .iter()
+            .filter_map(|statement| match statement {
+                Statement::DefineStruct(definition) => Some(definition),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        let mut table = Self::new();
+
+        for definition in &definitions {
+            if table.names.contains(&definition.name) {
+                return Err(SourceError::new(
+                    301,
+                    format!("struct {} is already defined", definition.name),
+                )
+                .with_span(definition.span));
+            }
+
+            table.names.push(definition.name);
+        }
+
+        for definition in &definitions {
+            let struct_type = table.resolve_fields(definition)?;
+            table.structs.insert(definition.name, struct_type);
+        }
+
+        for definition in &definitions {
+            if table.contains(definition.name, definition.name, &mut HashSet::new()) {
+                return Err(SourceError::new(
+                    302,
+                    format!("recursive struct {} has infinite size", definition.name),
+                )
+                .with_span(definition.span)
+                .with_help("a struct cannot contain itself by value"));
+            }
+        }
+
+        Ok(table)
+    }
+
+    fn resolve_fields(
+        &self,
+        definition: &StructDefinitionStatement,
+    ) -> Result<StructType, SourceError> {
+        let mut fields: Vec<(Symbol, ValueType)> = vec![];
+
+        for field in &definition.fields {
+            if fields.iter().any(|(name, _)| *name == field.name) {
+                return Err(SourceError::new(
+                    303,
+                    format!(
+                        "field {} is declared more than once in struct {}",
+                        field.name, definition.name
+                    ),
+                )
+                .with_span(field.span));
+            }
+
+            let value_type = self.resolve_type(field.type_name).ok_or_else(|| {
+                SourceError::new(
+                    304,
+                    format!(
+                        "unknown type {} for field {} of struct {}",
+                        field.type_name, field.name, definition.name
+                    ),
+                )
+                .with_span(field.span)
+            })?;
+
+            fields.push((field.name, value_type));
+        }
+
+        Ok(StructType {
+            name: definition.name,
+            fields,
+        })
+    }
+
+    // name 구조체가 값으로(필드를 따라가며) target 구조체를 포함하는지 검사합니다.
+    fn contains(&self, name: Symbol, target: Symbol, visited: &mut HashSet<Symbol>) -> bool {
+        if !visited.insert(name) {
+            return false;
+        }
+
+        self.structs[&name]
+            .fields
+            .iter()
+            .any(|(_, value_type)| match value_type {
+                ValueType::Struct(field) => {
+                    *field == target || self.contains(*field, target, visited)
+                }
+                _ => false,
+            })
+    }
+
+    pub fn get(&self, name: Symbol) -> Option<&StructType> {
+        self.structs.get(&name)
+    }
+
+    // 기본 타입 이름이나 선언된 구조체 이름을 값 타입으로 바꿉니다.
+    pub fn resolve_type(&self, name: Symbol) -> Option<ValueType> {
+        ValueType::from_name(&name.text()).or_else(|| {
+            self.names
+                .contains(&name)
+                .then_some(ValueType::Struct(name))
+        })
+    }
+
+    // 타입을 생략한 매개변수와 반환값은 i64입니다.
+    pub fn signature(
+        &self,
+        function: &FunctionDefinitionStatement,
+    ) -> Result<FunctionSignature, SourceError> {
+        let mut parameters = vec![];
+
+        for parameter in &function.parameters {
+            let value_type = match parameter.type_name {
+                Some(type_name) => self.resolve_type(type_name).ok_or_else(|| {
+                    SourceError::new(
+                        305,
+                        format!(
+                            "unknown type {} for parameter {} of function {}",
+                            type_name, parameter.name, function.name
+                        ),
+                    )
+                    .with_span(function.span)
+                })?,
+                None => ValueType::Integer,
+            };
+
+            parameters.push(value_type);
+        }
+
+        let return_type = match function.return_type {
+            Some(return_type) => self.resolve_type(return_type).ok_or_else(|| {
+                SourceError::new(
+                    306,
+                    format!(
+                        "unknown return type {} for function {}",
+                        return_type, function.name
+                    ),
+                )
+                .with_span(function.span)
+            })?,
+            None => ValueType::Integer,
+        };
+
+        Ok(FunctionSignature {
+            parameters,
+            return_type,
+        })
+    }
+
+    // 시그니처에 이 모듈에서 선언하지 않은 구조체가 있으면 그 이름을 반환합니다.
+    pub fn foreign_struct(&self, signature: &FunctionSignature) -> Option<Symbol> {
+        signature
+            .parameters
+            .iter()
+            .chain([&signature.return_type])
+            .find_map(|value_type| match value_type {
+                ValueType::Struct(name) if !self.structs.contains_key(name) => Some(*name),
+                _ => None,
+            })
+    }
+
+    // 모듈 맨 앞에 둘 타입 정의입니다.
+    pub fn definitions(&self) -> Vec<String> {
+        self.names
+            .iter()
+            .map(|name| self.structs[name].definition())
+            .collect()
+    }
+
+    // LLVM이 x86-64에서 잡는 메모리 배치와 같은 크기입니다. 필드 사이에는 정렬을 위한 빈 공간이 들어갑니다.
+    pub fn size_of(&self, value_type: ValueType) -> usize {
+        match value_type {
+            ValueType::Integer | ValueType::Float | ValueType::Pointer => 8,
+            ValueType::Int32 | ValueType::Char | ValueType::Float32 => 4,
+            ValueType::Boolean => 1,
+            ValueType::String => 16,
+            ValueType::Void => 0,
+            ValueType::Struct(name) => {
+                let mut size = 0;
+
+                for (_, field) in &self.structs[&name].fields {
+                    size = align_to(size, self.align_of(*field)) + self.size_of(*field);
+                }
+
+                align_to(size, self.align_of(value_type))
+            }
+        }
+    }
+
+    pub fn align_of(&self, value_type: ValueType) -> usize {
+        match value_type {
+            ValueType::Integer | ValueType::Float | ValueType::Pointer | ValueType::String => 8,
+            ValueType::Int32 | ValueType::Char | ValueType::Float32 => 4,
+            ValueType::Boolean | ValueType::Void => 1,
+            ValueType::Struct(name) => self.structs[&name]
+                .fields
+                .iter()
+                .map(|(_, field)| self.align_of(*field))
+                .max()
+                .unwrap_or(1),
+        }
+    }
+
+    // 작은 구조체는 값 그대로 레지스터로 주고받고,
+    // 큰 구조체는 호출하는 쪽의 복사본을 가리키는 포인터(byval, sret)로 주고받습니다.
+    pub fn is_passed_by_pointer(&self, value_type: ValueType) -> bool {
+        matches!(value_type, ValueType::Struct(_))
+            && self.size_of(value_type) > REGISTER_STRUCT_SIZE
+    }
+}
+
+fn align_to(offset: usize, align: usize) -> usize {
+    offset.div_ceil(align) * align
+}
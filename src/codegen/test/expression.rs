@@ -0,0 +1,615 @@
+#![cfg(test)]
+
+use crate::{
+    ast::{
+        expression::{
+            binary::BinaryExpression, call::CallExpression, literal::LiteralExpression,
+            unary::UnaryExpression, variable::VariableExpression, Expression,
+        },
+        operator::{binary::BinaryOperator, unary::UnaryOperator},
+        statement::{define_variable::VariableDefinitionStatement, Statement},
+    },
+    codegen::CodeGenerator,
+    compiler::parse,
+    error::all_error::AllError,
+    semantic::SemanticAnalyzer,
+    utils::testing::run_llvm_ir,
+};
+
+fn integer(value: i64) -> Expression {
+    LiteralExpression::Integer(value).into()
+}
+
+fn string(value: &str) -> Expression {
+    LiteralExpression::String(value.into()).into()
+}
+
+fn variable(name: &str) -> Expression {
+    VariableExpression {
+        name: name.into(),
+        span: Default::default(),
+    }
+    .into()
+}
+
+fn binary(lhs: Expression, operator: BinaryOperator, rhs: Expression) -> Expression {
+    BinaryExpression {
+        lhs: Box::new(lhs),
+        rhs: Box::new(rhs),
+        operator,
+        span: Default::default(),
+    }
+    .into()
+}
+
+fn call(name: &str, arguments: Vec<Expression>) -> Statement {
+    Expression::from(CallExpression {
+        function_name: name.into(),
+        arguments,
+        span: Default::default(),
+    })
+    .into()
+}
+
+fn define(name: &str, value: Expression) -> Statement {
+    VariableDefinitionStatement {
+        mutable: false,
+        name: name.into(),
+        value: Some(value),
+        type_name: None,
+        span: Default::default(),
+    }
+    .into()
+}
+
+// 생성된 코드에서 진입점 함수 부분만 잘라냅니다.
+fn entry_function(statements: Vec<Statement>) -> Vec<String> {
+    let mut generator = CodeGenerator::new();
+    generator.set_statements(statements);
+    let codes = generator.generate().unwrap();
+
+    let start = codes
+        .iter()
+        .position(|line| line.starts_with("define i64 @__foolang_main"))
+        .unwrap();
+    let end = start + codes[start..].iter().position(|line| line == "}").unwrap();
+    codes[start..=end].to_vec()
+}
+
+#[test]
+pub fn integer_arithmetic_structure() {
+    let codes = entry_function(vec![define(
+        "foo",
+        binary(
+            integer(1),
+            BinaryOperator::Add,
+            binary(integer(2), BinaryOperator::Multiply, integer(3)),
+        ),
+    )]);
+
+    assert_eq!(
+        codes,
+        vec![
+            "define i64 @__foolang_main() {",
+            "entry:",
+            "  %t0 = mul i64 2, 3",
+            "  %t1 = add i64 1, %t0",
+            "  store i64 %t1, i64* @foo.0",
+            "  ret i64 0",
+            "}",
+        ]
+    );
+}
+
+#[test]
+pub fn variable_and_call_structure() {
+    let codes = entry_function(vec![
+        define("foo", integer(10)),
+        call(
+            "println",
+            vec![binary(
+                variable("foo"),
+                BinaryOperator::LessThan,
+                integer(20),
+            )],
+        ),
+    ]);
+
+    assert_eq!(
+        codes,
+        vec![
+            "define i64 @__foolang_main() {",
+            "entry:",
+            "  %t0 = load i64, i64* @foo.0",
+            "  %t1 = icmp slt i64 %t0, 20",
+            "  call void @__foolang_println_bool(i1 %t1)",
+            "  ret i64 0",
+            "}",
+        ]
+    );
+}
+
+#[test]
+pub fn negative_literals_are_constants() {
+    let negative = |value: Expression| -> Expression {
+        UnaryExpression {
+            operator: UnaryOperator::Minus,
+            operand: Box::new(value),
+            span: Default::default(),
+        }
+        .into()
+    };
+
+    let mut generator = CodeGenerator::new();
+    generator.set_statements(vec![
+        define("foo", negative(integer(5))),
+        define("bar", negative(LiteralExpression::Float(2.5).into())),
+        define("baz", negative(variable("foo"))),
+    ]);
+    let codes = generator.generate().unwrap();
+
+    assert!(codes.contains(&"@foo.0 = internal global i64 -5".to_owned()));
+    assert!(codes.contains(&format!(
+        "@bar.1 = internal global double 0x{:016X}",
+        (-2.5f64).to_bits()
+    )));
+    assert!(codes.contains(&"  %t1 = sub i64 0, %t0".to_owned()));
+}
+
+#[test]
+pub fn string_constants_are_pooled() {
+    let mut generator = CodeGenerator::new();
+    generator.set_statements(vec![
+        call("println", vec![string("hello")]),
+        call("println", vec![string("hello")]),
+        call("println", vec![string("world")]),
+    ]);
+    let codes = generator.generate().unwrap();
+
+    let globals = codes
+        .iter()
+        .filter(|line| line.starts_with("@__foolang.str."))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        globals,
+        vec![
+            r#"@__foolang.str.5.a430d84680aabd0b = linkonce_odr unnamed_addr constant [5 x i8] c"hello", comdat"#,
+            r#"@__foolang.str.5.4f59ff5e730c8af3 = linkonce_odr unnamed_addr constant [5 x i8] c"world", comdat"#,
+        ]
+    );
+    assert!(codes.contains(&"$__foolang.str.5.a430d84680aabd0b = comdat any".to_owned()));
+}
+
+#[test]
+pub fn type_errors() {
+    for statements in [
+        vec![define(
+            "foo",
+            binary(integer(1), BinaryOperator::Add, string("a")),
+        )],
+        vec![call("println", vec![variable("undefined")])],
+        vec![call("undefined", vec![])],
+        vec![call("alloc", vec![string("a")])],
+        vec![define(
+            "foo",
+            Expression::from(call_expression("println", vec![integer(1)])),
+        )],
+    ] {
+        let mut generator = CodeGenerator::new();
+        generator.set_statements(statements);
+
+        assert!(matches!(
+            generator.generate(),
+            Err(AllError::CodegenError(_))
+        ));
+    }
+}
+
+fn call_expression(name: &str, arguments: Vec<Expression>) -> CallExpression {
+    CallExpression {
+        function_name: name.into(),
+        arguments,
+        span: Default::default(),
+    }
+}
+
+// 생성한 프로그램을 실제로 빌드해서 실행합니다.
+fn run(statements: Vec<Statement>, no_std: bool) -> Option<std::process::Output> {
+    let mut generator = CodeGenerator::new();
+    generator.set_statements(statements);
+    generator.set_no_std(no_std);
+
+    run_llvm_ir(&generator.generate().unwrap(), no_std, &[])
+}
+
+#[test]
+pub fn arithmetic_and_strings() {
+    for no_std in [false, true] {
+        let statements = vec![
+            define(
+                "a",
+                binary(integer(7), BinaryOperator::Multiply, integer(6)),
+            ),
+            define(
+                "b",
+                binary(variable("a"), BinaryOperator::Modulo, integer(5)),
+            ),
+            call("println", vec![variable("a")]),
+            call(
+                "println",
+                vec![Expression::from(UnaryExpression {
+                    operator: UnaryOperator::Minus,
+                    operand: Box::new(variable("b")),
+                    span: Default::default(),
+                })],
+            ),
+            call(
+                "println",
+                vec![binary(
+                    LiteralExpression::Float(1.5).into(),
+                    BinaryOperator::Divide,
+                    LiteralExpression::Float(4.0).into(),
+                )],
+            ),
+            call(
+                "println",
+                vec![binary(string("foo"), BinaryOperator::Add, string("bar"))],
+            ),
+            call(
+                "println",
+                vec![binary(
+                    string("abc"),
+                    BinaryOperator::LessThan,
+                    string("abd"),
+                )],
+            ),
+            call(
+                "println",
+                vec![Expression::from(call_expression(
+                    "len",
+                    vec![binary(string("foo"), BinaryOperator::Add, string("한글"))],
+                ))],
+            ),
+        ];
+
+        if let Some(output) = run(statements, no_std) {
+            assert_eq!(
+                String::from_utf8_lossy(&output.stdout),
+                "42\n-2\n0.375000\nfoobar\ntrue\n9\n"
+            );
+        }
+    }
+}
+
+// 실수 사칙연산과 비교가 double 명령어로 계산되는지 실행해서 확인합니다.
+#[test]
+pub fn float_arithmetic() {
+    let float = |value: f64| -> Expression { LiteralExpression::Float(value).into() };
+
+    for no_std in [false, true] {
+        let statements = vec![
+            define("a", float(2.5)),
+            define("b", float(0.5)),
+            call(
+                "println",
+                vec![binary(variable("a"), BinaryOperator::Add, variable("b"))],
+            ),
+            call(
+                "println",
+                vec![binary(
+                    variable("a"),
+                    BinaryOperator::Subtract,
+                    variable("b"),
+                )],
+            ),
+            call(
+                "println",
+                vec![binary(
+                    variable("a"),
+                    BinaryOperator::Multiply,
+                    variable("b"),
+                )],
+            ),
+            call(
+                "println",
+                vec![binary(variable("a"), BinaryOperator::Divide, variable("b"))],
+            ),
+            call(
+                "println",
+                vec![binary(
+                    variable("a"),
+                    BinaryOperator::GreaterThan,
+                    variable("b"),
+                )],
+            ),
+        ];
+
+        if let Some(output) = run(statements, no_std) {
+            assert_eq!(
+                String::from_utf8_lossy(&output.stdout),
+                "3.000000\n2.000000\n1.250000\n5.000000\ntrue\n"
+            );
+        }
+    }
+}
+
+// 정수 비교는 부호 있는 비교여야 하므로 음수와 양수를 섞어서 모든 비교 연산자를 확인합니다.
+#[test]
+pub fn signed_comparisons() {
+    let operators = [
+        BinaryOperator::Equal,
+        BinaryOperator::NotEqual,
+        BinaryOperator::LessThan,
+        BinaryOperator::LessThanOrEqual,
+        BinaryOperator::GreaterThan,
+        BinaryOperator::GreaterThanOrEqual,
+    ];
+
+    let mut statements = vec![];
+    for (lhs, rhs) in [(-1, 1), (1, 1), (1, -1)] {
+        for operator in operators.iter() {
+            statements.push(call(
+                "print",
+                vec![binary(integer(lhs), operator.clone(), integer(rhs))],
+            ));
+        }
+        statements.push(call("println", vec![string("")]));
+    }
+
+    if let Some(output) = run(statements, false) {
+        assert_eq!(
+            String::from_utf8_lossy(&output.stdout),
+            concat!(
+                "falsetruetruetruefalsefalse\n",
+                "truefalsefalsetruefalsetrue\n",
+                "falsetruefalsefalsetruetrue\n",
+            )
+        );
+    }
+}
+
+#[test]
+pub fn bitwise_operations() {
+    for no_std in [false, true] {
+        let statements = vec![
+            define("a", integer(0b1100)),
+            define("b", integer(0b1010)),
+            call(
+                "println",
+                vec![binary(
+                    variable("a"),
+                    BinaryOperator::BitwiseAnd,
+                    variable("b"),
+                )],
+            ),
+            call(
+                "println",
+                vec![binary(
+                    variable("a"),
+                    BinaryOperator::BitwiseOr,
+                    variable("b"),
+                )],
+            ),
+            call(
+                "println",
+                vec![binary(
+                    variable("a"),
+                    BinaryOperator::BitwiseXor,
+                    variable("b"),
+                )],
+            ),
+            call(
+                "println",
+                vec![binary(variable("a"), BinaryOperator::LeftShift, integer(2))],
+            ),
+            // 산술 시프트이므로 부호가 유지됩니다.
+            call(
+                "println",
+                vec![binary(integer(-16), BinaryOperator::RightShift, integer(2))],
+            ),
+            // 시프트 양은 하위 6비트만 사용합니다.
+            call(
+                "println",
+                vec![binary(integer(1), BinaryOperator::LeftShift, integer(65))],
+            ),
+        ];
+
+        if let Some(output) = run(statements, no_std) {
+            assert_eq!(
+                String::from_utf8_lossy(&output.stdout),
+                "8\n14\n6\n48\n-4\n2\n"
+            );
+        }
+    }
+}
+
+#[test]
+pub fn division_by_zero_panics() {
+    let statements = vec![
+        define("zero", integer(0)),
+        call(
+            "println",
+            vec![binary(integer(1), BinaryOperator::Divide, variable("zero"))],
+        ),
+    ];
+
+    if let Some(output) = run(statements, true) {
+        assert_eq!(output.status.code(), Some(101));
+        assert_eq!(
+            String::from_utf8_lossy(&output.stderr),
+            "panic at main.foo:0:0: attempt to divide by zero\n"
+        );
+    }
+}
+
+fn boolean(value: bool) -> Expression {
+    LiteralExpression::Boolean(value).into()
+}
+
+#[test]
+pub fn logical_structure() {
+    let codes = entry_function(vec![define(
+        "foo",
+        binary(
+            boolean(true),
+            BinaryOperator::And,
+            binary(boolean(false), BinaryOperator::Or, boolean(true)),
+        ),
+    )]);
+
+    assert_eq!(
+        codes,
+        vec![
+            "define i64 @__foolang_main() {",
+            "entry:",
+            "  br i1 true, label %logical_rhs.0, label %logical_end.1",
+            "logical_rhs.0:",
+            "  br i1 false, label %logical_end.3, label %logical_rhs.2",
+            "logical_rhs.2:",
+            "  br label %logical_end.3",
+            "logical_end.3:",
+            "  %t4 = phi i1 [ true, %logical_rhs.0 ], [ true, %logical_rhs.2 ]",
+            "  br label %logical_end.1",
+            "logical_end.1:",
+            "  %t5 = phi i1 [ false, %entry ], [ %t4, %logical_end.3 ]",
+            "  store i1 %t5, i1* @foo.0",
+            "  ret i64 0",
+            "}",
+        ]
+    );
+}
+
+// !는 i1과 true를 xor해서 뒤집습니다.
+#[test]
+pub fn not_structure() {
+    let codes = entry_function(vec![define(
+        "foo",
+        UnaryExpression {
+            operator: UnaryOperator::Not,
+            operand: Box::new(boolean(true)),
+            span: Default::default(),
+        }
+        .into(),
+    )]);
+
+    assert_eq!(codes[2], "  %t0 = xor i1 true, true");
+}
+
+// 선택될 수 없는 갈래는 switch에 넣지 않고, _가 없으면 match_default로 갑니다.
+#[test]
+pub fn match_structure() {
+    let codes =
+        entry_function(parse("let a = match true { true => 1, false => 2, true => 3 }").unwrap());
+
+    assert_eq!(
+        codes,
+        vec![
+            "define i64 @__foolang_main() {",
+            "entry:",
+            "  switch i1 true, label %match_default.3 [ i1 true, label %match_arm.1 i1 false, label %match_arm.2 ]",
+            "match_arm.1:",
+            "  br label %match_end.0",
+            "match_arm.2:",
+            "  br label %match_end.0",
+            "match_default.3:",
+            "  unreachable",
+            "match_end.0:",
+            "  %t4 = phi i64 [ 1, %match_arm.1 ], [ 2, %match_arm.2 ]",
+            "  store i64 %t4, i64* @a.0",
+            "  ret i64 0",
+            "}",
+        ]
+    );
+}
+
+// i32로 정해진 정수 리터럴과 변수는 i32 명령어로 계산하고, 내장 함수에는 i64로 넓혀서 넘깁니다.
+#[test]
+pub fn int32_structure() {
+    let mut statements = parse(concat!(
+        "let a: i32 = 1\n",
+        "let b = a << 40\n",
+        "println(-b)\n",
+        "for i in 0..a { }\n",
+    ))
+    .unwrap();
+
+    let mut analyzer = SemanticAnalyzer::new();
+    analyzer.analyze(&statements).unwrap();
+    analyzer.annotate(&mut statements);
+
+    let mut generator = CodeGenerator::new();
+    generator.set_statements(statements);
+    let codes = generator.generate().unwrap();
+
+    for line in [
+        "@a.0 = internal global i32 1",
+        "  %t1 = and i32 40, 31",
+        "  %t2 = shl i32 %t0, %t1",
+        "  %t4 = sub i32 0, %t3",
+        "  %t5 = sext i32 %t4 to i64",
+        "  %t13 = icmp slt i32 %t12, %t6",
+        "  %t15 = add i32 %t14, 1",
+    ] {
+        assert!(codes.iter().any(|code| code == line), "{}", line);
+    }
+}
+
+// f32 상수는 double의 비트로 적고, 내장 함수에는 f64로 넓혀서 넘깁니다.
+#[test]
+pub fn float32_structure() {
+    let mut generator = CodeGenerator::new();
+    generator.set_statements(
+        parse(concat!(
+            "let a = 0.1f32\n",
+            "let b = a * -2.0f32\n",
+            "println(b as f64 as f32)\n",
+        ))
+        .unwrap(),
+    );
+    let codes = generator.generate().unwrap();
+
+    for line in [
+        "@a.0 = internal global float 0x3FB99999A0000000",
+        "  %t1 = fmul float %t0, 0xC000000000000000",
+        "  %t3 = fpext float %t2 to double",
+        "  %t4 = fptrunc double %t3 to float",
+        "  %t5 = fpext float %t4 to double",
+    ] {
+        assert!(codes.iter().any(|code| code == line), "{}", line);
+    }
+}
+
+// as는 크기와 부호에 맞는 변환 명령어 하나가 됩니다. 같은 타입으로의 변환은 명령어를 만들지 않습니다.
+#[test]
+pub fn cast_structure() {
+    let mut generator = CodeGenerator::new();
+    generator.set_statements(
+        parse(concat!(
+            "fn convert(a: i32, b) -> i64 {\n",
+            "    let c = a as i64 + b as i32 as i64\n",
+            "    let d = (c as f64) as i32 as i64\n",
+            "    return d + (b > 0) as i64 + c as i64\n",
+            "}\n",
+        ))
+        .unwrap(),
+    );
+    let codes = generator.generate().unwrap();
+
+    for line in [
+        "  %t3 = sext i32 %t2 to i64",
+        "  %t5 = trunc i64 %t4 to i32",
+        "  %t6 = sext i32 %t5 to i64",
+        "  %t10 = sitofp i64 %t9 to double",
+        "  %t11 = fptosi double %t10 to i32",
+        "  %t12 = sext i32 %t11 to i64",
+        "  %t17 = zext i1 %t16 to i64",
+    ] {
+        assert!(codes.iter().any(|code| code == line), "{}", line);
+    }
+
+    // c as i64
+    assert!(codes
+        .iter()
+        .any(|code| code == "  %t20 = add i64 %t18, %t19"));
+}
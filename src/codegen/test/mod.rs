@@ -0,0 +1,4 @@
+pub(crate) mod expression;
+pub(crate) mod snapshot;
+pub(crate) mod statement;
+pub(crate) mod structure;
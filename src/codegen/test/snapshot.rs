@@ -0,0 +1,78 @@
+#![cfg(test)]
+
+use crate::utils::testing::{assert_snapshot, objdump, with_object};
+
+// 생성한 코드를 linux-amd64 오브젝트 파일로 만들고, 섹션 헤더와 심볼, 재배치를 스냅샷 파일과 비교합니다.
+// 오브젝트 파일의 경로는 테스트마다 다르므로 main.o로 바꿉니다.
+// llc나 objdump가 없으면 비교하지 않습니다.
+fn assert_program_snapshot(name: &str, source: &str) {
+    let dump = with_object(source, false, |path| {
+        objdump(path, &["-h", "-t", "-r"])
+            .map(|dump| dump.replace(&path.display().to_string(), "main.o"))
+    })
+    .flatten();
+
+    if let Some(dump) = dump {
+        assert_snapshot(
+            &format!("src/codegen/test/snapshots/{}.objdump", name),
+            &dump,
+        );
+    }
+}
+
+#[test]
+pub fn arithmetic() {
+    assert_program_snapshot(
+        "arithmetic",
+        concat!(
+            "let a = 7 * 6\n",
+            "mut b: i32 = 1\n",
+            "b += 2\n",
+            "println(a / 4 - a % 5)\n",
+            "println(b as i64 + a)\n",
+            "println(1.5 * 2.0)\n",
+        ),
+    );
+}
+
+#[test]
+pub fn control_flow() {
+    assert_program_snapshot(
+        "control_flow",
+        concat!(
+            "fn fib(n) -> i64 {\n",
+            "    if n < 2 {\n",
+            "        return n\n",
+            "    }\n",
+            "    return fib(n - 1) + fib(n - 2)\n",
+            "}\n",
+            "for i in 0..5 {\n",
+            "    match i % 3 {\n",
+            "        0 => println(fib(i)),\n",
+            "        _ => println(i),\n",
+            "    }\n",
+            "}\n",
+            "mut i = 0\n",
+            "while i < 3 {\n",
+            "    i += 1\n",
+            "}\n",
+        ),
+    );
+}
+
+#[test]
+pub fn structs_and_strings() {
+    assert_program_snapshot(
+        "structs_and_strings",
+        concat!(
+            "struct Pair { a: i64, b: i64 }\n",
+            "struct Named { pair: Pair, name: String }\n",
+            "fn swap(p: Pair) -> Pair {\n",
+            "    return Pair { a: p.b, b: p.a }\n",
+            "}\n",
+            "let named = Named { pair: swap(Pair { a: 1, b: 2 }), name: \"pair\" }\n",
+            "println(named.name + \"!\")\n",
+            "println(named.pair.a)\n",
+        ),
+    );
+}
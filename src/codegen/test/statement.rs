@@ -0,0 +1,385 @@
+#![cfg(test)]
+
+use crate::{
+    ast::{
+        expression::{
+            binary::BinaryExpression, call::CallExpression, literal::LiteralExpression,
+            variable::VariableExpression, Expression,
+        },
+        operator::binary::BinaryOperator,
+        statement::{
+            define_function::FunctionDefinitionStatement,
+            define_variable::VariableDefinitionStatement, for_statement::ForStatement,
+            if_statement::IfStatement, loop_statement::LoopStatement, Statement,
+        },
+    },
+    codegen::CodeGenerator,
+    utils::testing::run_llvm_ir,
+};
+
+fn variable(name: &str) -> Expression {
+    VariableExpression {
+        name: name.into(),
+        span: Default::default(),
+    }
+    .into()
+}
+
+fn call(name: &str, arguments: Vec<Expression>) -> Expression {
+    CallExpression {
+        function_name: name.into(),
+        arguments,
+        span: Default::default(),
+    }
+    .into()
+}
+
+// fn add(a, b) { return a + b }
+fn add_function() -> Statement {
+    Statement::DefineFunction(FunctionDefinitionStatement {
+        name: "add".into(),
+        parameters: vec!["a".into(), "b".into()],
+        return_type: None,
+        body: vec![Statement::Return(
+            BinaryExpression {
+                lhs: Box::new(variable("a")),
+                rhs: Box::new(variable("b")),
+                operator: BinaryOperator::Add,
+                span: Default::default(),
+            }
+            .into(),
+            Default::default(),
+        )],
+        span: Default::default(),
+    })
+}
+
+#[test]
+pub fn function_structure() {
+    let mut generator = CodeGenerator::new();
+    generator.set_statements(vec![add_function()]);
+    let codes = generator.generate().unwrap();
+
+    let start = codes
+        .iter()
+        .position(|line| line.starts_with("define i64 @foolang.add"))
+        .unwrap();
+
+    assert_eq!(
+        codes[start..start + 12].to_vec(),
+        vec![
+            "define i64 @foolang.add(i64 %param.a, i64 %param.b) {",
+            "entry:",
+            "  %a.0 = alloca i64",
+            "  %b.1 = alloca i64",
+            "  store i64 %param.a, i64* %a.0",
+            "  store i64 %param.b, i64* %b.1",
+            "  %t2 = load i64, i64* %a.0",
+            "  %t3 = load i64, i64* %b.1",
+            "  %t4 = add i64 %t2, %t3",
+            "  ret i64 %t4",
+            "after_return.5:",
+            "  unreachable",
+        ]
+    );
+}
+
+// 선언보다 앞에서 호출하고, 최상위 return 값이 종료 코드가 되는지 확인합니다.
+#[test]
+pub fn call_before_definition_and_exit_code() {
+    for no_std in [false, true] {
+        let mut generator = CodeGenerator::new();
+        generator.set_no_std(no_std);
+        generator.set_statements(vec![
+            Statement::Expression(call(
+                "println",
+                vec![call(
+                    "add",
+                    vec![
+                        LiteralExpression::Integer(40).into(),
+                        LiteralExpression::Integer(2).into(),
+                    ],
+                )],
+            )),
+            add_function(),
+            Statement::Return(LiteralExpression::Integer(3).into(), Default::default()),
+        ]);
+        let codes = generator.generate().unwrap();
+
+        if let Some(output) = run_llvm_ir(&codes, no_std, &[]) {
+            assert_eq!(String::from_utf8_lossy(&output.stdout), "42\n");
+            assert_eq!(output.status.code(), Some(3));
+        }
+    }
+}
+
+#[test]
+pub fn wrong_argument_count() {
+    let mut generator = CodeGenerator::new();
+    generator.set_statements(vec![
+        add_function(),
+        Statement::Expression(call("add", vec![LiteralExpression::Integer(1).into()])),
+    ]);
+
+    assert!(generator.generate().is_err());
+}
+
+// 레지스터로 넘기는 6개를 넘어서 스택으로 넘어가는 인자까지 순서대로 전달되는지 확인합니다.
+#[test]
+pub fn stack_arguments() {
+    let names = ["a", "b", "c", "d", "e", "f", "g", "h"];
+
+    // fn weighted(a, ..., h) { return a * 1 + b * 2 + ... + h * 8 }
+    let body = names
+        .iter()
+        .enumerate()
+        .map(|(index, name)| -> Expression {
+            BinaryExpression {
+                lhs: Box::new(variable(name)),
+                rhs: Box::new(LiteralExpression::Integer(index as i64 + 1).into()),
+                operator: BinaryOperator::Multiply,
+                span: Default::default(),
+            }
+            .into()
+        })
+        .reduce(|lhs, rhs| {
+            BinaryExpression {
+                lhs: Box::new(lhs),
+                rhs: Box::new(rhs),
+                operator: BinaryOperator::Add,
+                span: Default::default(),
+            }
+            .into()
+        })
+        .unwrap();
+
+    let mut generator = CodeGenerator::new();
+    generator.set_statements(vec![
+        Statement::DefineFunction(FunctionDefinitionStatement {
+            name: "weighted".into(),
+            parameters: names.iter().map(|name| (*name).into()).collect(),
+            return_type: None,
+            body: vec![Statement::Return(body, Default::default())],
+            span: Default::default(),
+        }),
+        Statement::Expression(call(
+            "println",
+            vec![call(
+                "weighted",
+                (1..=8)
+                    .map(|value| LiteralExpression::Integer(value * 10).into())
+                    .collect(),
+            )],
+        )),
+    ]);
+    let codes = generator.generate().unwrap();
+
+    // 10*1 + 20*2 + ... + 80*8 = 2040
+    if let Some(output) = run_llvm_ir(&codes, false, &[]) {
+        assert_eq!(String::from_utf8_lossy(&output.stdout), "2040\n");
+    }
+}
+
+fn define(name: &str, value: Expression) -> Statement {
+    VariableDefinitionStatement {
+        mutable: false,
+        name: name.into(),
+        value: Some(value),
+        type_name: None,
+        span: Default::default(),
+    }
+    .into()
+}
+
+fn function(name: &str, parameters: &[&str], value: Expression) -> Statement {
+    Statement::DefineFunction(FunctionDefinitionStatement {
+        name: name.into(),
+        parameters: parameters.iter().map(|name| (*name).into()).collect(),
+        return_type: None,
+        body: vec![Statement::Return(value, Default::default())],
+        span: Default::default(),
+    })
+}
+
+// 최상위 변수는 전역 변수가 되고, 함수 안에서는 매개변수가 같은 이름의 전역 변수를 가립니다.
+#[test]
+pub fn global_variables() {
+    let statements = vec![
+        define("base", LiteralExpression::Integer(40).into()),
+        define(
+            "text",
+            call("to_string", vec![LiteralExpression::Integer(2).into()]),
+        ),
+        function(
+            "get",
+            &[],
+            BinaryExpression {
+                lhs: Box::new(variable("base")),
+                rhs: Box::new(LiteralExpression::Integer(2).into()),
+                operator: BinaryOperator::Add,
+                span: Default::default(),
+            }
+            .into(),
+        ),
+        function("shadow", &["base"], variable("base")),
+        Statement::Expression(call("println", vec![call("get", vec![])])),
+        Statement::Expression(call(
+            "println",
+            vec![call("shadow", vec![LiteralExpression::Integer(1).into()])],
+        )),
+        Statement::Expression(call("println", vec![variable("text")])),
+    ];
+
+    let mut generator = CodeGenerator::new();
+    generator.set_statements(statements.clone());
+    let codes = generator.generate().unwrap();
+
+    assert!(codes.contains(&"@base.0 = internal global i64 40".to_owned()));
+    assert!(codes.contains(&"@text.1 = internal global { i8*, i64 } zeroinitializer".to_owned()));
+
+    for no_std in [false, true] {
+        let mut generator = CodeGenerator::new();
+        generator.set_no_std(no_std);
+        generator.set_statements(statements.clone());
+        let codes = generator.generate().unwrap();
+
+        if let Some(output) = run_llvm_ir(&codes, no_std, &[]) {
+            assert_eq!(String::from_utf8_lossy(&output.stdout), "42\n1\n2\n");
+        }
+    }
+}
+
+// 종료 조건을 쓸 수 있는 조건문이 생기기 전까지는 실행하지 않고 호출 코드만 확인합니다.
+#[test]
+pub fn self_recursive_call() {
+    // fn fact(n) { return n * fact(n - 1) }
+    let mut generator = CodeGenerator::new();
+    generator.set_statements(vec![function(
+        "fact",
+        &["n"],
+        BinaryExpression {
+            lhs: Box::new(variable("n")),
+            rhs: Box::new(call(
+                "fact",
+                vec![BinaryExpression {
+                    lhs: Box::new(variable("n")),
+                    rhs: Box::new(LiteralExpression::Integer(1).into()),
+                    operator: BinaryOperator::Subtract,
+                    span: Default::default(),
+                }
+                .into()],
+            )),
+            operator: BinaryOperator::Multiply,
+            span: Default::default(),
+        }
+        .into(),
+    )]);
+    let codes = generator.generate().unwrap();
+
+    let start = codes
+        .iter()
+        .position(|line| line.starts_with("define i64 @foolang.fact"))
+        .unwrap();
+
+    assert_eq!(
+        codes[start + 4..start + 9].to_vec(),
+        vec![
+            "  %t1 = load i64, i64* %n.0",
+            "  %t2 = load i64, i64* %n.0",
+            "  %t3 = sub i64 %t2, 1",
+            "  %t4 = call i64 @foolang.fact(i64 %t3)",
+            "  %t5 = mul i64 %t1, %t4",
+        ]
+    );
+}
+
+#[test]
+pub fn if_structure() {
+    let mut generator = CodeGenerator::new();
+    generator.set_statements(vec![IfStatement {
+        condition: LiteralExpression::Boolean(true).into(),
+        then_body: vec![Statement::Expression(call(
+            "println",
+            vec![LiteralExpression::Integer(1).into()],
+        ))],
+        else_body: None,
+    }
+    .into()]);
+    let codes = generator.generate().unwrap();
+
+    assert_eq!(
+        codes[3..11].to_vec(),
+        vec![
+            "  br i1 true, label %if_then.0, label %if_else.1",
+            "if_then.0:",
+            "  call void @__foolang_println_i64(i64 1)",
+            "  br label %if_end.2",
+            "if_else.1:",
+            "  br label %if_end.2",
+            "if_end.2:",
+            "  ret i64 0",
+        ]
+    );
+}
+
+#[test]
+pub fn loop_structure() {
+    let mut generator = CodeGenerator::new();
+    generator.set_statements(vec![LoopStatement {
+        body: vec![Statement::Break(Default::default())],
+        span: Default::default(),
+    }
+    .into()]);
+    let codes = generator.generate().unwrap();
+
+    assert_eq!(
+        codes[3..10].to_vec(),
+        vec![
+            "  br label %loop_body.0",
+            "loop_body.0:",
+            "  br label %loop_end.1",
+            "after_jump.2:",
+            "  br label %loop_body.0",
+            "loop_end.1:",
+            "  ret i64 0",
+        ]
+    );
+}
+
+#[test]
+pub fn for_structure() {
+    let mut generator = CodeGenerator::new();
+    generator.set_statements(vec![ForStatement {
+        variable: "i".into(),
+        start: LiteralExpression::Integer(0).into(),
+        end: LiteralExpression::Integer(3).into(),
+        body: vec![Statement::Continue(Default::default())],
+        span: Default::default(),
+    }
+    .into()]);
+    let codes = generator.generate().unwrap();
+
+    assert_eq!(
+        codes[3..21].to_vec(),
+        vec![
+            "  %i.0 = alloca i64",
+            "  store i64 0, i64* %i.0",
+            "  br label %for_cond.1",
+            "for_cond.1:",
+            "  %t5 = load i64, i64* %i.0",
+            "  %t6 = icmp slt i64 %t5, 3",
+            "  br i1 %t6, label %for_body.2, label %for_end.4",
+            "for_body.2:",
+            "  br label %for_step.3",
+            "after_jump.7:",
+            "  br label %for_step.3",
+            "for_step.3:",
+            "  %t8 = load i64, i64* %i.0",
+            "  %t9 = add i64 %t8, 1",
+            "  store i64 %t9, i64* %i.0",
+            "  br label %for_cond.1",
+            "for_end.4:",
+            "  ret i64 0",
+        ]
+    );
+}
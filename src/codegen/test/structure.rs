@@ -0,0 +1,94 @@
+#![cfg(test)]
+
+use crate::{
+    codegen::{structure::StructTable, value::ValueType, CodeGenerator},
+    compiler::parse,
+};
+
+fn generate(source: &str) -> Vec<String> {
+    let mut generator = CodeGenerator::new();
+    generator.set_statements(parse(source).unwrap());
+
+    generator.generate().unwrap()
+}
+
+// 필드 사이의 빈 공간과 끝의 빈 공간까지 LLVM의 x86-64 배치와 같아야 합니다.
+#[test]
+pub fn layout() {
+    let table = StructTable::from_statements(
+        &parse(concat!(
+            "struct Flags { a: bool, b: bool }\n",
+            "struct Padded { flag: bool, value: i64, last: bool }\n",
+            "struct Small { value: i64, flag: bool }\n",
+            "struct Named { name: String, flags: Flags }\n",
+            "struct Empty { }\n",
+            "struct Pixel { x: i32, flag: bool, y: i32 }\n",
+        ))
+        .unwrap(),
+    )
+    .unwrap();
+
+    for (name, size, align, by_pointer) in [
+        ("Flags", 2, 1, false),
+        ("Padded", 24, 8, true),
+        ("Small", 16, 8, false),
+        ("Named", 24, 8, true),
+        ("Empty", 0, 1, false),
+        ("Pixel", 12, 4, false),
+    ] {
+        let value_type = ValueType::Struct(name.into());
+
+        assert_eq!(table.size_of(value_type), size, "{}", name);
+        assert_eq!(table.align_of(value_type), align, "{}", name);
+        assert_eq!(
+            table.is_passed_by_pointer(value_type),
+            by_pointer,
+            "{}",
+            name
+        );
+    }
+
+    assert_eq!(
+        table.definitions(),
+        vec![
+            "%foolang.Flags = type { i1, i1 }",
+            "%foolang.Padded = type { i1, i64, i1 }",
+            "%foolang.Small = type { i64, i1 }",
+            "%foolang.Named = type { { i8*, i64 }, %foolang.Flags }",
+            "%foolang.Empty = type {  }",
+            "%foolang.Pixel = type { i32, i1, i32 }",
+        ]
+    );
+}
+
+// 16바이트 이하의 구조체는 값 그대로, 더 큰 구조체는 byval, sret 포인터로 주고받습니다.
+#[test]
+pub fn abi_structure() {
+    let codes = generate(concat!(
+        "struct Small { a: i64, b: i64 }\n",
+        "struct Large { a: i64, b: i64, c: i64 }\n",
+        "fn small(s: Small) -> Small { return s }\n",
+        "fn large(l: Large) -> Large { return l }\n",
+        "let s = small(Small { a: 1, b: 2 })\n",
+        "let l = large(Large { a: 1, b: 2, c: s.b })\n",
+    ));
+
+    assert_eq!(codes[1], "%foolang.Small = type { i64, i64 }");
+    assert_eq!(codes[2], "%foolang.Large = type { i64, i64, i64 }");
+
+    for line in [
+        "  %t2 = call %foolang.Small @foolang.small(%foolang.Small %t1)",
+        "  %t4 = extractvalue %foolang.Small %t3, 1",
+        "  store %foolang.Large %t7, %foolang.Large* %slot.9",
+        "  call void @foolang.large(%foolang.Large* sret(%foolang.Large) align 8 %slot.8, %foolang.Large* byval(%foolang.Large) align 8 %slot.9)",
+        "  %t10 = load %foolang.Large, %foolang.Large* %slot.8",
+        "define %foolang.Small @foolang.small(%foolang.Small %param.s) {",
+        "  ret %foolang.Small %t1",
+        "define void @foolang.large(%foolang.Large* sret(%foolang.Large) align 8 %return.slot, %foolang.Large* byval(%foolang.Large) align 8 %param.l) {",
+        "  %t1 = load %foolang.Large, %foolang.Large* %param.l",
+        "  store %foolang.Large %t2, %foolang.Large* %return.slot",
+        "@s.0 = internal global %foolang.Small zeroinitializer",
+    ] {
+        assert!(codes.contains(&line.to_owned()), "{}\n{}", line, codes.join("\n"));
+    }
+}
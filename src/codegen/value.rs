@@ -0,0 +1,178 @@
+use std::borrow::Cow;
+
+use crate::{runtime::string::STRING_TYPE, utils::interner::Symbol};
+
+use super::function::identifier;
+
+// 언어의 값 타입과 대응하는 LLVM 타입입니다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValueType {
+    Integer,        // i64
+    Int32,          // i32
+    Float,          // double
+    Float32,        // float
+    Boolean,        // i1
+    Char,           // i32 (유니코드 코드 포인트)
+    String,         // { i8*, i64 }
+    Pointer,        // i8*
+    Struct(Symbol), // %foolang.이름
+    Void,
+}
+
+impl ValueType {
+    // 구조체 타입 이름만 새로 만들고, 나머지는 고정된 이름입니다.
+    pub fn llvm_type(&self) -> Cow<'static, str> {
+        match self {
+            Self::Integer => "i64".into(),
+            Self::Int32 => "i32".into(),
+            Self::Float => "double".into(),
+            Self::Float32 => "float".into(),
+            Self::Boolean => "i1".into(),
+            Self::Char => "i32".into(),
+            Self::String => STRING_TYPE.into(),
+            Self::Pointer => "i8*".into(),
+            Self::Struct(name) => identifier('%', &format!("foolang.{}", name)).into(),
+            Self::Void => "void".into(),
+        }
+    }
+
+    // 소스코드에 적는 기본 타입 이름입니다. 구조체 이름은 StructTable에서 찾습니다.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "i64" => Some(Self::Integer),
+            "i32" => Some(Self::Int32),
+            "f64" => Some(Self::Float),
+            "f32" => Some(Self::Float32),
+            "bool" => Some(Self::Boolean),
+            "char" => Some(Self::Char),
+            "String" => Some(Self::String),
+            _ => None,
+        }
+    }
+
+    // from_name의 반대입니다. 소스코드에 적을 수 없는 타입은 LLVM 타입 이름을 씁니다.
+    pub fn name(&self) -> Cow<'static, str> {
+        match self {
+            Self::Integer => "i64".into(),
+            Self::Int32 => "i32".into(),
+            Self::Float => "f64".into(),
+            Self::Float32 => "f32".into(),
+            Self::Boolean => "bool".into(),
+            Self::Char => "char".into(),
+            Self::String => "String".into(),
+            Self::Struct(name) => name.to_string().into(),
+            _ => self.llvm_type(),
+        }
+    }
+
+    // 정수 리터럴이 될 수 있는 타입입니다.
+    pub fn is_integer(&self) -> bool {
+        matches!(self, Self::Integer | Self::Int32)
+    }
+
+    pub fn is_float(&self) -> bool {
+        matches!(self, Self::Float | Self::Float32)
+    }
+
+    // 정수처럼 크기를 비교하고 1씩 셀 수 있는 타입입니다. 정수 리터럴로 char의 코드 포인트를 적을 수도 있습니다.
+    pub fn is_integral(&self) -> bool {
+        self.is_integer() || *self == Self::Char
+    }
+
+    // 이항 연산의 두 피연산자 타입이 다를 때 보여 줄 도움말입니다.
+    // 문자열이 섞였다면 to_string()을, 숫자끼리라면 as로 맞추는 방법을 알려 줍니다.
+    pub fn mismatch_help(&self, other: Self) -> String {
+        let numeric = |value_type: &Self| value_type.is_integral() || value_type.is_float();
+
+        if *self == Self::String || other == Self::String {
+            "both operands must have the same type; use to_string() to build strings".to_owned()
+        } else if numeric(self) && numeric(&other) {
+            format!(
+                "both operands must have the same type; convert one of them with `as {}` or `as {}`",
+                self.name(),
+                other.name()
+            )
+        } else {
+            "both operands must have the same type".to_owned()
+        }
+    }
+
+    pub fn bits(&self) -> u32 {
+        match self {
+            Self::Int32 | Self::Char | Self::Float32 => 32,
+            Self::Boolean => 1,
+            _ => 64,
+        }
+    }
+
+    // as로 바꿀 때 쓰는 LLVM 명령어입니다. 바꿀 수 없는 타입 쌍이면 None이고,
+    // 같은 타입으로 바꾸면 명령어 없이 값을 그대로 쓰므로 빈 문자열입니다.
+    // x86-64에서 llc는 sext를 MOVSX(MOVSXD), zext를 MOVZX, sitofp를 CVTSI2SD, fptosi를 CVTTSD2SI로 낮춥니다.
+    pub fn cast_instruction(&self, target: Self) -> Option<&'static str> {
+        match (self, target) {
+            (
+                Self::Integer
+                | Self::Int32
+                | Self::Float
+                | Self::Float32
+                | Self::Boolean
+                | Self::Char,
+                _,
+            ) if *self == target => Some(""),
+            (Self::Integer, Self::Int32) => Some("trunc"),
+            (Self::Int32, Self::Integer) => Some("sext"),
+            // bool은 0 또는 1이 됩니다. 정수를 bool로 바꾸는 것은 허용하지 않습니다. (!= 0을 쓰세요)
+            (Self::Boolean, Self::Integer | Self::Int32) => Some("zext"),
+            (Self::Integer | Self::Int32, Self::Float | Self::Float32) => Some("sitofp"),
+            // 소수점 아래는 버립니다. 정수 범위를 벗어나는 값의 결과는 정해져 있지 않습니다.
+            (Self::Float | Self::Float32, Self::Integer | Self::Int32) => Some("fptosi"),
+            // f64를 f32로 바꾸면 가장 가까운 값으로 반올림합니다.
+            (Self::Float, Self::Float32) => Some("fptrunc"),
+            (Self::Float32, Self::Float) => Some("fpext"),
+            // char는 i32와 비트가 같습니다. 코드 포인트는 0 이상이므로 i64로는 zext입니다.
+            (Self::Char, Self::Int32) | (Self::Int32, Self::Char) => Some(""),
+            (Self::Char, Self::Integer) => Some("zext"),
+            (Self::Integer, Self::Char) => Some("trunc"),
+            _ => None,
+        }
+    }
+
+    pub fn from_llvm_type(llvm_type: &str) -> Option<Self> {
+        match llvm_type {
+            "i64" => Some(Self::Integer),
+            "i32" => Some(Self::Int32),
+            "double" => Some(Self::Float),
+            "float" => Some(Self::Float32),
+            "i1" => Some(Self::Boolean),
+            STRING_TYPE => Some(Self::String),
+            "i8*" => Some(Self::Pointer),
+            "void" => Some(Self::Void),
+            _ => None,
+        }
+    }
+}
+
+// 표현식을 계산한 결과입니다. operand는 상수이거나 %로 시작하는 레지스터 이름입니다.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Value {
+    pub value_type: ValueType,
+    pub operand: String,
+}
+
+impl Value {
+    pub fn new(value_type: ValueType, operand: impl Into<String>) -> Self {
+        Self {
+            value_type,
+            operand: operand.into(),
+        }
+    }
+
+    pub fn void() -> Self {
+        Self::new(ValueType::Void, "")
+    }
+
+    // 호출 인자 등에 쓰는 "타입 값" 형태입니다.
+    pub fn typed(&self) -> String {
+        format!("{} {}", self.value_type.llvm_type(), self.operand)
+    }
+}
@@ -0,0 +1,19 @@
+use serde::Deserialize;
+
+use clap::Args;
+
+#[derive(Clone, Debug, Default, Deserialize, Args)]
+pub struct ConfigOption {
+    #[clap(long, default_value = "100000")]
+    pub statements: usize,
+
+    #[clap(long, default_value = "5")]
+    pub iterations: usize,
+}
+
+#[derive(Clone, Debug, Args)]
+#[clap(name = "bench")]
+pub struct Action {
+    #[clap(flatten)]
+    pub value: ConfigOption,
+}
@@ -2,10 +2,42 @@ use serde::Deserialize;
 
 use clap::Args;
 
+use foolang::builder::{
+    emit::{AssemblySyntax, Emit},
+    target::Target,
+};
+
 #[derive(Clone, Debug, Default, Deserialize, Args)]
 pub struct ConfigOption {
-    #[clap(name = "filename")]
-    pub filename: String,
+    // 첫 번째 파일이 프로그램 진입점이고, 나머지 파일에는 함수 정의만 올 수 있습니다.
+    #[clap(name = "filename", required = true)]
+    pub filenames: Vec<String>,
+
+    // libc 없이 내장 런타임(_start, 시스템 콜)만으로 빌드합니다.
+    #[clap(long = "no-std")]
+    #[serde(default)]
+    pub no_std: bool,
+
+    // 빌드 결과물의 종류입니다. exe 이외에는 링크하지 않고 해당 파일만 생성합니다.
+    // tokens, ast는 소스 파일마다 하나씩 생성합니다.
+    #[clap(long = "emit", value_enum, default_value = "exe")]
+    #[serde(default)]
+    pub emit: Emit,
+
+    // 오브젝트 파일을 만들 때 llc가 생성한 어셈블리를 as로 어셈블합니다.
+    #[clap(long = "via-asm")]
+    #[serde(default)]
+    pub via_asm: bool,
+
+    // --emit asm과 --via-asm에서 생성하는 어셈블리의 문법입니다. (att, intel)
+    #[clap(long = "asm-syntax", value_enum, default_value = "att")]
+    #[serde(default)]
+    pub asm_syntax: AssemblySyntax,
+
+    // 오브젝트 파일을 생성할 대상 플랫폼입니다. 링크에는 해당 플랫폼용 cc가 필요합니다.
+    #[clap(long = "target", value_enum, default_value = "linux-amd64")]
+    #[serde(default)]
+    pub target: Target,
 }
 
 #[derive(Clone, Debug, Args)]
@@ -0,0 +1,22 @@
+use serde::Deserialize;
+
+use clap::Args;
+
+#[derive(Clone, Debug, Default, Deserialize, Args)]
+pub struct ConfigOption {
+    // build와 마찬가지로 첫 번째 파일이 프로그램 진입점입니다.
+    #[clap(name = "filename", required = true)]
+    pub filenames: Vec<String>,
+
+    // --no-std로 빌드할 때의 런타임을 기준으로 검사합니다.
+    #[clap(long = "no-std")]
+    #[serde(default)]
+    pub no_std: bool,
+}
+
+#[derive(Clone, Debug, Args)]
+#[clap(name = "check")]
+pub struct Action {
+    #[clap(flatten)]
+    pub value: ConfigOption,
+}
@@ -0,0 +1,21 @@
+use serde::Deserialize;
+
+use clap::Args;
+
+#[derive(Clone, Debug, Default, Deserialize, Args)]
+pub struct ConfigOption {
+    #[clap(name = "filename", required = true)]
+    pub filenames: Vec<String>,
+
+    // 파일을 고치지 않고, 형식이 맞지 않는 파일이 있으면 실패합니다. (CI)
+    #[clap(long = "check")]
+    #[serde(default)]
+    pub check: bool,
+}
+
+#[derive(Clone, Debug, Args)]
+#[clap(name = "fmt")]
+pub struct Action {
+    #[clap(flatten)]
+    pub value: ConfigOption,
+}
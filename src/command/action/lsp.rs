@@ -0,0 +1,19 @@
+use serde::Deserialize;
+
+use clap::Args;
+
+#[derive(Clone, Debug, Default, Deserialize, Args)]
+pub struct ConfigOption {
+    // --no-std로 빌드할 때의 런타임을 기준으로 검사합니다.
+    #[clap(long = "no-std")]
+    #[serde(default)]
+    pub no_std: bool,
+}
+
+// 표준 입출력으로 편집기와 통신하는 Language Server를 실행합니다.
+#[derive(Clone, Debug, Args)]
+#[clap(name = "lsp")]
+pub struct Action {
+    #[clap(flatten)]
+    pub value: ConfigOption,
+}
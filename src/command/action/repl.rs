@@ -0,0 +1,19 @@
+use serde::Deserialize;
+
+use clap::Args;
+
+#[derive(Clone, Debug, Default, Deserialize, Args)]
+pub struct ConfigOption {
+    // libc 없이 ld로 직접 링크해서 실행합니다.
+    #[clap(long = "no-std")]
+    #[serde(default)]
+    pub no_std: bool,
+}
+
+// 한 줄씩 입력받아 실행하는 대화형 모드입니다.
+#[derive(Clone, Debug, Args)]
+#[clap(name = "repl")]
+pub struct Action {
+    #[clap(flatten)]
+    pub value: ConfigOption,
+}
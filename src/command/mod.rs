@@ -2,14 +2,51 @@ pub mod action;
 
 use clap::Parser;
 
+use foolang::{utils::timings::TimingsFormat, MessageFormat};
+
 #[derive(Parser, Debug)]
 #[clap(author, version, about, long_about = None)]
 pub struct Command {
     #[clap(subcommand)]
     pub action: SubCommand,
+
+    // 오류와 경고를 출력하는 형식입니다.
+    #[clap(
+        long = "message-format",
+        alias = "error-format",
+        value_enum,
+        global = true,
+        default_value = "human"
+    )]
+    pub message_format: MessageFormat,
+
+    // 컴파일 단계별 로그를 표준 에러로 출력합니다. -v는 info, -vv는 debug, -vvv는 trace까지 출력합니다.
+    #[clap(short = 'v', long = "verbose", action = clap::ArgAction::Count, global = true)]
+    pub verbose: u8,
+
+    // 출력한 로그를 시간과 함께 Chrome trace event 형식의 JSON 파일로 기록합니다.
+    #[clap(long = "trace-file", global = true)]
+    pub trace_file: Option<String>,
+
+    // 컴파일 단계별로 걸린 시간을 출력합니다. json, html은 현재 디렉터리의 foolang-timings 파일로 기록합니다.
+    #[clap(
+        long = "timings",
+        value_enum,
+        global = true,
+        min_values = 0,
+        require_equals = true,
+        default_missing_value = "text"
+    )]
+    pub timings: Option<TimingsFormat>,
 }
 
 #[derive(clap::Subcommand, Debug)]
 pub enum SubCommand {
     Build(action::build::Action),
+    Check(action::check::Action),
+    Lsp(action::lsp::Action),
+    Fmt(action::fmt::Action),
+    Repl(action::repl::Action),
+    #[cfg(feature = "bench")]
+    Bench(action::bench::Action),
 }
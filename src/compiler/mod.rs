@@ -0,0 +1,310 @@
+pub(crate) mod test;
+
+use std::collections::HashMap;
+
+use crate::{
+    ast::{
+        expression::Expression,
+        statement::{clear_spans, Statement},
+    },
+    builder::{emit::Emit, target::Target, toolchain::Toolchain, Builder, IntermediateDirectory},
+    codegen::{function::FunctionSignature, structure::StructTable, CodeGenerator},
+    error::all_error::{source_error::SourceError, AllError},
+    formatter::Formatter,
+    lexer::tokenizer::Tokenizer,
+    parser::Parser,
+    semantic::{reference::SymbolInfo, SemanticAnalyzer},
+    utils::{
+        interner::Symbol,
+        logger::{Level, Logger},
+        timings::Timings,
+    },
+};
+
+// 다른 도구에서 프로세스를 띄우지 않고 컴파일러를 호출하기 위한 API입니다.
+
+// 소스코드를 토큰으로 나누고 AST로 파싱합니다.
+pub fn parse(source: &str) -> Result<Vec<Statement>, AllError> {
+    let mut parser = Parser::new();
+    parser.set_tokenizer(Tokenizer::new(source));
+
+    // 토큰은 파싱하면서 읽으므로 lex와 parse를 한 단계로 잽니다.
+    let statements = Timings::measure("lex+parse", || parser.parse())?;
+    Logger::debug("parse", format!("{} statements", statements.len()));
+
+    Ok(statements)
+}
+
+// --emit=tokens의 결과물입니다. 한 줄에 토큰 하나씩 출력합니다.
+pub fn dump_tokens(source: &str) -> Result<String, AllError> {
+    let tokens = Tokenizer::string_to_tokens(source)?;
+
+    Ok(tokens
+        .iter()
+        .map(|token| format!("{:?}\n", token))
+        .collect())
+}
+
+// --emit=ast의 결과물입니다. 최상위 statement마다 들여쓰기된 Debug 표현을 출력합니다.
+pub fn dump_ast(source: &str) -> Result<String, AllError> {
+    let statements = parse(source)?;
+
+    Ok(statements
+        .iter()
+        .map(|statement| format!("{:#?}\n", statement))
+        .collect())
+}
+
+// 소스코드를 foolang fmt의 모양으로 다시 출력합니다.
+// 출력한 코드를 다시 파싱해서 AST가 같은지 확인하고, 다르면 소스를 고치지 않도록 오류를 반환합니다.
+pub fn format_source(source: &str) -> Result<String, AllError> {
+    let mut statements = parse(source)?;
+    let mut formatter = Formatter::new();
+    formatter.set_source(source);
+    let formatted = formatter.format(&statements);
+
+    // 줄과 열, 주석 줄 끝의 공백은 바뀌어도 되므로 지우고 비교합니다.
+    clear_spans(&mut statements);
+    trim_comments(&mut statements);
+    let reparsed = parse(&formatted).map(|mut reparsed| {
+        clear_spans(&mut reparsed);
+        trim_comments(&mut reparsed);
+        reparsed
+    });
+
+    match reparsed {
+        Ok(reparsed) if reparsed == statements => Ok(formatted),
+        _ => Err(AllError::FormatterError(SourceError::new(
+            2,
+            "formatting would change the meaning of the program".to_owned(),
+        ))),
+    }
+}
+
+// 주석 statement의 각 줄 끝 공백을 지웁니다. 포매터는 주석의 줄 끝 공백을 지워서 출력합니다.
+fn trim_comments(statements: &mut [Statement]) {
+    for statement in statements {
+        match statement {
+            Statement::Expression(Expression::Comment(comment, _)) => {
+                *comment = comment
+                    .split('\n')
+                    .map(str::trim_end)
+                    .collect::<Vec<_>>()
+                    .join("\n");
+            }
+            Statement::DefineFunction(statement) => trim_comments(&mut statement.body),
+            Statement::If(statement) => {
+                trim_comments(&mut statement.then_body);
+                if let Some(else_body) = &mut statement.else_body {
+                    trim_comments(else_body);
+                }
+            }
+            Statement::While(statement) => trim_comments(&mut statement.body),
+            Statement::Loop(statement) => trim_comments(&mut statement.body),
+            Statement::For(statement) => trim_comments(&mut statement.body),
+            _ => {}
+        }
+    }
+}
+
+// 의미 분석을 거친 뒤, AST를 런타임 코드까지 포함한 LLVM-IR로 변환합니다. (한 줄에 한 항목)
+pub fn lower_to_ir(mut statements: Vec<Statement>, no_std: bool) -> Result<Vec<String>, AllError> {
+    let mut analyzer = SemanticAnalyzer::new();
+    Timings::measure("analyze", || {
+        analyzer.analyze(&statements)?;
+        analyzer.annotate(&mut statements);
+        Ok::<_, AllError>(())
+    })?;
+
+    let mut generator = CodeGenerator::new();
+    generator.set_statements(statements);
+    generator.set_no_std(no_std);
+
+    let codes = Timings::measure("codegen", || generator.generate())?;
+    trace_ir("main.foo", &codes);
+
+    Ok(codes)
+}
+
+// 생성한 LLVM-IR의 크기를 기록하고, trace 단계에서는 IR 전체를 출력합니다.
+fn trace_ir(source_name: &str, codes: &[String]) {
+    Logger::debug(
+        "codegen",
+        format!("{}: {} lines of LLVM-IR", source_name, codes.len()),
+    );
+
+    if Logger::enabled(Level::Trace) {
+        for line in codes {
+            Logger::trace("codegen", line.as_str());
+        }
+    }
+}
+
+// 여러 소스 파일을 각각의 LLVM-IR 모듈로 변환합니다. 첫 번째 파일이 프로그램 진입점입니다.
+// 다른 파일의 최상위 함수는 declare로 선언하고, 실제 연결은 링크 단계에서 이루어집니다.
+pub fn lower_modules(
+    sources: Vec<(String, Vec<Statement>)>,
+    no_std: bool,
+) -> Result<Vec<Vec<String>>, AllError> {
+    lower_modules_with_warnings(sources, no_std).map(|(modules, _)| modules)
+}
+
+// lower_modules와 같지만, 의미 분석에서 발견한 경고도 함께 반환합니다.
+pub fn lower_modules_with_warnings(
+    sources: Vec<(String, Vec<Statement>)>,
+    no_std: bool,
+) -> Result<(Vec<Vec<String>>, Vec<SourceError>), AllError> {
+    let mut definitions: HashMap<Symbol, (usize, FunctionSignature)> = HashMap::new();
+
+    // 다른 파일에서 호출할 수 있도록 함수마다 매개변수와 반환값 타입을 정의한 파일의 구조체로 풀어 둡니다.
+    for (index, (source_name, statements)) in sources.iter().enumerate() {
+        let structs = StructTable::from_statements(statements)
+            .map_err(|error| AllError::SemanticError(error.with_file(source_name)))?;
+
+        for statement in statements {
+            if let Statement::DefineFunction(function) = statement {
+                let signature = structs
+                    .signature(function)
+                    .map_err(|error| AllError::SemanticError(error.with_file(source_name)))?;
+                let previous = definitions.insert(function.name, (index, signature));
+
+                if let Some((previous, _)) = previous {
+                    if previous != index {
+                        return Err(AllError::CodegenError(SourceError::new(
+                            2,
+                            format!(
+                                "function {} is defined in both {} and {}",
+                                function.name, sources[previous].0, source_name
+                            ),
+                        )));
+                    }
+                }
+            }
+        }
+    }
+
+    let mut modules = vec![];
+    let mut warnings = vec![];
+
+    for (index, (source_name, mut statements)) in sources.into_iter().enumerate() {
+        let external_functions = definitions
+            .iter()
+            .filter(|(_, (module, _))| *module != index)
+            .map(|(name, (_, signature))| (*name, signature.clone()))
+            .collect::<HashMap<_, _>>();
+
+        let mut analyzer = SemanticAnalyzer::new();
+        analyzer.set_source_name(&source_name);
+        analyzer.set_external_functions(external_functions.clone());
+        Timings::measure("analyze", || {
+            analyzer.analyze(&statements)?;
+            analyzer.annotate(&mut statements);
+            Ok::<_, AllError>(())
+        })?;
+        warnings.extend_from_slice(analyzer.warnings());
+
+        let mut generator = CodeGenerator::new();
+        generator.set_statements(statements);
+        generator.set_no_std(no_std);
+        generator.set_source_name(&source_name);
+        generator.set_entry(index == 0);
+        generator.set_external_functions(external_functions);
+
+        let codes = Timings::measure("codegen", || generator.generate())?;
+        trace_ir(&source_name, &codes);
+        modules.push(codes);
+    }
+
+    Ok((modules, warnings))
+}
+
+// 결과물을 만들지 않고 lower_modules까지의 검사와 LLVM-IR 검증만 수행하고, 경고 목록을 반환합니다.
+// llc가 없는 환경에서는 LLVM-IR 검증을 건너뜁니다.
+pub fn check(
+    sources: Vec<(String, Vec<Statement>)>,
+    no_std: bool,
+) -> Result<Vec<SourceError>, AllError> {
+    let (modules, warnings) = lower_modules_with_warnings(sources, no_std)?;
+
+    let toolchain = Toolchain::new();
+    if !toolchain.is_available() {
+        return Ok(warnings);
+    }
+
+    let directory = IntermediateDirectory::new()?;
+
+    for (index, codes) in modules.iter().enumerate() {
+        let ir_path = directory.path().join(format!("{}.ll", index));
+        std::fs::write(&ir_path, codes.join("\n"))
+            .map_err(|error| AllError::IOError(format!("{}: {}", ir_path.display(), error)))?;
+
+        toolchain.verify(&ir_path)?;
+    }
+
+    Ok(warnings)
+}
+
+// 편집기에서 파일 하나를 분석한 결과입니다.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Analysis {
+    // 처음 만난 오류. 오류가 있어도 그 앞까지의 symbols는 채워집니다.
+    pub error: Option<AllError>,
+    pub warnings: Vec<SourceError>,
+    pub symbols: Vec<SymbolInfo>,
+}
+
+// 파일을 만들지 않고 lower_to_ir까지의 검사를 수행하고, 이름과 타입 정보를 함께 반환합니다.
+// 다른 파일의 함수는 알 수 없으므로 파일 하나만으로 검사합니다.
+pub fn analyze(source_name: &str, source: &str, no_std: bool) -> Analysis {
+    let mut statements = match parse(source) {
+        Ok(statements) => statements,
+        Err(error) => {
+            return Analysis {
+                error: Some(error.with_file(source_name)),
+                ..Default::default()
+            }
+        }
+    };
+
+    let mut analyzer = SemanticAnalyzer::new();
+    analyzer.set_source_name(source_name);
+
+    let error = match analyzer.analyze(&statements) {
+        Ok(()) => {
+            analyzer.annotate(&mut statements);
+
+            let mut generator = CodeGenerator::new();
+            generator.set_statements(statements);
+            generator.set_no_std(no_std);
+            generator.set_source_name(source_name);
+
+            generator.generate().err()
+        }
+        Err(error) => Some(error),
+    };
+
+    Analysis {
+        error: error.map(|error| error.with_file(source_name)),
+        warnings: analyzer.warnings().to_vec(),
+        symbols: analyzer.symbols(),
+    }
+}
+
+// 소스코드를 target용 오브젝트 파일로 컴파일하고, 그 내용을 반환합니다.
+// 런타임은 libc를 사용하는 모드로 포함됩니다.
+pub fn compile_source(source: &str, target: Target) -> Result<Vec<u8>, AllError> {
+    let codes = lower_to_ir(parse(source)?, false)?;
+
+    let directory = IntermediateDirectory::new()?;
+
+    let mut builder = Builder::new();
+    builder.set_filenames(codes);
+    builder.set_emit(Emit::Object);
+    builder.set_target(target);
+    builder.set_source_path(directory.path().join("main.foo"));
+
+    let object_path = builder.build()?;
+
+    std::fs::read(&object_path)
+        .map_err(|error| AllError::IOError(format!("{}: {}", object_path, error)))
+}
@@ -0,0 +1,325 @@
+#![cfg(test)]
+
+use crate::{
+    builder::{target::Target, toolchain::Toolchain},
+    compiler::{check, compile_source, dump_ast, dump_tokens, lower_modules, lower_to_ir, parse},
+    error::all_error::AllError,
+    utils::testing::{run_llvm_ir, run_test_cases, TestCase},
+};
+
+#[test]
+pub fn parse_and_lower() {
+    let statements = parse("let foo = 10 + 20").unwrap();
+    assert_eq!(statements.len(), 1);
+
+    let codes = lower_to_ir(statements, false).unwrap();
+    assert_eq!(codes[0], "source_filename = \"main.foo\"");
+    assert_eq!(codes[1], "define i64 @__foolang_main() {");
+    assert!(codes.contains(&"define i32 @main(i32 %argc, i8** %argv) nounwind {".to_owned()));
+}
+
+#[test]
+pub fn lexer_errors_are_returned() {
+    assert!(matches!(
+        parse("\"unterminated"),
+        Err(AllError::LexerError(_))
+    ));
+    assert!(matches!(
+        parse("let a = 1\nprintln(a)\nlet b = 'bc'"),
+        Err(AllError::LexerError(_))
+    ));
+}
+
+// 파서는 토큰을 필요한 만큼만 읽으므로, 파싱 오류보다 뒤에 있는 어휘 오류는 보고하지 않습니다.
+#[test]
+pub fn first_error_is_returned() {
+    assert!(matches!(
+        parse("let = 1\nlet b = 'bc'"),
+        Err(AllError::ParserError(_))
+    ));
+}
+
+// 모든 target용 오브젝트를 만들고, 현재 플랫폼에서는 실행 결과까지 비교합니다.
+#[test]
+pub fn compile_for_every_target() {
+    run_test_cases(&[
+        TestCase {
+            name: "integer",
+            source: "println(1 + 2)",
+            stdout: "3\n",
+        },
+        TestCase {
+            name: "float",
+            source: "println(1.5 * 2.0)",
+            stdout: "3.000000\n",
+        },
+        TestCase {
+            name: "function",
+            source: concat!(
+                "fn square(n) -> i64 {\n",
+                "    return n * n\n",
+                "}\n",
+                "for i in 1..4 {\n",
+                "    println(square(i))\n",
+                "}\n",
+            ),
+            stdout: "1\n4\n9\n",
+        },
+        TestCase {
+            name: "string",
+            source: "let s = \"foo\" + \"bar\"\nprintln(s)",
+            stdout: "foobar\n",
+        },
+    ]);
+}
+
+// 같은 소스는 빌드할 때마다 같은 IR과 같은 오브젝트가 됩니다.
+// 오브젝트에는 중간 파일(main.ll)이 아니라 소스 파일의 이름이 기록됩니다.
+#[test]
+pub fn reproducible_output() {
+    let sources = || {
+        vec![
+            (
+                "main.foo".to_owned(),
+                parse("println(add(1, 2))\nprintln(\"a\" + \"b\")").unwrap(),
+            ),
+            (
+                "math.foo".to_owned(),
+                parse("fn add(a, b) -> i64 {\n    return a + b\n}\nfn sub(a, b) -> i64 {\n    return a - b\n}")
+                    .unwrap(),
+            ),
+        ]
+    };
+    assert_eq!(
+        lower_modules(sources(), false).unwrap(),
+        lower_modules(sources(), false).unwrap()
+    );
+
+    if !Toolchain::new().is_available() {
+        return;
+    }
+
+    let source = "struct Pair { a: i64, b: i64 }\nlet p = Pair { a: 1, b: 2 }\nprintln(p.a)";
+    let object = compile_source(source, Target::LinuxAmd64).unwrap();
+    assert_eq!(object, compile_source(source, Target::LinuxAmd64).unwrap());
+    assert!(object.windows(9).any(|name| name == b"main.foo\0"));
+}
+
+#[test]
+pub fn check_without_output() {
+    let sources = |text: &str| vec![("main.foo".to_owned(), parse(text).unwrap())];
+
+    assert!(check(sources("println(1 + 2)"), false).is_ok());
+    assert!(check(sources("println(1 + 2)"), true).is_ok());
+    assert!(matches!(
+        check(sources("println(1 + \"foo\")"), false),
+        Err(AllError::SemanticError(_))
+    ));
+
+    let warnings = check(sources("match true { true => println(1) }"), false).unwrap();
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(
+        warnings[0].message,
+        "non-exhaustive match: `false` not covered"
+    );
+}
+
+#[test]
+pub fn dump_frontend_stages() {
+    assert_eq!(
+        dump_tokens("let foo = 1").unwrap(),
+        "Keyword(Let)\nPrimary(Identifier(\"foo\"))\nOperator(Assign)\nPrimary(Integer(1))\n"
+    );
+
+    let ast = dump_ast("let foo = 1\nprintln(foo)").unwrap();
+    assert!(ast.starts_with("DefineVariable(\n"));
+    assert_eq!(ast.lines().filter(|line| !line.starts_with(' ')).count(), 4);
+}
+
+// 파서와 의미 분석 단계의 오류 메시지에 소스 위치가 붙는지 확인합니다.
+#[test]
+pub fn errors_report_source_position() {
+    let error = parse("let foo = 1\nlet = 2").unwrap_err();
+    assert!(error.to_string().starts_with("2:5: "), "{}", error);
+
+    let error = lower_to_ir(parse("let foo = 1\nprintln(foo + bar)").unwrap(), false).unwrap_err();
+    assert_eq!(error.to_string(), "main.foo:2:15: undefined variable bar");
+
+    let error = lower_to_ir(parse("println(1)\n  println(1 + \"a\")").unwrap(), false).unwrap_err();
+    assert!(error
+        .to_string()
+        .contains("main.foo:2:13: mismatched operand types"));
+}
+
+#[test]
+pub fn panic_location() {
+    let codes = lower_to_ir(parse("let zero = 0\nprintln(10 / zero)").unwrap(), true).unwrap();
+
+    if let Some(output) = run_llvm_ir(&codes, true, &[]) {
+        assert_eq!(
+            String::from_utf8_lossy(&output.stderr),
+            "panic at main.foo:2:12: attempt to divide by zero\n"
+        );
+    }
+}
+
+// 가장 작은 정수를 -1로 나누면 몫이 범위를 넘으므로, 0으로 나눌 때처럼 위치와 함께 panic합니다.
+#[test]
+pub fn division_overflow_panics() {
+    for (source, message) in [
+        (
+            "let a = -9223372036854775808\nlet b = -1\nprintln(a / b)",
+            "panic at main.foo:3:11: attempt to divide with overflow\n",
+        ),
+        (
+            "let a = -9223372036854775808\nlet b = -1\nprintln(a % b)",
+            "panic at main.foo:3:11: attempt to calculate the remainder with overflow\n",
+        ),
+        (
+            "let a: i32 = -2147483648\nlet b: i32 = -1\nprintln(a / b)",
+            "panic at main.foo:3:11: attempt to divide with overflow\n",
+        ),
+    ] {
+        let codes = lower_to_ir(parse(source).unwrap(), false).unwrap();
+
+        if let Some(output) = run_llvm_ir(&codes, false, &[]) {
+            assert_eq!(
+                String::from_utf8_lossy(&output.stderr),
+                message,
+                "{}",
+                source
+            );
+        }
+    }
+
+    // -1이 아닌 수로 나누거나 가장 작은 정수가 아니면 그대로 계산합니다.
+    let source = concat!(
+        "let a = -9223372036854775808\n",
+        "let b = -1\n",
+        "println(a / 2)\n",
+        "println(7 / b)\n",
+        "println(7 % b)\n",
+    );
+    let codes = lower_to_ir(parse(source).unwrap(), false).unwrap();
+
+    if let Some(output) = run_llvm_ir(&codes, false, &[]) {
+        assert_eq!(
+            String::from_utf8_lossy(&output.stdout),
+            "-4611686018427387904\n-7\n0\n"
+        );
+    }
+}
+
+#[test]
+pub fn operator_precedence() {
+    let source = concat!(
+        "println(2 + 3 * 4 - 10 / 2 % 3)\n",
+        "println(1 << 2 + 1)\n",
+        "println(6 & 3 | 8)\n",
+        "println(5 ^ 1 & 3)\n",
+        "println(1 + 2 * 3 == 7 && 2 < 1 || 3 >= 3)\n",
+    );
+    let codes = lower_to_ir(parse(source).unwrap(), false).unwrap();
+
+    if let Some(output) = run_llvm_ir(&codes, false, &[]) {
+        assert_eq!(
+            String::from_utf8_lossy(&output.stdout),
+            "12\n8\n10\n4\ntrue\n"
+        );
+    }
+}
+
+// 부호를 붙여야 범위에 들어가는 가장 작은 정수도 리터럴로 적을 수 있습니다.
+#[test]
+pub fn minimum_integer_literals() {
+    let source = concat!(
+        "let a = -9223372036854775808\n",
+        "println(a)\n",
+        "let b: i32 = -2147483648i32\n",
+        "println(b)\n",
+        "println(match a { -9223372036854775808 => 1, _ => 0 })\n",
+    );
+    let codes = lower_to_ir(parse(source).unwrap(), false).unwrap();
+
+    if let Some(output) = run_llvm_ir(&codes, false, &[]) {
+        assert_eq!(
+            String::from_utf8_lossy(&output.stdout),
+            "-9223372036854775808\n-2147483648\n1\n"
+        );
+    }
+
+    for (source, message) in [
+        (
+            "let a = 9223372036854775808",
+            "literal 9223372036854775808 is out of range for i64",
+        ),
+        (
+            "let a = 2147483648i32",
+            "literal 2147483648 is out of range for i32",
+        ),
+        (
+            "let a = -9223372036854775809",
+            "literal 9223372036854775809 is out of range for i64",
+        ),
+    ] {
+        match parse(source) {
+            Err(AllError::ParserError(error)) => assert_eq!(error.message, message, "{}", source),
+            Err(AllError::LexerError(error)) => assert_eq!(error.message, message, "{}", source),
+            result => panic!("{}: {:?}", source, result),
+        }
+    }
+}
+
+// 정수가 아닌 매개변수와 반환값도 값 그대로 주고받습니다.
+#[test]
+pub fn function_parameter_types() {
+    let source = concat!(
+        "fn negate(b: bool) -> bool {\n",
+        "    return !b\n",
+        "}\n",
+        "fn half(x: f64) -> f64 {\n",
+        "    return x / 2.0\n",
+        "}\n",
+        "fn third(x: f32) -> f32 {\n",
+        "    return x / 3.0f32\n",
+        "}\n",
+        "fn greet(name: String) -> String {\n",
+        "    return \"hello \" + name\n",
+        "}\n",
+        "println(negate(false))\n",
+        "println(half(5.0))\n",
+        "println(third(6.0f32))\n",
+        "println(greet(\"foo\"))\n",
+    );
+    let codes = lower_to_ir(parse(source).unwrap(), false).unwrap();
+
+    if let Some(output) = run_llvm_ir(&codes, false, &[]) {
+        assert_eq!(
+            String::from_utf8_lossy(&output.stdout),
+            "true\n2.500000\n2.000000\nhello foo\n"
+        );
+    }
+}
+
+// 접미사가 없는 숫자 리터럴은 적은 실수 타입의 값으로 만들어집니다.
+#[test]
+pub fn float_literal_types() {
+    let source = concat!(
+        "fn third(x: f32) -> f32 {\n",
+        "    return x / 3\n",
+        "}\n",
+        "let a: f32 = 1.5\n",
+        "let b: f64 = 1\n",
+        "println(a + 0.25)\n",
+        "println(b)\n",
+        "println(third(6))\n",
+    );
+    let codes = lower_to_ir(parse(source).unwrap(), false).unwrap();
+
+    if let Some(output) = run_llvm_ir(&codes, false, &[]) {
+        assert_eq!(
+            String::from_utf8_lossy(&output.stdout),
+            "1.750000\n1.000000\n2.000000\n"
+        );
+    }
+}
@@ -0,0 +1,360 @@
+#![cfg(test)]
+
+use crate::{
+    compiler::{lower_to_ir, parse},
+    error::all_error::AllError,
+    utils::testing::run_llvm_ir,
+};
+
+// 소스코드를 빌드해서 실행하고 표준 출력을 반환합니다. 툴체인이 없으면 None입니다.
+fn run(source: &str) -> Option<String> {
+    let codes = lower_to_ir(parse(source).unwrap(), false).unwrap();
+
+    run_llvm_ir(&codes, false, &[])
+        .map(|output| String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+#[test]
+pub fn if_else_branches() {
+    let source = |value: i64| {
+        format!(
+            concat!(
+                "let a = {}\n",
+                "if a < 0 {{\n",
+                "    println(\"negative\")\n",
+                "}} else if a == 0 {{\n",
+                "    println(\"zero\")\n",
+                "}} else {{\n",
+                "    let b = a * 2\n",
+                "    println(b)\n",
+                "}}\n",
+                "println(\"end\")\n",
+            ),
+            value
+        )
+    };
+
+    for (value, expected) in [
+        (-1, "negative\nend\n"),
+        (0, "zero\nend\n"),
+        (21, "42\nend\n"),
+    ] {
+        if let Some(stdout) = run(&source(value)) {
+            assert_eq!(stdout, expected);
+        }
+    }
+}
+
+// 블록 안에서 선언한 변수는 바깥 변수를 가리고, 블록이 끝나면 다시 바깥 변수가 보입니다.
+#[test]
+pub fn block_scope() {
+    let source = concat!(
+        "let a = 1\n",
+        "if true {\n",
+        "    let a = \"inner\"\n",
+        "    println(a)\n",
+        "}\n",
+        "println(a)\n",
+    );
+
+    if let Some(stdout) = run(source) {
+        assert_eq!(stdout, "inner\n1\n");
+    }
+
+    assert!(matches!(
+        lower_to_ir(parse("if true { let b = 1 }\nprintln(b)").unwrap(), false),
+        Err(AllError::SemanticError(_))
+    ));
+}
+
+#[test]
+pub fn condition_must_be_boolean() {
+    assert!(matches!(
+        lower_to_ir(parse("if 1 { println(1) }").unwrap(), false),
+        Err(AllError::SemanticError(_))
+    ));
+}
+
+#[test]
+pub fn countdown_while_loop() {
+    let source = concat!(
+        "mut i = 3\n",
+        "while i > 0 {\n",
+        "    println(i)\n",
+        "    i = i - 1\n",
+        "}\n",
+        "println(\"liftoff\")\n",
+    );
+
+    if let Some(stdout) = run(source) {
+        assert_eq!(stdout, "3\n2\n1\nliftoff\n");
+    }
+}
+
+#[test]
+pub fn loop_with_break_and_continue() {
+    let source = concat!(
+        "mut i = 0\n",
+        "loop {\n",
+        "    i = i + 1\n",
+        "    if i > 6 { break }\n",
+        "    let odd = i % 2\n",
+        "    if odd == 0 { continue }\n",
+        "    println(i)\n",
+        "}\n",
+        "println(i)\n",
+    );
+
+    if let Some(stdout) = run(source) {
+        assert_eq!(stdout, "1\n3\n5\n7\n");
+    }
+}
+
+#[test]
+pub fn for_in_range() {
+    let source = concat!(
+        "mut sum = 0\n",
+        "for i in 0..10 {\n",
+        "    if i == 7 { break }\n",
+        "    let odd = i % 2\n",
+        "    if odd == 1 { continue }\n",
+        "    sum = sum + i\n",
+        "}\n",
+        "println(sum)\n",
+        "for i in 3..1 { println(i) }\n",
+    );
+
+    if let Some(stdout) = run(source) {
+        assert_eq!(stdout, "12\n");
+    }
+}
+
+#[test]
+pub fn recursive_function() {
+    let source = concat!(
+        "fn fib(n) -> i64 {\n",
+        "    if n < 2 { return n }\n",
+        "    return fib(n - 1) + fib(n - 2)\n",
+        "}\n",
+        "for i in 0..8 { println(fib(i)) }\n",
+    );
+
+    if let Some(stdout) = run(source) {
+        assert_eq!(stdout, "0\n1\n1\n2\n3\n5\n8\n13\n");
+    }
+}
+
+#[test]
+pub fn compound_assignment() {
+    let source = concat!(
+        "mut x = 5\n",
+        "x += 10\n",
+        "x -= 3\n",
+        "x *= 4\n",
+        "x /= 6\n",
+        "x %= 5\n",
+        "println(x)\n",
+        "x <<= 4\n",
+        "x >>= 1\n",
+        "x |= 3\n",
+        "x &= 14\n",
+        "x ^= 1\n",
+        "println(x)\n",
+        "mut s = \"foo\"\n",
+        "s += \"bar\"\n",
+        "println(s)\n",
+    );
+
+    // ((5 + 10 - 3) * 4 / 6) % 5 = 3, ((3 << 4 >> 1 | 3) & 14) ^ 1 = 11
+    if let Some(stdout) = run(source) {
+        assert_eq!(stdout, "3\n11\nfoobar\n");
+    }
+}
+
+// 오른쪽이 계산되는지 출력으로 확인합니다.
+#[test]
+pub fn short_circuit() {
+    let source = concat!(
+        "fn side(x) -> i64 {\n",
+        "    println(x)\n",
+        "    return x\n",
+        "}\n",
+        "println(false && side(1) == 1)\n",
+        "println(true || side(2) == 2)\n",
+        "println(true && side(3) == 3)\n",
+        "println((1 < 2 || side(4) == 4) && (side(5) == 0 || side(6) == 6))\n",
+        "let zero = 0\n",
+        "println(zero != 0 && 10 / zero > 1)\n",
+        "println(!(zero == 0) || !false)\n",
+    );
+
+    if let Some(stdout) = run(source) {
+        assert_eq!(stdout, "false\ntrue\n3\ntrue\n5\n6\ntrue\nfalse\ntrue\n");
+    }
+}
+
+#[test]
+pub fn match_expression() {
+    let source = concat!(
+        "for i in -1..4 {\n",
+        "    match i {\n",
+        "        0 => println(\"zero\"),\n",
+        "        -1 => println(\"minus one\"),\n",
+        "        _ => println(i * 10),\n",
+        "    }\n",
+        "}\n",
+        "let even = 4 % 2 == 0\n",
+        "println(match even { true => \"even\", false => \"odd\" })\n",
+        "println(match 7 { 1 => 1, 7 => match even { true => 70, _ => 0 }, _ => 2 } + 1)\n",
+    );
+
+    if let Some(stdout) = run(source) {
+        assert_eq!(stdout, "minus one\nzero\n10\n20\n30\neven\n71\n");
+    }
+}
+
+// Pair(16바이트)는 레지스터로, Box(24바이트)는 byval, sret 포인터로 주고받습니다.
+#[test]
+pub fn structs() {
+    let source = concat!(
+        "struct Pair { a: i64, b: i64 }\n",
+        "struct Box { min: Pair, size: i64, name: String, visible: bool }\n",
+        "fn swap(p: Pair) -> Pair {\n",
+        "    return Pair { a: p.b, b: p.a }\n",
+        "}\n",
+        "fn grow(b: Box, by) -> Box {\n",
+        "    return Box { size: b.size + by, min: swap(b.min), name: b.name + \"!\", visible: !b.visible }\n",
+        "}\n",
+        "fn area(b: Box) -> i64 {\n",
+        "    return b.size * b.size\n",
+        "}\n",
+        "let p = swap(Pair { a: 1, b: 2 })\n",
+        "println(p.a)\n",
+        "println(p.b)\n",
+        "mut b = Box { min: p, size: 3, name: \"box\", visible: true }\n",
+        "b = grow(b, 2)\n",
+        "println(b.min.a * 10 + b.min.b)\n",
+        "println(area(b))\n",
+        "println(b.name)\n",
+        "if b.visible { println(\"visible\") } else { println(\"hidden\") }\n",
+        "println(-b.size)\n",
+    );
+
+    if let Some(stdout) = run(source) {
+        assert_eq!(stdout, "2\n1\n12\n25\nbox!\nhidden\n-5\n");
+    }
+}
+
+// i32 값은 32비트로 계산하므로 i32의 범위를 넘으면 감싸집니다.
+#[test]
+pub fn int32_values() {
+    let source = concat!(
+        "struct Pixel { x: i32, y: i32 }\n",
+        "fn scale(p: Pixel, k: i32) -> Pixel {\n",
+        "    return Pixel { x: p.x * k, y: p.y * k }\n",
+        "}\n",
+        "fn sum(n: i32) -> i32 {\n",
+        "    mut total = 0\n",
+        "    for i in 0..n {\n",
+        "        total += i\n",
+        "    }\n",
+        "    return total\n",
+        "}\n",
+        "let max: i32 = 2147483647\n",
+        "println(max + 1)\n",
+        "let p = scale(Pixel { x: 3, y: -4 }, 5)\n",
+        "println(p.x + p.y)\n",
+        "println(sum(10))\n",
+        "let shifted: i32 = 1 << 33\n",
+        "println(shifted)\n",
+    );
+
+    if let Some(stdout) = run(source) {
+        assert_eq!(stdout, "-2147483648\n-5\n45\n2\n");
+    }
+}
+
+// 실수를 정수로 바꾸면 소수점 아래를 버리고, i64를 i32로 바꾸면 하위 32비트만 남습니다.
+#[test]
+pub fn casts() {
+    let source = concat!(
+        "let big = 4294967297\n",
+        "println(big as i32)\n",
+        "let small: i32 = -7\n",
+        "println(small as i64 * 2)\n",
+        "println(small as f64 / 2.0)\n",
+        "println(-2.75 as i64)\n",
+        "println((1 < 2) as i32 + true as i32)\n",
+    );
+
+    if let Some(stdout) = run(source) {
+        assert_eq!(stdout, "1\n-14\n-3.500000\n-2\n2\n");
+    }
+}
+
+#[test]
+pub fn numeric_literals() {
+    let source = concat!(
+        "println(0xFF + 0o17 + 0b11)\n",
+        "println(1_000_000)\n",
+        "let a = 0x7fi32 * 2\n",
+        "println(a)\n",
+        "let f = 2.5f32 * 3.0f32\n",
+        "println(f)\n",
+        "println(match 3i64 { -3 => 0, 3 => 1, _ => 2 })\n",
+    );
+
+    if let Some(stdout) = run(source) {
+        assert_eq!(stdout, "273\n1000000\n254\n7.500000\n1\n");
+    }
+}
+
+#[test]
+pub fn chars() {
+    let source = concat!(
+        "fn next(c: char) -> char {\n",
+        "    return c + 1\n",
+        "}\n",
+        "println(next('a'))\n",
+        "println('가')\n",
+        "println(to_string('\\t' as i64) + to_string('é'))\n",
+        "for c in 'v'..'z' {\n",
+        "    print(c)\n",
+        "}\n",
+        "println(match 'x' { 'x' => 1, 121 => 2, _ => 0 })\n",
+        "println('a' < 'b')\n",
+        "println(90 as char)\n",
+    );
+
+    if let Some(stdout) = run(source) {
+        assert_eq!(stdout, "b\n가\n9é\nvwxy1\ntrue\nZ\n");
+    }
+}
+
+#[test]
+pub fn loop_errors() {
+    for source in [
+        "break",
+        "if true { continue }",
+        "let i = 1\ni = 2",
+        "mut i = 1\ni = \"two\"",
+        "j = 1",
+        "while 1 { }",
+        "let i = 1\ni += 1",
+        "mut s = \"a\"\ns -= \"b\"",
+        "mut f = 1.5\nf <<= 1",
+        "j += 1",
+        "for i in 0..\"ten\" { }",
+        "for i in 0..3 { i = 1 }",
+        "for i in 0..3 { }\nprintln(i)",
+    ] {
+        assert!(
+            matches!(
+                lower_to_ir(parse(source).unwrap(), false),
+                Err(AllError::SemanticError(_))
+            ),
+            "{}",
+            source
+        );
+    }
+}
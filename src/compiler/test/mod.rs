@@ -0,0 +1,3 @@
+pub(crate) mod compile;
+pub(crate) mod control_flow;
+pub(crate) mod modules;
@@ -0,0 +1,205 @@
+#![cfg(test)]
+
+use std::process::Command;
+
+use crate::{
+    ast::statement::Statement,
+    builder::{toolchain::Toolchain, Builder},
+    compiler::{lower_modules, parse},
+    error::all_error::AllError,
+    utils::testing::temporary_directory,
+};
+
+fn add_function() -> Vec<Statement> {
+    parse("fn add(a, b) -> i64 {\n    return a + b\n}").unwrap()
+}
+
+fn add_i32_function() -> Vec<Statement> {
+    parse("fn add(a: i32, b: i32) -> i32 {\n    return a + b\n}").unwrap()
+}
+
+#[test]
+pub fn external_functions_are_declared() {
+    let modules = lower_modules(
+        vec![
+            ("main.foo".into(), parse("println(add(1, 2))").unwrap()),
+            ("math.foo".into(), add_function()),
+        ],
+        false,
+    )
+    .unwrap();
+
+    assert!(modules[0].contains(&"declare i64 @foolang.add(i64, i64)".to_owned()));
+    assert!(modules[0]
+        .iter()
+        .any(|line| line.starts_with("define i32 @main(")));
+
+    assert!(modules[1]
+        .iter()
+        .any(|line| line.starts_with("define i64 @foolang.add(")));
+    assert!(!modules[1]
+        .iter()
+        .any(|line| line.starts_with("define i32 @main(")));
+}
+
+// 다른 파일의 함수는 정의한 파일에서 풀어 둔 매개변수와 반환값 타입으로 선언합니다.
+#[test]
+pub fn external_functions_keep_their_types() {
+    let modules = lower_modules(
+        vec![
+            ("main.foo".into(), parse("println(add(-1, -2))").unwrap()),
+            ("math.foo".into(), add_i32_function()),
+        ],
+        false,
+    )
+    .unwrap();
+
+    assert!(modules[0].contains(&"declare i32 @foolang.add(i32, i32)".to_owned()));
+    assert!(modules[0]
+        .iter()
+        .any(|line| line.contains("call i32 @foolang.add(i32 -1, i32 -2)")));
+}
+
+// 다른 파일의 구조체는 크기를 알 수 없으므로, 구조체를 주고받는 함수는 같은 파일에서만 호출할 수 있습니다.
+#[test]
+pub fn struct_functions_stay_in_their_file() {
+    let result = lower_modules(
+        vec![
+            ("main.foo".into(), parse("origin()").unwrap()),
+            (
+                "point.foo".into(),
+                parse(concat!(
+                    "struct Point {\n    x: i64,\n    y: i64\n}\n",
+                    "fn origin() -> Point {\n    return Point { x: 0, y: 0 }\n}",
+                ))
+                .unwrap(),
+            ),
+        ],
+        false,
+    );
+
+    assert!(matches!(result, Err(AllError::SemanticError(_))));
+}
+
+// 같은 문자열 리터럴은 모든 모듈에서 같은 이름의 comdat 상수가 되어 링크할 때 하나로 합쳐집니다.
+#[test]
+pub fn string_constants_are_shared() {
+    let modules = lower_modules(
+        vec![
+            ("main.foo".into(), parse("println(\"hello\")").unwrap()),
+            (
+                "greet.foo".into(),
+                parse("fn greet() -> i64 {\n    println(\"hello\")\n    return 0\n}").unwrap(),
+            ),
+        ],
+        false,
+    )
+    .unwrap();
+
+    let constants = modules
+        .iter()
+        .map(|module| {
+            module
+                .iter()
+                .filter(|line| line.starts_with("@__foolang.str."))
+                .cloned()
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(constants[0].len(), 1);
+    assert_eq!(constants[0], constants[1]);
+    assert!(constants[0][0].ends_with(r#"constant [5 x i8] c"hello", comdat"#));
+}
+
+// 다른 파일에 정의된 함수를 호출하는 프로그램을 링크하고 실행합니다.
+#[test]
+pub fn link_multiple_files() {
+    if !Toolchain::new().is_available() {
+        return;
+    }
+
+    for no_std in [false, true] {
+        let mut modules = lower_modules(
+            vec![
+                (
+                    "main.foo".into(),
+                    parse("println(add(40, 2))\nprintln(\"hello\")").unwrap(),
+                ),
+                ("math.foo".into(), add_function()),
+            ],
+            no_std,
+        )
+        .unwrap();
+
+        let directory = temporary_directory();
+
+        let mut builder = Builder::new();
+        builder.set_filenames(modules.remove(0));
+        builder.set_modules(modules);
+        builder.set_no_std(no_std);
+        builder.set_source_path(directory.join("main.foo"));
+
+        let executable = builder.build().unwrap();
+        let output = Command::new(&executable).output().unwrap();
+        assert_eq!(String::from_utf8_lossy(&output.stdout), "42\nhello\n");
+
+        std::fs::remove_dir_all(&directory).ok();
+    }
+}
+
+// i64가 아닌 매개변수와 반환값도 다른 파일로 그대로 주고받습니다.
+#[test]
+pub fn link_typed_function() {
+    if !Toolchain::new().is_available() {
+        return;
+    }
+
+    let mut modules = lower_modules(
+        vec![
+            ("main.foo".into(), parse("println(add(-1, -2))").unwrap()),
+            ("math.foo".into(), add_i32_function()),
+        ],
+        false,
+    )
+    .unwrap();
+
+    let directory = temporary_directory();
+
+    let mut builder = Builder::new();
+    builder.set_filenames(modules.remove(0));
+    builder.set_modules(modules);
+    builder.set_source_path(directory.join("main.foo"));
+
+    let executable = builder.build().unwrap();
+    let output = Command::new(&executable).output().unwrap();
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "-3\n");
+
+    std::fs::remove_dir_all(&directory).ok();
+}
+
+#[test]
+pub fn duplicate_function_across_files() {
+    let result = lower_modules(
+        vec![
+            ("main.foo".into(), add_function()),
+            ("math.foo".into(), add_function()),
+        ],
+        false,
+    );
+
+    assert!(matches!(result, Err(AllError::CodegenError(_))));
+}
+
+#[test]
+pub fn top_level_code_only_in_main_file() {
+    let result = lower_modules(
+        vec![
+            ("main.foo".into(), vec![]),
+            ("math.foo".into(), parse("println(1)").unwrap()),
+        ],
+        false,
+    );
+
+    assert!(matches!(result, Err(AllError::CodegenError(_))));
+}
@@ -1,25 +1,64 @@
 #![allow(dead_code)]
 
-use self::parser_error::ParserError;
+use self::{parser_error::ParserError, source_error::SourceError};
 
 pub mod parser_error;
+pub mod source_error;
 
+// 단계별 오류는 안쪽 오류의 메시지를 그대로 보여줍니다. 단계는 오류 코드의 글자로 구분합니다.
 #[derive(thiserror::Error, Debug, Clone, PartialEq, Eq, Hash)]
 pub enum AllError {
-    #[error("Lexer error: {0}")]
-    LexerError(String),
-    #[error("Parser error: {0}")]
-    ParserError(ParserError),
-    #[error("Codegen error: {0}")]
-    CodegenError(String),
+    #[error(transparent)]
+    LexerError(SourceError),
+    #[error(transparent)]
+    ParserError(#[from] ParserError),
+    #[error(transparent)]
+    SemanticError(SourceError),
+    #[error(transparent)]
+    CodegenError(SourceError),
+    #[error(transparent)]
+    BuilderError(SourceError),
     #[error("IO error: {0}")]
     IOError(String),
     #[error("FileNotFound error: {0}")]
     FileNotFound(String),
+    #[error(transparent)]
+    FormatterError(SourceError),
 }
 
-impl From<ParserError> for AllError {
-    fn from(error: ParserError) -> Self {
-        Self::ParserError(error)
+impl AllError {
+    // 오류가 난 단계를 나타내는 글자와 오류 번호입니다.
+    // L: 렉서, P: 파서, S: 의미 분석, C: 코드 생성, B: 빌드 도구, I: 입출력, F: 파일, T: 포맷터
+    // 입출력 오류와 파일을 찾지 못한 오류는 종류가 하나뿐이므로 번호가 고정되어 있습니다.
+    pub fn code(&self) -> String {
+        match self {
+            Self::LexerError(error) => format!("L{:04}", error.uid),
+            Self::ParserError(error) => format!("P{:04}", error.uid),
+            Self::SemanticError(error) => format!("S{:04}", error.uid),
+            Self::CodegenError(error) => format!("C{:04}", error.uid),
+            Self::BuilderError(error) => format!("B{:04}", error.uid),
+            Self::IOError(_) => "I0001".to_owned(),
+            Self::FileNotFound(_) => "F0001".to_owned(),
+            Self::FormatterError(error) => format!("T{:04}", error.uid),
+        }
+    }
+
+    // 어느 파일에서 난 오류인지 모르는 렉서, 파서 오류에 파일 이름을 붙입니다.
+    pub fn with_file(self, file: &str) -> Self {
+        match self {
+            Self::LexerError(error) if error.file.is_none() => {
+                Self::LexerError(error.with_file(file))
+            }
+            Self::ParserError(error) if error.file.is_none() => {
+                Self::ParserError(error.with_file(file))
+            }
+            Self::SemanticError(error) if error.file.is_none() => {
+                Self::SemanticError(error.with_file(file))
+            }
+            Self::CodegenError(error) if error.file.is_none() => {
+                Self::CodegenError(error.with_file(file))
+            }
+            error => error,
+        }
     }
 }
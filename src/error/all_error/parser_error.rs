@@ -1,20 +1,54 @@
 use std::fmt::{Display, Formatter};
 
-#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq, Hash)]
+use crate::utils::span::Span;
 
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct ParserError {
     pub message: String,
     pub uid: i32,
+    // 오류가 난 토큰의 위치
+    pub span: Span,
+    // 오류가 난 소스 파일 이름. 모르면 None입니다.
+    pub file: Option<String>,
 }
 
 impl Display for ParserError {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "Parser error: {} ({})", self.message, self.uid)
+        if let Some(file) = &self.file {
+            write!(f, "{}:", file)?;
+        }
+
+        if self.span.is_unknown() {
+            if self.file.is_some() {
+                write!(f, " ")?;
+            }
+        } else {
+            write!(f, "{}: ", self.span)?;
+        }
+
+        write!(f, "{} ({})", self.message, self.uid)
     }
 }
 
+impl std::error::Error for ParserError {}
+
 impl ParserError {
     pub fn new(uid: i32, message: String) -> Self {
-        Self { message, uid }
+        Self {
+            message,
+            uid,
+            span: Span::default(),
+            file: None,
+        }
+    }
+
+    pub fn with_span(mut self, span: Span) -> Self {
+        self.span = span;
+        self
+    }
+
+    pub fn with_file(mut self, file: impl Into<String>) -> Self {
+        self.file = Some(file.into());
+        self
     }
 }
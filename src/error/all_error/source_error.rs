@@ -0,0 +1,57 @@
+use std::fmt::{Display, Formatter};
+
+use crate::utils::span::Span;
+
+// 소스코드의 특정 위치에서 발생한 렉서, 의미 분석, 코드 생성 오류입니다.
+// 빌드 도구와 포맷터 오류도 위치 없이 이 형식을 씁니다.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SourceError {
+    pub message: String,
+    // 오류 코드의 번호. 단계마다 따로 매기고, 같은 종류의 오류는 같은 번호입니다.
+    pub uid: i32,
+    pub span: Span,
+    // 오류가 난 소스 파일 이름. 모르면 None입니다.
+    pub file: Option<String>,
+    // 오류를 고치는 방법에 대한 안내
+    pub help: Option<String>,
+}
+
+impl Display for SourceError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match (&self.file, self.span.is_unknown()) {
+            (Some(file), false) => write!(f, "{}:{}: {}", file, self.span, self.message),
+            (Some(file), true) => write!(f, "{}: {}", file, self.message),
+            (None, false) => write!(f, "{}: {}", self.span, self.message),
+            (None, true) => write!(f, "{}", self.message),
+        }
+    }
+}
+
+impl std::error::Error for SourceError {}
+
+impl SourceError {
+    pub fn new(uid: i32, message: String) -> Self {
+        Self {
+            message,
+            uid,
+            span: Span::default(),
+            file: None,
+            help: None,
+        }
+    }
+
+    pub fn with_span(mut self, span: Span) -> Self {
+        self.span = span;
+        self
+    }
+
+    pub fn with_file(mut self, file: impl Into<String>) -> Self {
+        self.file = Some(file.into());
+        self
+    }
+
+    pub fn with_help(mut self, help: impl Into<String>) -> Self {
+        self.help = Some(help.into());
+        self
+    }
+}
@@ -0,0 +1,180 @@
+use std::fmt::{Display, Formatter};
+
+use clap::ValueEnum;
+use serde::Deserialize;
+
+use crate::utils::{json::Json, span::Span};
+
+use super::all_error::{source_error::SourceError, AllError};
+
+// 경고는 컴파일을 멈추지 않고 알리기만 합니다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+impl Display for Severity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Severity::Error => write!(f, "error"),
+            Severity::Warning => write!(f, "warning"),
+        }
+    }
+}
+
+// 진단을 출력하는 형식입니다. (--message-format)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, ValueEnum)]
+#[serde(rename_all = "kebab-case")]
+pub enum MessageFormat {
+    // 소스 줄과 캐럿을 함께 보여주는 형식
+    #[default]
+    Human,
+    // 진단 하나를 JSON 객체 한 줄로 출력하는 형식. 편집기나 CI가 읽습니다.
+    Json,
+}
+
+// 사용자에게 보여줄 오류 정보입니다. rustc처럼 소스 줄과 오류 위치를 함께 출력합니다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    // AllError::code 참고. 경고는 W와 경고의 번호입니다.
+    pub code: String,
+    pub message: String,
+    pub file: Option<String>,
+    pub span: Span,
+    pub help: Option<String>,
+}
+
+impl From<&AllError> for Diagnostic {
+    fn from(error: &AllError) -> Self {
+        let (message, file, span, help) = match error {
+            AllError::LexerError(error)
+            | AllError::SemanticError(error)
+            | AllError::CodegenError(error)
+            | AllError::BuilderError(error)
+            | AllError::FormatterError(error) => (
+                error.message.clone(),
+                error.file.clone(),
+                error.span,
+                error.help.clone(),
+            ),
+            AllError::ParserError(error) => {
+                (error.message.clone(), error.file.clone(), error.span, None)
+            }
+            AllError::IOError(message) => (message.clone(), None, Span::default(), None),
+            AllError::FileNotFound(filename) => (
+                format!("file not found: {}", filename),
+                None,
+                Span::default(),
+                Some("check that the path is correct and the file is readable".to_owned()),
+            ),
+        };
+
+        Self {
+            severity: Severity::Error,
+            code: error.code(),
+            message,
+            file,
+            span,
+            help,
+        }
+    }
+}
+
+impl Diagnostic {
+    // 의미 분석에서 발견한 경고입니다.
+    pub fn warning(warning: &SourceError) -> Self {
+        Self {
+            severity: Severity::Warning,
+            code: format!("W{:04}", warning.uid),
+            message: warning.message.clone(),
+            file: warning.file.clone(),
+            span: warning.span,
+            help: warning.help.clone(),
+        }
+    }
+
+    // source는 오류가 난 파일의 전체 내용입니다. 없으면 소스 줄 없이 위치만 출력합니다.
+    pub fn render(&self, source: Option<&str>) -> String {
+        let mut lines = vec![format!(
+            "{}[{}]: {}",
+            self.severity, self.code, self.message
+        )];
+
+        let line = if self.span.is_unknown() {
+            None
+        } else {
+            source.and_then(|source| source.lines().nth(self.span.line - 1))
+        };
+
+        // 줄 번호 칸의 너비
+        let width = self.span.line.to_string().len();
+        let arrow = format!("{:width$}-->", "", width = width);
+
+        match (&self.file, self.span.is_unknown()) {
+            (Some(file), false) => lines.push(format!("{} {}:{}", arrow, file, self.span)),
+            (Some(file), true) => lines.push(format!("{} {}", arrow, file)),
+            (None, false) => lines.push(format!("{} {}", arrow, self.span)),
+            (None, true) => {}
+        }
+
+        let gutter = format!("{:width$} |", "", width = width);
+
+        if let Some(line) = line {
+            // 탭은 그대로 두어야 캐럿이 같은 열에 표시됩니다.
+            let padding = line
+                .chars()
+                .take(self.span.column.saturating_sub(1))
+                .map(|c| if c == '\t' { '\t' } else { ' ' })
+                .collect::<String>();
+
+            lines.push(gutter.clone());
+            lines.push(format!("{} | {}", self.span.line, line));
+            lines.push(format!(
+                "{} {}{}",
+                gutter,
+                padding,
+                "^".repeat(self.span.length.max(1))
+            ));
+        }
+
+        if let Some(help) = &self.help {
+            lines.push(format!("{:width$} = help: {}", "", help, width = width));
+        }
+
+        lines.join("\n") + "\n"
+    }
+
+    // {"severity":"error","code":"P0004","message":...,"file":...,"span":{...},"help":...,"rendered":...}
+    // 알 수 없는 파일, 위치, 도움말은 null입니다. rendered는 human 형식으로 출력했을 때의 내용입니다.
+    pub fn to_json(&self, source: Option<&str>) -> String {
+        let span = if self.span.is_unknown() {
+            Json::Null
+        } else {
+            Json::object([
+                ("line", self.span.line.into()),
+                ("column", self.span.column.into()),
+                ("length", self.span.length.into()),
+            ])
+        };
+
+        Json::object([
+            ("severity", self.severity.to_string().into()),
+            ("code", self.code.as_str().into()),
+            ("message", self.message.as_str().into()),
+            ("file", self.file.as_deref().into()),
+            ("span", span),
+            ("help", self.help.as_deref().into()),
+            ("rendered", self.render(source).into()),
+        ])
+        .to_string()
+    }
+
+    pub fn format(&self, format: MessageFormat, source: Option<&str>) -> String {
+        match format {
+            MessageFormat::Human => self.render(source),
+            MessageFormat::Json => self.to_json(source) + "\n",
+        }
+    }
+}
@@ -1 +1,4 @@
-pub(crate) mod all_error;
+pub mod all_error;
+pub mod diagnostic;
+
+pub(crate) mod test;
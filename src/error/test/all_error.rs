@@ -0,0 +1,64 @@
+#![cfg(test)]
+
+use std::error::Error;
+
+use crate::{
+    compiler::{lower_to_ir, parse},
+    error::all_error::{source_error::SourceError, AllError},
+};
+
+// 단계를 나타내는 글자와, 오류의 종류마다 다른 번호로 이루어집니다.
+#[test]
+pub fn error_codes() {
+    assert_eq!(parse("let = 2").unwrap_err().code(), "P0004");
+    assert_eq!(parse("let a = 1.2.3").unwrap_err().code(), "L0002");
+    assert_eq!(parse("let a = 'ab'").unwrap_err().code(), "L0012");
+
+    let code = |source: &str| {
+        lower_to_ir(parse(source).unwrap(), false)
+            .unwrap_err()
+            .code()
+    };
+    assert_eq!(code("println(bar)"), "S0015");
+    assert_eq!(code("let a = 1\na = 2"), "S0016");
+    assert_eq!(code("foo()"), "S0033");
+    assert_eq!(code("fn foo() -> i64 { }"), "S0011");
+    assert_eq!(code("struct P {\n    p: P\n}"), "S0302");
+
+    assert_eq!(
+        AllError::BuilderError(SourceError::new(2, "llc not found".into())).code(),
+        "B0002"
+    );
+    assert_eq!(AllError::IOError("a".into()).code(), "I0001");
+    assert_eq!(AllError::FileNotFound("a.foo".into()).code(), "F0001");
+}
+
+// 같은 종류의 오류는 어디에서 발견해도 같은 번호입니다.
+#[test]
+pub fn same_kind_same_code() {
+    let code = |source: &str| {
+        lower_to_ir(parse(source).unwrap(), false)
+            .unwrap_err()
+            .code()
+    };
+    assert_eq!(code("println(bar)"), code("bar = 1"));
+    assert_eq!(code("let a: i64 = true"), code("mut a = 1\na = true"));
+}
+
+// 단계별 오류는 안쪽 오류를 그대로 보여주고, 같은 메시지를 source로 한 번 더 돌려주지 않습니다.
+#[test]
+pub fn error_source() {
+    let error = parse("let foo = 1\nlet = 2").unwrap_err();
+
+    assert_eq!(
+        error.to_string(),
+        "2:5: Expected identifier for variable name. but found Operator(Assign) (4)"
+    );
+    assert!(error.source().is_none());
+
+    let error = lower_to_ir(parse("println(bar)").unwrap(), false).unwrap_err();
+    assert_eq!(error.to_string(), "main.foo:1:9: undefined variable bar");
+    assert!(error.source().is_none());
+
+    assert!(AllError::IOError("a".into()).source().is_none());
+}
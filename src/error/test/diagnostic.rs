@@ -0,0 +1,153 @@
+#![cfg(test)]
+
+use crate::{
+    compiler::{lower_modules_with_warnings, lower_to_ir, parse},
+    error::{
+        all_error::{source_error::SourceError, AllError},
+        diagnostic::{Diagnostic, MessageFormat},
+    },
+};
+
+fn render(source: &str, error: AllError) -> String {
+    Diagnostic::from(&error.with_file("main.foo")).render(Some(source))
+}
+
+#[test]
+pub fn parser_error_snippet() {
+    let source = "let foo = 1\nlet = 2\n";
+    let error = parse(source).unwrap_err();
+
+    assert_eq!(
+        render(source, error),
+        concat!(
+            "error[P0004]: Expected identifier for variable name. but found Operator(Assign)\n",
+            " --> main.foo:2:5\n",
+            "  |\n",
+            "2 | let = 2\n",
+            "  |     ^\n",
+        )
+    );
+}
+
+#[test]
+pub fn error_with_help() {
+    let source = "let foo = 1\nprintln(foo + bar)";
+    let error = lower_to_ir(parse(source).unwrap(), false).unwrap_err();
+
+    assert_eq!(
+        render(source, error),
+        concat!(
+            "error[S0015]: undefined variable bar\n",
+            " --> main.foo:2:15\n",
+            "  |\n",
+            "2 | println(foo + bar)\n",
+            "  |               ^^^\n",
+            "  = help: declare it with `let bar = ...` before using it\n",
+        )
+    );
+}
+
+// 탭으로 들여쓴 줄에서도 캐럿이 같은 열에 오도록 탭을 유지합니다.
+#[test]
+pub fn tabs_are_kept_before_caret() {
+    let source = "println(1)\n\tprintln(1 + \"a\")";
+    let error = lower_to_ir(parse(source).unwrap(), false).unwrap_err();
+
+    let rendered = render(source, error);
+    assert!(rendered.contains("\n  | \t          ^\n"), "{}", rendered);
+}
+
+#[test]
+pub fn error_without_location() {
+    let diagnostic = Diagnostic::from(&AllError::FileNotFound("foo.foo".into()));
+
+    assert_eq!(
+        diagnostic.render(None),
+        concat!(
+            "error[F0001]: file not found: foo.foo\n",
+            "  = help: check that the path is correct and the file is readable\n",
+        )
+    );
+}
+
+#[test]
+pub fn warning_snippet() {
+    let source = "match 1 {\n    1 => println(1)\n}";
+    let (_, warnings) =
+        lower_modules_with_warnings(vec![("main.foo".into(), parse(source).unwrap())], false)
+            .unwrap();
+
+    assert_eq!(
+        Diagnostic::warning(&warnings[0]).render(Some(source)),
+        concat!(
+            "warning[W0029]: non-exhaustive match: `i64` values not covered\n",
+            " --> main.foo:1:1\n",
+            "  |\n",
+            "1 | match 1 {\n",
+            "  | ^^^^^\n",
+            "  = help: add a `_ => ...` arm\n",
+        )
+    );
+}
+
+#[test]
+pub fn json_output() {
+    let source = "let foo = 1\nprintln(foo + bar)";
+    let error = lower_to_ir(parse(source).unwrap(), false).unwrap_err();
+
+    assert_eq!(
+        Diagnostic::from(&error).to_json(Some(source)),
+        concat!(
+            r#"{"severity":"error","code":"S0015","message":"undefined variable bar","#,
+            r#""file":"main.foo","span":{"line":2,"column":15,"length":3},"#,
+            r#""help":"declare it with `let bar = ...` before using it","#,
+            r#""rendered":"error[S0015]: undefined variable bar\n --> main.foo:2:15\n  |\n"#,
+            r#"2 | println(foo + bar)\n  |               ^^^\n"#,
+            r#"  = help: declare it with `let bar = ...` before using it\n"}"#,
+        )
+    );
+
+    let diagnostic = Diagnostic::from(&AllError::BuilderError(SourceError::new(
+        10,
+        "\"cc\" failed:\n\tno".into(),
+    )));
+    assert_eq!(
+        diagnostic.format(MessageFormat::Json, None),
+        concat!(
+            r#"{"severity":"error","code":"B0010","message":"\"cc\" failed:\n\tno","#,
+            r#""file":null,"span":null,"help":null,"#,
+            r#""rendered":"error[B0010]: \"cc\" failed:\n\tno\n"}"#,
+            "\n",
+        )
+    );
+}
+
+// 모든 단계의 진단이 한 줄짜리 JSON 객체가 됩니다.
+#[test]
+pub fn json_is_one_line() {
+    let sources = [
+        "let a = 1.2.3",
+        "let = 2",
+        "println(1 + \"a\")",
+        "match 1 {\n    1 => println(1)\n}",
+    ];
+
+    for source in sources {
+        let diagnostic = match parse(source) {
+            Ok(statements) => {
+                match lower_modules_with_warnings(vec![("main.foo".into(), statements)], false) {
+                    Ok((_, warnings)) => Diagnostic::warning(&warnings[0]),
+                    Err(error) => Diagnostic::from(&error),
+                }
+            }
+            Err(error) => Diagnostic::from(&error.with_file("main.foo")),
+        };
+
+        let json = diagnostic.format(MessageFormat::Json, Some(source));
+        assert_eq!(json.lines().count(), 1, "{}", json);
+        assert!(json.starts_with(&format!(
+            "{{\"severity\":\"{}\",\"code\":\"{}\"",
+            diagnostic.severity, diagnostic.code
+        )));
+    }
+}
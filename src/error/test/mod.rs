@@ -0,0 +1,2 @@
+pub(crate) mod all_error;
+pub(crate) mod diagnostic;
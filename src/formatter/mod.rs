@@ -0,0 +1,441 @@
+pub(crate) mod test;
+
+use crate::{
+    ast::{
+        expression::{
+            literal::LiteralExpression,
+            match_expression::{MatchExpression, Pattern},
+            Expression,
+        },
+        statement::{if_statement::IfStatement, Statement},
+    },
+    utils::span::{LineIndex, Span},
+};
+
+const INDENT: &str = "    ";
+
+// AST를 정해진 모양의 소스코드로 다시 출력합니다. (foolang fmt)
+// - 들여쓰기는 공백 4칸이고, statement는 한 줄에 하나씩 적습니다.
+// - 이항 연산자, =, =>, 타입의 : 뒤에는 공백을 하나씩 둡니다.
+// - 여러 줄로 적는 구조체 필드와 match 갈래는 끝에 쉼표를 붙이고, 한 줄로 적는 목록에는 붙이지 않습니다.
+// - 최상위의 함수와 구조체 선언 앞뒤에는 빈 줄을 하나 둡니다.
+// - statement 사이의 빈 줄은 여러 줄이어도 하나로 줄여서 유지합니다.
+// - 리터럴은 소스코드에 적은 그대로(진법, 자릿수 사이의 밑줄, 원시 문자열) 적습니다.
+// - 주석은 원래 모양(// 또는 /* */)대로 적고, statement 뒤에 같은 줄로 적은 주석은 그 줄에 남깁니다.
+// 빈 줄과 리터럴, 주석의 원래 모양은 set_source로 받은 원본에서 AST의 위치로 찾습니다.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Formatter {
+    lines: Vec<String>,
+    // 현재 들여쓰기 단계
+    depth: usize,
+    // AST를 파싱한 원본 소스코드. 없으면 빈 줄을 유지하지 않고 리터럴은 값으로부터 다시 만듭니다.
+    source: String,
+    line_index: LineIndex,
+}
+
+impl Formatter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn set_source(&mut self, source: &str) {
+        self.source = source.to_owned();
+        self.line_index = LineIndex::new(source);
+    }
+
+    pub fn format(mut self, statements: &[Statement]) -> String {
+        for (index, statement) in statements.iter().enumerate() {
+            if index > 0 && self.trailing_comment(&statements[index - 1], statement) {
+                continue;
+            }
+
+            if index > 0
+                && (is_item(statement)
+                    || is_item(&statements[index - 1])
+                    || self.had_blank_line(&statements[index - 1], statement))
+            {
+                self.lines.push(String::new());
+            }
+
+            self.statement(statement, statements.get(index + 1));
+        }
+
+        self.lines
+            .iter()
+            .map(|line| format!("{}\n", line))
+            .collect()
+    }
+
+    fn push(&mut self, line: String) {
+        self.lines
+            .push(format!("{}{}", INDENT.repeat(self.depth), line));
+    }
+
+    // next는 같은 블록에서 다음에 오는 statement입니다.
+    // 다음 statement가 이 식에 이어지는 연산자나 호출로 읽히지 않도록 필요할 때만 ;를 붙입니다.
+    fn statement(&mut self, statement: &Statement, next: Option<&Statement>) {
+        let line = match statement {
+            Statement::Expression(expression) => self.expression(expression),
+            Statement::DefineVariable(variable) => {
+                let mut line = format!(
+                    "{} {}",
+                    if variable.mutable { "mut" } else { "let" },
+                    variable.name
+                );
+
+                if let Some(type_name) = variable.type_name {
+                    line += &format!(": {}", type_name);
+                }
+
+                if let Some(value) = &variable.value {
+                    line += &format!(" = {}", self.expression(value));
+                }
+
+                line
+            }
+            Statement::DefineFunction(function) => {
+                let parameters = function
+                    .parameters
+                    .iter()
+                    .map(|parameter| match parameter.type_name {
+                        Some(type_name) => format!("{}: {}", parameter.name, type_name),
+                        None => parameter.name.to_string(),
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                let mut head = format!("fn {}({})", function.name, parameters);
+                if let Some(return_type) = function.return_type {
+                    head += &format!(" -> {}", return_type);
+                }
+
+                return self.block(head, &function.body);
+            }
+            Statement::DefineStruct(definition) => {
+                if definition.fields.is_empty() {
+                    return self.push(format!("struct {} {{}}", definition.name));
+                }
+
+                self.push(format!("struct {} {{", definition.name));
+                self.depth += 1;
+                for field in &definition.fields {
+                    self.push(format!("{}: {},", field.name, field.type_name));
+                }
+                self.depth -= 1;
+
+                return self.push("}".to_owned());
+            }
+            Statement::Return(expression, _) => format!("return {}", self.expression(expression)),
+            Statement::If(statement) => return self.if_statement(statement, "if"),
+            Statement::While(statement) => {
+                let head = format!("while {}", self.expression(&statement.condition));
+                return self.block(head, &statement.body);
+            }
+            Statement::Loop(statement) => return self.block("loop".to_owned(), &statement.body),
+            Statement::For(statement) => {
+                let head = format!(
+                    "for {} in {}..{}",
+                    statement.variable,
+                    self.expression(&statement.start),
+                    self.expression(&statement.end)
+                );
+                return self.block(head, &statement.body);
+            }
+            Statement::Break(_) => "break".to_owned(),
+            Statement::Continue(_) => "continue".to_owned(),
+            Statement::Assign(statement) => format!(
+                "{} {}= {}",
+                statement.name,
+                statement
+                    .operator
+                    .as_ref()
+                    .map_or("", |operator| operator.as_str()),
+                self.expression(&statement.value)
+            ),
+        };
+
+        let continues = match next {
+            Some(Statement::Expression(expression)) => self
+                .expression(expression)
+                .starts_with(['(', '-', '+', '*', '&']),
+            _ => false,
+        };
+        let ends_with_expression = !matches!(
+            statement,
+            Statement::Break(_)
+                | Statement::Continue(_)
+                | Statement::Expression(Expression::Comment(..))
+        );
+
+        if continues && ends_with_expression {
+            self.push(line + ";");
+        } else {
+            self.push(line);
+        }
+    }
+
+    // head { body }
+    fn block(&mut self, head: String, body: &[Statement]) {
+        if body.is_empty() {
+            return self.push(format!("{} {{}}", head));
+        }
+
+        self.push(format!("{} {{", head));
+        self.body(body);
+        self.push("}".to_owned());
+    }
+
+    fn body(&mut self, body: &[Statement]) {
+        self.depth += 1;
+        for (index, statement) in body.iter().enumerate() {
+            if index > 0 && self.trailing_comment(&body[index - 1], statement) {
+                continue;
+            }
+
+            if index > 0 && self.had_blank_line(&body[index - 1], statement) {
+                self.lines.push(String::new());
+            }
+
+            self.statement(statement, body.get(index + 1));
+        }
+        self.depth -= 1;
+    }
+
+    // 원본에서 앞의 statement와 같은 줄에 적은 주석이라면 이미 출력한 마지막 줄 뒤에 붙입니다.
+    fn trailing_comment(&mut self, previous: &Statement, statement: &Statement) -> bool {
+        let (comment, span) = match statement {
+            Statement::Expression(Expression::Comment(comment, span)) => (comment, *span),
+            _ => return false,
+        };
+
+        if span.is_unknown() || span.line != previous.span().line {
+            return false;
+        }
+
+        let comment = self.comment(comment, span);
+        match self.lines.last_mut() {
+            Some(line) => {
+                *line += &format!(" {}", comment);
+                true
+            }
+            None => false,
+        }
+    }
+
+    // 원본에서 statement 바로 윗줄이 빈 줄이었는지 확인합니다.
+    // 앞의 statement와 같은 줄에서 시작했다면 그 빈 줄은 둘 사이가 아니므로 제외합니다.
+    fn had_blank_line(&self, previous: &Statement, statement: &Statement) -> bool {
+        let line = statement.span().line;
+
+        line > 1
+            && previous.span().line < line - 1
+            && self
+                .line_index
+                .line(&self.source, line - 1)
+                .is_some_and(|text| text.trim().is_empty())
+    }
+
+    // else 블록이 if 문 하나뿐이면 else if로 적습니다.
+    fn if_statement(&mut self, statement: &IfStatement, keyword: &str) {
+        let condition = self.expression(&statement.condition);
+        self.push(format!("{} {} {{", keyword, condition));
+        self.body(&statement.then_body);
+
+        match statement.else_body.as_deref() {
+            // 닫는 중괄호와 else if를 같은 줄에 적습니다.
+            Some([Statement::If(else_if)]) => self.if_statement(else_if, "} else if"),
+            Some(else_body) => {
+                self.push("} else {".to_owned());
+                self.body(else_body);
+                self.push("}".to_owned());
+            }
+            None => self.push("}".to_owned()),
+        }
+    }
+
+    fn expression(&mut self, expression: &Expression) -> String {
+        match expression {
+            Expression::Binary(binary) => {
+                let precedence = binary.operator.get_precedence();
+
+                format!(
+                    "{} {} {}",
+                    self.operand(&binary.lhs, precedence, false),
+                    binary.operator.as_str(),
+                    self.operand(&binary.rhs, precedence, true)
+                )
+            }
+            Expression::Unary(unary) => {
+                let operand = self.expression(&unary.operand);
+
+                // - -a, & &a는 띄워서 적습니다. 붙이면 --a로 보이거나 &&로 읽힙니다.
+                if operand.starts_with(['-', '&']) {
+                    format!("{} {}", unary.operator.as_str(), operand)
+                } else {
+                    format!("{}{}", unary.operator.as_str(), operand)
+                }
+            }
+            Expression::Literal(literal, span) => self.literal(literal, *span),
+            Expression::Variable(variable) => variable.name.to_string(),
+            Expression::Call(call) => format!(
+                "{}({})",
+                call.function_name,
+                call.arguments
+                    .iter()
+                    .map(|argument| self.expression(argument))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            Expression::Parentheses(parentheses) => {
+                format!("({})", self.expression(&parentheses.expression))
+            }
+            Expression::Match(expression) => self.match_expression(expression),
+            Expression::StructLiteral(literal) => {
+                if literal.fields.is_empty() {
+                    return format!("{} {{}}", literal.name);
+                }
+
+                format!(
+                    "{} {{ {} }}",
+                    literal.name,
+                    literal
+                        .fields
+                        .iter()
+                        .map(|(name, value)| format!("{}: {}", name, self.expression(value)))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )
+            }
+            Expression::Field(field) => {
+                format!("{}.{}", self.expression(&field.object), field.field)
+            }
+            Expression::Cast(cast) => {
+                format!("{} as {}", self.expression(&cast.value), cast.type_name)
+            }
+            Expression::Comment(comment, span) => self.comment(comment, *span),
+        }
+    }
+
+    // 이항 연산의 피연산자입니다. 파서는 피연산자의 소괄호를 벗기므로, 묶는 순서가 그대로 읽히도록 필요한 소괄호를 다시 붙입니다.
+    // 우선순위가 더 낮은 연산은 감싸고, 모든 이항 연산자는 왼쪽부터 묶으므로 우선순위가 같으면 오른쪽 피연산자만 감쌉니다.
+    fn operand(&mut self, operand: &Expression, precedence: u8, right: bool) -> String {
+        let text = self.expression(operand);
+
+        match operand {
+            Expression::Binary(binary)
+                if binary.operator.get_precedence() < precedence
+                    || (right && binary.operator.get_precedence() == precedence) =>
+            {
+                format!("({})", text)
+            }
+            _ => text,
+        }
+    }
+
+    // 갈래는 한 단계 들여써서 한 줄에 하나씩 적습니다.
+    fn match_expression(&mut self, expression: &MatchExpression) -> String {
+        let mut text = format!("match {} {{\n", self.expression(&expression.subject));
+
+        self.depth += 1;
+        for arm in &expression.arms {
+            let pattern = match &arm.pattern {
+                Pattern::Literal(literal) => self.literal(literal, arm.span),
+                Pattern::Wildcard => "_".to_owned(),
+            };
+            let body = self.expression(&arm.body);
+
+            text += &format!("{}{} => {},\n", INDENT.repeat(self.depth), pattern, body);
+        }
+        self.depth -= 1;
+
+        text + &INDENT.repeat(self.depth) + "}"
+    }
+
+    // 원본에 적힌 모양대로 주석을 적고, 줄 끝의 공백은 지웁니다.
+    // 원본이 없거나 위치를 모르면 여러 줄 주석은 /* */로, 한 줄 주석은 //로 적습니다.
+    fn comment(&self, comment: &str, span: Span) -> String {
+        let block = match self.line_index.slice(&self.source, span) {
+            Some(text) if !text.is_empty() => text.starts_with("/*"),
+            _ => comment.contains('\n'),
+        };
+
+        if block {
+            // 마지막 줄의 공백은 */ 앞에 있으므로 남깁니다.
+            let mut lines = comment.split('\n').collect::<Vec<_>>();
+            let last = lines.pop().unwrap_or_default();
+            let lines = lines
+                .into_iter()
+                .map(str::trim_end)
+                .chain([last])
+                .collect::<Vec<_>>();
+
+            format!("/*{}*/", lines.join("\n"))
+        } else {
+            format!("//{}", comment.trim_end())
+        }
+    }
+
+    // 원본에 적힌 리터럴입니다. 음수 패턴의 -와 숫자 사이의 공백은 없앱니다.
+    // 원본이 없거나 위치를 모르면 값으로부터 다시 만듭니다.
+    fn literal(&self, literal: &LiteralExpression, span: Span) -> String {
+        match self.line_index.slice(&self.source, span) {
+            Some(text) if !text.is_empty() => match text.strip_prefix('-') {
+                Some(magnitude) => format!("-{}", magnitude.trim_start()),
+                None => text.to_owned(),
+            },
+            _ => literal_text(literal),
+        }
+    }
+}
+
+// 최상위에서 앞뒤로 빈 줄을 두는 선언입니다.
+fn is_item(statement: &Statement) -> bool {
+    matches!(
+        statement,
+        Statement::DefineFunction(_) | Statement::DefineStruct(_)
+    )
+}
+
+// 원본이 없을 때 쓰는, 다시 읽었을 때 같은 리터럴이 되는 소스코드입니다.
+// 정수는 10진수로, 실수는 지수 없이 소수점을 붙여서 적습니다.
+fn literal_text(literal: &LiteralExpression) -> String {
+    match literal {
+        LiteralExpression::String(string) => {
+            let escaped = string
+                .as_str()
+                .chars()
+                .map(|c| escape(c, '"'))
+                .collect::<String>();
+
+            format!("\"{}\"", escaped)
+        }
+        LiteralExpression::Integer(integer) => integer.to_string(),
+        LiteralExpression::Int32(integer) => format!("{}i32", integer),
+        LiteralExpression::Int64(integer) => format!("{}i64", integer),
+        LiteralExpression::Float(float) => float_text(float.to_string()),
+        LiteralExpression::Float32(float) => float_text(float.to_string()) + "f32",
+        LiteralExpression::Boolean(boolean) => boolean.to_string(),
+        LiteralExpression::Char(character) => format!("'{}'", escape(*character, '\'')),
+    }
+}
+
+// f64의 Display는 지수를 쓰지 않지만, 정수 값이면 소수점이 없습니다.
+fn float_text(text: String) -> String {
+    if text.contains('.') {
+        text
+    } else {
+        text + ".0"
+    }
+}
+
+fn escape(c: char, quote: char) -> String {
+    match c {
+        '\n' => "\\n".to_owned(),
+        '\t' => "\\t".to_owned(),
+        '\r' => "\\r".to_owned(),
+        '\0' => "\\0".to_owned(),
+        '\\' => "\\\\".to_owned(),
+        c if c == quote => format!("\\{}", c),
+        c => c.to_string(),
+    }
+}
@@ -0,0 +1,305 @@
+#![cfg(test)]
+
+use crate::{compiler::format_source, error::all_error::AllError};
+
+#[test]
+pub fn canonical_layout() {
+    let source = concat!(
+        "struct Point{x:i64,y:i64}\n",
+        "fn add(a:i64,b)->i64{return a+b}\n",
+        "let   p=Point{x:1,y:2} mut total:i32=0\n",
+        "for i in 0..10{if i%2==0{total+=i as i32}else if i==3{continue}else{break}}\n",
+        "while total>0{total-=1}\n",
+        "loop{break}\n",
+        "println(match p.x{1=>\"one\",-2=>'a' as i64,_=>-(p.y)})",
+    );
+
+    assert_eq!(
+        format_source(source).unwrap(),
+        concat!(
+            "struct Point {\n",
+            "    x: i64,\n",
+            "    y: i64,\n",
+            "}\n",
+            "\n",
+            "fn add(a: i64, b) -> i64 {\n",
+            "    return a + b\n",
+            "}\n",
+            "\n",
+            "let p = Point { x: 1, y: 2 }\n",
+            "mut total: i32 = 0\n",
+            "for i in 0..10 {\n",
+            "    if i % 2 == 0 {\n",
+            "        total += i as i32\n",
+            "    } else if i == 3 {\n",
+            "        continue\n",
+            "    } else {\n",
+            "        break\n",
+            "    }\n",
+            "}\n",
+            "while total > 0 {\n",
+            "    total -= 1\n",
+            "}\n",
+            "loop {\n",
+            "    break\n",
+            "}\n",
+            "println(match p.x {\n",
+            "    1 => \"one\",\n",
+            "    -2 => 'a' as i64,\n",
+            "    _ => -(p.y),\n",
+            "})\n",
+        )
+    );
+}
+
+#[test]
+pub fn nested_match_is_indented() {
+    let source = "fn f(x) { return match x { 0 => 1, _ => match x { 1 => 2, _ => 3 } } }";
+
+    assert_eq!(
+        format_source(source).unwrap(),
+        concat!(
+            "fn f(x) {\n",
+            "    return match x {\n",
+            "        0 => 1,\n",
+            "        _ => match x {\n",
+            "            1 => 2,\n",
+            "            _ => 3,\n",
+            "        },\n",
+            "    }\n",
+            "}\n",
+        )
+    );
+}
+
+// 리터럴은 원본에 적은 그대로 유지합니다.
+#[test]
+pub fn literals() {
+    let source = r##"let a = "tab\t\"q\"\\" let b = r#"raw "x""# let c = '\'' let d = 0x1F"##;
+
+    assert_eq!(
+        format_source(&format!(
+            "{}\nlet e = 1_000i64\nlet f = 2.5f32\nlet g = 3f64\nlet h = 1e0 == 1e0",
+            source
+        ))
+        .map_err(|error| error.code()),
+        Err("L0001".to_owned())
+    );
+
+    assert_eq!(
+        format_source(&format!(
+            "{}\nlet e = 1_000i64\nlet f = 2.5f32\nlet g = 3f64\nlet h = 10000000000000000000000.0",
+            source
+        ))
+        .unwrap(),
+        concat!(
+            "let a = \"tab\\t\\\"q\\\"\\\\\"\n",
+            "let b = r#\"raw \"x\"\"#\n",
+            "let c = '\\''\n",
+            "let d = 0x1F\n",
+            "let e = 1_000i64\n",
+            "let f = 2.5f32\n",
+            "let g = 3f64\n",
+            "let h = 10000000000000000000000.0\n",
+        )
+    );
+}
+
+#[test]
+pub fn literal_spelling_is_kept() {
+    let source = concat!(
+        "let a = 0xFF_i64 let b = 0b1010 let c = 1_000i32\n",
+        "let d = r\"raw \\n\" let e = -0o17 let f = 1_0.5_f32\n",
+        "println(match a { 0x10 => \"sixteen\", - 0b1 => r\"minus\none\", _ => \"\" })\n",
+    );
+
+    assert_eq!(
+        format_source(source).unwrap(),
+        concat!(
+            "let a = 0xFF_i64\n",
+            "let b = 0b1010\n",
+            "let c = 1_000i32\n",
+            "let d = r\"raw \\n\"\n",
+            "let e = -0o17\n",
+            "let f = 1_0.5_f32\n",
+            "println(match a {\n",
+            "    0x10 => \"sixteen\",\n",
+            "    -0b1 => r\"minus\none\",\n",
+            "    _ => \"\",\n",
+            "})\n",
+        )
+    );
+}
+
+// statement 사이의 빈 줄은 하나로 줄여서 유지합니다.
+#[test]
+pub fn blank_lines_are_kept() {
+    let source = concat!(
+        "// header\n",
+        "\n",
+        "\n",
+        "let a = 1\n",
+        "let b = 2\n",
+        "\n",
+        "// section\n",
+        "fn f() {\n",
+        "    let c = 3\n",
+        "\n",
+        "    loop {\n",
+        "        println(c)\n",
+        "\n",
+        "        break\n",
+        "    }\n",
+        "}\n",
+        "mut d = 4\n",
+        "\n",
+        "\n",
+        "d = 5 let e = 6\n",
+    );
+
+    assert_eq!(
+        format_source(source).unwrap(),
+        concat!(
+            "// header\n",
+            "\n",
+            "let a = 1\n",
+            "let b = 2\n",
+            "\n",
+            "// section\n",
+            "\n",
+            "fn f() {\n",
+            "    let c = 3\n",
+            "\n",
+            "    loop {\n",
+            "        println(c)\n",
+            "\n",
+            "        break\n",
+            "    }\n",
+            "}\n",
+            "\n",
+            "mut d = 4\n",
+            "\n",
+            "d = 5\n",
+            "let e = 6\n",
+        )
+    );
+}
+
+// 다음 줄이 앞의 식에 이어지는 것으로 읽히면 ;로 끊습니다.
+#[test]
+pub fn semicolons_are_kept_where_needed() {
+    let source = "let a = 1; -a; println(a); (a); mut b = a\nb = 2; +b; break; (a)";
+
+    assert_eq!(
+        format_source(source).unwrap(),
+        concat!(
+            "let a = 1;\n",
+            "-a\n",
+            "println(a);\n",
+            "(a)\n",
+            "mut b = a\n",
+            "b = 2;\n",
+            "+b\n",
+            "break\n",
+            "(a)\n",
+        )
+    );
+}
+
+#[test]
+pub fn comments_and_unary_operators() {
+    let source = "// first\nlet a = - -1 /* block\ncomment */\nlet b = !!true\nlet c = - -a";
+
+    assert_eq!(
+        format_source(source).unwrap(),
+        concat!(
+            "// first\n",
+            "let a = - -1 /* block\ncomment */\n",
+            "let b = !!true\n",
+            "let c = - -a\n",
+        )
+    );
+}
+
+// 파서가 벗긴 소괄호는 우선순위와 결합 방향에 필요한 곳에만 다시 붙습니다.
+#[test]
+pub fn parentheses_follow_precedence() {
+    let source = concat!(
+        "let e = (1 + 2) * 3\n",
+        "let f = 1 - (2 - 3)\n",
+        "let g = (1 - 2) - 3\n",
+        "let h = (a || b) && !(c == (d < e))\n",
+        "let i = 8 / (4 / 2) % (1 << 2)\n",
+        "let j = ((1 * 2)) + (3)\n",
+    );
+
+    assert_eq!(
+        format_source(source).unwrap(),
+        concat!(
+            "let e = (1 + 2) * 3\n",
+            "let f = 1 - (2 - 3)\n",
+            "let g = 1 - 2 - 3\n",
+            "let h = (a || b) && !(c == (d < e))\n",
+            "let i = 8 / (4 / 2) % (1 << 2)\n",
+            "let j = 1 * 2 + 3\n",
+        )
+    );
+}
+
+#[test]
+pub fn formatting_is_idempotent() {
+    let sources = [
+        "fn main(){let x=1 if x>0{println(x)}}",
+        "struct S{} let s=S{} fn g()->i64{loop{}return 0}",
+        "mut i=0 while i<3{i+=1;(i)}",
+        "let e=(1+2)*3 let f=1-(2-3) let g=(a<<1)*(b|c)",
+        "let a=0x_1F\n\n\n// c\nfn h(){\n\nreturn 1}\n\nh()",
+    ];
+
+    for source in sources {
+        let formatted = format_source(source).unwrap();
+        assert_eq!(format_source(&formatted).unwrap(), formatted);
+    }
+}
+
+#[test]
+pub fn parse_errors_are_returned() {
+    assert!(matches!(
+        format_source("let = 1"),
+        Err(AllError::ParserError(_))
+    ));
+}
+
+// 주석은 원래 모양대로 적고, 줄 끝의 공백을 지우고, statement 뒤의 주석은 그 줄에 남깁니다.
+#[test]
+pub fn comment_style_and_placement() {
+    let source = concat!(
+        "/* single */\n",
+        "// line   \n",
+        "let a = 1 /* after */\n",
+        "fn f(x) {\n",
+        "    return x // trailing  \n",
+        "    /* own line */\n",
+        "}\n",
+        "/* block   \n",
+        "   comment */ let b = 2\n",
+    );
+
+    assert_eq!(
+        format_source(source).unwrap(),
+        concat!(
+            "/* single */\n",
+            "// line\n",
+            "let a = 1 /* after */\n",
+            "\n",
+            "fn f(x) {\n",
+            "    return x // trailing\n",
+            "    /* own line */\n",
+            "}\n",
+            "\n",
+            "/* block\n",
+            "   comment */\n",
+            "let b = 2\n",
+        )
+    );
+}
@@ -1,9 +1,10 @@
 use super::token::Token;
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum GeneralToken {
     // general syntax
     Arrow,            // ->
+    FatArrow,         // =>
     Comma,            // ,
     SemiColon,        // ;
     Colon,            // :
@@ -1,6 +1,6 @@
 use super::token::Token;
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum Keyword {
     Let,
     Const,
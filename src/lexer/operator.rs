@@ -1,6 +1,6 @@
 use super::token::Token;
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum OperatorToken {
     // comparison operators
     Equal,              // ==
@@ -91,6 +91,24 @@ impl OperatorToken {
         }
     }
 
+    // 대입 연산자는 이항 연산자로 분류되지만 식이 아니라 statement를 만듭니다.
+    pub fn is_assignment_operator(&self) -> bool {
+        matches!(
+            self,
+            OperatorToken::Assign
+                | OperatorToken::PlusAssign
+                | OperatorToken::MinusAssign
+                | OperatorToken::StarAssign
+                | OperatorToken::SlashAssign
+                | OperatorToken::ModuloAssign
+                | OperatorToken::AndAssign
+                | OperatorToken::OrAssign
+                | OperatorToken::XorAssign
+                | OperatorToken::LeftShiftAssign
+                | OperatorToken::RightShiftAssign
+        )
+    }
+
     pub fn is_unary_operator(&self) -> bool {
         match self {
             OperatorToken::Not
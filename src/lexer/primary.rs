@@ -1,12 +1,22 @@
+use crate::utils::interner::Symbol;
+
 use super::token::Token;
 
 #[derive(Clone, Debug, PartialEq)]
 pub enum PrimaryToken {
     // primary expression
-    Identifier(String),
-    Integer(i64),
+    Identifier(Symbol),
+    // 정수는 부호 없는 크기입니다. 앞의 -와 합친 범위는 파서에서 검사합니다.
+    Integer(u64),
+    // 10i32, 10i64, 2.5f32처럼 접미사로 타입을 정한 숫자
+    Int32(u32),
+    Int64(u64),
     Float(f64),
+    Float32(f32),
+    // 문자열과 주석의 내용은 인터닝하지 않고 토큰이 소유합니다.
     String(String),
+    // 'a' 형태의 문자 하나
+    Char(char),
     Boolean(bool),
     Comment(String),
 }
@@ -8,7 +8,7 @@ use crate::lexer::{
 pub fn binary_expression() {
     let text = r#"1+20"#.to_owned();
 
-    let tokens = Tokenizer::string_to_tokens(text).unwrap();
+    let tokens = Tokenizer::string_to_tokens(&text).unwrap();
 
     assert_eq!(
         tokens,
@@ -24,7 +24,7 @@ pub fn binary_expression() {
 pub fn binary_expression_more() {
     let text = r#"1+20*55"#.to_owned();
 
-    let tokens = Tokenizer::string_to_tokens(text).unwrap();
+    let tokens = Tokenizer::string_to_tokens(&text).unwrap();
 
     assert_eq!(
         tokens,
@@ -42,7 +42,7 @@ pub fn binary_expression_more() {
 pub fn parentheses_expression() {
     let text = r#"1+(20*55)"#.to_owned();
 
-    let tokens = Tokenizer::string_to_tokens(text).unwrap();
+    let tokens = Tokenizer::string_to_tokens(&text).unwrap();
 
     assert_eq!(
         tokens,
@@ -57,3 +57,22 @@ pub fn parentheses_expression() {
         ]
     );
 }
+
+#[test]
+pub fn range_expression() {
+    let text = r#"0..10 1.5..n"#.to_owned();
+
+    let tokens = Tokenizer::string_to_tokens(&text).unwrap();
+
+    assert_eq!(
+        tokens,
+        vec![
+            PrimaryToken::Integer(0).into(),
+            OperatorToken::Range.into(),
+            PrimaryToken::Integer(10).into(),
+            PrimaryToken::Float(1.5).into(),
+            OperatorToken::Range.into(),
+            PrimaryToken::Identifier("n".into()).into(),
+        ]
+    );
+}
@@ -1,21 +1,37 @@
 #![cfg(test)]
 
-use crate::lexer::{general::GeneralToken, tokenizer::Tokenizer};
+use crate::lexer::{general::GeneralToken, operator::OperatorToken, tokenizer::Tokenizer};
 
 #[test]
 pub fn arrow() {
     let text = r#"->"#.to_owned();
 
-    let tokens = Tokenizer::string_to_tokens(text).unwrap();
+    let tokens = Tokenizer::string_to_tokens(&text).unwrap();
 
     assert_eq!(tokens, vec![GeneralToken::Arrow.into()]);
 }
 
+#[test]
+pub fn fat_arrow() {
+    let text = r#"=> == ="#.to_owned();
+
+    let tokens = Tokenizer::string_to_tokens(&text).unwrap();
+
+    assert_eq!(
+        tokens,
+        vec![
+            GeneralToken::FatArrow.into(),
+            OperatorToken::Equal.into(),
+            OperatorToken::Assign.into()
+        ]
+    );
+}
+
 #[test]
 pub fn comma() {
     let text = r#","#.to_owned();
 
-    let tokens = Tokenizer::string_to_tokens(text).unwrap();
+    let tokens = Tokenizer::string_to_tokens(&text).unwrap();
 
     assert_eq!(tokens, vec![GeneralToken::Comma.into()]);
 }
@@ -24,7 +40,7 @@ pub fn comma() {
 pub fn semicolon() {
     let text = r#";"#.to_owned();
 
-    let tokens = Tokenizer::string_to_tokens(text).unwrap();
+    let tokens = Tokenizer::string_to_tokens(&text).unwrap();
 
     assert_eq!(tokens, vec![GeneralToken::SemiColon.into()]);
 }
@@ -33,7 +49,7 @@ pub fn semicolon() {
 pub fn colon() {
     let text = r#":"#.to_owned();
 
-    let tokens = Tokenizer::string_to_tokens(text).unwrap();
+    let tokens = Tokenizer::string_to_tokens(&text).unwrap();
 
     assert_eq!(tokens, vec![GeneralToken::Colon.into()]);
 }
@@ -42,7 +58,7 @@ pub fn colon() {
 pub fn left_parentheses() {
     let text = r#"("#.to_owned();
 
-    let tokens = Tokenizer::string_to_tokens(text).unwrap();
+    let tokens = Tokenizer::string_to_tokens(&text).unwrap();
 
     assert_eq!(tokens, vec![GeneralToken::LeftParentheses.into()]);
 }
@@ -51,7 +67,7 @@ pub fn left_parentheses() {
 pub fn right_parentheses() {
     let text = r#")"#.to_owned();
 
-    let tokens = Tokenizer::string_to_tokens(text).unwrap();
+    let tokens = Tokenizer::string_to_tokens(&text).unwrap();
 
     assert_eq!(tokens, vec![GeneralToken::RightParentheses.into()]);
 }
@@ -60,7 +76,7 @@ pub fn right_parentheses() {
 pub fn parentheses() {
     let text = r#"()"#.to_owned();
 
-    let tokens = Tokenizer::string_to_tokens(text).unwrap();
+    let tokens = Tokenizer::string_to_tokens(&text).unwrap();
 
     assert_eq!(
         tokens,
@@ -75,7 +91,7 @@ pub fn parentheses() {
 pub fn left_brace() {
     let text = r#"{"#.to_owned();
 
-    let tokens = Tokenizer::string_to_tokens(text).unwrap();
+    let tokens = Tokenizer::string_to_tokens(&text).unwrap();
 
     assert_eq!(tokens, vec![GeneralToken::LeftBrace.into()]);
 }
@@ -84,7 +100,7 @@ pub fn left_brace() {
 pub fn right_brace() {
     let text = r#"}"#.to_owned();
 
-    let tokens = Tokenizer::string_to_tokens(text).unwrap();
+    let tokens = Tokenizer::string_to_tokens(&text).unwrap();
 
     assert_eq!(tokens, vec![GeneralToken::RightBrace.into()]);
 }
@@ -93,7 +109,7 @@ pub fn right_brace() {
 pub fn brace() {
     let text = r#"{}"#.to_owned();
 
-    let tokens = Tokenizer::string_to_tokens(text).unwrap();
+    let tokens = Tokenizer::string_to_tokens(&text).unwrap();
 
     assert_eq!(
         tokens,
@@ -108,7 +124,7 @@ pub fn brace() {
 pub fn left_bracket() {
     let text = r#"["#.to_owned();
 
-    let tokens = Tokenizer::string_to_tokens(text).unwrap();
+    let tokens = Tokenizer::string_to_tokens(&text).unwrap();
 
     assert_eq!(tokens, vec![GeneralToken::LeftBracket.into()]);
 }
@@ -117,7 +133,7 @@ pub fn left_bracket() {
 pub fn right_bracket() {
     let text = r#"]"#.to_owned();
 
-    let tokens = Tokenizer::string_to_tokens(text).unwrap();
+    let tokens = Tokenizer::string_to_tokens(&text).unwrap();
 
     assert_eq!(tokens, vec![GeneralToken::RightBracket.into()]);
 }
@@ -126,7 +142,7 @@ pub fn right_bracket() {
 pub fn bracket() {
     let text = r#"[]"#.to_owned();
 
-    let tokens = Tokenizer::string_to_tokens(text).unwrap();
+    let tokens = Tokenizer::string_to_tokens(&text).unwrap();
 
     assert_eq!(
         tokens,
@@ -141,7 +157,7 @@ pub fn bracket() {
 pub fn at() {
     let text = r#"@"#.to_owned();
 
-    let tokens = Tokenizer::string_to_tokens(text).unwrap();
+    let tokens = Tokenizer::string_to_tokens(&text).unwrap();
 
     assert_eq!(tokens, vec![GeneralToken::At.into()]);
 }
@@ -150,7 +166,7 @@ pub fn at() {
 pub fn backtick() {
     let text = r#"`"#.to_owned();
 
-    let tokens = Tokenizer::string_to_tokens(text).unwrap();
+    let tokens = Tokenizer::string_to_tokens(&text).unwrap();
 
     assert_eq!(tokens, vec![GeneralToken::Backtick.into()]);
 }
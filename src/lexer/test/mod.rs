@@ -4,3 +4,5 @@ pub(crate) mod primary;
 pub(crate) mod general;
 
 pub(crate) mod expression;
+
+pub(crate) mod tokenizer;
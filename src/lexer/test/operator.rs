@@ -6,7 +6,7 @@ use crate::lexer::{operator::OperatorToken, tokenizer::Tokenizer};
 pub fn assign() {
     let text = r#"="#.to_owned();
 
-    let tokens = Tokenizer::string_to_tokens(text).unwrap();
+    let tokens = Tokenizer::string_to_tokens(&text).unwrap();
 
     assert_eq!(tokens, vec![OperatorToken::Assign.into()]);
 }
@@ -15,7 +15,7 @@ pub fn assign() {
 pub fn plus() {
     let text = r#"+"#.to_owned();
 
-    let tokens = Tokenizer::string_to_tokens(text).unwrap();
+    let tokens = Tokenizer::string_to_tokens(&text).unwrap();
 
     assert_eq!(tokens, vec![OperatorToken::Plus.into()]);
 }
@@ -24,7 +24,7 @@ pub fn plus() {
 pub fn plus_assign() {
     let text = r#"+="#.to_owned();
 
-    let tokens = Tokenizer::string_to_tokens(text).unwrap();
+    let tokens = Tokenizer::string_to_tokens(&text).unwrap();
 
     assert_eq!(tokens, vec![OperatorToken::PlusAssign.into()]);
 }
@@ -33,7 +33,7 @@ pub fn plus_assign() {
 pub fn minus() {
     let text = r#"-"#.to_owned();
 
-    let tokens = Tokenizer::string_to_tokens(text).unwrap();
+    let tokens = Tokenizer::string_to_tokens(&text).unwrap();
 
     assert_eq!(tokens, vec![OperatorToken::Minus.into()]);
 }
@@ -42,7 +42,7 @@ pub fn minus() {
 pub fn minus_assign() {
     let text = r#"-="#.to_owned();
 
-    let tokens = Tokenizer::string_to_tokens(text).unwrap();
+    let tokens = Tokenizer::string_to_tokens(&text).unwrap();
 
     assert_eq!(tokens, vec![OperatorToken::MinusAssign.into()]);
 }
@@ -51,7 +51,7 @@ pub fn minus_assign() {
 pub fn star() {
     let text = r#"*"#.to_owned();
 
-    let tokens = Tokenizer::string_to_tokens(text).unwrap();
+    let tokens = Tokenizer::string_to_tokens(&text).unwrap();
 
     assert_eq!(tokens, vec![OperatorToken::Star.into()]);
 }
@@ -60,7 +60,7 @@ pub fn star() {
 pub fn star_assign() {
     let text = r#"*="#.to_owned();
 
-    let tokens = Tokenizer::string_to_tokens(text).unwrap();
+    let tokens = Tokenizer::string_to_tokens(&text).unwrap();
 
     assert_eq!(tokens, vec![OperatorToken::StarAssign.into()]);
 }
@@ -69,7 +69,7 @@ pub fn star_assign() {
 pub fn slash() {
     let text = r#"/"#.to_owned();
 
-    let tokens = Tokenizer::string_to_tokens(text).unwrap();
+    let tokens = Tokenizer::string_to_tokens(&text).unwrap();
 
     assert_eq!(tokens, vec![OperatorToken::Slash.into()]);
 }
@@ -78,7 +78,7 @@ pub fn slash() {
 pub fn slash_assign() {
     let text = r#"/="#.to_owned();
 
-    let tokens = Tokenizer::string_to_tokens(text).unwrap();
+    let tokens = Tokenizer::string_to_tokens(&text).unwrap();
 
     assert_eq!(tokens, vec![OperatorToken::SlashAssign.into()]);
 }
@@ -87,7 +87,7 @@ pub fn slash_assign() {
 pub fn modulo() {
     let text = r#"%"#.to_owned();
 
-    let tokens = Tokenizer::string_to_tokens(text).unwrap();
+    let tokens = Tokenizer::string_to_tokens(&text).unwrap();
 
     assert_eq!(tokens, vec![OperatorToken::Modulo.into()]);
 }
@@ -96,7 +96,7 @@ pub fn modulo() {
 pub fn modulo_assign() {
     let text = r#"%="#.to_owned();
 
-    let tokens = Tokenizer::string_to_tokens(text).unwrap();
+    let tokens = Tokenizer::string_to_tokens(&text).unwrap();
 
     assert_eq!(tokens, vec![OperatorToken::ModuloAssign.into()]);
 }
@@ -105,7 +105,7 @@ pub fn modulo_assign() {
 pub fn ampersand() {
     let text = r#"&"#.to_owned();
 
-    let tokens = Tokenizer::string_to_tokens(text).unwrap();
+    let tokens = Tokenizer::string_to_tokens(&text).unwrap();
 
     assert_eq!(tokens, vec![OperatorToken::Ampersand.into()]);
 }
@@ -114,7 +114,7 @@ pub fn ampersand() {
 pub fn and_assign() {
     let text = r#"&="#.to_owned();
 
-    let tokens = Tokenizer::string_to_tokens(text).unwrap();
+    let tokens = Tokenizer::string_to_tokens(&text).unwrap();
 
     assert_eq!(tokens, vec![OperatorToken::AndAssign.into()]);
 }
@@ -123,7 +123,7 @@ pub fn and_assign() {
 pub fn bitwise_or() {
     let text = r#"|"#.to_owned();
 
-    let tokens = Tokenizer::string_to_tokens(text).unwrap();
+    let tokens = Tokenizer::string_to_tokens(&text).unwrap();
 
     assert_eq!(tokens, vec![OperatorToken::BitwiseOr.into()]);
 }
@@ -132,7 +132,7 @@ pub fn bitwise_or() {
 pub fn or_assign() {
     let text = r#"|="#.to_owned();
 
-    let tokens = Tokenizer::string_to_tokens(text).unwrap();
+    let tokens = Tokenizer::string_to_tokens(&text).unwrap();
 
     assert_eq!(tokens, vec![OperatorToken::OrAssign.into()]);
 }
@@ -141,7 +141,7 @@ pub fn or_assign() {
 pub fn bitwise_xor() {
     let text = r#"^"#.to_owned();
 
-    let tokens = Tokenizer::string_to_tokens(text).unwrap();
+    let tokens = Tokenizer::string_to_tokens(&text).unwrap();
 
     assert_eq!(tokens, vec![OperatorToken::BitwiseXor.into()]);
 }
@@ -150,7 +150,7 @@ pub fn bitwise_xor() {
 pub fn xor_assign() {
     let text = r#"^="#.to_owned();
 
-    let tokens = Tokenizer::string_to_tokens(text).unwrap();
+    let tokens = Tokenizer::string_to_tokens(&text).unwrap();
 
     assert_eq!(tokens, vec![OperatorToken::XorAssign.into()]);
 }
@@ -159,7 +159,7 @@ pub fn xor_assign() {
 pub fn bitwise_not() {
     let text = r#"~"#.to_owned();
 
-    let tokens = Tokenizer::string_to_tokens(text).unwrap();
+    let tokens = Tokenizer::string_to_tokens(&text).unwrap();
 
     assert_eq!(tokens, vec![OperatorToken::BitwiseNot.into()]);
 }
@@ -168,7 +168,7 @@ pub fn bitwise_not() {
 pub fn left_shift() {
     let text = r#"<<"#.to_owned();
 
-    let tokens = Tokenizer::string_to_tokens(text).unwrap();
+    let tokens = Tokenizer::string_to_tokens(&text).unwrap();
 
     assert_eq!(tokens, vec![OperatorToken::LeftShift.into()]);
 }
@@ -177,7 +177,7 @@ pub fn left_shift() {
 pub fn left_shift_assign() {
     let text = r#"<<="#.to_owned();
 
-    let tokens = Tokenizer::string_to_tokens(text).unwrap();
+    let tokens = Tokenizer::string_to_tokens(&text).unwrap();
 
     assert_eq!(tokens, vec![OperatorToken::LeftShiftAssign.into()]);
 }
@@ -186,7 +186,7 @@ pub fn left_shift_assign() {
 pub fn right_shift() {
     let text = r#">>"#.to_owned();
 
-    let tokens = Tokenizer::string_to_tokens(text).unwrap();
+    let tokens = Tokenizer::string_to_tokens(&text).unwrap();
 
     assert_eq!(tokens, vec![OperatorToken::RightShift.into()]);
 }
@@ -195,7 +195,7 @@ pub fn right_shift() {
 pub fn right_shift_assign() {
     let text = r#">>="#.to_owned();
 
-    let tokens = Tokenizer::string_to_tokens(text).unwrap();
+    let tokens = Tokenizer::string_to_tokens(&text).unwrap();
 
     assert_eq!(tokens, vec![OperatorToken::RightShiftAssign.into()]);
 }
@@ -204,7 +204,7 @@ pub fn right_shift_assign() {
 pub fn equal() {
     let text = r#"=="#.to_owned();
 
-    let tokens = Tokenizer::string_to_tokens(text).unwrap();
+    let tokens = Tokenizer::string_to_tokens(&text).unwrap();
 
     assert_eq!(tokens, vec![OperatorToken::Equal.into()]);
 }
@@ -213,7 +213,7 @@ pub fn equal() {
 pub fn not_equal() {
     let text = r#"!="#.to_owned();
 
-    let tokens = Tokenizer::string_to_tokens(text).unwrap();
+    let tokens = Tokenizer::string_to_tokens(&text).unwrap();
 
     assert_eq!(tokens, vec![OperatorToken::NotEqual.into()]);
 }
@@ -222,7 +222,7 @@ pub fn not_equal() {
 pub fn less_than() {
     let text = r#"<"#.to_owned();
 
-    let tokens = Tokenizer::string_to_tokens(text).unwrap();
+    let tokens = Tokenizer::string_to_tokens(&text).unwrap();
 
     assert_eq!(tokens, vec![OperatorToken::LessThan.into()]);
 }
@@ -231,7 +231,7 @@ pub fn less_than() {
 pub fn less_than_or_equal() {
     let text = r#"<="#.to_owned();
 
-    let tokens = Tokenizer::string_to_tokens(text).unwrap();
+    let tokens = Tokenizer::string_to_tokens(&text).unwrap();
 
     assert_eq!(tokens, vec![OperatorToken::LessThanOrEqual.into()]);
 }
@@ -240,7 +240,7 @@ pub fn less_than_or_equal() {
 pub fn greater_than() {
     let text = r#">"#.to_owned();
 
-    let tokens = Tokenizer::string_to_tokens(text).unwrap();
+    let tokens = Tokenizer::string_to_tokens(&text).unwrap();
 
     assert_eq!(tokens, vec![OperatorToken::GreaterThan.into()]);
 }
@@ -249,7 +249,7 @@ pub fn greater_than() {
 pub fn greater_than_or_equal() {
     let text = r#">="#.to_owned();
 
-    let tokens = Tokenizer::string_to_tokens(text).unwrap();
+    let tokens = Tokenizer::string_to_tokens(&text).unwrap();
 
     assert_eq!(tokens, vec![OperatorToken::GreaterThanOrEqual.into()]);
 }
@@ -258,7 +258,7 @@ pub fn greater_than_or_equal() {
 pub fn and() {
     let text = r#"&&"#.to_owned();
 
-    let tokens = Tokenizer::string_to_tokens(text).unwrap();
+    let tokens = Tokenizer::string_to_tokens(&text).unwrap();
 
     assert_eq!(tokens, vec![OperatorToken::And.into()]);
 }
@@ -267,7 +267,7 @@ pub fn and() {
 pub fn or() {
     let text = r#"||"#.to_owned();
 
-    let tokens = Tokenizer::string_to_tokens(text).unwrap();
+    let tokens = Tokenizer::string_to_tokens(&text).unwrap();
 
     assert_eq!(tokens, vec![OperatorToken::Or.into()]);
 }
@@ -276,7 +276,7 @@ pub fn or() {
 pub fn not() {
     let text = r#"!"#.to_owned();
 
-    let tokens = Tokenizer::string_to_tokens(text).unwrap();
+    let tokens = Tokenizer::string_to_tokens(&text).unwrap();
 
     assert_eq!(tokens, vec![OperatorToken::Not.into()]);
 }
@@ -285,7 +285,7 @@ pub fn not() {
 pub fn dot() {
     let text = r#"."#.to_owned();
 
-    let tokens = Tokenizer::string_to_tokens(text).unwrap();
+    let tokens = Tokenizer::string_to_tokens(&text).unwrap();
 
     assert_eq!(tokens, vec![OperatorToken::Dot.into()]);
 }
@@ -294,7 +294,7 @@ pub fn dot() {
 pub fn range() {
     let text = r#".."#.to_owned();
 
-    let tokens = Tokenizer::string_to_tokens(text).unwrap();
+    let tokens = Tokenizer::string_to_tokens(&text).unwrap();
 
     assert_eq!(tokens, vec![OperatorToken::Range.into()]);
 }
@@ -303,7 +303,7 @@ pub fn range() {
 pub fn question() {
     let text = r#"?"#.to_owned();
 
-    let tokens = Tokenizer::string_to_tokens(text).unwrap();
+    let tokens = Tokenizer::string_to_tokens(&text).unwrap();
 
     assert_eq!(tokens, vec![OperatorToken::Question.into()]);
 }
@@ -1,12 +1,16 @@
 #![cfg(test)]
 
-use crate::lexer::{primary::PrimaryToken, tokenizer::Tokenizer};
+use crate::{
+    error::all_error::AllError,
+    lexer::{operator::OperatorToken, primary::PrimaryToken, tokenizer::Tokenizer},
+    utils::interner::Symbol,
+};
 
 #[test]
 pub fn integer() {
     let text = r#"123234"#.to_owned();
 
-    let tokens = Tokenizer::string_to_tokens(text).unwrap();
+    let tokens = Tokenizer::string_to_tokens(&text).unwrap();
 
     assert_eq!(tokens, vec![PrimaryToken::Integer(123234).into()]);
 }
@@ -15,7 +19,7 @@ pub fn integer() {
 pub fn float() {
     let text = r#"123.234"#.to_owned();
 
-    let tokens = Tokenizer::string_to_tokens(text).unwrap();
+    let tokens = Tokenizer::string_to_tokens(&text).unwrap();
 
     assert_eq!(tokens, vec![PrimaryToken::Float(123.234).into()]);
 }
@@ -24,35 +28,29 @@ pub fn float() {
 pub fn string() {
     let text = r#""123.234""#.to_owned();
 
-    let tokens = Tokenizer::string_to_tokens(text).unwrap();
+    let tokens = Tokenizer::string_to_tokens(&text).unwrap();
 
-    assert_eq!(
-        tokens,
-        vec![PrimaryToken::String("123.234".to_owned()).into()]
-    );
+    assert_eq!(tokens, vec![PrimaryToken::String("123.234".into()).into()]);
 }
 
 #[test]
 pub fn identifier() {
     let text = r#"a"#.to_owned();
 
-    let tokens = Tokenizer::string_to_tokens(text).unwrap();
+    let tokens = Tokenizer::string_to_tokens(&text).unwrap();
 
-    assert_eq!(
-        tokens,
-        vec![PrimaryToken::Identifier("a".to_owned()).into()]
-    );
+    assert_eq!(tokens, vec![PrimaryToken::Identifier("a".into()).into()]);
 }
 
 #[test]
 pub fn line_comment() {
     let text = r#"// 123.234"#.to_owned();
 
-    let tokens = Tokenizer::string_to_tokens(text).unwrap();
+    let tokens = Tokenizer::string_to_tokens(&text).unwrap();
 
     assert_eq!(
         tokens,
-        vec![PrimaryToken::Comment(" 123.234".to_owned()).into()]
+        vec![PrimaryToken::Comment(" 123.234".into()).into()]
     );
 }
 
@@ -60,10 +58,238 @@ pub fn line_comment() {
 pub fn block_comment() {
     let text = r#"/* 123.234 */"#.to_owned();
 
+    let tokens = Tokenizer::string_to_tokens(&text).unwrap();
+
+    assert_eq!(
+        tokens,
+        vec![PrimaryToken::Comment(" 123.234 ".into()).into()]
+    );
+}
+
+#[test]
+pub fn multibyte_string() {
+    let text = r#""안녕하세요" + 1"#.to_owned();
+
+    let tokens = Tokenizer::string_to_tokens(&text).unwrap();
+
+    assert_eq!(
+        tokens,
+        vec![
+            PrimaryToken::String("안녕하세요".into()).into(),
+            OperatorToken::Plus.into(),
+            PrimaryToken::Integer(1).into()
+        ]
+    );
+}
+
+#[test]
+pub fn multibyte_line_comment() {
+    let text = "// 한글 주석\nfoo".to_owned();
+
+    let tokens = Tokenizer::string_to_tokens(&text).unwrap();
+
+    assert_eq!(
+        tokens,
+        vec![
+            PrimaryToken::Comment(" 한글 주석".into()).into(),
+            PrimaryToken::Identifier("foo".into()).into()
+        ]
+    );
+}
+
+#[test]
+pub fn integer_prefixes_and_separators() {
+    let text = r#"0xFF 0o777 0b1010 1_000_000 0xdead_beef 0..0x10"#.to_owned();
+
+    let tokens = Tokenizer::string_to_tokens(&text).unwrap();
+
+    assert_eq!(
+        tokens,
+        vec![
+            PrimaryToken::Integer(255).into(),
+            PrimaryToken::Integer(511).into(),
+            PrimaryToken::Integer(10).into(),
+            PrimaryToken::Integer(1_000_000).into(),
+            PrimaryToken::Integer(0xdead_beef).into(),
+            PrimaryToken::Integer(0).into(),
+            OperatorToken::Range.into(),
+            PrimaryToken::Integer(16).into(),
+        ]
+    );
+}
+
+#[test]
+pub fn number_suffixes() {
+    let text = r#"10i32 0x7fi64 2.5f32 1_0.5f64 3f64 0x1f32"#.to_owned();
+
+    let tokens = Tokenizer::string_to_tokens(&text).unwrap();
+
+    assert_eq!(
+        tokens,
+        vec![
+            PrimaryToken::Int32(10).into(),
+            PrimaryToken::Int64(127).into(),
+            PrimaryToken::Float32(2.5).into(),
+            PrimaryToken::Float(10.5).into(),
+            PrimaryToken::Float(3.0).into(),
+            // 16진수의 f는 자릿수입니다.
+            PrimaryToken::Integer(0x1f32).into(),
+        ]
+    );
+}
+
+// 부호는 파서가 붙이므로 -를 붙였을 때만 범위에 들어가는 크기도 토큰이 됩니다.
+#[test]
+pub fn integer_magnitudes() {
+    let text = r#"9223372036854775808 2147483648i32 9223372036854775808i64"#;
+
     let tokens = Tokenizer::string_to_tokens(text).unwrap();
 
     assert_eq!(
         tokens,
-        vec![PrimaryToken::Comment(" 123.234 ".to_owned()).into()]
+        vec![
+            PrimaryToken::Integer(9223372036854775808).into(),
+            PrimaryToken::Int32(2147483648).into(),
+            PrimaryToken::Int64(9223372036854775808).into(),
+        ]
     );
 }
+
+#[test]
+pub fn number_literal_errors() {
+    for (text, message) in [
+        ("0x", "invalid integer number format: 0x"),
+        ("0b102", "invalid integer number format: 0b102"),
+        ("0x1.5", "invalid floating point number format: 0x1.5"),
+        ("10u8", "invalid suffix u8 for number literal 10u8"),
+        ("1e5", "invalid suffix e5 for number literal 1e5"),
+        (
+            "3000000000i32",
+            "literal 3000000000i32 is out of range for i32",
+        ),
+        (
+            "9223372036854775809",
+            "literal 9223372036854775809 is out of range for i64",
+        ),
+        (
+            "18446744073709551616i64",
+            "literal 18446744073709551616i64 is out of range for i64",
+        ),
+    ] {
+        match Tokenizer::string_to_tokens(text) {
+            Err(AllError::LexerError(error)) => assert_eq!(error.message, message, "{}", text),
+            result => panic!("{}: {:?}", text, result),
+        }
+    }
+}
+
+#[test]
+pub fn string_escapes() {
+    let text = r#""a\tb\n\"c\"\\" "plain""#.to_owned();
+
+    let tokens = Tokenizer::string_to_tokens(&text).unwrap();
+
+    assert_eq!(
+        tokens,
+        vec![
+            PrimaryToken::String("a\tb\n\"c\"\\".into()).into(),
+            PrimaryToken::String("plain".into()).into(),
+        ]
+    );
+}
+
+#[test]
+pub fn raw_strings() {
+    let text = "r\"a\\n\nb\" r#\"say \"hi\"\"# r##\"\"#\"## raw".to_owned();
+
+    let tokens = Tokenizer::string_to_tokens(&text).unwrap();
+
+    assert_eq!(
+        tokens,
+        vec![
+            PrimaryToken::String("a\\n\nb".into()).into(),
+            PrimaryToken::String("say \"hi\"".into()).into(),
+            PrimaryToken::String("\"#".into()).into(),
+            PrimaryToken::Identifier("raw".into()).into(),
+        ]
+    );
+}
+
+#[test]
+pub fn string_errors() {
+    for (text, message) in [
+        (r#""a\q""#, "unknown character escape: \\q"),
+        (r#""a\"#, "unexpected EOF"),
+        (r#"r#"a""#, "unterminated raw string"),
+        (r#"r#a"#, "expected '\"' after r#, found Some('a')"),
+    ] {
+        match Tokenizer::string_to_tokens(text) {
+            Err(AllError::LexerError(error)) => assert_eq!(error.message, message, "{}", text),
+            result => panic!("{}: {:?}", text, result),
+        }
+    }
+}
+
+#[test]
+pub fn char_literal() {
+    let text = r#"'a' '가' '\n' '\t' '\0' '\\' '\'' '"'"#.to_owned();
+
+    let tokens = Tokenizer::string_to_tokens(&text).unwrap();
+
+    assert_eq!(
+        tokens,
+        ['a', '가', '\n', '\t', '\0', '\\', '\'', '"']
+            .into_iter()
+            .map(|character| PrimaryToken::Char(character).into())
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+pub fn char_literal_errors() {
+    for (text, message) in [
+        ("''", "empty char literal"),
+        ("'ab'", "char literal must contain exactly one character"),
+        ("'a", "unterminated char literal"),
+        ("'\\q'", "unknown character escape: \\q"),
+    ] {
+        match Tokenizer::string_to_tokens(text) {
+            Err(AllError::LexerError(error)) => assert_eq!(error.message, message, "{}", text),
+            result => panic!("{}: {:?}", text, result),
+        }
+    }
+}
+
+#[test]
+pub fn block_comment_with_star() {
+    let text = r#"/* a * b **/"#.to_owned();
+
+    let tokens = Tokenizer::string_to_tokens(&text).unwrap();
+
+    assert_eq!(
+        tokens,
+        vec![PrimaryToken::Comment(" a * b *".into()).into()]
+    );
+}
+
+#[test]
+pub fn unterminated_string() {
+    let text = r#""123"#.to_owned();
+
+    let result = Tokenizer::string_to_tokens(&text);
+
+    assert!(result.is_err());
+}
+
+// 문자열과 주석의 내용은 인터닝하지 않으므로 다시 읽어도 전역 인터너가 커지지 않습니다.
+#[test]
+pub fn literals_are_not_interned() {
+    let text = r#"string_name "string literal 4736" r"raw literal 4736" // comment 4736"#;
+
+    Tokenizer::string_to_tokens(text).unwrap();
+
+    assert!(Symbol::get("string_name").is_some());
+    assert_eq!(Symbol::get("string literal 4736"), None);
+    assert_eq!(Symbol::get("raw literal 4736"), None);
+    assert_eq!(Symbol::get(" comment 4736"), None);
+}
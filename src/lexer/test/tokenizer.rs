@@ -0,0 +1,81 @@
+#![cfg(test)]
+
+use crate::{
+    error::all_error::AllError,
+    lexer::{
+        general::GeneralToken,
+        operator::OperatorToken,
+        primary::PrimaryToken,
+        token::{SpannedToken, Token},
+        tokenizer::Tokenizer,
+    },
+};
+
+fn token(result: Option<&Result<SpannedToken, AllError>>) -> Option<Token> {
+    result.map(|result| result.as_ref().unwrap().token.clone())
+}
+
+#[test]
+pub fn iterate_tokens_with_spans() {
+    let tokens = Tokenizer::new("a + 1\n(b)")
+        .map(|token| token.unwrap())
+        .map(|token| (token.token, token.span.line, token.span.column))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        tokens,
+        vec![
+            (PrimaryToken::Identifier("a".into()).into(), 1, 1),
+            (OperatorToken::Plus.into(), 1, 3),
+            (PrimaryToken::Integer(1).into(), 1, 5),
+            (GeneralToken::LeftParentheses.into(), 2, 1),
+            (PrimaryToken::Identifier("b".into()).into(), 2, 2),
+            (GeneralToken::RightParentheses.into(), 2, 3),
+        ]
+    );
+}
+
+#[test]
+pub fn peek_does_not_consume() {
+    let mut tokenizer = Tokenizer::new("a b c");
+
+    assert_eq!(
+        token(tokenizer.peek()),
+        Some(PrimaryToken::Identifier("a".into()).into())
+    );
+    assert_eq!(
+        token(tokenizer.peek_n(2)),
+        Some(PrimaryToken::Identifier("c".into()).into())
+    );
+    assert_eq!(token(tokenizer.peek_n(3)), None);
+
+    let tokens = tokenizer
+        .map(|token| token.unwrap().token)
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        tokens,
+        vec![
+            PrimaryToken::Identifier("a".into()).into(),
+            PrimaryToken::Identifier("b".into()).into(),
+            PrimaryToken::Identifier("c".into()).into(),
+        ]
+    );
+}
+
+// 오류를 반환한 뒤에는 더 읽지 않습니다.
+#[test]
+pub fn iteration_stops_after_error() {
+    let mut tokenizer = Tokenizer::new("a 'bc' d");
+
+    assert!(matches!(tokenizer.next(), Some(Ok(_))));
+    assert!(matches!(
+        tokenizer.peek(),
+        Some(Err(AllError::LexerError(_)))
+    ));
+    assert!(matches!(
+        tokenizer.next(),
+        Some(Err(AllError::LexerError(_)))
+    ));
+    assert!(tokenizer.next().is_none());
+}
@@ -1,3 +1,5 @@
+use crate::utils::span::Span;
+
 use super::{
     general::GeneralToken, keyword::Keyword, operator::OperatorToken, primary::PrimaryToken,
 };
@@ -13,14 +15,14 @@ pub enum Token {
     Eof,
 }
 
-impl Token {
-    pub fn is_binary_operator(&self) -> bool {
-        match self {
-            Token::Operator(operator) => operator.is_binary_operator(),
-            _ => false,
-        }
-    }
+// 토큰과 그 토큰의 소스코드 위치입니다. Tokenizer를 반복자로 쓰면 이 값을 하나씩 반환합니다.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SpannedToken {
+    pub token: Token,
+    pub span: Span,
+}
 
+impl Token {
     pub fn is_unary_operator(&self) -> bool {
         match self {
             Token::Operator(operator) => operator.is_unary_operator(),
@@ -1,24 +1,40 @@
-use crate::{error::all_error::AllError, utils::logger::Logger};
+use std::{collections::VecDeque, num::IntErrorKind};
+
+use crate::{
+    error::all_error::{source_error::SourceError, AllError},
+    utils::span::LineIndex,
+};
 
 use super::{
-    general::GeneralToken, keyword::Keyword, operator::OperatorToken, primary::PrimaryToken,
-    token::Token,
+    general::GeneralToken,
+    keyword::Keyword,
+    operator::OperatorToken,
+    primary::PrimaryToken,
+    token::{SpannedToken, Token},
 };
 
+// 원본 텍스트를 복사하지 않고 바이트 위치로 순회하는 토크나이저입니다.
 #[derive(Debug)]
-pub struct Tokenizer {
-    buffer: Vec<char>,
-    buffer_index: Option<usize>,
+pub struct Tokenizer<'a> {
+    source: &'a str,
+    buffer_index: Option<usize>, // last_char의 바이트 위치
     last_char: Option<char>,
+    line_index: LineIndex,
+    // peek으로 미리 읽어 둔 토큰. next는 이 토큰부터 반환합니다.
+    lookahead: VecDeque<Result<SpannedToken, AllError>>,
+    // 오류를 반환한 뒤에는 더 읽지 않습니다.
+    failed: bool,
 }
 
-impl Tokenizer {
-    pub fn new(text: String) -> Self {
-        Logger::info(format!("echo: {:?}", text));
+impl<'a> Tokenizer<'a> {
+    pub fn new(source: &'a str) -> Self {
         Self {
             last_char: None,
-            buffer: text.chars().collect(),
+            source,
             buffer_index: None,
+            line_index: LineIndex::new(source),
+            lookahead: VecDeque::new(),
+            failed: false,
         }
     }
 
@@ -94,21 +110,32 @@ impl Tokenizer {
 
     fn is_eof(&self) -> bool {
         match self.buffer_index {
-            Some(index) => index >= self.buffer.len(),
+            Some(index) => index >= self.source.len(),
             _ => false,
         }
     }
 
+    fn char_at(&self, index: usize) -> Option<char> {
+        self.source
+            .get(index..)
+            .and_then(|rest| rest.chars().next())
+    }
+
+    // 현재 문자 바로 다음 문자의 바이트 위치입니다.
+    fn next_index(&self) -> usize {
+        match self.buffer_index {
+            Some(index) => index + self.char_at(index).map_or(1, char::len_utf8),
+            None => 0,
+        }
+    }
+
     // 버퍼에서 문자 하나를 읽어서 last_char에 보관합니다.
     fn read_char(&mut self) {
-        let buffer_index = match self.buffer_index {
-            Some(index) => index + 1,
-            None => 0,
-        };
+        let buffer_index = self.next_index();
 
         self.buffer_index = Some(buffer_index);
 
-        self.last_char = self.buffer.get(buffer_index).map(|e| e.to_owned());
+        self.last_char = self.char_at(buffer_index);
     }
 
     // 보관했던 문자 하나를 다시 버퍼에 돌려놓습니다.
@@ -118,18 +145,219 @@ impl Tokenizer {
         }
 
         let buffer_index = match self.buffer_index {
-            Some(index) => index - 1,
+            Some(index) => match self.source[..index].chars().next_back() {
+                Some(previous) => index - previous.len_utf8(),
+                None => {
+                    self.buffer_index = None;
+                    self.last_char = None;
+                    return;
+                }
+            },
             None => {
                 return;
             }
         };
         self.buffer_index = Some(buffer_index);
-        self.last_char = self.buffer.get(buffer_index).map(|e| e.to_owned());
+        self.last_char = self.char_at(buffer_index);
+    }
+
+    // start부터 현재 문자 직전까지의 원본 텍스트입니다.
+    fn slice_from(&self, start: usize) -> &'a str {
+        let end = self.buffer_index.unwrap_or(0).min(self.source.len());
+
+        &self.source[start..end]
+    }
+
+    // 숫자 리터럴 하나를 토큰으로 바꿉니다.
+    // 0x, 0o, 0b 접두사와 자릿수 사이의 밑줄(1_000), 타입을 정하는 접미사(10i32, 2.5f32)를 처리합니다.
+    // 접미사가 없는 정수는 의미 분석에서 타입을 추론하고, 접미사가 없는 실수는 f64입니다.
+    fn number_token(number_string: &str) -> Result<Token, AllError> {
+        let (radix, body) = match number_string.get(..2) {
+            Some("0x") => (16, &number_string[2..]),
+            Some("0o") => (8, &number_string[2..]),
+            Some("0b") => (2, &number_string[2..]),
+            _ => (10, number_string),
+        };
+
+        // 16진수의 f는 자릿수이므로 16진수에는 정수 접미사만 붙일 수 있습니다.
+        let suffix_start = body
+            .find(|c: char| {
+                if radix == 16 {
+                    c == 'i'
+                } else {
+                    c.is_ascii_alphabetic()
+                }
+            })
+            .unwrap_or(body.len());
+        let (digits, suffix) = body.split_at(suffix_start);
+        let digits = digits.replace('_', "");
+
+        let invalid_suffix = || {
+            AllError::LexerError(
+                SourceError::new(
+                    1,
+                    format!(
+                        "invalid suffix {} for number literal {}",
+                        suffix, number_string
+                    ),
+                )
+                .with_help("use one of i32, i64, f32 and f64"),
+            )
+        };
+
+        // .이 있거나 실수 접미사가 붙은 경우 실수, 아닌 경우 정수로 인식
+        if digits.contains('.') || matches!(suffix, "f32" | "f64") {
+            let invalid = || {
+                AllError::LexerError(SourceError::new(
+                    2,
+                    format!("invalid floating point number format: {}", number_string),
+                ))
+            };
+
+            if radix != 10 || digits.is_empty() {
+                return Err(invalid());
+            }
+
+            match suffix {
+                "" | "f64" => {
+                    Ok(PrimaryToken::Float(digits.parse().map_err(|_| invalid())?).into())
+                }
+                "f32" => Ok(PrimaryToken::Float32(digits.parse().map_err(|_| invalid())?).into()),
+                _ => Err(invalid_suffix()),
+            }
+        } else {
+            // 부호는 아직 모르므로 크기만 읽습니다. -9223372036854775808처럼 -를 붙여야 범위에 들어가는 값이 있으므로,
+            // 여기에서는 어떤 부호로도 범위를 넘는 값만 거부하고 나머지는 파서가 검사합니다.
+            let out_of_range = |type_name: &str| {
+                AllError::LexerError(SourceError::new(
+                    3,
+                    format!(
+                        "literal {} is out of range for {}",
+                        number_string, type_name
+                    ),
+                ))
+            };
+
+            let magnitude =
+                u64::from_str_radix(&digits, radix).map_err(|error| match error.kind() {
+                    IntErrorKind::PosOverflow => {
+                        out_of_range(if suffix == "i32" { "i32" } else { "i64" })
+                    }
+                    _ => AllError::LexerError(SourceError::new(
+                        4,
+                        format!("invalid integer number format: {}", number_string),
+                    )),
+                })?;
+
+            match suffix {
+                "" | "i64" if magnitude > i64::MIN.unsigned_abs() => Err(out_of_range("i64")),
+                "" => Ok(PrimaryToken::Integer(magnitude).into()),
+                "i64" => Ok(PrimaryToken::Int64(magnitude).into()),
+                "i32" => match u32::try_from(magnitude) {
+                    Ok(magnitude) if magnitude <= i32::MIN.unsigned_abs() => {
+                        Ok(PrimaryToken::Int32(magnitude).into())
+                    }
+                    _ => Err(out_of_range("i32")),
+                },
+                _ => Err(invalid_suffix()),
+            }
+        }
+    }
+
+    // 역슬래시 다음 글자(last_char)를 읽어 이스케이프된 문자를 반환합니다.
+    fn read_escape(&self) -> Result<char, AllError> {
+        match self.last_char {
+            Some('n') => Ok('\n'),
+            Some('t') => Ok('\t'),
+            Some('r') => Ok('\r'),
+            Some('0') => Ok('\0'),
+            Some('\\') => Ok('\\'),
+            Some('\'') => Ok('\''),
+            Some('"') => Ok('"'),
+            Some(character) => Err(AllError::LexerError(SourceError::new(
+                5,
+                format!("unknown character escape: \\{}", character),
+            ))),
+            None => Err(AllError::LexerError(SourceError::new(
+                6,
+                "unexpected EOF".to_string(),
+            ))),
+        }
+    }
+
+    // r"..." 형태의 raw 문자열을 읽습니다. 이스케이프를 처리하지 않고 줄바꿈도 그대로 담습니다.
+    // r#"..."#처럼 따옴표 앞뒤에 같은 개수의 #을 붙이면 문자열 안에 "를 쓸 수 있습니다.
+    fn read_raw_string(&mut self) -> Result<Token, AllError> {
+        let mut hashes = 0;
+
+        self.read_char();
+        while let Some('#') = self.last_char {
+            hashes += 1;
+            self.read_char();
+        }
+
+        if self.last_char != Some('"') {
+            return Err(AllError::LexerError(SourceError::new(
+                7,
+                format!(
+                    "expected '\"' after r{}, found {:?}",
+                    "#".repeat(hashes),
+                    self.last_char
+                ),
+            )));
+        }
+
+        let start = self.next_index();
+        let terminator = format!("\"{}", "#".repeat(hashes));
+
+        let end = self.source[start..]
+            .find(&terminator)
+            .map(|offset| start + offset)
+            .ok_or_else(|| {
+                AllError::LexerError(SourceError::new(8, "unterminated raw string".to_string()))
+            })?;
+
+        // 닫는 따옴표와 #까지 읽습니다.
+        while self.next_index() < end + terminator.len() {
+            self.read_char();
+        }
+
+        Ok(PrimaryToken::String(self.source[start..end].to_owned()).into())
+    }
+
+    // 다음 토큰을 소비하지 않고 확인합니다. next가 다음에 반환할 값과 같습니다.
+    // 파서는 여러 토큰을 앞서 보므로 peek_n을 씁니다.
+    #[allow(dead_code)]
+    pub fn peek(&mut self) -> Option<&Result<SpannedToken, AllError>> {
+        self.peek_n(0)
+    }
+
+    // n개 뒤의 토큰을 소비하지 않고 확인합니다. peek_n(0)은 peek과 같습니다.
+    // 확인한 토큰까지는 미리 읽어서 보관하므로, 필요한 만큼만 앞을 읽습니다.
+    pub fn peek_n(&mut self, n: usize) -> Option<&Result<SpannedToken, AllError>> {
+        while self.lookahead.len() <= n {
+            let token = self.lex_next()?;
+            self.lookahead.push_back(token);
+        }
+
+        self.lookahead.get(n)
+    }
+
+    // 소스코드에서 토큰 하나를 새로 읽습니다. 끝까지 읽었거나 이미 오류를 반환했다면 None입니다.
+    fn lex_next(&mut self) -> Option<Result<SpannedToken, AllError>> {
+        if self.failed || !self.has_next() {
+            return None;
+        }
+
+        let token = self.read_spanned_token();
+        self.failed = token.is_err();
+
+        Some(token)
     }
 
-    // 주어진 텍스트에서 토큰을 순서대로 획득해 반환합니다.
-    // 끝을 만날 경우 Token::EOF를 반환합니다.
-    pub fn get_token(&mut self) -> Result<Token, AllError> {
+    // 주어진 텍스트에서 토큰을 하나 읽어서 위치와 함께 반환합니다.
+    // 끝을 만날 경우 Token::EOF를 반환합니다. 오류 메시지 앞에는 토큰이 시작한 위치를 붙입니다.
+    fn read_spanned_token(&mut self) -> Result<SpannedToken, AllError> {
         self.read_char();
 
         // 화이트 스페이스 삼킴
@@ -137,27 +365,53 @@ impl Tokenizer {
             self.read_char();
         }
 
+        let start = self.buffer_index.unwrap_or(0).min(self.source.len());
+
+        let token = self.read_token().map_err(|error| match error {
+            AllError::LexerError(error) => AllError::LexerError(
+                error.with_span(self.line_index.span(self.source, start, start)),
+            ),
+            error => error,
+        })?;
+
+        // 식별자 뒤의 공백 한 글자까지 읽은 상태일 수 있으므로 끝의 공백은 제외합니다.
+        let end = self.next_index().min(self.source.len()).max(start);
+        let end = start + self.source[start..end].trim_end().len();
+
+        Ok(SpannedToken {
+            token,
+            span: self.line_index.span(self.source, start, end),
+        })
+    }
+
+    // 공백을 건너뛴 뒤 last_char부터 토큰 하나를 읽습니다.
+    fn read_token(&mut self) -> Result<Token, AllError> {
+        // r" 또는 r#"으로 시작하면 raw 문자열
+        let token = if self.last_char == Some('r')
+            && matches!(self.char_at(self.next_index()), Some('"' | '#'))
+        {
+            self.read_raw_string()?
+        }
         // 첫번째 글짜가 알파벳일 경우 식별자 및 키워드로 인식
-        let token = if self.is_alphabet() || self.is_underscore() {
-            let mut identifier = vec![self.last_char.unwrap()];
+        else if self.is_alphabet() || self.is_underscore() {
+            let start = self.buffer_index.unwrap_or(0);
 
             self.read_char();
             loop {
                 if self.is_alphabet_or_number() || self.is_underscore() {
-                    identifier.push(self.last_char.unwrap());
                     self.read_char();
                 } else {
                     break;
                 }
             }
 
+            let identifier = self.slice_from(start);
+
             if self.is_general_syntax_character() || self.is_operator_character() {
                 self.unread_char();
             }
 
-            let identifier: String = identifier.into_iter().collect::<String>();
-
-            let token = match identifier.as_str() {
+            let token = match identifier {
                 "let" => Token::Keyword(Keyword::Let),
                 "const" => Token::Keyword(Keyword::Const),
                 "mut" => Token::Keyword(Keyword::Mut),
@@ -188,24 +442,32 @@ impl Tokenizer {
                 "void" => Token::Keyword(Keyword::Void),
                 "self" => Token::Keyword(Keyword::_Self),
                 "Self" => Token::Keyword(Keyword::_SelfType),
-                _ => PrimaryToken::Identifier(identifier).into(),
+                _ => PrimaryToken::Identifier(identifier.into()).into(),
             };
 
             return Ok(token);
         }
         // 첫번째 글자가 숫자일 경우 정수 및 실수값으로 인식
         else if self.is_digit() {
-            let mut number_string = vec![self.last_char.unwrap()];
+            let start = self.buffer_index.unwrap_or(0);
+            let mut end = self.next_index();
 
-            // 숫자나 .이 나올 때까지만 버퍼에서 읽어서 number_string에 저장
+            // 숫자, ., 밑줄, 접두사와 접미사의 글자가 이어지는 동안 버퍼에서 읽어서 number_string 범위에 포함
             loop {
                 if self.is_eof() {
                     break;
                 }
 
                 self.read_char();
-                if self.is_digit() || self.is_dot() {
-                    number_string.push(self.last_char.unwrap());
+
+                // 0..10처럼 범위 연산자가 이어지면 숫자는 . 앞에서 끝납니다.
+                if self.is_dot() && self.char_at(self.next_index()) == Some('.') {
+                    self.unread_char();
+                    break;
+                }
+
+                if self.is_alphabet_or_number() || self.is_underscore() || self.is_dot() {
+                    end = self.next_index();
                     continue;
                 } else {
                     self.unread_char();
@@ -213,35 +475,7 @@ impl Tokenizer {
                 }
             }
 
-            let number_string: String =
-                number_string.into_iter().collect::<String>().to_uppercase();
-
-            // .이 있을 경우 실수, 아닌 경우 정수로 인식
-            if number_string.contains('.') {
-                let number = number_string.parse::<f64>();
-
-                match number {
-                    Ok(number) => PrimaryToken::Float(number).into(),
-                    Err(_) => {
-                        return Err(AllError::LexerError(format!(
-                            "invalid floating point number format: {}",
-                            number_string
-                        )))
-                    }
-                }
-            } else {
-                let number = number_string.parse::<i64>();
-
-                match number {
-                    Ok(number) => PrimaryToken::Integer(number).into(),
-                    Err(_) => {
-                        return Err(AllError::LexerError(format!(
-                            "invalid integer number format: {}",
-                            number_string
-                        )))
-                    }
-                }
-            }
+            Self::number_token(&self.source[start..end])?
         }
         // 특수문자일 경우
         else if self.is_operator_character() {
@@ -266,35 +500,32 @@ impl Tokenizer {
 
                     match self.last_char {
                         Some('*') => {
-                            let mut comment = vec![];
+                            let start = self.next_index();
+                            let mut end = self.source.len();
 
                             self.read_char();
                             while !self.is_eof() {
-                                match self.last_char {
-                                    Some('*') => {
-                                        self.read_char();
-                                        if self.last_char == Some('/') {
-                                            break;
-                                        }
-                                    }
-                                    Some(c) => {
-                                        comment.push(c);
-                                    }
-                                    None => {
-                                        return Err(AllError::LexerError(
-                                            "unexpected EOF".to_string(),
-                                        ));
+                                if let Some('*') = self.last_char {
+                                    let star_index = self.buffer_index.unwrap_or(start);
+
+                                    self.read_char();
+                                    if self.last_char == Some('/') {
+                                        end = star_index;
+                                        break;
                                     }
+
+                                    // *가 연속될 수 있으므로 방금 읽은 문자를 다시 검사
+                                    continue;
                                 }
 
                                 self.read_char();
                             }
 
-                            let comment: String = comment.into_iter().collect();
-                            PrimaryToken::Comment(comment).into()
+                            PrimaryToken::Comment(self.source[start..end].to_owned()).into()
                         }
                         Some('/') => {
-                            let mut comment = vec![];
+                            let start = self.next_index();
+                            let mut end = start;
 
                             while self.has_next() {
                                 self.read_char();
@@ -303,19 +534,19 @@ impl Tokenizer {
                                     Some('\n') => {
                                         break;
                                     }
-                                    Some(c) => {
-                                        comment.push(c);
+                                    Some(_) => {
+                                        end = self.next_index();
                                     }
                                     None => {
-                                        return Err(AllError::LexerError(
+                                        return Err(AllError::LexerError(SourceError::new(
+                                            6,
                                             "unexpected EOF".to_string(),
-                                        ));
+                                        )));
                                     }
                                 }
                             }
 
-                            let comment: String = comment.into_iter().collect();
-                            PrimaryToken::Comment(comment).into()
+                            PrimaryToken::Comment(self.source[start..end].to_owned()).into()
                         }
                         Some('=') => OperatorToken::SlashAssign.into(),
                         _ => {
@@ -323,47 +554,6 @@ impl Tokenizer {
                             OperatorToken::Slash.into()
                         }
                     }
-
-                    // if self.last_char == '*' {
-                    //     let mut comment = vec![];
-
-                    //     self.read_char();
-                    //     while !self.is_eof() {
-                    //         if self.last_char == '*' {
-                    //             self.read_char();
-                    //             if self.last_char == '/' {
-                    //                 break;
-                    //             }
-                    //         } else {
-                    //             comment.push(self.last_char);
-                    //         }
-
-                    //         self.read_char();
-                    //     }
-
-                    //     let comment: String = comment.into_iter().collect();
-                    //     PrimaryToken::Comment(comment).into()
-                    // } else if self.last_char == '/' {
-                    //     let mut comment = vec![];
-
-                    //     while !self.is_eof() {
-                    //         self.read_char();
-
-                    //         if self.last_char == '\n' {
-                    //             break;
-                    //         } else {
-                    //             comment.push(self.last_char);
-                    //         }
-                    //     }
-
-                    //     let comment: String = comment.into_iter().collect();
-                    //     PrimaryToken::Comment(comment).into()
-                    // } else if self.last_char == '=' {
-                    //     OperatorToken::SlashAssign.into()
-                    // } else {
-                    //     self.unread_char();
-                    //     OperatorToken::Slash.into()
-                    // }
                 }
                 '%' => {
                     self.read_char();
@@ -426,6 +616,7 @@ impl Tokenizer {
 
                     match self.last_char {
                         Some('=') => OperatorToken::Equal.into(),
+                        Some('>') => GeneralToken::FatArrow.into(),
                         _ => {
                             self.unread_char();
                             OperatorToken::Assign.into()
@@ -513,9 +704,9 @@ impl Tokenizer {
                 }
                 '~' => OperatorToken::BitwiseNot.into(),
                 _ => {
-                    return Err(AllError::LexerError(format!(
-                        "unexpected operator: {:?}",
-                        self.last_char
+                    return Err(AllError::LexerError(SourceError::new(
+                        9,
+                        format!("unexpected operator: {:?}", self.last_char),
                     )))
                 }
             }
@@ -523,51 +714,93 @@ impl Tokenizer {
         // 따옴표일 경우 처리
         else if self.is_quote() {
             if let Some('"') = self.last_char {
-                let mut identifier = vec![];
+                let start = self.next_index();
+                let mut segment_start = start;
+
+                // 이스케이프가 없다면 원본 텍스트를 그대로 복사하고, 있을 때만 조각을 이어 붙입니다.
+                let mut escaped: Option<String> = None;
 
                 self.read_char();
                 loop {
-                    if let Some('"') = self.last_char {
-                        break;
-                    }
+                    match self.last_char {
+                        Some('"') => break,
+                        Some('\\') => {
+                            let backslash = self.buffer_index.unwrap_or(start);
+                            self.read_char();
 
-                    identifier.push(self.last_char.unwrap());
-                    self.read_char();
+                            let string = escaped.get_or_insert_with(String::new);
+                            string.push_str(&self.source[segment_start..backslash]);
+                            string.push(self.read_escape()?);
+
+                            segment_start = self.next_index();
+                            self.read_char();
+                        }
+                        Some(_) => self.read_char(),
+                        None => {
+                            return Err(AllError::LexerError(SourceError::new(
+                                6,
+                                "unexpected EOF".to_string(),
+                            )));
+                        }
+                    }
                 }
 
-                let identifier: String = identifier.into_iter().collect::<String>();
+                let string = match escaped {
+                    Some(mut escaped) => {
+                        escaped.push_str(self.slice_from(segment_start));
+                        escaped
+                    }
+                    None => self.slice_from(start).to_owned(),
+                };
 
-                PrimaryToken::String(identifier).into()
+                PrimaryToken::String(string).into()
             } else if let Some('\'') = self.last_char {
-                let mut string = vec![];
-
                 self.read_char();
-                while !self.is_eof() {
-                    if let Some('\'') = self.last_char {
-                        self.read_char();
 
-                        // '' 의 형태일 경우 '로 이스케이프
-                        // 아닐 경우 문자열 종료
-                        if let Some('\'') = self.last_char {
-                            string.push('\'');
-                        } else {
-                            self.unread_char();
-                            break;
-                        }
-                    } else if let Some(c) = self.last_char {
-                        string.push(c);
+                let character = match self.last_char {
+                    Some('\\') => {
+                        self.read_char();
+                        self.read_escape()?
                     }
+                    Some('\'') => {
+                        return Err(AllError::LexerError(
+                            SourceError::new(10, "empty char literal".to_string())
+                                .with_help("a char literal must contain exactly one character"),
+                        ));
+                    }
+                    Some(character) => character,
+                    None => {
+                        return Err(AllError::LexerError(SourceError::new(
+                            11,
+                            "unterminated char literal".to_string(),
+                        )));
+                    }
+                };
 
-                    self.read_char();
-                }
-
-                let string: String = string.into_iter().collect::<String>();
+                self.read_char();
 
-                PrimaryToken::String(string).into()
+                match self.last_char {
+                    Some('\'') => PrimaryToken::Char(character).into(),
+                    Some(_) => {
+                        return Err(AllError::LexerError(
+                            SourceError::new(
+                                12,
+                                "char literal must contain exactly one character".to_string(),
+                            )
+                            .with_help("use double quotes for strings"),
+                        ));
+                    }
+                    None => {
+                        return Err(AllError::LexerError(SourceError::new(
+                            11,
+                            "unterminated char literal".to_string(),
+                        )));
+                    }
+                }
             } else {
-                return Err(AllError::LexerError(format!(
-                    "unexpected character: {:?}",
-                    self.last_char
+                return Err(AllError::LexerError(SourceError::new(
+                    13,
+                    format!("unexpected character: {:?}", self.last_char),
                 )));
             }
         }
@@ -586,9 +819,9 @@ impl Tokenizer {
                 '`' => GeneralToken::Backtick.into(),
                 ',' => GeneralToken::Comma.into(),
                 _ => {
-                    return Err(AllError::LexerError(format!(
-                        "unexpected token: {:?}",
-                        self.last_char
+                    return Err(AllError::LexerError(SourceError::new(
+                        14,
+                        format!("unexpected token: {:?}", self.last_char),
                     )))
                 }
             }
@@ -597,9 +830,9 @@ impl Tokenizer {
         else if self.is_eof() {
             Token::Eof
         } else {
-            return Err(AllError::LexerError(format!(
-                "unexpected character: {:?}",
-                self.last_char
+            return Err(AllError::LexerError(SourceError::new(
+                13,
+                format!("unexpected character: {:?}", self.last_char),
             )));
         };
 
@@ -608,29 +841,32 @@ impl Tokenizer {
         Ok(token)
     }
 
-    pub fn has_next(&self) -> bool {
+    fn has_next(&self) -> bool {
         match self.buffer_index {
-            Some(buffer_index) => buffer_index + 1 < self.buffer.len(),
+            Some(_) => self.next_index() < self.source.len(),
             None => true,
         }
     }
 
     // Tokenizer 생성 없이 토큰 목록을 가져올 수 있는 boilerplate 함수입니다.
-    pub fn string_to_tokens(text: String) -> Result<Vec<Token>, AllError> {
-        let mut tokenizer = Tokenizer::new(text);
-
-        let mut tokens = vec![];
+    pub fn string_to_tokens(text: &str) -> Result<Vec<Token>, AllError> {
+        Tokenizer::new(text)
+            .map(|token| token.map(|token| token.token))
+            .collect()
+    }
+}
 
-        while tokenizer.has_next() {
-            let token = tokenizer.get_token()?;
-            tokens.push(token);
-        }
+// 토큰을 소스코드 앞에서부터 하나씩 읽습니다. 파일 전체의 토큰 목록을 한 번에 만들지 않아도 됩니다.
+// 오류가 나면 그 오류를 마지막으로 반환하고 끝납니다.
+impl Iterator for Tokenizer<'_> {
+    type Item = Result<SpannedToken, AllError>;
 
-        Ok(tokens)
+    fn next(&mut self) -> Option<Self::Item> {
+        self.lookahead.pop_front().or_else(|| self.lex_next())
     }
 }
 
-impl std::fmt::Display for Tokenizer {
+impl std::fmt::Display for Tokenizer<'_> {
     fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
         write!(formatter, "Tokenizer: {:?}", self)
     }
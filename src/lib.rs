@@ -0,0 +1,27 @@
+#![allow(clippy::match_like_matches_macro)]
+
+pub mod ast;
+#[cfg(feature = "bench")]
+pub mod bench;
+pub mod builder;
+pub mod codegen;
+pub mod compiler;
+pub mod constant;
+pub mod error;
+pub mod formatter;
+pub mod lexer;
+pub mod lsp;
+pub mod parser;
+pub mod repl;
+pub mod runtime;
+pub mod semantic;
+pub mod utils;
+
+pub use compiler::{
+    analyze, check, compile_source, dump_ast, dump_tokens, format_source, lower_modules,
+    lower_modules_with_warnings, lower_to_ir, parse,
+};
+pub use error::{
+    all_error::{source_error::SourceError, AllError},
+    diagnostic::{Diagnostic, MessageFormat},
+};
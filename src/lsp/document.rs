@@ -0,0 +1,84 @@
+use crate::{
+    compiler::{analyze, Analysis},
+    semantic::reference::SymbolInfo,
+    utils::{interner::session, json::Json, span::Span},
+};
+
+// 편집기에서 연 파일 하나입니다. 내용이 바뀔 때마다 다시 분석합니다.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Document {
+    pub uri: String,
+    pub text: String,
+    pub analysis: Analysis,
+}
+
+impl Document {
+    pub fn new(uri: String, text: String, no_std: bool) -> Self {
+        // 편집할 때마다 다시 분석하므로, 분석에서 인터닝한 이름은 분석이 끝나면 해제합니다.
+        let analysis = session(|| analyze(&source_name(&uri), &text, no_std));
+
+        Self {
+            uri,
+            text,
+            analysis,
+        }
+    }
+
+    // LSP 위치(0부터 시작하는 줄과 UTF-16 오프셋)에 있는 이름입니다.
+    pub fn symbol_at(&self, position: &Json) -> Option<&SymbolInfo> {
+        let line = position.get("line")?.as_u64()? as usize;
+        let character = position.get("character")?.as_u64()? as usize;
+
+        let text = self.text.lines().nth(line)?;
+        let mut offset = 0;
+        let column = text
+            .chars()
+            .take_while(|c| {
+                offset += c.len_utf16();
+                offset <= character
+            })
+            .count();
+
+        self.analysis
+            .symbols
+            .iter()
+            .find(|symbol| symbol.contains(line + 1, column + 1))
+    }
+
+    // span을 LSP의 Range로 바꿉니다. 위치를 모르면 파일 맨 앞입니다.
+    pub fn range(&self, span: Span) -> Json {
+        let (line, start, end) = if span.is_unknown() {
+            (0, 0, 0)
+        } else {
+            let text = self.text.lines().nth(span.line - 1).unwrap_or_default();
+            let utf16 = |column: usize| {
+                text.chars()
+                    .take(column - 1)
+                    .map(char::len_utf16)
+                    .sum::<usize>()
+            };
+
+            (
+                span.line - 1,
+                utf16(span.column),
+                utf16(span.column + span.length),
+            )
+        };
+
+        Json::object([
+            (
+                "start",
+                Json::object([("line", line.into()), ("character", start.into())]),
+            ),
+            (
+                "end",
+                Json::object([("line", line.into()), ("character", end.into())]),
+            ),
+        ])
+    }
+}
+
+// 진단 메시지에 표시할 파일 경로입니다. file:// URI가 아니면 그대로 씁니다.
+pub fn source_name(uri: &str) -> String {
+    uri.strip_prefix("file://").unwrap_or(uri).to_owned()
+}
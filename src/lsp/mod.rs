@@ -0,0 +1,345 @@
+pub mod document;
+
+pub(crate) mod test;
+
+use std::{
+    collections::HashMap,
+    io::{BufRead, Write},
+};
+
+use crate::{
+    error::{
+        all_error::AllError,
+        diagnostic::{Diagnostic, Severity},
+    },
+    utils::json::Json,
+};
+
+use self::document::Document;
+
+// JSON-RPC 오류 코드
+const PARSE_ERROR: i64 = -32700;
+const INVALID_REQUEST: i64 = -32600;
+const METHOD_NOT_FOUND: i64 = -32601;
+
+// 표준 입출력으로 통신하는 Language Server입니다.
+// 문서를 열거나 고칠 때마다 다시 분석해서 진단을 보내고, 정의로 이동과 타입 보기를 지원합니다.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Server {
+    // URI별로 열려 있는 문서
+    documents: HashMap<String, Document>,
+    no_std: bool,
+    // shutdown 요청을 받은 뒤에는 exit 외의 요청을 받지 않습니다.
+    shutdown: bool,
+    exited: bool,
+}
+
+impl Server {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn set_no_std(&mut self, no_std: bool) {
+        self.no_std = no_std;
+    }
+
+    // exit 알림을 받을 때까지 메시지를 읽고 응답합니다.
+    pub fn run(&mut self, mut input: impl BufRead, mut output: impl Write) -> Result<(), AllError> {
+        while !self.exited {
+            let content = if let Some(content) = read_message(&mut input)? {
+                content
+            } else {
+                break;
+            };
+
+            let responses = match Json::parse(&content) {
+                Ok(message) => self.handle(&message),
+                Err(error) => vec![error_response(Json::Null, PARSE_ERROR, error)],
+            };
+
+            for response in responses {
+                write_message(&mut output, &response.to_string())?;
+            }
+        }
+
+        Ok(())
+    }
+
+    // 메시지 하나를 처리하고, 보낼 응답과 알림을 반환합니다.
+    pub fn handle(&mut self, message: &Json) -> Vec<Json> {
+        let method = message
+            .get("method")
+            .and_then(Json::as_str)
+            .unwrap_or_default();
+        let params = message.get("params").unwrap_or(&Json::Null);
+
+        // id가 없으면 응답하지 않는 알림입니다.
+        let id = if let Some(id) = message.get("id") {
+            id.clone()
+        } else {
+            return self.notification(method, params);
+        };
+
+        if self.shutdown {
+            return vec![error_response(
+                id,
+                INVALID_REQUEST,
+                "server is shutting down".to_owned(),
+            )];
+        }
+
+        let result = match method {
+            "initialize" => Json::object([
+                (
+                    "capabilities",
+                    Json::object([
+                        // 변경할 때마다 문서 전체를 받습니다.
+                        ("textDocumentSync", 1.into()),
+                        ("definitionProvider", true.into()),
+                        ("hoverProvider", true.into()),
+                    ]),
+                ),
+                (
+                    "serverInfo",
+                    Json::object([
+                        ("name", "foolang".into()),
+                        ("version", env!("CARGO_PKG_VERSION").into()),
+                    ]),
+                ),
+            ]),
+            "shutdown" => {
+                self.shutdown = true;
+                Json::Null
+            }
+            "textDocument/definition" => self.definition(params),
+            "textDocument/hover" => self.hover(params),
+            _ => {
+                return vec![error_response(
+                    id,
+                    METHOD_NOT_FOUND,
+                    format!("unknown method {}", method),
+                )]
+            }
+        };
+
+        vec![Json::object([
+            ("jsonrpc", "2.0".into()),
+            ("id", id),
+            ("result", result),
+        ])]
+    }
+
+    fn notification(&mut self, method: &str, params: &Json) -> Vec<Json> {
+        let uri = params
+            .get("textDocument")
+            .and_then(|document| document.get("uri"))
+            .and_then(Json::as_str)
+            .map(str::to_owned);
+
+        match (method, uri) {
+            ("exit", _) => {
+                self.exited = true;
+                vec![]
+            }
+            ("textDocument/didOpen", Some(uri)) => {
+                let text = params
+                    .get("textDocument")
+                    .and_then(|document| document.get("text"))
+                    .and_then(Json::as_str)
+                    .unwrap_or_default();
+
+                self.open(uri, text.to_owned())
+            }
+            // 전체 동기화이므로 마지막 변경 내용이 문서 전체입니다.
+            ("textDocument/didChange", Some(uri)) => {
+                let text = params
+                    .get("contentChanges")
+                    .and_then(Json::as_array)
+                    .and_then(|changes| changes.last())
+                    .and_then(|change| change.get("text"))
+                    .and_then(Json::as_str);
+
+                match text {
+                    Some(text) => self.open(uri, text.to_owned()),
+                    None => vec![],
+                }
+            }
+            // 닫은 문서의 진단은 지웁니다.
+            ("textDocument/didClose", Some(uri)) => {
+                self.documents.remove(&uri);
+                vec![publish_diagnostics(&uri, vec![])]
+            }
+            _ => vec![],
+        }
+    }
+
+    fn open(&mut self, uri: String, text: String) -> Vec<Json> {
+        let document = Document::new(uri.clone(), text, self.no_std);
+
+        let mut diagnostics = document
+            .analysis
+            .warnings
+            .iter()
+            .map(Diagnostic::warning)
+            .collect::<Vec<_>>();
+        diagnostics.extend(document.analysis.error.as_ref().map(Diagnostic::from));
+
+        let diagnostics = diagnostics
+            .iter()
+            .map(|diagnostic| lsp_diagnostic(&document, diagnostic))
+            .collect();
+
+        self.documents.insert(uri.clone(), document);
+
+        vec![publish_diagnostics(&uri, diagnostics)]
+    }
+
+    fn document(&self, params: &Json) -> Option<&Document> {
+        let uri = params.get("textDocument")?.get("uri")?.as_str()?;
+
+        self.documents.get(uri)
+    }
+
+    fn definition(&self, params: &Json) -> Json {
+        let document = if let Some(document) = self.document(params) {
+            document
+        } else {
+            return Json::Null;
+        };
+
+        let definition = params
+            .get("position")
+            .and_then(|position| document.symbol_at(position))
+            .and_then(|symbol| symbol.definition);
+
+        match definition {
+            Some(span) => Json::object([
+                ("uri", document.uri.as_str().into()),
+                ("range", document.range(span)),
+            ]),
+            None => Json::Null,
+        }
+    }
+
+    fn hover(&self, params: &Json) -> Json {
+        let document = if let Some(document) = self.document(params) {
+            document
+        } else {
+            return Json::Null;
+        };
+
+        let symbol = params
+            .get("position")
+            .and_then(|position| document.symbol_at(position));
+
+        match symbol {
+            Some(symbol) => Json::object([
+                (
+                    "contents",
+                    Json::object([
+                        ("kind", "plaintext".into()),
+                        ("value", symbol.detail.as_str().into()),
+                    ]),
+                ),
+                ("range", document.range(symbol.span)),
+            ]),
+            None => Json::Null,
+        }
+    }
+}
+
+fn error_response(id: Json, code: i64, message: String) -> Json {
+    Json::object([
+        ("jsonrpc", "2.0".into()),
+        ("id", id),
+        (
+            "error",
+            Json::object([
+                ("code", Json::Number(code as f64)),
+                ("message", message.into()),
+            ]),
+        ),
+    ])
+}
+
+fn publish_diagnostics(uri: &str, diagnostics: Vec<Json>) -> Json {
+    Json::object([
+        ("jsonrpc", "2.0".into()),
+        ("method", "textDocument/publishDiagnostics".into()),
+        (
+            "params",
+            Json::object([
+                ("uri", uri.into()),
+                ("diagnostics", Json::Array(diagnostics)),
+            ]),
+        ),
+    ])
+}
+
+// 도움말은 메시지 다음 줄에 붙입니다.
+fn lsp_diagnostic(document: &Document, diagnostic: &Diagnostic) -> Json {
+    let severity = match diagnostic.severity {
+        Severity::Error => 1,
+        Severity::Warning => 2,
+    };
+
+    let message = match &diagnostic.help {
+        Some(help) => format!("{}\nhelp: {}", diagnostic.message, help),
+        None => diagnostic.message.clone(),
+    };
+
+    Json::object([
+        ("range", document.range(diagnostic.span)),
+        ("severity", Json::Number(severity as f64)),
+        ("code", diagnostic.code.as_str().into()),
+        ("source", "foolang".into()),
+        ("message", message.into()),
+    ])
+}
+
+// Content-Length 헤더로 길이를 알려주는 메시지 하나를 읽습니다. 입력이 끝났으면 None입니다.
+fn read_message(input: &mut impl BufRead) -> Result<Option<String>, AllError> {
+    let io_error = |error: std::io::Error| AllError::IOError(format!("stdin: {}", error));
+    let mut length = None;
+
+    loop {
+        let mut line = String::new();
+
+        if input.read_line(&mut line).map_err(io_error)? == 0 {
+            return Ok(None);
+        }
+
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+
+        if let Some((name, value)) = line.split_once(':') {
+            if name.eq_ignore_ascii_case("content-length") {
+                length = value.trim().parse::<usize>().ok();
+            }
+        }
+    }
+
+    let length = length.ok_or_else(|| {
+        AllError::IOError("stdin: message without Content-Length header".to_owned())
+    })?;
+
+    let mut content = vec![0; length];
+    input.read_exact(&mut content).map_err(io_error)?;
+
+    String::from_utf8(content)
+        .map(Some)
+        .map_err(|error| AllError::IOError(format!("stdin: {}", error)))
+}
+
+fn write_message(output: &mut impl Write, content: &str) -> Result<(), AllError> {
+    write!(
+        output,
+        "Content-Length: {}\r\n\r\n{}",
+        content.len(),
+        content
+    )
+    .and_then(|_| output.flush())
+    .map_err(|error| AllError::IOError(format!("stdout: {}", error)))
+}
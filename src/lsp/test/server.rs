@@ -0,0 +1,241 @@
+#![cfg(test)]
+
+use crate::{lsp::Server, utils::json::Json};
+
+fn message(text: &str) -> Json {
+    Json::parse(text).unwrap()
+}
+
+fn open(server: &mut Server, text: &str) -> Vec<Json> {
+    server.handle(&Json::object([
+        ("jsonrpc", "2.0".into()),
+        ("method", "textDocument/didOpen".into()),
+        (
+            "params",
+            Json::object([(
+                "textDocument",
+                Json::object([
+                    ("uri", "file:///tmp/main.foo".into()),
+                    ("languageId", "foolang".into()),
+                    ("version", 1usize.into()),
+                    ("text", text.into()),
+                ]),
+            )]),
+        ),
+    ]))
+}
+
+fn request(server: &mut Server, method: &str, line: usize, character: usize) -> Json {
+    let mut responses = server.handle(&Json::object([
+        ("jsonrpc", "2.0".into()),
+        ("id", 7usize.into()),
+        ("method", method.into()),
+        (
+            "params",
+            Json::object([
+                (
+                    "textDocument",
+                    Json::object([("uri", "file:///tmp/main.foo".into())]),
+                ),
+                (
+                    "position",
+                    Json::object([("line", line.into()), ("character", character.into())]),
+                ),
+            ]),
+        ),
+    ]));
+
+    assert_eq!(responses.len(), 1);
+    responses.remove(0).get("result").unwrap().clone()
+}
+
+#[test]
+pub fn initialize() {
+    let mut server = Server::new();
+
+    let responses = server.handle(&message(
+        r#"{"jsonrpc":"2.0","id":1,"method":"initialize","params":{"capabilities":{}}}"#,
+    ));
+
+    assert_eq!(
+        responses[0].to_string(),
+        format!(
+            concat!(
+                r#"{{"jsonrpc":"2.0","id":1,"result":{{"capabilities":{{"textDocumentSync":1,"#,
+                r#""definitionProvider":true,"hoverProvider":true}},"#,
+                r#""serverInfo":{{"name":"foolang","version":"{}"}}}}}}"#,
+            ),
+            env!("CARGO_PKG_VERSION")
+        )
+    );
+
+    assert!(server
+        .handle(&message(
+            r#"{"jsonrpc":"2.0","method":"initialized","params":{}}"#
+        ))
+        .is_empty());
+
+    let responses = server.handle(&message(r#"{"jsonrpc":"2.0","id":2,"method":"foo"}"#));
+    assert_eq!(
+        responses[0].to_string(),
+        r#"{"jsonrpc":"2.0","id":2,"error":{"code":-32601,"message":"unknown method foo"}}"#
+    );
+
+    let responses = server.handle(&message(r#"{"jsonrpc":"2.0","id":3,"method":"shutdown"}"#));
+    assert_eq!(
+        responses[0].to_string(),
+        r#"{"jsonrpc":"2.0","id":3,"result":null}"#
+    );
+}
+
+#[test]
+pub fn publish_diagnostics() {
+    let mut server = Server::new();
+
+    let notifications = open(&mut server, "let a = 1\nprintln(a + b)");
+    assert_eq!(
+        notifications[0].to_string(),
+        concat!(
+            r#"{"jsonrpc":"2.0","method":"textDocument/publishDiagnostics","params":{"#,
+            r#""uri":"file:///tmp/main.foo","diagnostics":[{"#,
+            r#""range":{"start":{"line":1,"character":12},"end":{"line":1,"character":13}},"#,
+            r#""severity":1,"code":"S0015","source":"foolang","#,
+            r#""message":"undefined variable b\nhelp: declare it with `let b = ...` before using it"}]}}"#,
+        )
+    );
+
+    // 고친 뒤에는 빈 진단 목록을 보냅니다.
+    let notifications = server.handle(&message(concat!(
+        r#"{"jsonrpc":"2.0","method":"textDocument/didChange","params":{"#,
+        r#""textDocument":{"uri":"file:///tmp/main.foo","version":2},"#,
+        r#""contentChanges":[{"text":"let a = 1\nprintln(a)"}]}}"#,
+    )));
+    assert_eq!(
+        notifications[0].get("params").unwrap().get("diagnostics"),
+        Some(&Json::Array(vec![]))
+    );
+
+    let notifications = open(&mut server, "match 1 {\n    1 => println(1)\n}");
+    let diagnostics = notifications[0].get("params").unwrap().get("diagnostics");
+    let diagnostic = &diagnostics.unwrap().as_array().unwrap()[0];
+    assert_eq!(diagnostic.get("severity"), Some(&Json::Number(2.0)));
+    assert_eq!(diagnostic.get("code"), Some(&"W0029".into()));
+}
+
+#[test]
+pub fn definition() {
+    let mut server = Server::new();
+    open(
+        &mut server,
+        "fn add(a, b) {\n    return a + b\n}\nlet x = 1\nmut y = add(x, 2)\ny = x",
+    );
+
+    let location = |line, start, end| {
+        format!(
+            concat!(
+                r#"{{"uri":"file:///tmp/main.foo","range":{{"start":{{"line":{},"character":{}}},"#,
+                r#""end":{{"line":{},"character":{}}}}}}}"#,
+            ),
+            line, start, line, end
+        )
+    };
+
+    // 함수 호출 -> 함수 이름
+    assert_eq!(
+        request(&mut server, "textDocument/definition", 4, 9).to_string(),
+        location(0, 3, 6)
+    );
+    // 변수 -> 선언
+    assert_eq!(
+        request(&mut server, "textDocument/definition", 4, 12).to_string(),
+        location(3, 4, 5)
+    );
+    // 매개변수
+    assert_eq!(
+        request(&mut server, "textDocument/definition", 1, 15).to_string(),
+        location(0, 10, 11)
+    );
+    // 대입
+    assert_eq!(
+        request(&mut server, "textDocument/definition", 5, 0).to_string(),
+        location(4, 4, 5)
+    );
+    // 이름이 아닌 곳
+    assert_eq!(
+        request(&mut server, "textDocument/definition", 4, 2),
+        Json::Null
+    );
+}
+
+#[test]
+pub fn hover() {
+    let mut server = Server::new();
+    open(
+        &mut server,
+        "fn scale(p: i32, k) -> i32 {\n    return p\n}\nlet a = 1\nlet b: i32 = a\nmut c = scale(b, 2)\nfor i in 'a'..'c' {\n}",
+    );
+
+    let hover = |server: &mut Server, line, character| {
+        request(server, "textDocument/hover", line, character)
+            .get("contents")
+            .and_then(|contents| contents.get("value"))
+            .and_then(Json::as_str)
+            .map(str::to_owned)
+    };
+
+    assert_eq!(
+        hover(&mut server, 0, 4).as_deref(),
+        Some("fn scale(p: i32, k: i64) -> i32")
+    );
+    assert_eq!(
+        hover(&mut server, 5, 9).as_deref(),
+        Some("fn scale(p: i32, k: i64) -> i32")
+    );
+    // 나중에 i32로 정해진 정수 변수
+    assert_eq!(hover(&mut server, 3, 4).as_deref(), Some("a: i32"));
+    assert_eq!(hover(&mut server, 5, 4).as_deref(), Some("mut c: i32"));
+    assert_eq!(hover(&mut server, 6, 4).as_deref(), Some("i: char"));
+    assert_eq!(hover(&mut server, 1, 11).as_deref(), Some("p: i32"));
+    assert_eq!(hover(&mut server, 6, 0), None);
+}
+
+// 다른 언어의 문자가 앞에 있으면 열은 UTF-16 단위로 계산합니다.
+#[test]
+pub fn utf16_positions() {
+    let mut server = Server::new();
+    open(&mut server, "let s = \"😀\"; let t = s");
+
+    let result = request(&mut server, "textDocument/definition", 0, 22);
+    assert_eq!(
+        result.get("range").unwrap().to_string(),
+        r#"{"start":{"line":0,"character":4},"end":{"line":0,"character":5}}"#
+    );
+
+    let result = request(&mut server, "textDocument/hover", 0, 22);
+    assert_eq!(
+        result.get("range").unwrap().to_string(),
+        r#"{"start":{"line":0,"character":22},"end":{"line":0,"character":23}}"#
+    );
+}
+
+#[test]
+pub fn run_over_stdio() {
+    let messages = [
+        r#"{"jsonrpc":"2.0","id":1,"method":"shutdown"}"#,
+        r#"{"jsonrpc":"2.0","method":"exit"}"#,
+        r#"{"jsonrpc":"2.0","id":2,"method":"shutdown"}"#,
+    ];
+    let input = messages
+        .iter()
+        .map(|message| format!("Content-Length: {}\r\n\r\n{}", message.len(), message))
+        .collect::<String>();
+
+    let mut output = vec![];
+    Server::new().run(input.as_bytes(), &mut output).unwrap();
+
+    // exit 뒤의 메시지는 읽지 않습니다.
+    assert_eq!(
+        String::from_utf8(output).unwrap(),
+        "Content-Length: 38\r\n\r\n{\"jsonrpc\":\"2.0\",\"id\":1,\"result\":null}"
+    );
+}
@@ -1,48 +1,119 @@
-#![allow(clippy::match_like_matches_macro)]
-
+#[cfg(feature = "bench")]
+use action::bench::execute_bench;
 use action::build::execute_build;
+use action::check::execute_check;
+use action::fmt::execute_fmt;
+use action::lsp::execute_lsp;
+use action::repl::execute_repl;
 use command::{Command, SubCommand};
-use error::all_error::AllError;
+use foolang::{
+    utils::{
+        logger::{Level, Logger},
+        timings::{TimingReport, Timings, TimingsFormat},
+    },
+    AllError, Diagnostic, MessageFormat,
+};
 
 mod action;
-mod ast;
-mod builder;
-mod codegen;
 mod command;
-mod constant;
-mod error;
-mod lexer;
-mod parser;
-mod utils;
 
 use clap::Parser;
 
 use libc::c_int;
-#[link(name="llvm", kind="static")]
-extern
-{
-    fn add(_: c_int, _: c_int)->c_int;
+#[link(name = "llvm", kind = "static")]
+extern "C" {
+    #[allow(dead_code)]
+    fn add(_: c_int, _: c_int) -> c_int;
 }
 
+#[tokio::main]
+async fn main() {
+    let command = Command::parse();
+    let message_format = command.message_format;
+    let trace_file = command.trace_file.clone();
+    let timings = command.timings;
+
+    Logger::set_level(Level::from_verbosity(command.verbose));
+    if trace_file.is_some() {
+        Logger::start_trace();
+    }
+    if timings.is_some() {
+        Timings::start();
+    }
 
+    let result = run(command).await;
 
-#[tokio::main]
-async fn main() -> Result<(), AllError> {
-    println!("Hello, world!");
-
-    let a = 1;
-    let b = 2;
-    let c = unsafe { add(a, b) };
-    println!("{} + {} = {}", a, b, c);
-
-    // let command = Command::parse();
-
-    // match command.action {
-    //     SubCommand::Build(action) => {
-    //         let executable_filename = execute_build(action).await?;
-    //         println!("executable: {}", executable_filename);
-    //     }
-    // }
+    // 실패한 빌드도 어느 단계까지 진행했는지 볼 수 있도록 trace를 먼저 기록합니다.
+    if let (Some(trace_file), Some(trace)) = (trace_file, Logger::finish_trace()) {
+        if let Err(error) = std::fs::write(&trace_file, trace.to_string()) {
+            Logger::error("trace", format!("{}: {}", trace_file, error));
+        }
+    }
+
+    if let (Some(format), Some(report)) = (timings, Timings::finish()) {
+        print_timings(format, &report);
+    }
+
+    if let Err(error) = result {
+        print_diagnostic(&Diagnostic::from(&error), message_format);
+        std::process::exit(1);
+    }
+}
+
+// 진단을 표준 에러로 출력합니다.
+pub(crate) fn print_diagnostic(diagnostic: &Diagnostic, format: MessageFormat) {
+    // 소스 줄을 보여주기 위해 진단이 가리키는 파일을 다시 읽습니다.
+    let source = diagnostic
+        .file
+        .as_ref()
+        .and_then(|file| std::fs::read_to_string(file).ok());
+
+    eprint!("{}", diagnostic.format(format, source.as_deref()));
+}
+
+// 표는 표준 에러로 출력하고, json과 html은 파일로 기록합니다.
+fn print_timings(format: TimingsFormat, report: &TimingReport) {
+    let (path, content) = match format {
+        TimingsFormat::Text => return eprint!("{}", report.render()),
+        TimingsFormat::Json => ("foolang-timings.json", report.to_json().to_string()),
+        TimingsFormat::Html => ("foolang-timings.html", report.to_html()),
+    };
+
+    match std::fs::write(path, content) {
+        Ok(()) => eprintln!("timings: {}", path),
+        Err(error) => Logger::error("timings", format!("{}: {}", path, error)),
+    }
+}
+
+async fn run(command: Command) -> Result<(), AllError> {
+    match command.action {
+        SubCommand::Build(action) => {
+            let output_filename = execute_build(action, command.message_format).await?;
+            println!("output: {}", output_filename);
+        }
+        SubCommand::Check(action) => {
+            let filenames = execute_check(action, command.message_format).await?;
+            println!("checked: {}", filenames.join(", "));
+        }
+        SubCommand::Fmt(action) => {
+            let filenames = execute_fmt(action).await?;
+
+            if !filenames.is_empty() {
+                println!("formatted: {}", filenames.join(", "));
+            }
+        }
+        SubCommand::Lsp(action) => {
+            execute_lsp(action).await?;
+        }
+        SubCommand::Repl(action) => {
+            execute_repl(action, command.message_format).await?;
+        }
+        #[cfg(feature = "bench")]
+        SubCommand::Bench(action) => {
+            let report = execute_bench(action).await?;
+            print!("{}", report);
+        }
+    }
 
     Ok(())
 }
@@ -0,0 +1,65 @@
+use crate::{
+    ast::{
+        operator::binary::BinaryOperator,
+        statement::{assign::AssignStatement, Statement},
+    },
+    error::all_error::{parser_error::ParserError, AllError},
+    lexer::{primary::PrimaryToken, token::Token},
+};
+
+use super::{Parser, ParserContext};
+
+impl Parser<'_> {
+    // 이름 = 값, 또는 이름 += 값 같은 복합 대입
+    pub(crate) fn parse_assign_statement(
+        &mut self,
+        context: ParserContext,
+    ) -> Result<Statement, AllError> {
+        let span = self.current_span();
+
+        let name = if let Some(Token::Primary(PrimaryToken::Identifier(identifier))) =
+            self.get_current_token()
+        {
+            *identifier
+        } else {
+            return Err(ParserError::new(
+                600,
+                format!("Expected identifier, found {:?}", self.get_current_token()),
+            )
+            .into());
+        };
+
+        // eat name
+        self.next();
+
+        let operator = match self.get_current_token() {
+            Some(Token::Operator(operator)) if operator.is_assignment_operator() => {
+                BinaryOperator::from_compound_assignment(*operator)
+            }
+            current_token => {
+                return Err(ParserError::new(
+                    602,
+                    format!("Expected assignment operator, found {:?}", current_token),
+                )
+                .into());
+            }
+        };
+
+        // eat =, += 등
+        self.next();
+
+        if self.get_current_token().is_none() {
+            return Err(ParserError::new(601, "Unexpected end of tokens".to_string()).into());
+        }
+
+        let value = self.parse_expression(context)?;
+
+        Ok(AssignStatement {
+            name,
+            operator,
+            value,
+            span,
+        }
+        .into())
+    }
+}
@@ -1,9 +1,29 @@
 #[derive(Debug, Clone, PartialEq)]
-pub struct ParserContext {}
+pub struct ParserContext {
+    // if 조건처럼 바로 뒤에 블록이 오는 자리에서는 `이름 {`를 구조체 리터럴로 읽지 않습니다.
+    struct_literal: bool,
+}
 
 impl ParserContext {
     pub fn new() -> Self {
-        Self {}
+        Self {
+            struct_literal: true,
+        }
+    }
+
+    pub fn set_struct_literal(&mut self, struct_literal: bool) {
+        self.struct_literal = struct_literal;
+    }
+
+    pub fn allows_struct_literal(&self) -> bool {
+        self.struct_literal
+    }
+
+    // 블록 앞의 식을 파싱할 때 쓰는 문맥입니다.
+    pub fn without_struct_literal(&self) -> Self {
+        let mut context = self.clone();
+        context.set_struct_literal(false);
+        context
     }
 }
 
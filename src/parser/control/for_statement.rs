@@ -0,0 +1,79 @@
+use crate::{
+    ast::statement::{for_statement::ForStatement, Statement},
+    error::all_error::{parser_error::ParserError, AllError},
+    lexer::{keyword::Keyword, operator::OperatorToken, primary::PrimaryToken, token::Token},
+    parser::{Parser, ParserContext},
+};
+
+impl Parser<'_> {
+    // for 변수 in start..end { ... }
+    pub(crate) fn parse_for_statement(
+        &mut self,
+        context: ParserContext,
+    ) -> Result<Statement, AllError> {
+        // eat for
+        self.next();
+
+        let span = self.current_span();
+
+        let variable = match self.get_current_token() {
+            Some(Token::Primary(PrimaryToken::Identifier(identifier))) => *identifier,
+            Some(current_token) => {
+                return Err(ParserError::new(
+                    520,
+                    format!("Expected loop variable name, found {:?}", current_token),
+                )
+                .into());
+            }
+            None => {
+                return Err(ParserError::new(521, "Unexpected end of tokens".to_string()).into());
+            }
+        };
+
+        self.next();
+
+        match self.get_current_token() {
+            Some(Token::Keyword(Keyword::In)) => self.next(),
+            current_token => {
+                return Err(ParserError::new(
+                    522,
+                    format!("Expected 'in', found {:?}", current_token),
+                )
+                .into());
+            }
+        }
+
+        if self.get_current_token().is_none() {
+            return Err(ParserError::new(523, "Unexpected end of tokens".to_string()).into());
+        }
+
+        let start = self.parse_expression(context.without_struct_literal())?;
+
+        match self.get_current_token() {
+            Some(Token::Operator(OperatorToken::Range)) => self.next(),
+            current_token => {
+                return Err(ParserError::new(
+                    524,
+                    format!("Expected '..', found {:?}", current_token),
+                )
+                .into());
+            }
+        }
+
+        if self.get_current_token().is_none() {
+            return Err(ParserError::new(525, "Unexpected end of tokens".to_string()).into());
+        }
+
+        let end = self.parse_expression(context.without_struct_literal())?;
+        let body = self.parse_block()?;
+
+        Ok(ForStatement {
+            variable,
+            start,
+            end,
+            body,
+            span,
+        }
+        .into())
+    }
+}
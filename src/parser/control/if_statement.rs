@@ -0,0 +1,48 @@
+use crate::{
+    ast::statement::{if_statement::IfStatement, Statement},
+    error::all_error::{parser_error::ParserError, AllError},
+    lexer::{keyword::Keyword, token::Token},
+    parser::{Parser, ParserContext},
+};
+
+impl Parser<'_> {
+    // if 조건 { ... } [else if 조건 { ... }]* [else { ... }]
+    pub(crate) fn parse_if_statement(
+        &mut self,
+        context: ParserContext,
+    ) -> Result<Statement, AllError> {
+        // eat if
+        self.next();
+
+        if self.get_current_token().is_none() {
+            return Err(ParserError::new(500, "Unexpected end of tokens".to_string()).into());
+        }
+
+        let condition = self.parse_expression(context.without_struct_literal())?;
+        let then_body = self.parse_block()?;
+
+        let else_body = if let Some(Token::Keyword(Keyword::Else)) = self.get_current_token() {
+            // eat else
+            self.next();
+
+            match self.get_current_token() {
+                Some(Token::Keyword(Keyword::If)) => Some(vec![self.parse_if_statement(context)?]),
+                Some(_) => Some(self.parse_block()?),
+                None => {
+                    return Err(
+                        ParserError::new(501, "Unexpected end of tokens".to_string()).into(),
+                    );
+                }
+            }
+        } else {
+            None
+        };
+
+        Ok(IfStatement {
+            condition,
+            then_body,
+            else_body,
+        }
+        .into())
+    }
+}
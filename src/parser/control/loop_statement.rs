@@ -0,0 +1,22 @@
+use crate::{
+    ast::statement::{loop_statement::LoopStatement, Statement},
+    error::all_error::AllError,
+    parser::{Parser, ParserContext},
+};
+
+impl Parser<'_> {
+    // loop { ... }
+    pub(crate) fn parse_loop_statement(
+        &mut self,
+        _context: ParserContext,
+    ) -> Result<Statement, AllError> {
+        let span = self.current_span();
+
+        // eat loop
+        self.next();
+
+        let body = self.parse_block()?;
+
+        Ok(LoopStatement { body, span }.into())
+    }
+}
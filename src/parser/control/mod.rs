@@ -0,0 +1,5 @@
+pub(crate) mod for_statement;
+pub(crate) mod if_statement;
+pub(crate) mod loop_statement;
+pub(crate) mod return_statement;
+pub(crate) mod while_statement;
@@ -0,0 +1,26 @@
+use crate::{
+    ast::statement::Statement,
+    error::all_error::{parser_error::ParserError, AllError},
+    parser::{Parser, ParserContext},
+};
+
+impl Parser<'_> {
+    // return 값
+    pub(crate) fn parse_return_statement(
+        &mut self,
+        context: ParserContext,
+    ) -> Result<Statement, AllError> {
+        let span = self.current_span();
+
+        // eat return
+        self.next();
+
+        if self.get_current_token().is_none() {
+            return Err(ParserError::new(530, "Unexpected end of tokens".to_string()).into());
+        }
+
+        let value = self.parse_expression(context)?;
+
+        Ok(Statement::Return(value, span))
+    }
+}
@@ -0,0 +1,25 @@
+use crate::{
+    ast::statement::{while_statement::WhileStatement, Statement},
+    error::all_error::{parser_error::ParserError, AllError},
+    parser::{Parser, ParserContext},
+};
+
+impl Parser<'_> {
+    // while 조건 { ... }
+    pub(crate) fn parse_while_statement(
+        &mut self,
+        context: ParserContext,
+    ) -> Result<Statement, AllError> {
+        // eat while
+        self.next();
+
+        if self.get_current_token().is_none() {
+            return Err(ParserError::new(510, "Unexpected end of tokens".to_string()).into());
+        }
+
+        let condition = self.parse_expression(context.without_struct_literal())?;
+        let body = self.parse_block()?;
+
+        Ok(WhileStatement { condition, body }.into())
+    }
+}
@@ -0,0 +1,159 @@
+use crate::{
+    ast::statement::{
+        define_function::{FunctionDefinitionStatement, Parameter},
+        Statement,
+    },
+    error::all_error::{parser_error::ParserError, AllError},
+    lexer::{general::GeneralToken, primary::PrimaryToken, token::Token},
+    parser::{Parser, ParserContext},
+};
+
+impl Parser<'_> {
+    // fn 이름(매개변수: 타입, ...) -> 타입 { ... }
+    // 매개변수의 타입과 반환 타입은 생략할 수 있습니다.
+    pub(crate) fn parse_function_declaration(
+        &mut self,
+        _context: ParserContext,
+    ) -> Result<Statement, AllError> {
+        // eat fn
+        self.next();
+
+        let span = self.current_span();
+
+        let name = match self.get_current_token() {
+            Some(Token::Primary(PrimaryToken::Identifier(identifier))) => *identifier,
+            Some(current_token) => {
+                return Err(ParserError::new(
+                    700,
+                    format!("Expected function name, found {:?}", current_token),
+                )
+                .into());
+            }
+            None => {
+                return Err(ParserError::new(701, "Unexpected end of tokens".to_string()).into());
+            }
+        };
+
+        self.next();
+
+        let parameters = self.parse_parameters()?;
+
+        let return_type = match self.get_current_token() {
+            Some(Token::GeneralToken(GeneralToken::Arrow)) => {
+                self.next();
+
+                match self.get_current_token() {
+                    Some(Token::Primary(PrimaryToken::Identifier(identifier))) => {
+                        let return_type = *identifier;
+                        self.next();
+                        Some(return_type)
+                    }
+                    current_token => {
+                        return Err(ParserError::new(
+                            702,
+                            format!("Expected return type, found {:?}", current_token),
+                        )
+                        .into());
+                    }
+                }
+            }
+            _ => None,
+        };
+
+        let body = self.parse_block()?;
+
+        Ok(FunctionDefinitionStatement {
+            name,
+            parameters,
+            return_type,
+            body,
+            span,
+        }
+        .into())
+    }
+
+    // (a, b: 타입, c) 형태의 매개변수 목록입니다. 닫는 괄호까지 소비합니다.
+    fn parse_parameters(&mut self) -> Result<Vec<Parameter>, AllError> {
+        match self.get_current_token() {
+            Some(Token::GeneralToken(GeneralToken::LeftParentheses)) => self.next(),
+            current_token => {
+                return Err(ParserError::new(
+                    703,
+                    format!("Expected '(', found {:?}", current_token),
+                )
+                .into());
+            }
+        }
+
+        let mut parameters = vec![];
+
+        loop {
+            match self.get_current_token() {
+                Some(Token::GeneralToken(GeneralToken::RightParentheses)) => {
+                    self.next();
+                    break;
+                }
+                Some(Token::Primary(PrimaryToken::Identifier(identifier))) => {
+                    let name = *identifier;
+                    let span = self.current_span();
+                    self.next();
+
+                    let type_name = if let Some(Token::GeneralToken(GeneralToken::Colon)) =
+                        self.get_current_token()
+                    {
+                        self.next();
+
+                        match self.get_current_token() {
+                            Some(Token::Primary(PrimaryToken::Identifier(identifier))) => {
+                                let type_name = *identifier;
+                                self.next();
+                                Some(type_name)
+                            }
+                            current_token => {
+                                return Err(ParserError::new(
+                                    707,
+                                    format!("Expected parameter type, found {:?}", current_token),
+                                )
+                                .into());
+                            }
+                        }
+                    } else {
+                        None
+                    };
+
+                    parameters.push(Parameter {
+                        name,
+                        type_name,
+                        span,
+                    });
+
+                    match self.get_current_token() {
+                        Some(Token::GeneralToken(GeneralToken::Comma)) => self.next(),
+                        Some(Token::GeneralToken(GeneralToken::RightParentheses)) => {}
+                        current_token => {
+                            return Err(ParserError::new(
+                                704,
+                                format!("Expected ',' or ')', found {:?}", current_token),
+                            )
+                            .into());
+                        }
+                    }
+                }
+                Some(current_token) => {
+                    return Err(ParserError::new(
+                        705,
+                        format!("Expected parameter name, found {:?}", current_token),
+                    )
+                    .into());
+                }
+                None => {
+                    return Err(
+                        ParserError::new(706, "Unexpected end of tokens".to_string()).into(),
+                    );
+                }
+            }
+        }
+
+        Ok(parameters)
+    }
+}
@@ -1 +1,3 @@
+pub(crate) mod function;
+pub(crate) mod struct_declaration;
 pub(crate) mod variable;
@@ -0,0 +1,124 @@
+use crate::{
+    ast::statement::{
+        define_struct::{StructDefinitionStatement, StructField},
+        Statement,
+    },
+    error::all_error::{parser_error::ParserError, AllError},
+    lexer::{general::GeneralToken, primary::PrimaryToken, token::Token},
+    parser::{Parser, ParserContext},
+};
+
+impl Parser<'_> {
+    // struct 이름 { 필드: 타입, ... }
+    // 마지막 필드 뒤의 쉼표는 생략할 수 있습니다.
+    pub(crate) fn parse_struct_declaration(
+        &mut self,
+        _context: ParserContext,
+    ) -> Result<Statement, AllError> {
+        // eat struct
+        self.next();
+
+        let span = self.current_span();
+
+        let name = match self.get_current_token() {
+            Some(Token::Primary(PrimaryToken::Identifier(identifier))) => *identifier,
+            Some(current_token) => {
+                return Err(ParserError::new(
+                    900,
+                    format!("Expected struct name, found {:?}", current_token),
+                )
+                .into());
+            }
+            None => {
+                return Err(ParserError::new(901, "Unexpected end of tokens".to_string()).into());
+            }
+        };
+
+        self.next();
+
+        match self.get_current_token() {
+            Some(Token::GeneralToken(GeneralToken::LeftBrace)) => self.next(),
+            current_token => {
+                return Err(ParserError::new(
+                    902,
+                    format!("Expected '{{', found {:?}", current_token),
+                )
+                .into());
+            }
+        }
+
+        let mut fields = vec![];
+
+        loop {
+            match self.get_current_token() {
+                Some(Token::GeneralToken(GeneralToken::RightBrace)) => {
+                    self.next();
+                    break;
+                }
+                Some(Token::Primary(PrimaryToken::Identifier(identifier))) => {
+                    let field = *identifier;
+                    let field_span = self.current_span();
+                    self.next();
+
+                    match self.get_current_token() {
+                        Some(Token::GeneralToken(GeneralToken::Colon)) => self.next(),
+                        current_token => {
+                            return Err(ParserError::new(
+                                903,
+                                format!("Expected ':', found {:?}", current_token),
+                            )
+                            .into());
+                        }
+                    }
+
+                    let type_name = match self.get_current_token() {
+                        Some(Token::Primary(PrimaryToken::Identifier(identifier))) => *identifier,
+                        current_token => {
+                            return Err(ParserError::new(
+                                904,
+                                format!("Expected field type, found {:?}", current_token),
+                            )
+                            .into());
+                        }
+                    };
+
+                    self.next();
+
+                    fields.push(StructField {
+                        name: field,
+                        type_name,
+                        span: field_span,
+                    });
+
+                    match self.get_current_token() {
+                        Some(Token::GeneralToken(GeneralToken::Comma)) => self.next(),
+                        Some(Token::GeneralToken(GeneralToken::RightBrace)) => {}
+                        current_token => {
+                            return Err(ParserError::new(
+                                905,
+                                format!("Expected ',' or '}}', found {:?}", current_token),
+                            )
+                            .into());
+                        }
+                    }
+                }
+                Some(Token::Eof) | None => {
+                    return Err(ParserError::new(
+                        906,
+                        "Expected '}', found end of file".to_string(),
+                    )
+                    .into());
+                }
+                Some(current_token) => {
+                    return Err(ParserError::new(
+                        907,
+                        format!("Expected field name, found {:?}", current_token),
+                    )
+                    .into());
+                }
+            }
+        }
+
+        Ok(StructDefinitionStatement { name, fields, span }.into())
+    }
+}
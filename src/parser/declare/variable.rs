@@ -1,11 +1,14 @@
 use crate::{
     ast::statement::{define_variable::VariableDefinitionStatement, Statement},
     error::all_error::{parser_error::ParserError, AllError},
-    lexer::{keyword::Keyword, operator::OperatorToken, primary::PrimaryToken, token::Token},
+    lexer::{
+        general::GeneralToken, keyword::Keyword, operator::OperatorToken, primary::PrimaryToken,
+        token::Token,
+    },
     parser::{Parser, ParserContext},
 };
 
-impl Parser {
+impl Parser<'_> {
     pub(crate) fn parse_declare_variable(
         &mut self,
         _context: ParserContext,
@@ -38,15 +41,33 @@ impl Parser {
         // eat let
         self.next();
 
+        self.parse_variable_definition(false)
+    }
+
+    // mut로 선언한 변수만 다시 대입할 수 있습니다.
+    pub(crate) fn parse_mut_variable(
+        &mut self,
+        _context: ParserContext,
+    ) -> Result<Statement, AllError> {
+        // eat mut
+        self.next();
+
+        self.parse_variable_definition(true)
+    }
+
+    // 이름 = 값, 이름: 타입 = 값
+    fn parse_variable_definition(&mut self, mutable: bool) -> Result<Statement, AllError> {
         let current_token = if let Some(token) = self.get_current_token() {
             token
         } else {
             return Err(ParserError::new(3, "Unexpected end of tokens".to_string()).into());
         };
 
+        let span = self.current_span();
+
         let variable_name =
             if let Token::Primary(PrimaryToken::Identifier(identifier)) = current_token {
-                identifier
+                *identifier
             } else {
                 return Err(ParserError::new(
                     4,
@@ -60,6 +81,28 @@ impl Parser {
 
         self.next();
 
+        let type_name =
+            if let Some(Token::GeneralToken(GeneralToken::Colon)) = self.get_current_token() {
+                self.next();
+
+                match self.get_current_token() {
+                    Some(Token::Primary(PrimaryToken::Identifier(identifier))) => {
+                        let type_name = *identifier;
+                        self.next();
+                        Some(type_name)
+                    }
+                    current_token => {
+                        return Err(ParserError::new(
+                            16,
+                            format!("Expected type name, found {:?}", current_token),
+                        )
+                        .into());
+                    }
+                }
+            } else {
+                None
+            };
+
         let current_token = if let Some(token) = self.get_current_token() {
             token
         } else {
@@ -75,7 +118,9 @@ impl Parser {
                 let statement = VariableDefinitionStatement {
                     name: variable_name,
                     value: Some(expression),
-                    mutable: false,
+                    type_name,
+                    mutable,
+                    span,
                 }
                 .into();
 
@@ -91,14 +136,4 @@ impl Parser {
             .into()),
         }
     }
-
-    pub(crate) fn parse_mut_variable(
-        &mut self,
-        _context: ParserContext,
-    ) -> Result<Statement, AllError> {
-        // eat mut
-        self.next();
-
-        todo!()
-    }
 }
@@ -1,22 +1,69 @@
 pub(crate) mod binary;
+pub(crate) mod cast;
+pub(crate) mod field;
 pub(crate) mod function_call;
+pub(crate) mod match_expression;
 pub(crate) mod parentheses;
+pub(crate) mod struct_literal;
 pub(crate) mod unary;
 pub(crate) mod variable;
 
 use crate::{
-    ast::expression::Expression,
+    ast::expression::{literal::LiteralExpression, Expression},
     error::all_error::{parser_error::ParserError, AllError},
-    lexer::{general::GeneralToken, primary::PrimaryToken, token::Token},
+    lexer::{general::GeneralToken, keyword::Keyword, primary::PrimaryToken, token::Token},
 };
 
 use super::{Parser, ParserContext};
 
-impl Parser {
+impl Parser<'_> {
+    // 식 전체를 파싱합니다. 이항 연산자는 우선순위 등반(precedence climbing)으로 묶습니다.
     pub(super) fn parse_expression(
         &mut self,
         context: ParserContext,
     ) -> Result<Expression, AllError> {
+        if let Some(Token::Primary(PrimaryToken::Comment(comment))) = self.get_current_token() {
+            let comment = comment.clone();
+            let span = self.current_span();
+            self.next();
+            return Ok(Expression::Comment(comment, span));
+        }
+
+        let lhs = self.parse_operand_expression(context.clone())?;
+
+        self.parse_binary_expression(lhs, 0, context)
+    }
+
+    // 이항 연산자의 피연산자 하나를 파싱합니다.
+    // as는 이항 연산자보다 먼저, 단항 연산자보다 나중에 묶입니다. -a as i32 * b는 ((-a) as i32) * b입니다.
+    pub(super) fn parse_operand_expression(
+        &mut self,
+        context: ParserContext,
+    ) -> Result<Expression, AllError> {
+        let expression = self.parse_primary_expression(context.clone())?;
+
+        self.parse_cast_expression(expression, context)
+    }
+
+    // 이항 연산자를 포함하지 않는 식 하나를 파싱합니다.
+    // 리터럴, 변수, 함수 호출, 단항 연산, 소괄호, match, 구조체 리터럴과 그 뒤의 필드 접근이 여기에 해당합니다.
+    pub(super) fn parse_primary_expression(
+        &mut self,
+        context: ParserContext,
+    ) -> Result<Expression, AllError> {
+        // 단항 연산자의 피연산자도 필드 접근까지 읽으므로 -p.x는 -(p.x)입니다.
+        let expression = self.parse_atom_expression(context.clone())?;
+
+        self.parse_field_expression(expression, context)
+    }
+
+    fn parse_atom_expression(&mut self, context: ParserContext) -> Result<Expression, AllError> {
+        // 식별자 뒤의 ( 또는 {로 함수 호출과 구조체 리터럴을 구분합니다.
+        let next_token = match self.get_next_token() {
+            Some(Token::GeneralToken(token)) => Some(*token),
+            _ => None,
+        };
+
         let current_token = if let Some(token) = self.get_current_token() {
             token
         } else {
@@ -24,40 +71,29 @@ impl Parser {
         };
 
         match current_token {
-            Token::Primary(PrimaryToken::Comment(comment)) => {
-                self.next();
-                Ok(Expression::Comment(comment))
-            }
             Token::Primary(PrimaryToken::Identifier(_)) => {
-                let next_token = self.get_next_token();
-
-                if let Some(Token::GeneralToken(GeneralToken::LeftParentheses)) = next_token {
+                if let Some(GeneralToken::LeftParentheses) = next_token {
                     let function_call_expression = self.parse_function_call_expression(context)?;
 
                     Ok(function_call_expression)
+                } else if let (Some(GeneralToken::LeftBrace), true) =
+                    (next_token, context.allows_struct_literal())
+                {
+                    let struct_literal_expression =
+                        self.parse_struct_literal_expression(context)?;
+
+                    Ok(struct_literal_expression)
                 } else {
                     let variable_expression = self.parse_variable_expression(context)?;
 
                     Ok(variable_expression)
                 }
             }
-            Token::Primary(primary) => {
-                if let Some(next_token) = self.get_next_token() {
-                    if next_token.is_binary_operator() {
-                        self.next();
-
-                        let binary_expression =
-                            self.parse_binary_expression(Expression::from(primary), context)?;
-
-                        Ok(binary_expression)
-                    } else {
-                        self.next();
-                        Ok(primary.into())
-                    }
-                } else {
-                    self.next();
-                    Ok(primary.into())
-                }
+            Token::Primary(_) => {
+                let span = self.current_span();
+                let literal = self.parse_literal(false)?;
+
+                Ok(Expression::Literal(literal, span))
             }
             Token::Operator(operator) => {
                 if operator.is_unary_operator() {
@@ -72,25 +108,76 @@ impl Parser {
                     .into())
                 }
             }
+            Token::Keyword(Keyword::Match) => {
+                let match_expression = self.parse_match_expression(context)?;
+
+                Ok(match_expression)
+            }
             Token::GeneralToken(GeneralToken::LeftParentheses) => {
-                let parentheses_expression = self.parse_parentheses_expression(context.clone())?;
-
-                if let Some(current_token) = self.get_current_token() {
-                    if current_token.is_binary_operator() {
-                        let binary_expression =
-                            self.parse_binary_expression(parentheses_expression, context)?;
-
-                        Ok(binary_expression)
-                    } else {
-                        self.next();
-                        Ok(parentheses_expression)
-                    }
-                } else {
-                    self.next();
-                    Ok(parentheses_expression)
-                }
+                let parentheses_expression = self.parse_parentheses_expression(context)?;
+
+                Ok(parentheses_expression)
+            }
+            _ => Err(ParserError::new(
+                15,
+                format!("Expected expression, found {:?}", current_token),
+            )
+            .into()),
+        }
+    }
+
+    // 현재 리터럴 토큰을 소비합니다. negative는 리터럴 바로 앞에 -가 있는지입니다.
+    // 정수 토큰은 부호 없는 크기이므로 범위는 부호를 알고 나서 검사합니다.
+    // -9223372036854775808의 9223372036854775808은 i64로 표현할 수 없지만 비트가 같으므로 i64::MIN으로 담고,
+    // 부호를 붙이면 같은 값이 됩니다. (-2147483648i32도 마찬가지입니다.)
+    pub(super) fn parse_literal(&mut self, negative: bool) -> Result<LiteralExpression, AllError> {
+        let literal = match self.get_current_token() {
+            Some(Token::Primary(PrimaryToken::Integer(magnitude))) => LiteralExpression::Integer(
+                Self::check_magnitude(*magnitude, i64::MAX as u64, negative, "i64")? as i64,
+            ),
+            Some(Token::Primary(PrimaryToken::Int64(magnitude))) => LiteralExpression::Int64(
+                Self::check_magnitude(*magnitude, i64::MAX as u64, negative, "i64")? as i64,
+            ),
+            Some(Token::Primary(PrimaryToken::Int32(magnitude))) => LiteralExpression::Int32(
+                Self::check_magnitude(*magnitude as u64, i32::MAX as u64, negative, "i32")? as i32,
+            ),
+            Some(Token::Primary(
+                primary @ (PrimaryToken::Float(_)
+                | PrimaryToken::Float32(_)
+                | PrimaryToken::String(_)
+                | PrimaryToken::Boolean(_)
+                | PrimaryToken::Char(_)),
+            )) => primary.clone().into(),
+            current_token => {
+                return Err(ParserError::new(
+                    16,
+                    format!("Expected literal, found {:?}", current_token),
+                )
+                .into());
             }
-            _ => todo!(),
+        };
+
+        self.next();
+
+        Ok(literal)
+    }
+
+    fn check_magnitude(
+        magnitude: u64,
+        max: u64,
+        negative: bool,
+        type_name: &str,
+    ) -> Result<u64, AllError> {
+        let limit = if negative { max + 1 } else { max };
+
+        if magnitude > limit {
+            return Err(ParserError::new(
+                17,
+                format!("literal {} is out of range for {}", magnitude, type_name),
+            )
+            .into());
         }
+
+        Ok(magnitude)
     }
 }
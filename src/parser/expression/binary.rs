@@ -3,127 +3,87 @@ use crate::{
         expression::{binary::BinaryExpression, Expression},
         operator::binary::BinaryOperator,
     },
-    error::all_error::{parser_error::ParserError, AllError},
-    lexer::token::Token,
+    error::all_error::AllError,
+    lexer::{primary::PrimaryToken, token::Token},
 };
 
 use super::{Parser, ParserContext};
 
-impl Parser {
+impl Parser<'_> {
+    // lhs 뒤에 이어지는 이항 연산자들을 우선순위 등반으로 묶습니다.
+    // 우선순위가 min_precedence보다 낮은 연산자를 만나면 멈추고, 그 연산자는 호출한 쪽에서 묶습니다.
     pub(super) fn parse_binary_expression(
         &mut self,
-        lhs: Expression,
-        _context: ParserContext,
+        mut lhs: Expression,
+        min_precedence: u8,
+        context: ParserContext,
     ) -> Result<Expression, AllError> {
-        let current_token = if let Some(token) = self.get_current_token() {
-            token
-        } else {
-            return Err(ParserError::new(9, "Unexpected end of tokens".to_string()).into());
-        };
+        while let Some(operator) = self.current_binary_operator() {
+            let precedence = operator.get_precedence();
 
-        if !current_token.is_binary_operator() {
-            return Err(ParserError::new(
-                7,
-                format!("Expected binary operator, found {:?}", current_token),
-            )
-            .into());
-        }
-
-        let operator: BinaryOperator = if let Token::Operator(operator) = current_token {
-            operator.into()
-        } else {
-            return Err(ParserError::new(
-                8,
-                format!("Expected binary operator, found {:?}", current_token),
-            )
-            .into());
-        };
-
-        // 현재 연산자의 우선순위
-        let current_precedence = operator.get_precedence();
-
-        // rhs에 괄호 연산자가 있는 경우
-        let mut rhs_has_parentheses = false;
-
-        // lhs에 괄호 연산자가 있는 경우
-        let mut lhs_has_parentheses = false;
-
-        self.next();
-        let rhs = self.parse_expression(_context)?;
+            if precedence < min_precedence {
+                break;
+            }
 
-        // 소괄호가 있다면 벗기고 플래그값 설정
-        let rhs = if let Expression::Parentheses(paren) = rhs {
-            rhs_has_parentheses = true;
-            *paren.expression
-        } else {
-            rhs
-        };
+            let span = self.current_span();
 
-        let lhs = if let Expression::Parentheses(paren) = lhs {
-            lhs_has_parentheses = true;
-            *paren.expression
-        } else {
-            lhs
-        };
+            // eat operator
+            self.next();
+            // 1 + /* c */ 2처럼 연산자와 피연산자 사이의 주석은 식에 남기지 않습니다.
+            self.skip_comments();
 
-        // rhs에 또 binary operation이 중첩되는 경우 처리
-        if let Expression::Binary(rhs_binary_expression) = rhs.clone() {
-            if lhs.is_unary() {
-                let lhs = Box::new(lhs);
+            let mut rhs = self.parse_operand_expression(context.clone())?;
 
-                let new_lhs = Box::new(
-                    BinaryExpression {
-                        lhs,
-                        rhs: rhs_binary_expression.lhs,
-                        operator,
-                    }
-                    .into(),
-                );
-                Ok(BinaryExpression {
-                    lhs: new_lhs,
-                    rhs: rhs_binary_expression.rhs,
-                    operator: rhs_binary_expression.operator,
-                }
-                .into())
-            } else {
-                if lhs_has_parentheses {
-                    return Ok(BinaryExpression {
-                        lhs: Box::new(lhs),
-                        rhs: Box::new(rhs),
-                        operator,
-                    }
-                    .into());
+            // 오른쪽에 우선순위가 더 높은 연산자가 오면 그쪽을 먼저 묶습니다.
+            while let Some(next_operator) = self.current_binary_operator() {
+                if next_operator.get_precedence() <= precedence {
+                    break;
                 }
 
-                let next_precedence = rhs_binary_expression.operator.get_precedence();
+                rhs = self.parse_binary_expression(rhs, precedence + 1, context.clone())?;
+            }
 
-                let lhs = Box::new(lhs);
-                let rhs = Box::new(rhs);
+            // 묶는 순서가 트리 모양에 이미 반영되었으므로 피연산자의 소괄호는 벗깁니다.
+            lhs = BinaryExpression {
+                lhs: Box::new(Self::strip_parentheses(lhs)),
+                rhs: Box::new(Self::strip_parentheses(rhs)),
+                operator,
+                span,
+            }
+            .into();
+        }
 
-                // 오른쪽 연산자의 우선순위가 더 크거나, 소괄호가 있을 경우 오른쪽을 먼저 묶어서 바인딩
-                if next_precedence > current_precedence || rhs_has_parentheses {
-                    Ok(BinaryExpression { lhs, rhs, operator }.into())
-                }
-                // 아니라면 왼쪽으로 묶어서 바인딩
-                else {
-                    let new_lhs = BinaryExpression {
-                        lhs,
-                        rhs: rhs_binary_expression.lhs,
-                        operator,
-                    };
-                    Ok(BinaryExpression {
-                        lhs: Box::new(new_lhs.into()),
-                        rhs: rhs_binary_expression.rhs,
-                        operator: rhs_binary_expression.operator,
-                    }
-                    .into())
+        Ok(lhs)
+    }
+
+    // 현재 토큰이 식 안에서 쓸 수 있는 이항 연산자라면 반환합니다.
+    // 대입 연산자는 statement이므로 여기에서 멈춥니다.
+    // 1 /* c */ + 2처럼 연산자 앞에 주석이 있으면 주석을 건너뛰고, 연산자가 없으면 주석은 다음 statement로 남깁니다.
+    fn current_binary_operator(&mut self) -> Option<BinaryOperator> {
+        let mut offset = 0;
+
+        loop {
+            match self.get_token_after(offset) {
+                Some(Token::Primary(PrimaryToken::Comment(_))) => offset += 1,
+                Some(Token::Operator(operator))
+                    if operator.is_binary_operator() && !operator.is_assignment_operator() =>
+                {
+                    let operator = (*operator).into();
+                    self.skip_comments();
+
+                    return Some(operator);
                 }
+                _ => return None,
             }
-        } else {
-            let lhs = Box::new(lhs);
-            let rhs = Box::new(rhs);
+        }
+    }
 
-            Ok(BinaryExpression { lhs, rhs, operator }.into())
+    // ((a))처럼 겹친 소괄호도 모두 벗깁니다.
+    fn strip_parentheses(mut expression: Expression) -> Expression {
+        while let Expression::Parentheses(parentheses) = expression {
+            expression = *parentheses.expression;
         }
+
+        expression
     }
 }
@@ -0,0 +1,52 @@
+use crate::{
+    ast::expression::{cast::CastExpression, Expression},
+    error::all_error::{parser_error::ParserError, AllError},
+    lexer::{keyword::Keyword, primary::PrimaryToken, token::Token},
+};
+
+use super::{Parser, ParserContext};
+
+impl Parser<'_> {
+    // 식 뒤에 이어지는 as 타입을 모두 읽습니다. a as i32 as i64는 (a as i32) as i64입니다.
+    pub(super) fn parse_cast_expression(
+        &mut self,
+        value: Expression,
+        _context: ParserContext,
+    ) -> Result<Expression, AllError> {
+        let mut value = value;
+
+        while let Some(Token::Keyword(Keyword::As)) = self.get_current_token() {
+            let span = self.current_span();
+
+            // eat as
+            self.next();
+
+            let type_name = match self.get_current_token() {
+                Some(Token::Primary(PrimaryToken::Identifier(identifier))) => *identifier,
+                Some(current_token) => {
+                    return Err(ParserError::new(
+                        930,
+                        format!("Expected type name after 'as', found {:?}", current_token),
+                    )
+                    .into());
+                }
+                None => {
+                    return Err(
+                        ParserError::new(931, "Unexpected end of tokens".to_string()).into(),
+                    );
+                }
+            };
+
+            self.next();
+
+            value = CastExpression {
+                value: Box::new(value),
+                type_name,
+                span,
+            }
+            .into();
+        }
+
+        Ok(value)
+    }
+}
@@ -0,0 +1,50 @@
+use crate::{
+    ast::expression::{field::FieldExpression, Expression},
+    error::all_error::{parser_error::ParserError, AllError},
+    lexer::{operator::OperatorToken, primary::PrimaryToken, token::Token},
+};
+
+use super::{Parser, ParserContext};
+
+impl Parser<'_> {
+    // 식 뒤에 이어지는 .필드를 모두 읽습니다. a.b.c는 (a.b).c입니다.
+    pub(super) fn parse_field_expression(
+        &mut self,
+        object: Expression,
+        _context: ParserContext,
+    ) -> Result<Expression, AllError> {
+        let mut object = object;
+
+        while let Some(Token::Operator(OperatorToken::Dot)) = self.get_current_token() {
+            // eat .
+            self.next();
+
+            let field = match self.get_current_token() {
+                Some(Token::Primary(PrimaryToken::Identifier(identifier))) => *identifier,
+                Some(current_token) => {
+                    return Err(ParserError::new(
+                        920,
+                        format!("Expected field name, found {:?}", current_token),
+                    )
+                    .into());
+                }
+                None => {
+                    return Err(
+                        ParserError::new(921, "Unexpected end of tokens".to_string()).into(),
+                    );
+                }
+            };
+
+            object = FieldExpression {
+                object: Box::new(object),
+                field,
+                span: self.current_span(),
+            }
+            .into();
+
+            self.next();
+        }
+
+        Ok(object)
+    }
+}
@@ -6,10 +6,10 @@ use crate::{
 
 use super::{Parser, ParserContext};
 
-impl Parser {
+impl Parser<'_> {
     pub(super) fn parse_function_call_expression(
         &mut self,
-        context: ParserContext,
+        _context: ParserContext,
     ) -> Result<Expression, AllError> {
         let current_token = if let Some(token) = self.get_current_token() {
             token
@@ -17,8 +17,10 @@ impl Parser {
             return Err(ParserError::new(100, "Unexpected end of tokens".to_string()).into());
         };
 
+        let span = self.current_span();
+
         let function_name = if let Token::Primary(PrimaryToken::Identifier(id)) = current_token {
-            id
+            *id
         } else {
             return Err(ParserError::new(
                 101,
@@ -48,13 +50,15 @@ impl Parser {
         // parsing arguments
         loop {
             let current_token = self.get_current_token();
-         
+
             match current_token {
                 Some(Token::GeneralToken(GeneralToken::RightParentheses)) => {
                     self.next();
                     break;
                 }
-                Some(Token::GeneralToken(GeneralToken::Comma)) => {
+                // 인자 사이의 주석은 인자가 아닙니다.
+                Some(Token::GeneralToken(GeneralToken::Comma))
+                | Some(Token::Primary(PrimaryToken::Comment(_))) => {
                     self.next();
                     continue;
                 }
@@ -64,38 +68,17 @@ impl Parser {
                 _ => {}
             }
 
-            // 각 argument를 파싱
-            let expression = self.parse_expression(context.clone())?;
+            // 각 argument를 파싱. 괄호 안에서는 구조체 리터럴을 다시 쓸 수 있습니다.
+            let expression = self.parse_expression(ParserContext::new())?;
             arguments.push(expression);
         }
 
         let function_call_expression = CallExpression {
             function_name,
             arguments,
+            span,
         };
 
-        if let Some(next_token) = self.get_next_token() {
-            if next_token.is_binary_operator() {
-                self.next();
-                let binary_expression =
-                    self.parse_binary_expression(function_call_expression.into(), context)?;
-
-                Ok(binary_expression)
-            } else {
-                match next_token {
-                    Token::GeneralToken(GeneralToken::SemiColon) => {
-                        self.next();
-                        Ok(function_call_expression.into())
-                    }
-                    _ => Err(ParserError::new(
-                        106,
-                        format!("Expected binary operator, found {:?}", next_token),
-                    )
-                    .into()),
-                }
-            }
-        } else {
-            Ok(function_call_expression.into())
-        }
+        Ok(function_call_expression.into())
     }
 }
@@ -0,0 +1,150 @@
+use crate::{
+    ast::expression::{
+        match_expression::{MatchArm, MatchExpression, Pattern},
+        Expression,
+    },
+    error::all_error::{parser_error::ParserError, AllError},
+    lexer::{general::GeneralToken, operator::OperatorToken, primary::PrimaryToken, token::Token},
+    utils::span::Span,
+};
+
+use super::{Parser, ParserContext};
+
+impl Parser<'_> {
+    // match 대상 { 패턴 => 식, ... }
+    // 갈래 사이의 쉼표는 생략할 수 있습니다.
+    pub(super) fn parse_match_expression(
+        &mut self,
+        context: ParserContext,
+    ) -> Result<Expression, AllError> {
+        let span = self.current_span();
+
+        // eat match
+        self.next();
+
+        if self.get_current_token().is_none() {
+            return Err(ParserError::new(800, "Unexpected end of tokens".to_string()).into());
+        }
+
+        let subject = self.parse_expression(context.without_struct_literal())?;
+
+        match self.get_current_token() {
+            Some(Token::GeneralToken(GeneralToken::LeftBrace)) => self.next(),
+            current_token => {
+                return Err(ParserError::new(
+                    801,
+                    format!("Expected '{{', found {:?}", current_token),
+                )
+                .into());
+            }
+        }
+
+        let mut arms = vec![];
+
+        loop {
+            match self.get_current_token() {
+                Some(Token::GeneralToken(GeneralToken::RightBrace)) => {
+                    self.next();
+                    break;
+                }
+                Some(Token::GeneralToken(GeneralToken::Comma)) => {
+                    self.next();
+                }
+                Some(Token::Eof) | None => {
+                    return Err(ParserError::new(
+                        802,
+                        "Expected '}', found end of file".to_string(),
+                    )
+                    .into());
+                }
+                _ => {
+                    let (pattern, arm_span) = self.parse_pattern()?;
+
+                    match self.get_current_token() {
+                        Some(Token::GeneralToken(GeneralToken::FatArrow)) => self.next(),
+                        current_token => {
+                            return Err(ParserError::new(
+                                803,
+                                format!("Expected '=>', found {:?}", current_token),
+                            )
+                            .into());
+                        }
+                    }
+
+                    if self.get_current_token().is_none() {
+                        return Err(
+                            ParserError::new(804, "Unexpected end of tokens".to_string()).into(),
+                        );
+                    }
+
+                    let body = self.parse_expression(ParserContext::new())?;
+
+                    arms.push(MatchArm {
+                        pattern,
+                        body,
+                        span: arm_span,
+                    });
+                }
+            }
+        }
+
+        Ok(MatchExpression {
+            subject: Box::new(subject),
+            arms,
+            span,
+        }
+        .into())
+    }
+
+    // 리터럴 하나, 음수 리터럴, 또는 모든 값과 일치하는 _입니다. 패턴 전체의 위치와 함께 반환합니다.
+    fn parse_pattern(&mut self) -> Result<(Pattern, Span), AllError> {
+        let span = self.current_span();
+
+        let number_follows = matches!(
+            self.get_next_token(),
+            Some(Token::Primary(
+                PrimaryToken::Integer(_)
+                    | PrimaryToken::Int32(_)
+                    | PrimaryToken::Int64(_)
+                    | PrimaryToken::Float(_)
+                    | PrimaryToken::Float32(_),
+            ))
+        );
+
+        match self.get_current_token() {
+            Some(Token::Primary(PrimaryToken::Identifier(identifier)))
+                if &*identifier.text() == "_" =>
+            {
+                self.next();
+                Ok((Pattern::Wildcard, span))
+            }
+            Some(Token::Primary(
+                PrimaryToken::Integer(_)
+                | PrimaryToken::Int32(_)
+                | PrimaryToken::Int64(_)
+                | PrimaryToken::Float(_)
+                | PrimaryToken::Float32(_)
+                | PrimaryToken::String(_)
+                | PrimaryToken::Boolean(_)
+                | PrimaryToken::Char(_),
+            )) => Ok((Pattern::Literal(self.parse_literal(false)?), span)),
+            Some(Token::Operator(OperatorToken::Minus)) if number_follows => {
+                // eat -
+                self.next();
+
+                let literal_span = self.current_span();
+                let literal = self.parse_literal(true)?;
+
+                Ok((
+                    Pattern::Literal(literal.negated().expect("numeric literal")),
+                    span.to(literal_span),
+                ))
+            }
+            current_token => Err(ParserError::new(
+                805,
+                format!("Expected pattern, found {:?}", current_token),
+            )
+            .into()),
+        }
+    }
+}
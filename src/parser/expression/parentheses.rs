@@ -6,7 +6,7 @@ use crate::{
 
 use super::{Parser, ParserContext};
 
-impl Parser {
+impl Parser<'_> {
     pub(super) fn parse_parentheses_expression(
         &mut self,
         _context: ParserContext,
@@ -24,8 +24,9 @@ impl Parser {
             );
         }
 
+        // 괄호 안에서는 구조체 리터럴을 다시 쓸 수 있습니다.
         self.next();
-        let expression = self.parse_expression(_context)?;
+        let expression = self.parse_expression(ParserContext::new())?;
 
         let current_token = if let Some(token) = self.get_current_token() {
             token
@@ -0,0 +1,103 @@
+use crate::{
+    ast::expression::{struct_literal::StructLiteralExpression, Expression},
+    error::all_error::{parser_error::ParserError, AllError},
+    lexer::{general::GeneralToken, primary::PrimaryToken, token::Token},
+};
+
+use super::{Parser, ParserContext};
+
+impl Parser<'_> {
+    // 이름 { 필드: 식, ... }
+    // 마지막 필드 뒤의 쉼표는 생략할 수 있습니다.
+    pub(super) fn parse_struct_literal_expression(
+        &mut self,
+        _context: ParserContext,
+    ) -> Result<Expression, AllError> {
+        let span = self.current_span();
+
+        let name = match self.get_current_token() {
+            Some(Token::Primary(PrimaryToken::Identifier(identifier))) => *identifier,
+            Some(current_token) => {
+                return Err(ParserError::new(
+                    910,
+                    format!("Expected struct name, found {:?}", current_token),
+                )
+                .into());
+            }
+            None => {
+                return Err(ParserError::new(911, "Unexpected end of tokens".to_string()).into());
+            }
+        };
+
+        self.next();
+
+        match self.get_current_token() {
+            Some(Token::GeneralToken(GeneralToken::LeftBrace)) => self.next(),
+            current_token => {
+                return Err(ParserError::new(
+                    912,
+                    format!("Expected '{{', found {:?}", current_token),
+                )
+                .into());
+            }
+        }
+
+        let mut fields = vec![];
+
+        loop {
+            match self.get_current_token() {
+                Some(Token::GeneralToken(GeneralToken::RightBrace)) => {
+                    self.next();
+                    break;
+                }
+                Some(Token::Primary(PrimaryToken::Identifier(identifier))) => {
+                    let field = *identifier;
+                    self.next();
+
+                    match self.get_current_token() {
+                        Some(Token::GeneralToken(GeneralToken::Colon)) => self.next(),
+                        current_token => {
+                            return Err(ParserError::new(
+                                913,
+                                format!("Expected ':', found {:?}", current_token),
+                            )
+                            .into());
+                        }
+                    }
+
+                    // 중괄호 안에서는 구조체 리터럴을 다시 쓸 수 있습니다.
+                    let value = self.parse_expression(ParserContext::new())?;
+                    fields.push((field, value));
+
+                    match self.get_current_token() {
+                        Some(Token::GeneralToken(GeneralToken::Comma)) => self.next(),
+                        Some(Token::GeneralToken(GeneralToken::RightBrace)) => {}
+                        current_token => {
+                            return Err(ParserError::new(
+                                914,
+                                format!("Expected ',' or '}}', found {:?}", current_token),
+                            )
+                            .into());
+                        }
+                    }
+                }
+                Some(Token::Eof) | None => {
+                    return Err(ParserError::new(
+                        915,
+                        "Expected '}', found end of file".to_string(),
+                    )
+                    .into());
+                }
+                Some(current_token) => {
+                    return Err(ParserError::new(
+                        916,
+                        format!("Expected field name, found {:?}", current_token),
+                    )
+                    .into());
+                }
+            }
+        }
+
+        Ok(StructLiteralExpression { name, fields, span }.into())
+    }
+}
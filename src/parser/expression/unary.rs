@@ -4,12 +4,12 @@ use crate::{
         operator::unary::UnaryOperator,
     },
     error::all_error::{parser_error::ParserError, AllError},
-    lexer::token::Token,
+    lexer::{primary::PrimaryToken, token::Token},
 };
 
 use super::{Parser, ParserContext};
 
-impl Parser {
+impl Parser<'_> {
     pub(super) fn parse_unary_expression(
         &mut self,
         _context: ParserContext,
@@ -28,8 +28,9 @@ impl Parser {
             .into());
         }
 
+        let span = self.current_span();
         let operator: UnaryOperator = if let Token::Operator(operator) = current_token {
-            operator.into()
+            (*operator).into()
         } else {
             return Err(ParserError::new(
                 302,
@@ -38,10 +39,28 @@ impl Parser {
             .into());
         };
 
-        // rhs에 괄호 연산자가 있는 경우
-        let operand = self.parse_expression(_context)?;
+        // eat operator
+        self.next();
+
+        // 단항 연산자는 이항 연산자보다 먼저 묶입니다. -a * b는 (-a) * b입니다.
+        // -9223372036854775808처럼 부호를 붙여야 범위에 들어가는 정수 리터럴이 있으므로, 정수 리터럴은 부호를 알려 줍니다.
+        let operand = match self.get_current_token() {
+            Some(Token::Primary(
+                PrimaryToken::Integer(_) | PrimaryToken::Int32(_) | PrimaryToken::Int64(_),
+            )) if operator == UnaryOperator::Minus => {
+                let literal_span = self.current_span();
+                let literal = self.parse_literal(true)?;
+                self.parse_field_expression(Expression::Literal(literal, literal_span), _context)?
+            }
+            _ => self.parse_primary_expression(_context)?,
+        };
         let operand = Box::new(operand);
 
-        Ok(UnaryExpression { operator, operand }.into())
+        Ok(UnaryExpression {
+            operator,
+            operand,
+            span,
+        }
+        .into())
     }
 }
@@ -6,7 +6,7 @@ use crate::{
 
 use super::{Parser, ParserContext};
 
-impl Parser {
+impl Parser<'_> {
     pub(super) fn parse_variable_expression(
         &mut self,
         _context: ParserContext,
@@ -19,7 +19,7 @@ impl Parser {
 
         let current_identifer = if let Token::Primary(PrimaryToken::Identifier(id)) = current_token
         {
-            id
+            *id
         } else {
             return Err(ParserError::new(
                 401,
@@ -30,25 +30,11 @@ impl Parser {
 
         let variable_expression = VariableExpression {
             name: current_identifer,
+            span: self.current_span(),
         };
 
-        if let Some(next_token) = self.get_next_token() {
-            if next_token.is_binary_operator() {
-                self.next();
-                let binary_expression =
-                    self.parse_binary_expression(variable_expression.into(), _context)?;
+        self.next();
 
-                Ok(binary_expression)
-            } else {
-                Err(ParserError::new(
-                    402,
-                    format!("Expected binary operator, found {:?}", next_token),
-                )
-                .into())
-            }
-        } else {
-            self.next();
-            Ok(variable_expression.into())
-        }
+        Ok(variable_expression.into())
     }
 }
@@ -1,4 +1,6 @@
+pub mod assign;
 pub mod context;
+pub mod control;
 pub mod declare;
 pub mod expression;
 pub use context::ParserContext;
@@ -7,75 +9,300 @@ pub(crate) mod test;
 
 use crate::{
     ast::statement::Statement,
-    error::all_error::AllError,
-    lexer::{general::GeneralToken, keyword::Keyword, token::Token},
+    error::all_error::{parser_error::ParserError, AllError},
+    lexer::{
+        general::GeneralToken, keyword::Keyword, primary::PrimaryToken, token::Token,
+        tokenizer::Tokenizer,
+    },
+    utils::{
+        logger::{Level, Logger},
+        span::Span,
+    },
 };
 
-#[derive(Debug, Clone, PartialEq)]
-pub(crate) struct Parser {
+#[derive(Debug)]
+pub(crate) struct Parser<'a> {
     tokens: Vec<Token>,
-    current: usize, // index of current token
+    spans: Vec<Span>, // tokens와 같은 순서의 토큰 위치
+    current: usize,   // index of current token
+    // 토큰 목록 대신 토크나이저를 받으면 파싱하면서 필요한 토큰만 읽습니다.
+    tokenizer: Option<Tokenizer<'a>>,
+    // 토크나이저가 반환한 오류. 파싱이 끝나면 파서 오류 대신 이 오류를 반환합니다.
+    lexer_error: Option<AllError>,
     context: ParserContext,
 }
 
-impl Parser {
+impl<'a> Parser<'a> {
     pub fn new() -> Self {
         Self {
             tokens: vec![],
+            spans: vec![],
             current: 0,
+            tokenizer: None,
+            lexer_error: None,
             context: ParserContext::new(),
         }
     }
 
+    // 이미 만든 토큰 목록으로 파싱합니다. (테스트와 벤치마크)
+    // 토큰 위치가 없으므로 AST와 오류의 위치는 알 수 없음(Span::default)으로 남습니다.
+    #[allow(dead_code)]
     pub fn set_tokens(&mut self, tokens: Vec<Token>) {
         self.tokens = tokens;
     }
 
-    #[allow(dead_code)]
-    fn prev(&mut self) {
-        self.current -= 1;
+    // 파일 전체의 토큰 목록을 만들지 않고, 현재 토큰만 보관하면서 파싱합니다.
+    // 다음 토큰은 토크나이저의 peek으로 확인합니다.
+    pub fn set_tokenizer(&mut self, tokenizer: Tokenizer<'a>) {
+        self.tokens.clear();
+        self.spans.clear();
+        self.current = 0;
+        self.tokenizer = Some(tokenizer);
+        self.fill_tokens();
     }
 
     fn next(&mut self) {
         self.current += 1;
+        self.fill_tokens();
+    }
+
+    // 토크나이저로 읽고 있다면 지나간 토큰을 버리고, 현재 토큰을 읽어 둡니다.
+    // 토큰을 다 읽은 뒤에도 current_span이 마지막 위치를 반환할 수 있도록 마지막 토큰 하나는 남깁니다.
+    fn fill_tokens(&mut self) {
+        let tokenizer = if let Some(tokenizer) = &mut self.tokenizer {
+            tokenizer
+        } else {
+            return;
+        };
+
+        let consumed = self.current.min(self.tokens.len().saturating_sub(1));
+        self.tokens.drain(..consumed);
+        self.spans.drain(..consumed);
+        self.current -= consumed;
+
+        while self.tokens.len() <= self.current {
+            match tokenizer.next() {
+                Some(Ok(token)) => {
+                    if Logger::enabled(Level::Trace) {
+                        Logger::trace("lex", format!("{} {:?}", token.span, token.token));
+                    }
+
+                    self.tokens.push(token.token);
+                    self.spans.push(token.span);
+                }
+                Some(Err(error)) => {
+                    self.lexer_error = Some(error);
+                    break;
+                }
+                None => break,
+            }
+        }
     }
 
-    fn get_current_token(&self) -> Option<Token> {
-        self.tokens.get(self.current).map(|e| e.to_owned())
+    // 토큰을 복제하지 않고 빌려서 반환합니다.
+    fn get_current_token(&self) -> Option<&Token> {
+        self.tokens.get(self.current)
     }
 
-    fn get_next_token(&self) -> Option<Token> {
-        self.tokens.get(self.current + 1).map(|e| e.to_owned())
+    fn get_next_token(&mut self) -> Option<&Token> {
+        self.get_token_after(1)
+    }
+
+    // 현재 토큰에서 offset개 뒤의 토큰을 반환합니다.
+    // 토크나이저로 읽고 있다면 뒤의 토큰은 아직 토크나이저에 있으므로 소비하지 않고 확인합니다.
+    // 그 토큰이 오류라면 None입니다. 오류는 그 토큰을 읽을 때 lexer_error로 남습니다.
+    fn get_token_after(&mut self, offset: usize) -> Option<&Token> {
+        let index = self.current + offset;
+
+        match &mut self.tokenizer {
+            Some(tokenizer) if index >= self.tokens.len() => {
+                match tokenizer.peek_n(index - self.tokens.len()) {
+                    Some(Ok(token)) => Some(&token.token),
+                    _ => None,
+                }
+            }
+            _ => self.tokens.get(index),
+        }
+    }
+
+    // 현재 위치의 주석을 모두 건너뜁니다.
+    fn skip_comments(&mut self) {
+        while let Some(Token::Primary(PrimaryToken::Comment(_))) = self.get_current_token() {
+            self.next();
+        }
+    }
+
+    // 현재 토큰의 위치입니다. 토큰을 다 읽었으면 마지막 토큰의 위치를 반환합니다.
+    fn current_span(&self) -> Span {
+        self.spans
+            .get(self.current)
+            .or_else(|| self.spans.last())
+            .copied()
+            .unwrap_or_default()
     }
 }
 
-impl Default for Parser {
+impl Default for Parser<'_> {
     fn default() -> Self {
         Self::new()
     }
 }
 
-impl Parser {
+impl Parser<'_> {
+    // 오류에 위치가 없으면 오류가 난 시점의 현재 토큰 위치를 붙입니다.
+    // 토크나이저가 오류를 반환했다면 그 뒤의 토큰은 없으므로, 파싱 결과와 상관없이 그 오류를 반환합니다.
     pub(crate) fn parse(&mut self) -> Result<Vec<Statement>, AllError> {
+        let result = self.parse_statements().map_err(|error| match error {
+            AllError::ParserError(error) if error.span.is_unknown() => {
+                error.with_span(self.current_span()).into()
+            }
+            error => error,
+        });
+
+        match self.lexer_error.take() {
+            Some(error) => Err(error),
+            None => result,
+        }
+    }
+
+    fn parse_statements(&mut self) -> Result<Vec<Statement>, AllError> {
         let mut statements = vec![];
 
         // top-level parser loop
         while let Some(current_token) = self.get_current_token() {
-            match current_token {
-                Token::Keyword(Keyword::Let | Keyword::Const) => {
-                    let statement = self.parse_declare_variable(self.context.clone())?;
-                    statements.push(statement);
-                }
-                Token::Primary(_) => {
-                    let statement = self.parse_expression(self.context.clone())?;
-                    statements.push(statement.into());
+            if let Token::Eof = current_token {
+                break;
+            }
+
+            if let Some(statement) = self.parse_statement()? {
+                statements.push(statement);
+            }
+        }
+
+        Ok(statements)
+    }
+
+    // statement 하나를 파싱합니다. 구분자(;)만 있었다면 None을 반환합니다.
+    pub(crate) fn parse_statement(&mut self) -> Result<Option<Statement>, AllError> {
+        let assignment = matches!(
+            self.get_next_token(),
+            Some(Token::Operator(operator)) if operator.is_assignment_operator()
+        );
+
+        let current_token = if let Some(token) = self.get_current_token() {
+            token
+        } else {
+            return Err(ParserError::new(11, "Unexpected end of tokens".to_string()).into());
+        };
+
+        match current_token {
+            Token::Keyword(Keyword::Let | Keyword::Const | Keyword::Mut) => {
+                let statement = self.parse_declare_variable(self.context.clone())?;
+                Ok(Some(statement))
+            }
+            Token::Keyword(Keyword::Fn) => {
+                let statement = self.parse_function_declaration(self.context.clone())?;
+                Ok(Some(statement))
+            }
+            Token::Keyword(Keyword::Struct) => {
+                let statement = self.parse_struct_declaration(self.context.clone())?;
+                Ok(Some(statement))
+            }
+            Token::Keyword(Keyword::Return) => {
+                let statement = self.parse_return_statement(self.context.clone())?;
+                Ok(Some(statement))
+            }
+            Token::Keyword(Keyword::If) => {
+                let statement = self.parse_if_statement(self.context.clone())?;
+                Ok(Some(statement))
+            }
+            Token::Keyword(Keyword::While) => {
+                let statement = self.parse_while_statement(self.context.clone())?;
+                Ok(Some(statement))
+            }
+            Token::Keyword(Keyword::Loop) => {
+                let statement = self.parse_loop_statement(self.context.clone())?;
+                Ok(Some(statement))
+            }
+            Token::Keyword(Keyword::For) => {
+                let statement = self.parse_for_statement(self.context.clone())?;
+                Ok(Some(statement))
+            }
+            Token::Keyword(Keyword::Break) => {
+                let span = self.current_span();
+                self.next();
+                Ok(Some(Statement::Break(span)))
+            }
+            Token::Keyword(Keyword::Continue) => {
+                let span = self.current_span();
+                self.next();
+                Ok(Some(Statement::Continue(span)))
+            }
+            Token::Primary(PrimaryToken::Identifier(_)) if assignment => {
+                let statement = self.parse_assign_statement(self.context.clone())?;
+                Ok(Some(statement))
+            }
+            Token::Primary(_) => {
+                let statement = self.parse_expression(self.context.clone())?;
+                Ok(Some(statement.into()))
+            }
+            Token::GeneralToken(GeneralToken::LeftParentheses)
+            | Token::Operator(_)
+            | Token::Keyword(Keyword::Match) => {
+                let statement = self.parse_expression(self.context.clone())?;
+                Ok(Some(statement.into()))
+            }
+            // statement 구분자
+            Token::GeneralToken(GeneralToken::SemiColon) => {
+                self.next();
+                Ok(None)
+            }
+            _ => Err(ParserError::new(
+                10,
+                format!(
+                    "Unexpected token at start of statement: {:?}",
+                    current_token
+                ),
+            )
+            .into()),
+        }
+    }
+
+    // { statement* } 형태의 블록을 파싱합니다. 닫는 중괄호까지 소비합니다.
+    pub(crate) fn parse_block(&mut self) -> Result<Vec<Statement>, AllError> {
+        match self.get_current_token() {
+            Some(Token::GeneralToken(GeneralToken::LeftBrace)) => self.next(),
+            Some(current_token) => {
+                return Err(ParserError::new(
+                    12,
+                    format!("Expected '{{', found {:?}", current_token),
+                )
+                .into());
+            }
+            None => {
+                return Err(ParserError::new(13, "Unexpected end of tokens".to_string()).into());
+            }
+        }
+
+        let mut statements = vec![];
+
+        loop {
+            match self.get_current_token() {
+                Some(Token::GeneralToken(GeneralToken::RightBrace)) => {
+                    self.next();
+                    break;
                 }
-                Token::GeneralToken(GeneralToken::LeftParentheses) => {
-                    let statement = self.parse_expression(self.context.clone())?;
-                    statements.push(statement.into());
+                Some(Token::Eof) | None => {
+                    return Err(ParserError::new(
+                        14,
+                        "Expected '}', found end of file".to_string(),
+                    )
+                    .into());
                 }
                 _ => {
-                    unimplemented!("not implemented yet")
+                    if let Some(statement) = self.parse_statement()? {
+                        statements.push(statement);
+                    }
                 }
             }
         }
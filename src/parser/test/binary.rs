@@ -2,18 +2,23 @@
 
 use crate::{
     ast::{
-        expression::{binary::BinaryExpression, literal::LiteralExpression, Expression},
-        operator::binary::BinaryOperator,
+        expression::{
+            binary::BinaryExpression, literal::LiteralExpression, unary::UnaryExpression,
+            variable::VariableExpression, Expression,
+        },
+        operator::{binary::BinaryOperator, unary::UnaryOperator},
+        statement::{assign::AssignStatement, define_variable::VariableDefinitionStatement},
     },
     lexer::tokenizer::Tokenizer,
     parser::Parser,
+    utils::testing::parse_without_spans,
 };
 
 #[test]
 pub fn add() {
     let text = r#"10 + 20"#.to_owned();
 
-    let tokens = Tokenizer::string_to_tokens(text).unwrap();
+    let tokens = Tokenizer::string_to_tokens(&text).unwrap();
 
     let mut parser = Parser::new();
     parser.set_tokens(tokens);
@@ -24,8 +29,9 @@ pub fn add() {
         statements,
         vec![Expression::Binary(BinaryExpression {
             operator: BinaryOperator::Add,
-            lhs: Box::new(Expression::Literal(LiteralExpression::Integer(10)).into()),
-            rhs: Box::new(Expression::Literal(LiteralExpression::Integer(20)).into()),
+            lhs: Box::new(Expression::from(LiteralExpression::Integer(10))),
+            rhs: Box::new(Expression::from(LiteralExpression::Integer(20))),
+            span: Default::default(),
         })
         .into()]
     );
@@ -35,7 +41,7 @@ pub fn add() {
 pub fn add_then_add() {
     let text = r#"10 + 20 + 30"#.to_owned();
 
-    let tokens = Tokenizer::string_to_tokens(text).unwrap();
+    let tokens = Tokenizer::string_to_tokens(&text).unwrap();
 
     let mut parser = Parser::new();
     parser.set_tokens(tokens);
@@ -48,11 +54,13 @@ pub fn add_then_add() {
             operator: BinaryOperator::Add,
             lhs: Expression::Binary(BinaryExpression {
                 operator: BinaryOperator::Add,
-                lhs: Box::new(Expression::Literal(LiteralExpression::Integer(10)).into()),
-                rhs: Box::new(Expression::Literal(LiteralExpression::Integer(20)).into()),
+                lhs: Box::new(Expression::from(LiteralExpression::Integer(10))),
+                rhs: Box::new(Expression::from(LiteralExpression::Integer(20))),
+                span: Default::default(),
             })
             .into(),
-            rhs: Box::new(Expression::Literal(LiteralExpression::Integer(30)).into()),
+            rhs: Box::new(Expression::from(LiteralExpression::Integer(30))),
+            span: Default::default(),
         })
         .into()]
     );
@@ -62,7 +70,7 @@ pub fn add_then_add() {
 pub fn add_then_multiply() {
     let text = r#"10 + 20 * 30"#.to_owned();
 
-    let tokens = Tokenizer::string_to_tokens(text).unwrap();
+    let tokens = Tokenizer::string_to_tokens(&text).unwrap();
 
     let mut parser = Parser::new();
     parser.set_tokens(tokens);
@@ -73,13 +81,15 @@ pub fn add_then_multiply() {
         statements,
         vec![Expression::Binary(BinaryExpression {
             operator: BinaryOperator::Add,
-            lhs: Box::new(Expression::Literal(LiteralExpression::Integer(10)).into()),
+            lhs: Box::new(Expression::from(LiteralExpression::Integer(10))),
             rhs: Expression::Binary(BinaryExpression {
                 operator: BinaryOperator::Multiply,
-                lhs: Box::new(Expression::Literal(LiteralExpression::Integer(20)).into()),
-                rhs: Box::new(Expression::Literal(LiteralExpression::Integer(30)).into()),
+                lhs: Box::new(Expression::from(LiteralExpression::Integer(20))),
+                rhs: Box::new(Expression::from(LiteralExpression::Integer(30))),
+                span: Default::default(),
             })
             .into(),
+            span: Default::default(),
         })
         .into()]
     );
@@ -89,7 +99,7 @@ pub fn add_then_multiply() {
 pub fn add_then_multiply_with_parenthese() {
     let text = r#"(10 + 20) * 30"#.to_owned();
 
-    let tokens = Tokenizer::string_to_tokens(text).unwrap();
+    let tokens = Tokenizer::string_to_tokens(&text).unwrap();
 
     let mut parser = Parser::new();
     parser.set_tokens(tokens);
@@ -102,11 +112,13 @@ pub fn add_then_multiply_with_parenthese() {
             operator: BinaryOperator::Multiply,
             lhs: Expression::Binary(BinaryExpression {
                 operator: BinaryOperator::Add,
-                lhs: Box::new(Expression::Literal(LiteralExpression::Integer(10)).into()),
-                rhs: Box::new(Expression::Literal(LiteralExpression::Integer(20)).into()),
+                lhs: Box::new(Expression::from(LiteralExpression::Integer(10))),
+                rhs: Box::new(Expression::from(LiteralExpression::Integer(20))),
+                span: Default::default(),
             })
             .into(),
-            rhs: Box::new(Expression::Literal(LiteralExpression::Integer(30)).into()),
+            rhs: Box::new(Expression::from(LiteralExpression::Integer(30))),
+            span: Default::default(),
         })
         .into()]
     );
@@ -116,7 +128,7 @@ pub fn add_then_multiply_with_parenthese() {
 pub fn add_then_multiply_with_parenthese_2() {
     let text = r#"30 * (10 + 20)"#.to_owned();
 
-    let tokens = Tokenizer::string_to_tokens(text).unwrap();
+    let tokens = Tokenizer::string_to_tokens(&text).unwrap();
 
     let mut parser = Parser::new();
     parser.set_tokens(tokens);
@@ -127,14 +139,286 @@ pub fn add_then_multiply_with_parenthese_2() {
         statements,
         vec![Expression::Binary(BinaryExpression {
             operator: BinaryOperator::Multiply,
-            lhs: Box::new(Expression::Literal(LiteralExpression::Integer(30)).into()),
+            lhs: Box::new(Expression::from(LiteralExpression::Integer(30))),
             rhs: Expression::Binary(BinaryExpression {
                 operator: BinaryOperator::Add,
-                lhs: Box::new(Expression::Literal(LiteralExpression::Integer(10)).into()),
-                rhs: Box::new(Expression::Literal(LiteralExpression::Integer(20)).into()),
+                lhs: Box::new(Expression::from(LiteralExpression::Integer(10))),
+                rhs: Box::new(Expression::from(LiteralExpression::Integer(20))),
+                span: Default::default(),
             })
             .into(),
+            span: Default::default(),
         })
         .into()]
     );
 }
+
+#[test]
+pub fn bitwise_operators() {
+    for (text, operator) in [
+        ("10 & 20", BinaryOperator::BitwiseAnd),
+        ("10 | 20", BinaryOperator::BitwiseOr),
+        ("10 ^ 20", BinaryOperator::BitwiseXor),
+        ("10 << 20", BinaryOperator::LeftShift),
+        ("10 >> 20", BinaryOperator::RightShift),
+    ] {
+        let tokens = Tokenizer::string_to_tokens(text).unwrap();
+
+        let mut parser = Parser::new();
+        parser.set_tokens(tokens);
+
+        let statements = parser.parse().unwrap();
+
+        assert_eq!(
+            statements,
+            vec![Expression::Binary(BinaryExpression {
+                operator,
+                lhs: Box::new(Expression::from(LiteralExpression::Integer(10))),
+                rhs: Box::new(Expression::from(LiteralExpression::Integer(20))),
+                span: Default::default(),
+            })
+            .into()]
+        );
+    }
+}
+
+const OPERATORS: [(&str, BinaryOperator); 18] = [
+    ("||", BinaryOperator::Or),
+    ("&&", BinaryOperator::And),
+    ("==", BinaryOperator::Equal),
+    ("!=", BinaryOperator::NotEqual),
+    ("<", BinaryOperator::LessThan),
+    ("<=", BinaryOperator::LessThanOrEqual),
+    (">", BinaryOperator::GreaterThan),
+    (">=", BinaryOperator::GreaterThanOrEqual),
+    ("|", BinaryOperator::BitwiseOr),
+    ("^", BinaryOperator::BitwiseXor),
+    ("&", BinaryOperator::BitwiseAnd),
+    ("<<", BinaryOperator::LeftShift),
+    (">>", BinaryOperator::RightShift),
+    ("+", BinaryOperator::Add),
+    ("-", BinaryOperator::Subtract),
+    ("*", BinaryOperator::Multiply),
+    ("/", BinaryOperator::Divide),
+    ("%", BinaryOperator::Modulo),
+];
+
+fn variable(name: &str) -> Expression {
+    VariableExpression {
+        name: name.into(),
+        span: Default::default(),
+    }
+    .into()
+}
+
+fn binary(lhs: Expression, operator: BinaryOperator, rhs: Expression) -> Expression {
+    BinaryExpression {
+        lhs: Box::new(lhs),
+        rhs: Box::new(rhs),
+        operator,
+        span: Default::default(),
+    }
+    .into()
+}
+
+// 모든 연산자 쌍에 대해 a op1 b op2 c가 우선순위 표대로 묶이는지 확인합니다.
+#[test]
+pub fn precedence_and_associativity() {
+    for (first_text, first) in OPERATORS.iter() {
+        for (second_text, second) in OPERATORS.iter() {
+            let source = format!("a {} b {} c", first_text, second_text);
+
+            let expected = if second.get_precedence() > first.get_precedence() {
+                binary(
+                    variable("a"),
+                    first.clone(),
+                    binary(variable("b"), second.clone(), variable("c")),
+                )
+            } else {
+                binary(
+                    binary(variable("a"), first.clone(), variable("b")),
+                    second.clone(),
+                    variable("c"),
+                )
+            };
+
+            assert_eq!(
+                parse_without_spans(&source).unwrap(),
+                vec![expected.into()],
+                "{}",
+                source
+            );
+        }
+    }
+}
+
+#[test]
+pub fn precedence_levels() {
+    let levels = [
+        BinaryOperator::Or,
+        BinaryOperator::And,
+        BinaryOperator::Equal,
+        BinaryOperator::BitwiseOr,
+        BinaryOperator::BitwiseXor,
+        BinaryOperator::BitwiseAnd,
+        BinaryOperator::LeftShift,
+        BinaryOperator::Add,
+        BinaryOperator::Multiply,
+    ];
+
+    for pair in levels.windows(2) {
+        assert!(pair[0].get_precedence() < pair[1].get_precedence());
+    }
+
+    // 여러 단계가 섞여도 높은 우선순위부터 묶입니다.
+    assert_eq!(
+        parse_without_spans("a || b && c == d + e * f").unwrap(),
+        vec![binary(
+            variable("a"),
+            BinaryOperator::Or,
+            binary(
+                variable("b"),
+                BinaryOperator::And,
+                binary(
+                    variable("c"),
+                    BinaryOperator::Equal,
+                    binary(
+                        variable("d"),
+                        BinaryOperator::Add,
+                        binary(variable("e"), BinaryOperator::Multiply, variable("f")),
+                    ),
+                ),
+            ),
+        )
+        .into()]
+    );
+    assert_eq!(
+        parse_without_spans("a * b + c == d && e || f").unwrap(),
+        vec![binary(
+            binary(
+                binary(
+                    binary(
+                        binary(variable("a"), BinaryOperator::Multiply, variable("b")),
+                        BinaryOperator::Add,
+                        variable("c"),
+                    ),
+                    BinaryOperator::Equal,
+                    variable("d"),
+                ),
+                BinaryOperator::And,
+                variable("e"),
+            ),
+            BinaryOperator::Or,
+            variable("f"),
+        )
+        .into()]
+    );
+}
+
+#[test]
+pub fn unary_binds_tighter() {
+    let negate = UnaryExpression {
+        operator: UnaryOperator::Minus,
+        operand: Box::new(variable("a")),
+        span: Default::default(),
+    };
+
+    assert_eq!(
+        parse_without_spans("-a * b").unwrap(),
+        vec![binary(negate.into(), BinaryOperator::Multiply, variable("b")).into()]
+    );
+
+    let not = UnaryExpression {
+        operator: UnaryOperator::Not,
+        operand: Box::new(variable("b")),
+        span: Default::default(),
+    };
+
+    assert_eq!(
+        parse_without_spans("a && !b").unwrap(),
+        vec![binary(variable("a"), BinaryOperator::And, not.into()).into()]
+    );
+}
+
+#[test]
+pub fn parentheses_override_precedence() {
+    assert_eq!(
+        parse_without_spans("a * (b + c) - d").unwrap(),
+        vec![binary(
+            binary(
+                variable("a"),
+                BinaryOperator::Multiply,
+                binary(variable("b"), BinaryOperator::Add, variable("c")),
+            ),
+            BinaryOperator::Subtract,
+            variable("d"),
+        )
+        .into()]
+    );
+}
+
+// 대입은 어떤 이항 연산자보다도 나중에 묶입니다.
+#[test]
+pub fn assignment_is_lowest() {
+    assert_eq!(
+        parse_without_spans("x = a || b && c").unwrap(),
+        vec![AssignStatement {
+            name: "x".into(),
+            operator: None,
+            value: binary(
+                variable("a"),
+                BinaryOperator::Or,
+                binary(variable("b"), BinaryOperator::And, variable("c")),
+            ),
+            span: Default::default(),
+        }
+        .into()]
+    );
+
+    assert!(parse_without_spans("x = a = b").is_err());
+    assert!(parse_without_spans("1 +").is_err());
+}
+
+// 연산자 앞뒤의 주석은 식을 끝내지 않습니다. 식 뒤의 주석은 주석 statement로 남습니다.
+#[test]
+pub fn comments_between_operands() {
+    let text = "let x = 1 /* a */ + // b\n 2 * /* c */ 3 // d\nx";
+
+    let sum = Expression::Binary(BinaryExpression {
+        operator: BinaryOperator::Add,
+        lhs: Box::new(Expression::from(LiteralExpression::Integer(1))),
+        rhs: Box::new(Expression::Binary(BinaryExpression {
+            operator: BinaryOperator::Multiply,
+            lhs: Box::new(Expression::from(LiteralExpression::Integer(2))),
+            rhs: Box::new(Expression::from(LiteralExpression::Integer(3))),
+            span: Default::default(),
+        })),
+        span: Default::default(),
+    });
+    let expected = vec![
+        VariableDefinitionStatement {
+            mutable: false,
+            name: "x".into(),
+            value: Some(sum),
+            type_name: None,
+            span: Default::default(),
+        }
+        .into(),
+        Expression::Comment(" d".into(), Default::default()).into(),
+        Expression::from(VariableExpression {
+            name: "x".into(),
+            span: Default::default(),
+        })
+        .into(),
+    ];
+
+    // 토크나이저로 읽을 때는 연산자를 찾으려고 주석 너머를 미리 봅니다.
+    assert_eq!(parse_without_spans(text).unwrap(), expected);
+
+    let mut parser = Parser::new();
+    parser.set_tokens(Tokenizer::string_to_tokens(text).unwrap());
+    let mut statements = parser.parse().unwrap();
+    crate::ast::statement::clear_spans(&mut statements);
+
+    assert_eq!(statements, expected);
+}
@@ -0,0 +1,95 @@
+#![cfg(test)]
+
+use crate::{
+    ast::{
+        expression::{
+            binary::BinaryExpression, cast::CastExpression, field::FieldExpression,
+            parentheses::ParenthesesExpression, unary::UnaryExpression,
+            variable::VariableExpression, Expression,
+        },
+        operator::{binary::BinaryOperator, unary::UnaryOperator},
+        statement::Statement,
+    },
+    utils::testing::parse_without_spans,
+};
+
+fn variable(name: &str) -> Expression {
+    VariableExpression {
+        name: name.into(),
+        span: Default::default(),
+    }
+    .into()
+}
+
+fn cast(value: Expression, type_name: &str) -> Expression {
+    CastExpression {
+        value: Box::new(value),
+        type_name: type_name.into(),
+        span: Default::default(),
+    }
+    .into()
+}
+
+// as는 단항 연산자와 필드 접근보다 나중에, 이항 연산자보다 먼저 묶입니다.
+#[test]
+pub fn cast_precedence() {
+    let statements = parse_without_spans("-a as f64 * p.x as f64 as i32").unwrap();
+
+    assert_eq!(
+        statements,
+        vec![Statement::Expression(
+            BinaryExpression {
+                lhs: Box::new(cast(
+                    UnaryExpression {
+                        operator: UnaryOperator::Minus,
+                        operand: Box::new(variable("a")),
+                        span: Default::default(),
+                    }
+                    .into(),
+                    "f64"
+                )),
+                rhs: Box::new(cast(
+                    cast(
+                        FieldExpression {
+                            object: Box::new(variable("p")),
+                            field: "x".into(),
+                            span: Default::default(),
+                        }
+                        .into(),
+                        "f64"
+                    ),
+                    "i32"
+                )),
+                operator: BinaryOperator::Multiply,
+                span: Default::default(),
+            }
+            .into()
+        )]
+    );
+
+    assert_eq!(
+        parse_without_spans("(a + b) as i32").unwrap(),
+        vec![Statement::Expression(cast(
+            ParenthesesExpression {
+                expression: Box::new(
+                    BinaryExpression {
+                        lhs: Box::new(variable("a")),
+                        rhs: Box::new(variable("b")),
+                        operator: BinaryOperator::Add,
+                        span: Default::default(),
+                    }
+                    .into()
+                ),
+            }
+            .into(),
+            "i32"
+        ))]
+    );
+}
+
+#[test]
+pub fn cast_errors() {
+    for source in ["a as", "a as 1", "a as (i32)", "as i32"] {
+        assert!(parse_without_spans(source).is_err(), "{}", source);
+    }
+}
@@ -2,19 +2,23 @@
 
 use crate::{
     ast::{
-        expression::{binary::BinaryExpression, literal::LiteralExpression, Expression},
-        operator::binary::BinaryOperator,
+        expression::{
+            binary::BinaryExpression, literal::LiteralExpression, unary::UnaryExpression,
+            Expression,
+        },
+        operator::{binary::BinaryOperator, unary::UnaryOperator},
         statement::define_variable::VariableDefinitionStatement,
     },
     lexer::tokenizer::Tokenizer,
     parser::Parser,
+    utils::testing::parse_without_spans,
 };
 
 #[test]
 pub fn declare_let_variable() {
     let text = r#"let foo = 10"#.to_owned();
 
-    let tokens = Tokenizer::string_to_tokens(text).unwrap();
+    let tokens = Tokenizer::string_to_tokens(&text).unwrap();
 
     let mut parser = Parser::new();
     parser.set_tokens(tokens);
@@ -24,9 +28,11 @@ pub fn declare_let_variable() {
     assert_eq!(
         statements,
         vec![VariableDefinitionStatement {
-            name: "foo".to_owned(),
-            value: Expression::Literal(LiteralExpression::Integer(10)).into(),
-            mutable: false
+            name: "foo".into(),
+            type_name: None,
+            value: Expression::from(LiteralExpression::Integer(10)).into(),
+            mutable: false,
+            span: Default::default()
         }
         .into()]
     );
@@ -36,7 +42,7 @@ pub fn declare_let_variable() {
 pub fn declare_let_variable_assign_binary() {
     let text = r#"let foo = 10 + 20"#.to_owned();
 
-    let tokens = Tokenizer::string_to_tokens(text).unwrap();
+    let tokens = Tokenizer::string_to_tokens(&text).unwrap();
 
     let mut parser = Parser::new();
     parser.set_tokens(tokens);
@@ -46,15 +52,68 @@ pub fn declare_let_variable_assign_binary() {
     assert_eq!(
         statements,
         vec![VariableDefinitionStatement {
-            name: "foo".to_owned(),
+            name: "foo".into(),
+            type_name: None,
             value: Expression::Binary(BinaryExpression {
                 operator: BinaryOperator::Add,
-                lhs: Box::new(Expression::Literal(LiteralExpression::Integer(10)).into()),
-                rhs: Box::new(Expression::Literal(LiteralExpression::Integer(20)).into()),
+                lhs: Box::new(Expression::from(LiteralExpression::Integer(10))),
+                rhs: Box::new(Expression::from(LiteralExpression::Integer(20))),
+                span: Default::default(),
             })
             .into(),
-            mutable: false
+            mutable: false,
+            span: Default::default()
         }
         .into()]
     );
 }
+
+#[test]
+pub fn declare_let_variable_negative() {
+    let text = r#"let foo = -5;"#.to_owned();
+
+    let tokens = Tokenizer::string_to_tokens(&text).unwrap();
+
+    let mut parser = Parser::new();
+    parser.set_tokens(tokens);
+
+    let statements = parser.parse().unwrap();
+
+    assert_eq!(
+        statements,
+        vec![VariableDefinitionStatement {
+            name: "foo".into(),
+            type_name: None,
+            value: Expression::Unary(UnaryExpression {
+                operator: UnaryOperator::Minus,
+                operand: Box::new(LiteralExpression::Integer(5).into()),
+                span: Default::default(),
+            })
+            .into(),
+            mutable: false,
+            span: Default::default()
+        }
+        .into()]
+    );
+}
+
+#[test]
+pub fn declare_let_variable_with_type() {
+    let statements = parse_without_spans("mut foo: i32 = 1").unwrap();
+
+    assert_eq!(
+        statements,
+        vec![VariableDefinitionStatement {
+            name: "foo".into(),
+            type_name: Some("i32".into()),
+            value: Some(LiteralExpression::Integer(1).into()),
+            mutable: true,
+            span: Default::default()
+        }
+        .into()]
+    );
+
+    for source in ["let foo: = 1", "let foo: 1 = 1", "let foo:"] {
+        assert!(parse_without_spans(source).is_err(), "{}", source);
+    }
+}
@@ -0,0 +1,77 @@
+#![cfg(test)]
+
+use crate::{
+    ast::{
+        expression::{binary::BinaryExpression, variable::VariableExpression, Expression},
+        operator::binary::BinaryOperator,
+        statement::{define_function::FunctionDefinitionStatement, Statement},
+    },
+    utils::testing::parse_without_spans,
+};
+
+fn variable(name: &str) -> Expression {
+    VariableExpression {
+        name: name.into(),
+        span: Default::default(),
+    }
+    .into()
+}
+
+#[test]
+pub fn function_with_return_type() {
+    let statements = parse_without_spans("fn add(a, b) -> i64 {\n    return a + b\n}").unwrap();
+
+    assert_eq!(
+        statements,
+        vec![FunctionDefinitionStatement {
+            name: "add".into(),
+            parameters: vec!["a".into(), "b".into()],
+            return_type: Some("i64".into()),
+            body: vec![Statement::Return(
+                BinaryExpression {
+                    lhs: Box::new(variable("a")),
+                    rhs: Box::new(variable("b")),
+                    operator: BinaryOperator::Add,
+                    span: Default::default(),
+                }
+                .into(),
+                Default::default()
+            )],
+            span: Default::default(),
+        }
+        .into()]
+    );
+}
+
+#[test]
+pub fn function_without_return_type() {
+    let statements = parse_without_spans("fn main() { return x }").unwrap();
+
+    assert_eq!(
+        statements,
+        vec![FunctionDefinitionStatement {
+            name: "main".into(),
+            parameters: vec![],
+            return_type: None,
+            body: vec![Statement::Return(variable("x"), Default::default())],
+            span: Default::default(),
+        }
+        .into()]
+    );
+}
+
+#[test]
+pub fn invalid_function_declaration() {
+    for source in [
+        "fn { }",
+        "fn foo { }",
+        "fn foo(a b) { }",
+        "fn foo(1) { }",
+        "fn foo(a,",
+        "fn foo() -> { }",
+        "fn foo()",
+        "return",
+    ] {
+        assert!(parse_without_spans(source).is_err(), "{}", source);
+    }
+}
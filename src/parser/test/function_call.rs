@@ -4,13 +4,14 @@ use crate::{
     ast::expression::{call::CallExpression, literal::LiteralExpression, Expression},
     lexer::tokenizer::Tokenizer,
     parser::Parser,
+    utils::testing::parse_without_spans,
 };
 
 #[test]
 pub fn function_call_no_arguments() {
     let text = r#"foo()"#.to_owned();
 
-    let tokens = Tokenizer::string_to_tokens(text).unwrap();
+    let tokens = Tokenizer::string_to_tokens(&text).unwrap();
 
     let mut parser = Parser::new();
     parser.set_tokens(tokens);
@@ -20,8 +21,9 @@ pub fn function_call_no_arguments() {
     assert_eq!(
         statements,
         vec![Expression::Call(CallExpression {
-            function_name: "foo".to_owned(),
+            function_name: "foo".into(),
             arguments: vec![],
+            span: Default::default(),
         })
         .into()]
     );
@@ -31,7 +33,7 @@ pub fn function_call_no_arguments() {
 pub fn function_call_one_arguments() {
     let text = r#"foo(10)"#.to_owned();
 
-    let tokens = Tokenizer::string_to_tokens(text).unwrap();
+    let tokens = Tokenizer::string_to_tokens(&text).unwrap();
 
     let mut parser = Parser::new();
     parser.set_tokens(tokens);
@@ -41,8 +43,9 @@ pub fn function_call_one_arguments() {
     assert_eq!(
         statements,
         vec![Expression::Call(CallExpression {
-            function_name: "foo".to_owned(),
+            function_name: "foo".into(),
             arguments: vec![LiteralExpression::Integer(10).into()],
+            span: Default::default(),
         })
         .into()]
     );
@@ -52,7 +55,7 @@ pub fn function_call_one_arguments() {
 pub fn function_call_two_arguments() {
     let text = r#"foo(10, 20)"#.to_owned();
 
-    let tokens = Tokenizer::string_to_tokens(text).unwrap();
+    let tokens = Tokenizer::string_to_tokens(&text).unwrap();
 
     let mut parser = Parser::new();
     parser.set_tokens(tokens);
@@ -62,11 +65,32 @@ pub fn function_call_two_arguments() {
     assert_eq!(
         statements,
         vec![Expression::Call(CallExpression {
-            function_name: "foo".to_owned(),
+            function_name: "foo".into(),
             arguments: vec![
                 LiteralExpression::Integer(10).into(),
                 LiteralExpression::Integer(20).into()
             ],
+            span: Default::default(),
+        })
+        .into()]
+    );
+}
+
+// 인자 사이의 주석은 인자가 아닙니다.
+#[test]
+pub fn comments_between_arguments() {
+    let statements = parse_without_spans("foo(1, // a\n2 /* b */, /* c */ 3)").unwrap();
+
+    assert_eq!(
+        statements,
+        vec![Expression::Call(CallExpression {
+            function_name: "foo".into(),
+            arguments: vec![
+                LiteralExpression::Integer(1).into(),
+                LiteralExpression::Integer(2).into(),
+                LiteralExpression::Integer(3).into(),
+            ],
+            span: Default::default(),
         })
         .into()]
     );
@@ -0,0 +1,95 @@
+#![cfg(test)]
+
+use crate::{
+    ast::{
+        expression::{
+            binary::BinaryExpression, call::CallExpression, literal::LiteralExpression,
+            variable::VariableExpression, Expression,
+        },
+        operator::binary::BinaryOperator,
+        statement::{if_statement::IfStatement, Statement},
+    },
+    error::all_error::AllError,
+    utils::testing::parse_without_spans,
+};
+
+fn variable(name: &str) -> Expression {
+    VariableExpression {
+        name: name.into(),
+        span: Default::default(),
+    }
+    .into()
+}
+
+fn println(value: i64) -> Statement {
+    Expression::from(CallExpression {
+        function_name: "println".into(),
+        arguments: vec![LiteralExpression::Integer(value).into()],
+        span: Default::default(),
+    })
+    .into()
+}
+
+#[test]
+pub fn if_else_if_else() {
+    let statements = parse_without_spans(concat!(
+        "if a < 10 {\n",
+        "    println(1)\n",
+        "} else if b {\n",
+        "    println(2); println(3)\n",
+        "} else {\n",
+        "}\n",
+    ))
+    .unwrap();
+
+    assert_eq!(
+        statements,
+        vec![IfStatement {
+            condition: BinaryExpression {
+                lhs: Box::new(variable("a")),
+                rhs: Box::new(LiteralExpression::Integer(10).into()),
+                operator: BinaryOperator::LessThan,
+                span: Default::default(),
+            }
+            .into(),
+            then_body: vec![println(1)],
+            else_body: Some(vec![IfStatement {
+                condition: variable("b"),
+                then_body: vec![println(2), println(3)],
+                else_body: Some(vec![]),
+            }
+            .into()]),
+        }
+        .into()]
+    );
+}
+
+#[test]
+pub fn if_without_else() {
+    let statements = parse_without_spans("if true { println(1) }\nprintln(2)").unwrap();
+
+    assert_eq!(
+        statements,
+        vec![
+            IfStatement {
+                condition: LiteralExpression::Boolean(true).into(),
+                then_body: vec![println(1)],
+                else_body: None,
+            }
+            .into(),
+            println(2),
+        ]
+    );
+}
+
+#[test]
+pub fn unclosed_block() {
+    assert!(matches!(
+        parse_without_spans("if true { println(1)"),
+        Err(AllError::ParserError(_))
+    ));
+    assert!(matches!(
+        parse_without_spans("if true println(1)"),
+        Err(AllError::ParserError(_))
+    ));
+}
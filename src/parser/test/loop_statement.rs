@@ -0,0 +1,135 @@
+#![cfg(test)]
+
+use crate::{
+    ast::{
+        expression::{
+            binary::BinaryExpression, literal::LiteralExpression, variable::VariableExpression,
+            Expression,
+        },
+        operator::binary::BinaryOperator,
+        statement::{
+            assign::AssignStatement, define_variable::VariableDefinitionStatement,
+            for_statement::ForStatement, loop_statement::LoopStatement,
+            while_statement::WhileStatement, Statement,
+        },
+    },
+    utils::testing::parse_without_spans,
+};
+
+fn variable(name: &str) -> Expression {
+    VariableExpression {
+        name: name.into(),
+        span: Default::default(),
+    }
+    .into()
+}
+
+fn binary(lhs: Expression, operator: BinaryOperator, rhs: i64) -> Expression {
+    BinaryExpression {
+        lhs: Box::new(lhs),
+        rhs: Box::new(LiteralExpression::Integer(rhs).into()),
+        operator,
+        span: Default::default(),
+    }
+    .into()
+}
+
+#[test]
+pub fn while_with_assignment() {
+    let statements = parse_without_spans("mut i = 3\nwhile i > 0 {\n    i = i - 1\n}").unwrap();
+
+    assert_eq!(
+        statements,
+        vec![
+            VariableDefinitionStatement {
+                mutable: true,
+                name: "i".into(),
+                value: Some(LiteralExpression::Integer(3).into()),
+                type_name: None,
+                span: Default::default(),
+            }
+            .into(),
+            WhileStatement {
+                condition: binary(variable("i"), BinaryOperator::GreaterThan, 0),
+                body: vec![AssignStatement {
+                    name: "i".into(),
+                    operator: None,
+                    value: binary(variable("i"), BinaryOperator::Subtract, 1),
+                    span: Default::default(),
+                }
+                .into()],
+            }
+            .into(),
+        ]
+    );
+}
+
+#[test]
+pub fn loop_with_break_and_continue() {
+    let statements = parse_without_spans("loop { continue; break }").unwrap();
+
+    assert_eq!(
+        statements,
+        vec![LoopStatement {
+            body: vec![
+                Statement::Continue(Default::default()),
+                Statement::Break(Default::default())
+            ],
+            span: Default::default(),
+        }
+        .into()]
+    );
+}
+
+#[test]
+pub fn for_in_range() {
+    let statements = parse_without_spans("for i in 0..n + 1 { continue }").unwrap();
+
+    assert_eq!(
+        statements,
+        vec![ForStatement {
+            variable: "i".into(),
+            start: LiteralExpression::Integer(0).into(),
+            end: binary(variable("n"), BinaryOperator::Add, 1),
+            body: vec![Statement::Continue(Default::default())],
+            span: Default::default(),
+        }
+        .into()]
+    );
+}
+
+#[test]
+pub fn for_without_range() {
+    for source in ["for i 0..3 { }", "for i in 0 { }", "for 1 in 0..3 { }"] {
+        assert!(parse_without_spans(source).is_err(), "{}", source);
+    }
+}
+
+#[test]
+pub fn compound_assignment() {
+    for (source, operator) in [
+        ("i += 2", BinaryOperator::Add),
+        ("i -= 2", BinaryOperator::Subtract),
+        ("i *= 2", BinaryOperator::Multiply),
+        ("i /= 2", BinaryOperator::Divide),
+        ("i %= 2", BinaryOperator::Modulo),
+        ("i &= 2", BinaryOperator::BitwiseAnd),
+        ("i |= 2", BinaryOperator::BitwiseOr),
+        ("i ^= 2", BinaryOperator::BitwiseXor),
+        ("i <<= 2", BinaryOperator::LeftShift),
+        ("i >>= 2", BinaryOperator::RightShift),
+    ] {
+        assert_eq!(
+            parse_without_spans(source).unwrap(),
+            vec![AssignStatement {
+                name: "i".into(),
+                operator: Some(operator),
+                value: LiteralExpression::Integer(2).into(),
+                span: Default::default(),
+            }
+            .into()],
+            "{}",
+            source
+        );
+    }
+}
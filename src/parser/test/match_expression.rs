@@ -0,0 +1,132 @@
+#![cfg(test)]
+
+use crate::{
+    ast::{
+        expression::{
+            binary::BinaryExpression,
+            call::CallExpression,
+            literal::LiteralExpression,
+            match_expression::{MatchArm, MatchExpression, Pattern},
+            variable::VariableExpression,
+            Expression,
+        },
+        operator::binary::BinaryOperator,
+        statement::define_variable::VariableDefinitionStatement,
+    },
+    utils::testing::parse_without_spans,
+};
+
+fn variable(name: &str) -> Expression {
+    VariableExpression {
+        name: name.into(),
+        span: Default::default(),
+    }
+    .into()
+}
+
+fn arm(pattern: Pattern, body: Expression) -> MatchArm {
+    MatchArm {
+        pattern,
+        body,
+        span: Default::default(),
+    }
+}
+
+fn integer(value: i64) -> Expression {
+    LiteralExpression::Integer(value).into()
+}
+
+#[test]
+pub fn match_statement() {
+    let statements = parse_without_spans(concat!(
+        "match x {\n",
+        "    1 => println(a),\n",
+        "    -2 => println(b)\n",
+        "    _ => c\n",
+        "}",
+    ))
+    .unwrap();
+
+    let println = |name: &str| -> Expression {
+        CallExpression {
+            function_name: "println".into(),
+            arguments: vec![variable(name)],
+            span: Default::default(),
+        }
+        .into()
+    };
+
+    assert_eq!(
+        statements,
+        vec![Expression::from(MatchExpression {
+            subject: Box::new(variable("x")),
+            arms: vec![
+                arm(
+                    Pattern::Literal(LiteralExpression::Integer(1)),
+                    println("a")
+                ),
+                arm(
+                    Pattern::Literal(LiteralExpression::Integer(-2)),
+                    println("b")
+                ),
+                arm(Pattern::Wildcard, variable("c")),
+            ],
+            span: Default::default(),
+        })
+        .into()]
+    );
+}
+
+#[test]
+pub fn match_as_value() {
+    let statements = parse_without_spans("let y = match x > 1 { true => 1, false => 0 }").unwrap();
+
+    assert_eq!(
+        statements,
+        vec![VariableDefinitionStatement {
+            mutable: false,
+            name: "y".into(),
+            type_name: None,
+            value: Some(
+                MatchExpression {
+                    subject: Box::new(
+                        BinaryExpression {
+                            lhs: Box::new(variable("x")),
+                            rhs: Box::new(integer(1)),
+                            operator: BinaryOperator::GreaterThan,
+                            span: Default::default(),
+                        }
+                        .into()
+                    ),
+                    arms: vec![
+                        arm(
+                            Pattern::Literal(LiteralExpression::Boolean(true)),
+                            integer(1)
+                        ),
+                        arm(
+                            Pattern::Literal(LiteralExpression::Boolean(false)),
+                            integer(0)
+                        ),
+                    ],
+                    span: Default::default(),
+                }
+                .into()
+            ),
+            span: Default::default(),
+        }
+        .into()]
+    );
+}
+
+#[test]
+pub fn invalid_match() {
+    for source in [
+        "match x 1 => 2",
+        "match x { 1 2 }",
+        "match x { y => 2 }",
+        "match x { 1 => 2",
+        "match x { 1 => }",
+    ] {
+        assert!(parse_without_spans(source).is_err(), "{}", source);
+    }
+}
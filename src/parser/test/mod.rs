@@ -1,4 +1,11 @@
 pub(crate) mod binary;
+pub(crate) mod cast;
 pub(crate) mod declare;
+pub(crate) mod function;
 pub(crate) mod function_call;
+pub(crate) mod if_statement;
+pub(crate) mod loop_statement;
+pub(crate) mod match_expression;
 pub(crate) mod primary;
+pub(crate) mod statement;
+pub(crate) mod struct_declaration;
@@ -10,7 +10,7 @@ use crate::{
 pub fn integer() {
     let text = r#"123234"#.to_owned();
 
-    let tokens = Tokenizer::string_to_tokens(text).unwrap();
+    let tokens = Tokenizer::string_to_tokens(&text).unwrap();
 
     let mut parser = Parser::new();
     parser.set_tokens(tokens);
@@ -19,7 +19,7 @@ pub fn integer() {
 
     assert_eq!(
         statements,
-        vec![Expression::Literal(LiteralExpression::Integer(123234)).into()]
+        vec![Expression::from(LiteralExpression::Integer(123234)).into()]
     );
 }
 
@@ -27,7 +27,7 @@ pub fn integer() {
 pub fn float() {
     let text = r#"123.234"#.to_owned();
 
-    let tokens = Tokenizer::string_to_tokens(text).unwrap();
+    let tokens = Tokenizer::string_to_tokens(&text).unwrap();
 
     let mut parser = Parser::new();
     parser.set_tokens(tokens);
@@ -36,7 +36,7 @@ pub fn float() {
 
     assert_eq!(
         statements,
-        vec![Expression::Literal(LiteralExpression::Float(123.234)).into()]
+        vec![Expression::from(LiteralExpression::Float(123.234)).into()]
     );
 }
 
@@ -44,7 +44,7 @@ pub fn float() {
 pub fn string() {
     let text = r#""123.234""#.to_owned();
 
-    let tokens = Tokenizer::string_to_tokens(text).unwrap();
+    let tokens = Tokenizer::string_to_tokens(&text).unwrap();
 
     let mut parser = Parser::new();
     parser.set_tokens(tokens);
@@ -53,7 +53,7 @@ pub fn string() {
 
     assert_eq!(
         statements,
-        vec![Expression::Literal(LiteralExpression::String("123.234".to_owned())).into()]
+        vec![Expression::from(LiteralExpression::String("123.234".into())).into()]
     );
 }
 
@@ -61,7 +61,7 @@ pub fn string() {
 pub fn boolean_true() {
     let text = r#"true"#.to_owned();
 
-    let tokens = Tokenizer::string_to_tokens(text).unwrap();
+    let tokens = Tokenizer::string_to_tokens(&text).unwrap();
 
     let mut parser = Parser::new();
     parser.set_tokens(tokens);
@@ -70,7 +70,7 @@ pub fn boolean_true() {
 
     assert_eq!(
         statements,
-        vec![Expression::Literal(LiteralExpression::Boolean(true)).into()]
+        vec![Expression::from(LiteralExpression::Boolean(true)).into()]
     );
 }
 
@@ -78,7 +78,7 @@ pub fn boolean_true() {
 pub fn boolean_false() {
     let text = r#"false"#.to_owned();
 
-    let tokens = Tokenizer::string_to_tokens(text).unwrap();
+    let tokens = Tokenizer::string_to_tokens(&text).unwrap();
 
     let mut parser = Parser::new();
     parser.set_tokens(tokens);
@@ -87,7 +87,7 @@ pub fn boolean_false() {
 
     assert_eq!(
         statements,
-        vec![Expression::Literal(LiteralExpression::Boolean(false)).into()]
+        vec![Expression::from(LiteralExpression::Boolean(false)).into()]
     );
 }
 
@@ -95,7 +95,7 @@ pub fn boolean_false() {
 pub fn variable() {
     let text = r#"a"#.to_owned();
 
-    let tokens = Tokenizer::string_to_tokens(text).unwrap();
+    let tokens = Tokenizer::string_to_tokens(&text).unwrap();
 
     let mut parser = Parser::new();
     parser.set_tokens(tokens);
@@ -104,6 +104,10 @@ pub fn variable() {
 
     assert_eq!(
         statements,
-        vec![Expression::Variable(VariableExpression { name: "a".into() }).into()]
+        vec![Expression::Variable(VariableExpression {
+            name: "a".into(),
+            span: Default::default()
+        })
+        .into()]
     );
 }
@@ -0,0 +1,157 @@
+#![cfg(test)]
+
+use crate::{
+    ast::{
+        expression::{
+            binary::BinaryExpression, call::CallExpression, literal::LiteralExpression,
+            unary::UnaryExpression, variable::VariableExpression, Expression,
+        },
+        operator::{binary::BinaryOperator, unary::UnaryOperator},
+        statement::{clear_spans, define_variable::VariableDefinitionStatement},
+    },
+    error::all_error::AllError,
+    lexer::tokenizer::Tokenizer,
+    parser::Parser,
+};
+
+fn parse(text: &str) -> Result<Vec<crate::ast::statement::Statement>, AllError> {
+    let tokens = Tokenizer::string_to_tokens(text).unwrap();
+
+    let mut parser = Parser::new();
+    parser.set_tokens(tokens);
+
+    parser.parse()
+}
+
+#[test]
+pub fn semicolons_and_trailing_newline() {
+    let statements = parse("let foo = 1;\nlet bar = foo;\nprintln(bar);\n").unwrap();
+
+    assert_eq!(
+        statements,
+        vec![
+            VariableDefinitionStatement {
+                name: "foo".into(),
+                type_name: None,
+                value: Expression::from(LiteralExpression::Integer(1)).into(),
+                mutable: false,
+                span: Default::default()
+            }
+            .into(),
+            VariableDefinitionStatement {
+                name: "bar".into(),
+                type_name: None,
+                value: Expression::Variable(VariableExpression {
+                    name: "foo".into(),
+                    span: Default::default()
+                })
+                .into(),
+                mutable: false,
+                span: Default::default()
+            }
+            .into(),
+            Expression::from(CallExpression {
+                function_name: "println".into(),
+                arguments: vec![VariableExpression {
+                    name: "bar".into(),
+                    span: Default::default()
+                }
+                .into()],
+                span: Default::default(),
+            })
+            .into(),
+        ]
+    );
+}
+
+#[test]
+pub fn call_followed_by_operator() {
+    let statements = parse("foo(a, (b)) + 1").unwrap();
+
+    assert_eq!(
+        statements,
+        vec![Expression::from(BinaryExpression {
+            lhs: Box::new(
+                CallExpression {
+                    function_name: "foo".into(),
+                    arguments: vec![
+                        VariableExpression {
+                            name: "a".into(),
+                            span: Default::default()
+                        }
+                        .into(),
+                        crate::ast::expression::parentheses::ParenthesesExpression {
+                            expression: Box::new(
+                                VariableExpression {
+                                    name: "b".into(),
+                                    span: Default::default()
+                                }
+                                .into()
+                            ),
+                        }
+                        .into(),
+                    ],
+                    span: Default::default(),
+                }
+                .into()
+            ),
+            rhs: Box::new(LiteralExpression::Integer(1).into()),
+            operator: BinaryOperator::Add,
+            span: Default::default(),
+        })
+        .into()]
+    );
+}
+
+#[test]
+pub fn unary_minus() {
+    let statements = parse("-foo").unwrap();
+
+    assert_eq!(
+        statements,
+        vec![Expression::from(UnaryExpression {
+            operator: UnaryOperator::Minus,
+            operand: Box::new(
+                VariableExpression {
+                    name: "foo".into(),
+                    span: Default::default()
+                }
+                .into()
+            ),
+            span: Default::default(),
+        })
+        .into()]
+    );
+}
+
+#[test]
+pub fn unexpected_top_level_token() {
+    assert!(matches!(parse("}"), Err(AllError::ParserError(_))));
+}
+
+// 토크나이저를 넘기면 다음 토큰은 peek으로만 확인하므로, 토큰 목록으로 파싱한 결과와 같아야 합니다.
+#[test]
+pub fn tokenizer_lookahead() {
+    let text = concat!(
+        "mut a = f(1)\n",
+        "a += 2\n",
+        "let b = match a { -1 => 0, _ => a }\n",
+        "let c = P { x: b }\n",
+    );
+
+    let mut parser = Parser::new();
+    parser.set_tokenizer(Tokenizer::new(text));
+    let mut streamed = parser.parse().unwrap();
+    clear_spans(&mut streamed);
+
+    assert_eq!(streamed, parse(text).unwrap());
+
+    // 다음 토큰이 잘못된 토큰이어도 그 토큰을 읽을 때 토크나이저의 오류를 반환합니다.
+    let mut parser = Parser::new();
+    parser.set_tokenizer(Tokenizer::new("a 1.2.3"));
+
+    match parser.parse() {
+        Err(AllError::LexerError(error)) => assert_eq!(error.span.to_string(), "1:3"),
+        result => panic!("expected a lexer error, found {:?}", result),
+    }
+}
@@ -0,0 +1,193 @@
+#![cfg(test)]
+
+use crate::{
+    ast::{
+        expression::{
+            binary::BinaryExpression, field::FieldExpression, literal::LiteralExpression,
+            struct_literal::StructLiteralExpression, unary::UnaryExpression,
+            variable::VariableExpression, Expression,
+        },
+        operator::{binary::BinaryOperator, unary::UnaryOperator},
+        statement::{
+            define_function::{FunctionDefinitionStatement, Parameter},
+            define_struct::{StructDefinitionStatement, StructField},
+            define_variable::VariableDefinitionStatement,
+            if_statement::IfStatement,
+            Statement,
+        },
+    },
+    utils::testing::parse_without_spans,
+};
+
+fn variable(name: &str) -> Expression {
+    VariableExpression {
+        name: name.into(),
+        span: Default::default(),
+    }
+    .into()
+}
+
+fn field(object: Expression, name: &str) -> Expression {
+    FieldExpression {
+        object: Box::new(object),
+        field: name.into(),
+        span: Default::default(),
+    }
+    .into()
+}
+
+fn struct_field(name: &str, type_name: &str) -> StructField {
+    StructField {
+        name: name.into(),
+        type_name: type_name.into(),
+        span: Default::default(),
+    }
+}
+
+#[test]
+pub fn struct_declaration() {
+    let statements =
+        parse_without_spans("struct Point { x: i64, y: i64, }\nstruct Empty {}").unwrap();
+
+    assert_eq!(
+        statements,
+        vec![
+            StructDefinitionStatement {
+                name: "Point".into(),
+                fields: vec![struct_field("x", "i64"), struct_field("y", "i64")],
+                span: Default::default(),
+            }
+            .into(),
+            StructDefinitionStatement {
+                name: "Empty".into(),
+                fields: vec![],
+                span: Default::default(),
+            }
+            .into(),
+        ]
+    );
+}
+
+#[test]
+pub fn struct_literal_and_field_access() {
+    let statements =
+        parse_without_spans("let p = Point { x: 1, y: -q.y }\nlet a = p.x + p.size.w").unwrap();
+
+    assert_eq!(
+        statements,
+        vec![
+            VariableDefinitionStatement {
+                mutable: false,
+                name: "p".into(),
+                type_name: None,
+                value: Some(
+                    StructLiteralExpression {
+                        name: "Point".into(),
+                        fields: vec![
+                            ("x".into(), LiteralExpression::Integer(1).into()),
+                            (
+                                "y".into(),
+                                UnaryExpression {
+                                    operator: UnaryOperator::Minus,
+                                    operand: Box::new(field(variable("q"), "y")),
+                                    span: Default::default(),
+                                }
+                                .into()
+                            ),
+                        ],
+                        span: Default::default(),
+                    }
+                    .into()
+                ),
+                span: Default::default(),
+            }
+            .into(),
+            VariableDefinitionStatement {
+                mutable: false,
+                name: "a".into(),
+                type_name: None,
+                value: Some(
+                    BinaryExpression {
+                        lhs: Box::new(field(variable("p"), "x")),
+                        rhs: Box::new(field(field(variable("p"), "size"), "w")),
+                        operator: BinaryOperator::Add,
+                        span: Default::default(),
+                    }
+                    .into()
+                ),
+                span: Default::default(),
+            }
+            .into(),
+        ]
+    );
+}
+
+// 블록 바로 앞의 `이름 {`는 구조체 리터럴이 아니라 블록의 시작입니다.
+#[test]
+pub fn struct_literal_before_block() {
+    let statements = parse_without_spans("if ready { }\nif (Point { x: 1 }).x == 1 { }").unwrap();
+
+    assert_eq!(
+        statements[0],
+        IfStatement {
+            condition: variable("ready"),
+            then_body: vec![],
+            else_body: None,
+        }
+        .into()
+    );
+    assert!(matches!(
+        &statements[1],
+        Statement::If(IfStatement {
+            condition: Expression::Binary(_),
+            ..
+        })
+    ));
+
+    assert!(parse_without_spans("while p { x: 1 } { }").is_err());
+}
+
+#[test]
+pub fn typed_parameters() {
+    let statements = parse_without_spans("fn norm(p: Point, scale) -> Point { return p }").unwrap();
+
+    assert_eq!(
+        statements,
+        vec![FunctionDefinitionStatement {
+            name: "norm".into(),
+            parameters: vec![
+                Parameter {
+                    name: "p".into(),
+                    type_name: Some("Point".into()),
+                    span: Default::default(),
+                },
+                "scale".into(),
+            ],
+            return_type: Some("Point".into()),
+            body: vec![Statement::Return(variable("p"), Default::default())],
+            span: Default::default(),
+        }
+        .into()]
+    );
+}
+
+#[test]
+pub fn invalid_struct() {
+    for source in [
+        "struct { }",
+        "struct Point",
+        "struct Point { x }",
+        "struct Point { x: }",
+        "struct Point { x: i64 y: i64 }",
+        "struct Point { x: i64,",
+        "let p = Point { x 1 }",
+        "let p = Point { x: 1 y: 2 }",
+        "let p = Point { 1 }",
+        "let p = Point { x: 1",
+        "let a = p.",
+        "let a = p.1",
+        "fn foo(p:) { }",
+    ] {
+        assert!(parse_without_spans(source).is_err(), "{}", source);
+    }
+}
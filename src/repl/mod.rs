@@ -0,0 +1,143 @@
+pub(crate) mod test;
+
+use std::process::{Command, Stdio};
+
+use crate::{
+    ast::{
+        expression::{call::CallExpression, Expression},
+        statement::Statement,
+    },
+    builder::{emit::Emit, toolchain::Toolchain, Builder, IntermediateDirectory},
+    compiler::{lower_to_ir, parse},
+    error::all_error::AllError,
+    lexer::{general::GeneralToken, token::Token, tokenizer::Tokenizer},
+    utils::span::Span,
+};
+
+// 진단에 표시할 입력의 이름입니다. 의미 분석과 코드 생성이 쓰는 기본 이름과 같습니다.
+const SOURCE_NAME: &str = "main.foo";
+
+// 입력 하나를 실행한 결과입니다.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Evaluation {
+    // 이번 입력이 새로 출력한 내용
+    pub stdout: String,
+    pub stderr: String,
+    // 프로그램이 실패했으면 종료 코드. 시그널로 끝났으면 -1입니다.
+    pub failure: Option<i32>,
+}
+
+// 대화형 실행 세션입니다. (foolang repl)
+// 지금까지 성공한 입력을 모두 모아 하나의 프로그램으로 다시 컴파일하고 실행합니다.
+// 앞의 입력이 출력한 내용은 다시 보여주지 않도록, 이전 실행의 출력 길이만큼 건너뜁니다.
+// 표준 입력은 REPL이 읽고 있고 앞의 입력도 매번 다시 실행되므로, 프로그램에는 표준 입력을 넘기지 않습니다.
+// 그래서 REPL에서 read_line은 언제나 빈 문자열을 반환합니다.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Session {
+    statements: Vec<Statement>,
+    // 지금까지의 statement가 출력하는 내용의 길이(바이트)
+    printed: usize,
+    no_std: bool,
+}
+
+impl Session {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn set_no_std(&mut self, no_std: bool) {
+        self.no_std = no_std;
+    }
+
+    // 식 하나를 입력하면 그 값을 출력합니다. 값을 출력할 수 없는 식은 statement로 실행합니다.
+    // 컴파일 오류가 나거나 프로그램이 실패한 입력은 세션에 남기지 않습니다.
+    pub fn evaluate(&mut self, input: &str) -> Result<Evaluation, AllError> {
+        let statements = parse(input).map_err(|error| error.with_file(SOURCE_NAME))?;
+
+        if let [Statement::Expression(expression)] = statements.as_slice() {
+            if !matches!(expression, Expression::Comment(..)) {
+                let print = CallExpression {
+                    function_name: "println".into(),
+                    arguments: vec![expression.clone()],
+                    span: Span::default(),
+                };
+                let program = self.program(&[Expression::Call(print).into()]);
+
+                if let Ok(codes) = lower_to_ir(program, self.no_std) {
+                    let (evaluation, _) = self.run(codes)?;
+                    return Ok(evaluation);
+                }
+            }
+        }
+
+        let codes = lower_to_ir(self.program(&statements), self.no_std)
+            .map_err(|error| error.with_file(SOURCE_NAME))?;
+        let (evaluation, printed) = self.run(codes)?;
+
+        if evaluation.failure.is_none() {
+            self.statements.extend(statements);
+            self.printed = printed;
+        }
+
+        Ok(evaluation)
+    }
+
+    fn program(&self, statements: &[Statement]) -> Vec<Statement> {
+        self.statements.iter().chain(statements).cloned().collect()
+    }
+
+    // 실행 결과와 전체 출력의 길이를 반환합니다.
+    // lli가 있으면 실행 파일을 만들지 않고 바로 실행합니다.
+    fn run(&self, codes: Vec<String>) -> Result<(Evaluation, usize), AllError> {
+        let mut builder = Builder::new();
+        builder.set_filenames(codes);
+        builder.set_no_std(self.no_std);
+
+        let output = if !self.no_std && Toolchain::new().is_jit_available() {
+            builder.execute_jit()?
+        } else {
+            let directory = IntermediateDirectory::new()?;
+
+            builder.set_emit(Emit::Executable);
+            builder.set_source_path(directory.path().join("repl.foo"));
+            let executable = builder.build()?;
+
+            Command::new(&executable)
+                .stdin(Stdio::null())
+                .output()
+                .map_err(|error| AllError::IOError(format!("{}: {}", executable, error)))?
+        };
+
+        // 출력이 이전 실행과 다르면(입력을 읽는 프로그램 등) 전체를 보여줍니다.
+        let stdout = output.stdout.get(self.printed..).unwrap_or(&output.stdout);
+
+        let evaluation = Evaluation {
+            stdout: String::from_utf8_lossy(stdout).into_owned(),
+            stderr: String::from_utf8_lossy(&output.stderr).into_owned(),
+            failure: (!output.status.success()).then(|| output.status.code().unwrap_or(-1)),
+        };
+
+        Ok((evaluation, output.stdout.len()))
+    }
+}
+
+// 괄호나 중괄호가 닫히지 않아 다음 줄을 더 읽어야 하는 입력입니다.
+pub fn is_incomplete(input: &str) -> bool {
+    let mut depth = 0;
+
+    for token in Tokenizer::new(input) {
+        match token.map(|token| token.token) {
+            Ok(Token::GeneralToken(GeneralToken::LeftBrace | GeneralToken::LeftParentheses)) => {
+                depth += 1
+            }
+            Ok(Token::GeneralToken(GeneralToken::RightBrace | GeneralToken::RightParentheses)) => {
+                depth -= 1
+            }
+            Ok(_) => {}
+            // 어휘 오류는 evaluate에서 보고합니다.
+            Err(_) => return false,
+        }
+    }
+
+    depth > 0
+}
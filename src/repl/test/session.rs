@@ -0,0 +1,120 @@
+#![cfg(test)]
+
+use crate::{
+    builder::toolchain::Toolchain,
+    error::all_error::AllError,
+    repl::{is_incomplete, Session},
+};
+
+#[test]
+pub fn expression_is_printed() {
+    if !Toolchain::new().is_available() {
+        return;
+    }
+
+    let mut session = Session::new();
+
+    let evaluation = session.evaluate("let a = 40").unwrap();
+    assert_eq!(evaluation.stdout, "");
+
+    let evaluation = session.evaluate("a + 2").unwrap();
+    assert_eq!(evaluation.stdout, "42\n");
+    assert_eq!(evaluation.failure, None);
+
+    // 값을 출력한 식은 세션에 남지 않습니다.
+    let evaluation = session.evaluate("a").unwrap();
+    assert_eq!(evaluation.stdout, "40\n");
+}
+
+#[test]
+pub fn output_is_not_repeated() {
+    if !Toolchain::new().is_available() {
+        return;
+    }
+
+    let mut session = Session::new();
+
+    let evaluation = session.evaluate("println(\"hello\")").unwrap();
+    assert_eq!(evaluation.stdout, "hello\n");
+
+    let evaluation = session.evaluate("println(\"world\")").unwrap();
+    assert_eq!(evaluation.stdout, "world\n");
+}
+
+#[test]
+pub fn define_and_call_function() {
+    if !Toolchain::new().is_available() {
+        return;
+    }
+
+    let mut session = Session::new();
+
+    session
+        .evaluate("fn double(x: i64) -> i64 {\n    return x * 2\n}")
+        .unwrap();
+
+    let evaluation = session.evaluate("double(21)").unwrap();
+    assert_eq!(evaluation.stdout, "42\n");
+}
+
+#[test]
+pub fn error_keeps_session() {
+    if !Toolchain::new().is_available() {
+        return;
+    }
+
+    let mut session = Session::new();
+
+    session.evaluate("let a = 1").unwrap();
+
+    let error = session.evaluate("let b = c").unwrap_err();
+    assert!(matches!(error, AllError::SemanticError(_)));
+
+    let evaluation = session.evaluate("a").unwrap();
+    assert_eq!(evaluation.stdout, "1\n");
+}
+
+// 파싱 오류도 의미 분석 오류와 같은 이름으로 위치를 표시합니다.
+#[test]
+pub fn errors_name_the_input() {
+    let mut session = Session::new();
+
+    let file = |error: AllError| match error {
+        AllError::ParserError(error) => error.file,
+        AllError::SemanticError(error) => error.file,
+        error => panic!("unexpected error {:?}", error),
+    };
+
+    assert_eq!(
+        file(session.evaluate("let = 1").unwrap_err()).as_deref(),
+        Some("main.foo")
+    );
+    assert_eq!(
+        file(session.evaluate("let b = c").unwrap_err()).as_deref(),
+        Some("main.foo")
+    );
+}
+
+// 프로그램에는 표준 입력을 넘기지 않으므로 read_line은 빈 문자열을 반환합니다.
+#[test]
+pub fn read_line_is_empty() {
+    if !Toolchain::new().is_available() {
+        return;
+    }
+
+    let mut session = Session::new();
+
+    let evaluation = session.evaluate("read_line() == \"\"").unwrap();
+    assert_eq!(evaluation.stdout, "true\n");
+}
+
+#[test]
+pub fn incomplete_input() {
+    assert!(is_incomplete("fn main() {"));
+    assert!(is_incomplete("println(1,"));
+    assert!(is_incomplete("if true {\n    println(1)\n"));
+    assert!(!is_incomplete("let a = 1"));
+    assert!(!is_incomplete("fn main() {}"));
+    assert!(!is_incomplete("}"));
+    assert!(!is_incomplete("\"{"));
+}
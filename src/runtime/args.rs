@@ -0,0 +1,49 @@
+use super::string::STRING_TYPE;
+
+// 프로그램 인자(argc, argv)를 보관하고 조회하는 런타임 함수를 생성합니다.
+// libc로 링크할 때는 crt1이, no_std에서는 _start가 스택에서 꺼낸 값이 main의 인자로 들어오므로
+// main의 첫 부분에서 initialize()가 생성하는 호출로 전역 변수에 저장해둡니다. (startup::generate_entry 참고)
+pub(crate) fn generate() -> Vec<String> {
+    vec![
+        "@__foolang_argc = linkonce_odr global i64 0".into(),
+        "@__foolang_argv = linkonce_odr global i8** null".into(),
+        "define linkonce_odr void @__foolang_args_init(i32 %argc, i8** %argv) nounwind {".into(),
+        "entry:".into(),
+        "  %count = sext i32 %argc to i64".into(),
+        "  store i64 %count, i64* @__foolang_argc".into(),
+        "  store i8** %argv, i8*** @__foolang_argv".into(),
+        "  ret void".into(),
+        "}".into(),
+        "define linkonce_odr i64 @__foolang_args_count() nounwind {".into(),
+        "entry:".into(),
+        "  %count = load i64, i64* @__foolang_argc".into(),
+        "  ret i64 %count".into(),
+        "}".into(),
+        // 범위를 벗어난 인덱스는 빈 문자열을 반환합니다.
+        format!(
+            "define linkonce_odr {} @__foolang_arg(i64 %index) nounwind {{",
+            STRING_TYPE
+        ),
+        "entry:".into(),
+        "  %count = load i64, i64* @__foolang_argc".into(),
+        "  %in_range = icmp ult i64 %index, %count".into(),
+        "  br i1 %in_range, label %found, label %missing".into(),
+        "found:".into(),
+        "  %argv = load i8**, i8*** @__foolang_argv".into(),
+        "  %slot = getelementptr i8*, i8** %argv, i64 %index".into(),
+        "  %text = load i8*, i8** %slot".into(),
+        format!(
+            "  %string = call {} @__foolang_string_from_cstr(i8* %text)",
+            STRING_TYPE
+        ),
+        format!("  ret {} %string", STRING_TYPE),
+        "missing:".into(),
+        format!("  ret {} zeroinitializer", STRING_TYPE),
+        "}".into(),
+    ]
+}
+
+// main에서 가장 먼저 실행해야 하는 인자 저장 호출입니다.
+pub(crate) fn initialize() -> String {
+    "  call void @__foolang_args_init(i32 %argc, i8** %argv)".into()
+}
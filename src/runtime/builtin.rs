@@ -0,0 +1,99 @@
+use super::string::STRING_TYPE;
+
+// 언어에서 선언 없이 호출할 수 있는 내장 함수 목록입니다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Builtin {
+    Print,     // print(value)
+    Println,   // println(value)
+    Alloc,     // alloc(size)
+    Free,      // free(pointer)
+    ArgsCount, // args_count()
+    Arg,       // arg(index)
+    Panic,     // panic(message)
+    Assert,    // assert(condition, message)
+    Read,      // read(fd, buffer, length)
+    ReadLine,  // read_line()
+    ToString,  // to_string(value)
+    ToHex,     // to_hex(value)
+    Len,       // len(string)
+}
+
+impl Builtin {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "print" => Some(Self::Print),
+            "println" => Some(Self::Println),
+            "alloc" => Some(Self::Alloc),
+            "free" => Some(Self::Free),
+            "args_count" => Some(Self::ArgsCount),
+            "arg" => Some(Self::Arg),
+            "panic" => Some(Self::Panic),
+            "assert" => Some(Self::Assert),
+            "read" => Some(Self::Read),
+            "read_line" => Some(Self::ReadLine),
+            "to_string" => Some(Self::ToString),
+            "to_hex" => Some(Self::ToHex),
+            "len" => Some(Self::Len),
+            _ => None,
+        }
+    }
+
+    // 인자의 LLVM 타입에 맞는 런타임 함수 이름을 반환합니다. 인자가 여러 개면 첫 번째 인자의 타입입니다.
+    // 인자가 없는 함수는 타입과 상관없이 같은 함수를 반환합니다.
+    // panic/assert의 호출 위치(file, line, column)는 codegen이 인자 뒤에 덧붙입니다.
+    // i32 정수 인자는 codegen이 i64로 넓혀서 넘기므로, i32 인자는 char입니다.
+    pub fn runtime_function(&self, argument_type: &str) -> Option<&'static str> {
+        match (self, argument_type) {
+            (Self::Print, "i64") => Some("__foolang_print_i64"),
+            (Self::Print, "i8*") => Some("__foolang_print_str"),
+            (Self::Print, "i1") => Some("__foolang_print_bool"),
+            (Self::Print, "double") => Some("__foolang_print_f64"),
+            (Self::Print, "i32") => Some("__foolang_print_char"),
+            (Self::Print, STRING_TYPE) => Some("__foolang_print_string"),
+            (Self::Println, "i64") => Some("__foolang_println_i64"),
+            (Self::Println, "i8*") => Some("__foolang_println_str"),
+            (Self::Println, "i1") => Some("__foolang_println_bool"),
+            (Self::Println, "double") => Some("__foolang_println_f64"),
+            (Self::Println, "i32") => Some("__foolang_println_char"),
+            (Self::Println, STRING_TYPE) => Some("__foolang_println_string"),
+            (Self::Alloc, "i64") => Some("__foolang_alloc"),
+            (Self::Free, "i8*") => Some("__foolang_free"),
+            (Self::ArgsCount, _) => Some("__foolang_args_count"),
+            (Self::Arg, "i64") => Some("__foolang_arg"),
+            (Self::Panic, STRING_TYPE) => Some("__foolang_panic"),
+            (Self::Assert, "i1") => Some("__foolang_assert"),
+            (Self::Read, "i64") => Some("__foolang_read"),
+            (Self::ReadLine, _) => Some("__foolang_read_line"),
+            (Self::ToString, "i64") => Some("__foolang_i64_to_string"),
+            (Self::ToString, "double") => Some("__foolang_f64_to_string"),
+            (Self::ToString, "i32") => Some("__foolang_char_to_string"),
+            (Self::ToHex, "i64") => Some("__foolang_hex_to_string"),
+            (Self::Len, STRING_TYPE) => Some("__foolang_string_length"),
+            _ => None,
+        }
+    }
+
+    // 런타임 함수의 매개변수 타입 목록입니다. panic/assert의 호출 위치는 포함하지 않습니다.
+    pub fn parameter_types(&self, argument_type: &str) -> Vec<String> {
+        match self {
+            Self::ArgsCount | Self::ReadLine => vec![],
+            Self::Assert => vec!["i1".into(), STRING_TYPE.into()],
+            Self::Read => vec!["i64".into(), "i8*".into(), "i64".into()],
+            _ => vec![argument_type.into()],
+        }
+    }
+
+    pub fn return_type(&self) -> &'static str {
+        match self {
+            Self::Print | Self::Println | Self::Free | Self::Panic | Self::Assert => "void",
+            Self::Alloc => "i8*",
+            Self::ArgsCount | Self::Read | Self::Len => "i64",
+            Self::Arg | Self::ReadLine | Self::ToString | Self::ToHex => STRING_TYPE,
+        }
+    }
+
+    // 호출 위치(file, line, column)를 인자 뒤에 덧붙여야 하는지 여부입니다.
+    pub fn takes_location(&self) -> bool {
+        matches!(self, Self::Panic | Self::Assert)
+    }
+}
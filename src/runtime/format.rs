@@ -0,0 +1,329 @@
+use super::string::STRING_TYPE;
+
+// 변환 결과를 담기에 충분한 버퍼 크기입니다. (부호, 정수부 19자리, 소수점, 소수부 6자리)
+pub const FORMAT_BUFFER_SIZE: i64 = 32;
+
+// f64를 출력할 때의 소수점 아래 자릿수입니다. (printf의 %f와 같음)
+pub const FLOAT_PRECISION: usize = 6;
+
+// printf 없이 숫자를 문자열로 변환하는 런타임 함수를 생성합니다.
+// __foolang_format_*은 buffer의 앞부분부터 문자를 채우고 길이를 반환하며, buffer는 FORMAT_BUFFER_SIZE 이상이어야 합니다.
+// __foolang_*_to_string은 같은 변환 결과를 힙에 할당한 문자열로 반환합니다.
+pub(crate) fn generate() -> Vec<String> {
+    let mut codes = vec![];
+
+    codes.append(&mut generate_radix());
+    codes.append(&mut generate_integer());
+    codes.append(&mut generate_float());
+    codes.append(&mut generate_char());
+    codes.append(&mut generate_to_string());
+
+    codes
+}
+
+// 부호 없는 정수를 radix 진법(2~16)의 소문자 문자열로 변환합니다.
+fn generate_radix() -> Vec<String> {
+    vec![
+        "define linkonce_odr i64 @__foolang_format_radix(i8* %buffer, i64 %value, i64 %radix) nounwind {".into(),
+        "entry:".into(),
+        "  %digits = alloca [64 x i8]".into(),
+        "  %count = alloca i64".into(),
+        "  %rest = alloca i64".into(),
+        "  %index = alloca i64".into(),
+        "  store i64 0, i64* %count".into(),
+        "  store i64 %value, i64* %rest".into(),
+        "  br label %digit".into(),
+        // 낮은 자리부터 digits에 역순으로 기록
+        "digit:".into(),
+        "  %current = load i64, i64* %rest".into(),
+        "  %position = load i64, i64* %count".into(),
+        "  %remainder = urem i64 %current, %radix".into(),
+        "  %remainder8 = trunc i64 %remainder to i8".into(),
+        "  %decimal = icmp ult i8 %remainder8, 10".into(),
+        "  %offset = select i1 %decimal, i8 48, i8 87".into(),
+        "  %character = add i8 %remainder8, %offset".into(),
+        "  %slot = getelementptr [64 x i8], [64 x i8]* %digits, i64 0, i64 %position".into(),
+        "  store i8 %character, i8* %slot".into(),
+        "  %next_position = add i64 %position, 1".into(),
+        "  store i64 %next_position, i64* %count".into(),
+        "  %quotient = udiv i64 %current, %radix".into(),
+        "  store i64 %quotient, i64* %rest".into(),
+        "  %more = icmp ne i64 %quotient, 0".into(),
+        "  br i1 %more, label %digit, label %reverse".into(),
+        // 역순으로 기록된 숫자를 buffer에 뒤집어서 복사
+        "reverse:".into(),
+        "  %total = load i64, i64* %count".into(),
+        "  store i64 0, i64* %index".into(),
+        "  br label %copy".into(),
+        "copy:".into(),
+        "  %i = load i64, i64* %index".into(),
+        "  %done = icmp eq i64 %i, %total".into(),
+        "  br i1 %done, label %exit, label %copy_body".into(),
+        "copy_body:".into(),
+        "  %reversed = sub i64 %total, %i".into(),
+        "  %from_index = sub i64 %reversed, 1".into(),
+        "  %from = getelementptr [64 x i8], [64 x i8]* %digits, i64 0, i64 %from_index".into(),
+        "  %byte = load i8, i8* %from".into(),
+        "  %to = getelementptr i8, i8* %buffer, i64 %i".into(),
+        "  store i8 %byte, i8* %to".into(),
+        "  %next_i = add i64 %i, 1".into(),
+        "  store i64 %next_i, i64* %index".into(),
+        "  br label %copy".into(),
+        "exit:".into(),
+        "  ret i64 %total".into(),
+        "}".into(),
+    ]
+}
+
+fn generate_integer() -> Vec<String> {
+    vec![
+        "define linkonce_odr i64 @__foolang_format_u64(i8* %buffer, i64 %value) nounwind {".into(),
+        "entry:".into(),
+        "  %length = call i64 @__foolang_format_radix(i8* %buffer, i64 %value, i64 10)".into(),
+        "  ret i64 %length".into(),
+        "}".into(),
+        // 16진수는 접두사(0x) 없이 2의 보수 비트 그대로 출력합니다.
+        "define linkonce_odr i64 @__foolang_format_hex(i8* %buffer, i64 %value) nounwind {".into(),
+        "entry:".into(),
+        "  %length = call i64 @__foolang_format_radix(i8* %buffer, i64 %value, i64 16)".into(),
+        "  ret i64 %length".into(),
+        "}".into(),
+        "define linkonce_odr i64 @__foolang_format_i64(i8* %buffer, i64 %value) nounwind {".into(),
+        "entry:".into(),
+        "  %negative = icmp slt i64 %value, 0".into(),
+        "  br i1 %negative, label %minus, label %plain".into(),
+        "minus:".into(),
+        "  store i8 45, i8* %buffer".into(),
+        "  %magnitude = sub i64 0, %value".into(),
+        "  %rest = getelementptr i8, i8* %buffer, i64 1".into(),
+        "  %length = call i64 @__foolang_format_u64(i8* %rest, i64 %magnitude)".into(),
+        "  %total = add i64 %length, 1".into(),
+        "  ret i64 %total".into(),
+        "plain:".into(),
+        "  %plain_length = call i64 @__foolang_format_u64(i8* %buffer, i64 %value)".into(),
+        "  ret i64 %plain_length".into(),
+        "}".into(),
+    ]
+}
+
+// f64는 printf의 %f처럼 소수점 아래 6자리까지 반올림해서 출력합니다.
+// 정수부가 u64 범위(2^63)를 넘으면 1.234567e20 형식으로 출력합니다.
+fn generate_float() -> Vec<String> {
+    let scale = 10_u64.pow(FLOAT_PRECISION as u32);
+
+    let mut codes = vec![
+        "declare double @llvm.fabs.f64(double)".into(),
+        // 0 이상 2^63 미만의 값을 고정 소수점으로 변환합니다.
+        "define linkonce_odr i64 @__foolang_format_fixed(i8* %buffer, double %value) nounwind {"
+            .into(),
+        "entry:".into(),
+        "  %integer = fptoui double %value to i64".into(),
+        "  %integer_float = uitofp i64 %integer to double".into(),
+        "  %fraction = fsub double %value, %integer_float".into(),
+        format!(
+            "  %scaled = fmul double %fraction, 0x{:016X}",
+            (scale as f64).to_bits()
+        ),
+        "  %rounded = fadd double %scaled, 5.000000e-01".into(),
+        "  %fraction_digits = fptoui double %rounded to i64".into(),
+        // 반올림으로 소수부가 1이 되면 정수부로 올립니다.
+        format!("  %carry = icmp uge i64 %fraction_digits, {}", scale),
+        "  %carried_integer = add i64 %integer, 1".into(),
+        format!("  %carried_fraction = sub i64 %fraction_digits, {}", scale),
+        "  %final_integer = select i1 %carry, i64 %carried_integer, i64 %integer".into(),
+        "  %digits0 = select i1 %carry, i64 %carried_fraction, i64 %fraction_digits".into(),
+        "  %length = call i64 @__foolang_format_u64(i8* %buffer, i64 %final_integer)".into(),
+        "  %point = getelementptr i8, i8* %buffer, i64 %length".into(),
+        "  store i8 46, i8* %point".into(),
+    ];
+
+    // 소수부는 앞자리 0을 채워서 뒤에서부터 기록합니다.
+    for digit in 0..FLOAT_PRECISION {
+        let position = FLOAT_PRECISION - digit;
+        codes.append(&mut vec![
+            format!("  %remainder{0} = urem i64 %digits{0}, 10", digit),
+            format!("  %remainder8_{0} = trunc i64 %remainder{0} to i8", digit),
+            format!("  %character{0} = add i8 %remainder8_{0}, 48", digit),
+            format!(
+                "  %slot{} = getelementptr i8, i8* %point, i64 {}",
+                digit, position
+            ),
+            format!("  store i8 %character{0}, i8* %slot{0}", digit),
+            format!("  %digits{} = udiv i64 %digits{}, 10", digit + 1, digit),
+        ]);
+    }
+
+    codes.append(&mut vec![
+        format!("  %total = add i64 %length, {}", FLOAT_PRECISION + 1),
+        "  ret i64 %total".into(),
+        "}".into(),
+        "define linkonce_odr i64 @__foolang_format_f64(i8* %buffer, double %value) nounwind {"
+            .into(),
+        "entry:".into(),
+        "  %nan = fcmp uno double %value, %value".into(),
+        "  br i1 %nan, label %not_a_number, label %number".into(),
+        "not_a_number:".into(),
+    ]);
+    codes.append(&mut store_text("%buffer", "NaN"));
+    codes.append(&mut vec![
+        "  ret i64 3".into(),
+        "number:".into(),
+        "  %bits = bitcast double %value to i64".into(),
+        "  %negative = icmp slt i64 %bits, 0".into(),
+        "  %sign_length = zext i1 %negative to i64".into(),
+        "  br i1 %negative, label %minus, label %magnitude".into(),
+        "minus:".into(),
+        "  store i8 45, i8* %buffer".into(),
+        "  br label %magnitude".into(),
+        "magnitude:".into(),
+        "  %rest = getelementptr i8, i8* %buffer, i64 %sign_length".into(),
+        "  %absolute = call double @llvm.fabs.f64(double %value)".into(),
+        "  %infinite = fcmp oeq double %absolute, 0x7FF0000000000000".into(),
+        "  br i1 %infinite, label %infinity, label %finite".into(),
+        "infinity:".into(),
+    ]);
+    codes.append(&mut store_text("%rest", "inf"));
+    codes.append(&mut vec![
+        "  %infinity_length = add i64 %sign_length, 3".into(),
+        "  ret i64 %infinity_length".into(),
+        "finite:".into(),
+        "  %large = fcmp oge double %absolute, 0x43E0000000000000".into(),
+        "  br i1 %large, label %scientific, label %fixed".into(),
+        "fixed:".into(),
+        "  %fixed_length = call i64 @__foolang_format_fixed(i8* %rest, double %absolute)".into(),
+        "  %fixed_total = add i64 %sign_length, %fixed_length".into(),
+        "  ret i64 %fixed_total".into(),
+        // 가수가 10보다 작아질 때까지 10으로 나누면서 지수를 셉니다.
+        "scientific:".into(),
+        "  %mantissa_slot = alloca double".into(),
+        "  %exponent_slot = alloca i64".into(),
+        "  store double %absolute, double* %mantissa_slot".into(),
+        "  store i64 0, i64* %exponent_slot".into(),
+        "  br label %normalize".into(),
+        "normalize:".into(),
+        "  %mantissa = load double, double* %mantissa_slot".into(),
+        "  %exponent = load i64, i64* %exponent_slot".into(),
+        "  %too_large = fcmp oge double %mantissa, 1.000000e+01".into(),
+        "  br i1 %too_large, label %divide, label %emit".into(),
+        "divide:".into(),
+        "  %divided = fdiv double %mantissa, 1.000000e+01".into(),
+        "  store double %divided, double* %mantissa_slot".into(),
+        "  %next_exponent = add i64 %exponent, 1".into(),
+        "  store i64 %next_exponent, i64* %exponent_slot".into(),
+        "  br label %normalize".into(),
+        "emit:".into(),
+        "  %mantissa_length = call i64 @__foolang_format_fixed(i8* %rest, double %mantissa)".into(),
+        "  %e = getelementptr i8, i8* %rest, i64 %mantissa_length".into(),
+        "  store i8 101, i8* %e".into(),
+        "  %exponent_digits = getelementptr i8, i8* %e, i64 1".into(),
+        "  %exponent_length = call i64 @__foolang_format_u64(i8* %exponent_digits, i64 %exponent)"
+            .into(),
+        "  %scientific_length = add i64 %mantissa_length, %exponent_length".into(),
+        "  %scientific_sign = add i64 %scientific_length, %sign_length".into(),
+        "  %scientific_total = add i64 %scientific_sign, 1".into(),
+        "  ret i64 %scientific_total".into(),
+        "}".into(),
+    ]);
+
+    codes
+}
+
+// char(유니코드 코드 포인트)를 UTF-8로 인코딩합니다. 길이는 1~4바이트입니다.
+fn generate_char() -> Vec<String> {
+    vec![
+        "define linkonce_odr i64 @__foolang_format_char(i8* %buffer, i32 %value) nounwind {".into(),
+        "entry:".into(),
+        "  %ascii = icmp ult i32 %value, 128".into(),
+        "  br i1 %ascii, label %single, label %multi".into(),
+        "single:".into(),
+        "  %byte = trunc i32 %value to i8".into(),
+        "  store i8 %byte, i8* %buffer".into(),
+        "  ret i64 1".into(),
+        // 길이에 따라 첫 바이트의 접두사가 110, 1110, 11110이 됩니다.
+        "multi:".into(),
+        "  %two = icmp ult i32 %value, 2048".into(),
+        "  %three = icmp ult i32 %value, 65536".into(),
+        "  %long_length = select i1 %three, i64 3, i64 4".into(),
+        "  %length = select i1 %two, i64 2, i64 %long_length".into(),
+        "  %long_prefix = select i1 %three, i32 224, i32 240".into(),
+        "  %prefix = select i1 %two, i32 192, i32 %long_prefix".into(),
+        "  br label %continuation".into(),
+        // 뒤쪽 바이트부터 하위 6비트씩 10xxxxxx로 기록
+        "continuation:".into(),
+        "  %index = phi i64 [ %length, %multi ], [ %previous, %continuation ]".into(),
+        "  %rest = phi i32 [ %value, %multi ], [ %shifted, %continuation ]".into(),
+        "  %previous = sub i64 %index, 1".into(),
+        "  %low = and i32 %rest, 63".into(),
+        "  %marked = or i32 %low, 128".into(),
+        "  %marked8 = trunc i32 %marked to i8".into(),
+        "  %slot = getelementptr i8, i8* %buffer, i64 %previous".into(),
+        "  store i8 %marked8, i8* %slot".into(),
+        "  %shifted = lshr i32 %rest, 6".into(),
+        "  %more = icmp ugt i64 %previous, 1".into(),
+        "  br i1 %more, label %continuation, label %lead".into(),
+        "lead:".into(),
+        "  %lead_bits = or i32 %shifted, %prefix".into(),
+        "  %lead8 = trunc i32 %lead_bits to i8".into(),
+        "  store i8 %lead8, i8* %buffer".into(),
+        "  ret i64 %length".into(),
+        "}".into(),
+    ]
+}
+
+// ASCII 문자열을 buffer에 한 바이트씩 기록합니다.
+fn store_text(buffer: &str, text: &str) -> Vec<String> {
+    let name = buffer.trim_start_matches('%');
+
+    text.bytes()
+        .enumerate()
+        .flat_map(|(index, byte)| {
+            vec![
+                format!(
+                    "  %{}_{}_{} = getelementptr i8, i8* {}, i64 {}",
+                    name, text, index, buffer, index
+                ),
+                format!("  store i8 {}, i8* %{}_{}_{}", byte, name, text, index),
+            ]
+        })
+        .collect()
+}
+
+fn generate_to_string() -> Vec<String> {
+    let mut codes = vec![];
+
+    for (name, format, parameter) in [
+        ("i64_to_string", "format_i64", "i64"),
+        ("u64_to_string", "format_u64", "i64"),
+        ("hex_to_string", "format_hex", "i64"),
+        ("f64_to_string", "format_f64", "double"),
+        ("char_to_string", "format_char", "i32"),
+    ] {
+        codes.append(&mut vec![
+            format!(
+                "define linkonce_odr {} @__foolang_{}({} %value) nounwind {{",
+                STRING_TYPE, name, parameter
+            ),
+            "entry:".into(),
+            format!(
+                "  %buffer = call i8* @__foolang_alloc(i64 {})",
+                FORMAT_BUFFER_SIZE
+            ),
+            format!(
+                "  %length = call i64 @__foolang_{}(i8* %buffer, {} %value)",
+                format, parameter
+            ),
+            format!(
+                "  %with_pointer = insertvalue {} undef, i8* %buffer, 0",
+                STRING_TYPE
+            ),
+            format!(
+                "  %string = insertvalue {} %with_pointer, i64 %length, 1",
+                STRING_TYPE
+            ),
+            format!("  ret {} %string", STRING_TYPE),
+            "}".into(),
+        ]);
+    }
+
+    codes
+}
@@ -0,0 +1,111 @@
+use super::{
+    string::STRING_TYPE,
+    syscall::{self, SYS_READ},
+};
+
+pub const STDIN: i64 = 0;
+
+// read_line이 처음 할당하는 버퍼 크기입니다. 부족하면 두 배씩 늘립니다.
+const LINE_BUFFER_SIZE: i64 = 64;
+
+// read/read_line 내장 함수가 호출하는 런타임 함수를 생성합니다.
+pub(crate) fn generate(no_std: bool) -> Vec<String> {
+    let mut codes = if no_std {
+        vec![
+            "define linkonce_odr i64 @__foolang_read(i64 %fd, i8* %buffer, i64 %length) nounwind {"
+                .into(),
+            "entry:".into(),
+            "  %address = ptrtoint i8* %buffer to i64".into(),
+            syscall::call("%count", SYS_READ, &["%fd", "%address", "%length"]),
+            "  ret i64 %count".into(),
+            "}".into(),
+        ]
+    } else {
+        vec![
+            "declare i64 @read(i32, i8*, i64) nounwind".into(),
+            "define linkonce_odr i64 @__foolang_read(i64 %fd, i8* %buffer, i64 %length) nounwind {"
+                .into(),
+            "entry:".into(),
+            "  %fd32 = trunc i64 %fd to i32".into(),
+            "  %count = call i64 @read(i32 %fd32, i8* %buffer, i64 %length)".into(),
+            "  ret i64 %count".into(),
+            "}".into(),
+        ]
+    };
+
+    codes.append(&mut generate_read_line());
+
+    codes
+}
+
+// stdin에서 줄바꿈 문자 또는 EOF까지 읽어서 줄바꿈을 뺀 문자열을 반환합니다.
+// 아무것도 읽지 못하고 EOF를 만나면 포인터가 null인 빈 문자열을 반환하므로 빈 줄과 구분할 수 있습니다.
+// 입력을 다음 줄까지 미리 읽지 않도록 한 바이트씩 읽습니다.
+fn generate_read_line() -> Vec<String> {
+    vec![
+        format!(
+            "define linkonce_odr {} @__foolang_read_line() nounwind {{",
+            STRING_TYPE
+        ),
+        "entry:".into(),
+        "  %buffer_slot = alloca i8*".into(),
+        "  %capacity_slot = alloca i64".into(),
+        "  %length_slot = alloca i64".into(),
+        format!(
+            "  %initial = call i8* @__foolang_alloc(i64 {})",
+            LINE_BUFFER_SIZE
+        ),
+        "  store i8* %initial, i8** %buffer_slot".into(),
+        format!("  store i64 {}, i64* %capacity_slot", LINE_BUFFER_SIZE),
+        "  store i64 0, i64* %length_slot".into(),
+        "  br label %check".into(),
+        "check:".into(),
+        "  %length = load i64, i64* %length_slot".into(),
+        "  %capacity = load i64, i64* %capacity_slot".into(),
+        "  %full = icmp eq i64 %length, %capacity".into(),
+        "  br i1 %full, label %grow, label %read".into(),
+        "grow:".into(),
+        "  %old = load i8*, i8** %buffer_slot".into(),
+        "  %new_capacity = mul i64 %capacity, 2".into(),
+        "  %new = call i8* @__foolang_alloc(i64 %new_capacity)".into(),
+        "  call void @__foolang_copy(i8* %new, i8* %old, i64 %length)".into(),
+        "  call void @__foolang_free(i8* %old)".into(),
+        "  store i8* %new, i8** %buffer_slot".into(),
+        "  store i64 %new_capacity, i64* %capacity_slot".into(),
+        "  br label %read".into(),
+        "read:".into(),
+        "  %buffer = load i8*, i8** %buffer_slot".into(),
+        "  %slot = getelementptr i8, i8* %buffer, i64 %length".into(),
+        format!(
+            "  %count = call i64 @__foolang_read(i64 {}, i8* %slot, i64 1)",
+            STDIN
+        ),
+        "  %received = icmp sgt i64 %count, 0".into(),
+        "  br i1 %received, label %received_byte, label %end_of_file".into(),
+        "received_byte:".into(),
+        "  %byte = load i8, i8* %slot".into(),
+        "  %newline = icmp eq i8 %byte, 10".into(),
+        "  br i1 %newline, label %done, label %append".into(),
+        "append:".into(),
+        "  %next_length = add i64 %length, 1".into(),
+        "  store i64 %next_length, i64* %length_slot".into(),
+        "  br label %check".into(),
+        "end_of_file:".into(),
+        "  %empty = icmp eq i64 %length, 0".into(),
+        "  br i1 %empty, label %nothing, label %done".into(),
+        "nothing:".into(),
+        "  call void @__foolang_free(i8* %buffer)".into(),
+        format!("  ret {} zeroinitializer", STRING_TYPE),
+        "done:".into(),
+        format!(
+            "  %with_pointer = insertvalue {} undef, i8* %buffer, 0",
+            STRING_TYPE
+        ),
+        format!(
+            "  %line = insertvalue {} %with_pointer, i64 %length, 1",
+            STRING_TYPE
+        ),
+        format!("  ret {} %line", STRING_TYPE),
+        "}".into(),
+    ]
+}
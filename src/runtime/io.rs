@@ -0,0 +1,203 @@
+use super::{
+    format::FORMAT_BUFFER_SIZE,
+    syscall::{self, SYS_WRITE},
+};
+
+pub const STDOUT: i64 = 1;
+pub const STDERR: i64 = 2;
+
+// print/println 내장 함수가 호출하는 런타임 함수를 생성합니다.
+// 모든 모드에서 같은 이름의 함수를 제공하므로 codegen은 모드와 상관없이 같은 호출을 생성합니다.
+pub(crate) fn generate(no_std: bool) -> Vec<String> {
+    let mut codes = vec![
+        r#"@__foolang_true = linkonce_odr unnamed_addr constant [5 x i8] c"true\00""#.into(),
+        r#"@__foolang_false = linkonce_odr unnamed_addr constant [6 x i8] c"false\00""#.into(),
+    ];
+
+    if no_std {
+        codes.append(&mut generate_syscall_io());
+    } else {
+        codes.append(&mut generate_libc_io());
+    }
+
+    // f64와 char는 printf 없이 변환한 문자열을 write로 출력합니다.
+    for (name, format, parameter, newline) in [
+        ("print_f64", "format_f64", "double", false),
+        ("println_f64", "format_f64", "double", true),
+        ("print_char", "format_char", "i32", false),
+        ("println_char", "format_char", "i32", true),
+    ] {
+        codes.append(&mut vec![
+            format!(
+                "define linkonce_odr void @__foolang_{}({} %value) nounwind {{",
+                name, parameter
+            ),
+            "entry:".into(),
+            format!("  %buffer = alloca [{} x i8]", FORMAT_BUFFER_SIZE),
+            format!(
+                "  %pointer = getelementptr [{size} x i8], [{size} x i8]* %buffer, i64 0, i64 0",
+                size = FORMAT_BUFFER_SIZE
+            ),
+            format!(
+                "  %length = call i64 @__foolang_{}(i8* %pointer, {} %value)",
+                format, parameter
+            ),
+            "  %end = getelementptr i8, i8* %pointer, i64 %length".into(),
+            "  store i8 10, i8* %end".into(),
+            format!("  %line_length = add i64 %length, {}", newline as i64),
+            format!(
+                "  call void @__foolang_write(i64 {}, i8* %pointer, i64 %line_length)",
+                STDOUT
+            ),
+            "  ret void".into(),
+            "}".into(),
+        ]);
+    }
+
+    // bool은 "true"/"false" 문자열 출력으로 처리합니다.
+    for (name, target) in [("print_bool", "print_str"), ("println_bool", "println_str")] {
+        codes.append(&mut vec![
+            format!(
+                "define linkonce_odr void @__foolang_{}(i1 %value) nounwind {{",
+                name
+            ),
+            "entry:".into(),
+            "  %true = getelementptr [5 x i8], [5 x i8]* @__foolang_true, i64 0, i64 0".into(),
+            "  %false = getelementptr [6 x i8], [6 x i8]* @__foolang_false, i64 0, i64 0".into(),
+            "  %text = select i1 %value, i8* %true, i8* %false".into(),
+            format!("  call void @__foolang_{}(i8* %text)", target),
+            "  ret void".into(),
+            "}".into(),
+        ]);
+    }
+
+    codes
+}
+
+// libc의 printf로 출력합니다.
+fn generate_libc_io() -> Vec<String> {
+    let mut codes = vec![
+        "declare i32 @printf(i8*, ...) nounwind".into(),
+        "declare i64 @write(i32, i8*, i64) nounwind".into(),
+        "declare i32 @fflush(i8*) nounwind".into(),
+        // printf로 버퍼에 쌓인 출력이 먼저 나가도록 비운 뒤에 씁니다.
+        "define linkonce_odr void @__foolang_write(i64 %fd, i8* %buffer, i64 %length) nounwind {"
+            .into(),
+        "entry:".into(),
+        "  %flushed = call i32 @fflush(i8* null)".into(),
+        "  %fd32 = trunc i64 %fd to i32".into(),
+        "  %ignored = call i64 @write(i32 %fd32, i8* %buffer, i64 %length)".into(),
+        "  ret void".into(),
+        "}".into(),
+        r#"@__foolang_printf_i64 = linkonce_odr unnamed_addr constant [4 x i8] c"%ld\00""#.into(),
+        r#"@__foolang_printf_i64_line = linkonce_odr unnamed_addr constant [5 x i8] c"%ld\0A\00""#
+            .into(),
+        r#"@__foolang_printf_str = linkonce_odr unnamed_addr constant [3 x i8] c"%s\00""#.into(),
+        r#"@__foolang_printf_str_line = linkonce_odr unnamed_addr constant [4 x i8] c"%s\0A\00""#
+            .into(),
+    ];
+
+    for (name, format, format_length, parameter) in [
+        ("print_i64", "printf_i64", 4, "i64"),
+        ("println_i64", "printf_i64_line", 5, "i64"),
+        ("print_str", "printf_str", 3, "i8*"),
+        ("println_str", "printf_str_line", 4, "i8*"),
+    ] {
+        codes.append(&mut vec![
+            format!(
+                "define linkonce_odr void @__foolang_{}({} %value) nounwind {{",
+                name, parameter
+            ),
+            "entry:".into(),
+            format!(
+                "  %format = getelementptr [{length} x i8], [{length} x i8]* @__foolang_{}, i64 0, i64 0",
+                format,
+                length = format_length
+            ),
+            format!(
+                "  %ignored = call i32 (i8*, ...) @printf(i8* %format, {} %value)",
+                parameter
+            ),
+            "  ret void".into(),
+            "}".into(),
+        ]);
+    }
+
+    codes
+}
+
+// libc 없이 write 시스템 콜로 출력합니다.
+fn generate_syscall_io() -> Vec<String> {
+    vec![
+        r#"@__foolang_newline = linkonce_odr unnamed_addr constant [1 x i8] c"\0A""#.into(),
+        "define linkonce_odr void @__foolang_write(i64 %fd, i8* %buffer, i64 %length) nounwind {"
+            .into(),
+        "entry:".into(),
+        "  %address = ptrtoint i8* %buffer to i64".into(),
+        syscall::call("%ignored", SYS_WRITE, &["%fd", "%address", "%length"]),
+        "  ret void".into(),
+        "}".into(),
+        "define linkonce_odr i64 @__foolang_strlen(i8* %text) nounwind {".into(),
+        "entry:".into(),
+        "  %index = alloca i64".into(),
+        "  store i64 0, i64* %index".into(),
+        "  br label %scan".into(),
+        "scan:".into(),
+        "  %i = load i64, i64* %index".into(),
+        "  %pointer = getelementptr i8, i8* %text, i64 %i".into(),
+        "  %character = load i8, i8* %pointer".into(),
+        "  %end = icmp eq i8 %character, 0".into(),
+        "  br i1 %end, label %exit, label %next".into(),
+        "next:".into(),
+        "  %next_i = add i64 %i, 1".into(),
+        "  store i64 %next_i, i64* %index".into(),
+        "  br label %scan".into(),
+        "exit:".into(),
+        "  ret i64 %i".into(),
+        "}".into(),
+        "define linkonce_odr void @__foolang_print_str(i8* %text) nounwind {".into(),
+        "entry:".into(),
+        "  %length = call i64 @__foolang_strlen(i8* %text)".into(),
+        format!(
+            "  call void @__foolang_write(i64 {}, i8* %text, i64 %length)",
+            STDOUT
+        ),
+        "  ret void".into(),
+        "}".into(),
+        "define linkonce_odr void @__foolang_println_str(i8* %text) nounwind {".into(),
+        "entry:".into(),
+        "  call void @__foolang_print_str(i8* %text)".into(),
+        "  %newline = getelementptr [1 x i8], [1 x i8]* @__foolang_newline, i64 0, i64 0".into(),
+        format!(
+            "  call void @__foolang_write(i64 {}, i8* %newline, i64 1)",
+            STDOUT
+        ),
+        "  ret void".into(),
+        "}".into(),
+        "define linkonce_odr void @__foolang_print_i64(i64 %value) nounwind {".into(),
+        "entry:".into(),
+        "  %buffer = alloca [24 x i8]".into(),
+        "  %pointer = getelementptr [24 x i8], [24 x i8]* %buffer, i64 0, i64 0".into(),
+        "  %length = call i64 @__foolang_format_i64(i8* %pointer, i64 %value)".into(),
+        format!(
+            "  call void @__foolang_write(i64 {}, i8* %pointer, i64 %length)",
+            STDOUT
+        ),
+        "  ret void".into(),
+        "}".into(),
+        "define linkonce_odr void @__foolang_println_i64(i64 %value) nounwind {".into(),
+        "entry:".into(),
+        "  %buffer = alloca [24 x i8]".into(),
+        "  %pointer = getelementptr [24 x i8], [24 x i8]* %buffer, i64 0, i64 0".into(),
+        "  %length = call i64 @__foolang_format_i64(i8* %pointer, i64 %value)".into(),
+        "  %end = getelementptr i8, i8* %pointer, i64 %length".into(),
+        "  store i8 10, i8* %end".into(),
+        "  %line_length = add i64 %length, 1".into(),
+        format!(
+            "  call void @__foolang_write(i64 {}, i8* %pointer, i64 %line_length)",
+            STDOUT
+        ),
+        "  ret void".into(),
+        "}".into(),
+    ]
+}
@@ -0,0 +1,90 @@
+use super::syscall::{self, SYS_MMAP};
+
+// mmap으로 한 번에 확보하는 최소 힙 크기 (1MiB)
+pub const HEAP_CHUNK_SIZE: i64 = 1 << 20;
+
+// 모든 할당은 16바이트 단위로 정렬합니다.
+pub const HEAP_ALIGNMENT: i64 = 16;
+
+const PROT_READ_WRITE: i64 = 0x3;
+const MAP_PRIVATE_ANONYMOUS: i64 = 0x22;
+
+// alloc/free 내장 함수가 호출하는 런타임 함수를 생성합니다.
+pub(crate) fn generate(no_std: bool) -> Vec<String> {
+    if no_std {
+        generate_bump_allocator()
+    } else {
+        vec![
+            "declare noalias i8* @malloc(i64) nounwind".into(),
+            "declare void @free(i8*) nounwind".into(),
+            "define linkonce_odr i8* @__foolang_alloc(i64 %size) nounwind {".into(),
+            "entry:".into(),
+            "  %pointer = call i8* @malloc(i64 %size)".into(),
+            "  ret i8* %pointer".into(),
+            "}".into(),
+            "define linkonce_odr void @__foolang_free(i8* %pointer) nounwind {".into(),
+            "entry:".into(),
+            "  call void @free(i8* %pointer)".into(),
+            "  ret void".into(),
+            "}".into(),
+        ]
+    }
+}
+
+// libc 없이 mmap으로 받은 영역을 앞에서부터 잘라 쓰는 bump allocator입니다.
+// 남은 영역이 부족하면 새 영역을 받으며, free는 아무것도 하지 않습니다.
+fn generate_bump_allocator() -> Vec<String> {
+    vec![
+        "@__foolang_heap_cursor = linkonce_odr global i64 0".into(),
+        "@__foolang_heap_end = linkonce_odr global i64 0".into(),
+        "define linkonce_odr i8* @__foolang_alloc(i64 %size) nounwind {".into(),
+        "entry:".into(),
+        format!("  %padded = add i64 %size, {}", HEAP_ALIGNMENT - 1),
+        format!("  %aligned = and i64 %padded, {}", -HEAP_ALIGNMENT),
+        "  %cursor = load i64, i64* @__foolang_heap_cursor".into(),
+        "  %end = load i64, i64* @__foolang_heap_end".into(),
+        "  %next = add i64 %cursor, %aligned".into(),
+        "  %fits = icmp ule i64 %next, %end".into(),
+        "  %initialized = icmp ne i64 %cursor, 0".into(),
+        "  %usable = and i1 %fits, %initialized".into(),
+        "  br i1 %usable, label %bump, label %grow".into(),
+        "grow:".into(),
+        format!("  %small = icmp ult i64 %aligned, {}", HEAP_CHUNK_SIZE),
+        format!(
+            "  %chunk = select i1 %small, i64 {}, i64 %aligned",
+            HEAP_CHUNK_SIZE
+        ),
+        syscall::call(
+            "%mapped",
+            SYS_MMAP,
+            &[
+                "0",
+                "%chunk",
+                &PROT_READ_WRITE.to_string(),
+                &MAP_PRIVATE_ANONYMOUS.to_string(),
+                "-1",
+                "0",
+            ],
+        ),
+        // 실패하면 -4095..-1 범위의 errno가 반환됩니다.
+        "  %failed = icmp ugt i64 %mapped, -4096".into(),
+        "  br i1 %failed, label %out_of_memory, label %mapped_chunk".into(),
+        "out_of_memory:".into(),
+        "  ret i8* null".into(),
+        "mapped_chunk:".into(),
+        "  %chunk_end = add i64 %mapped, %chunk".into(),
+        "  store i64 %chunk_end, i64* @__foolang_heap_end".into(),
+        "  br label %bump".into(),
+        "bump:".into(),
+        "  %base = phi i64 [ %cursor, %entry ], [ %mapped, %mapped_chunk ]".into(),
+        "  %new_cursor = add i64 %base, %aligned".into(),
+        "  store i64 %new_cursor, i64* @__foolang_heap_cursor".into(),
+        "  %pointer = inttoptr i64 %base to i8*".into(),
+        "  ret i8* %pointer".into(),
+        "}".into(),
+        "define linkonce_odr void @__foolang_free(i8* %pointer) nounwind {".into(),
+        "entry:".into(),
+        "  ret void".into(),
+        "}".into(),
+    ]
+}
@@ -0,0 +1,66 @@
+pub(crate) mod args;
+pub(crate) mod builtin;
+pub(crate) mod format;
+pub(crate) mod input;
+pub(crate) mod io;
+pub(crate) mod memory;
+pub(crate) mod panic;
+pub(crate) mod startup;
+pub(crate) mod string;
+pub(crate) mod syscall;
+
+pub(crate) mod test;
+
+// 컴파일된 프로그램에 함께 포함되는 런타임 코드를 생성합니다.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Runtime {
+    no_std: bool,
+    entry: bool,
+}
+
+impl Runtime {
+    pub fn new() -> Self {
+        Self {
+            no_std: false,
+            entry: true,
+        }
+    }
+
+    // libc 없이 시스템 콜만으로 동작하는 런타임을 생성할지 여부입니다.
+    pub fn set_no_std(&mut self, no_std: bool) {
+        self.no_std = no_std;
+    }
+
+    // 프로세스 시작 코드(_start)를 포함할지 여부입니다. 여러 모듈을 링크할 때는 진입점 모듈 하나에만 포함합니다.
+    // 나머지 런타임 코드는 linkonce_odr이므로 모든 모듈에 들어가도 링크할 때 하나로 합쳐집니다.
+    pub fn set_entry(&mut self, entry: bool) {
+        self.entry = entry;
+    }
+}
+
+impl Default for Runtime {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Runtime {
+    pub fn generate(&self) -> Vec<String> {
+        let mut codes = vec![];
+
+        if self.no_std {
+            codes.append(&mut syscall::generate());
+        }
+
+        codes.append(&mut format::generate());
+        codes.append(&mut startup::generate(self.no_std, self.entry));
+        codes.append(&mut io::generate(self.no_std));
+        codes.append(&mut memory::generate(self.no_std));
+        codes.append(&mut string::generate(self.no_std));
+        codes.append(&mut input::generate(self.no_std));
+        codes.append(&mut args::generate());
+        codes.append(&mut panic::generate());
+
+        codes
+    }
+}
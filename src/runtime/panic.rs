@@ -0,0 +1,70 @@
+use super::{io::STDERR, string::STRING_TYPE};
+
+// panic으로 종료할 때의 프로세스 종료 코드
+pub const PANIC_EXIT_CODE: i64 = 101;
+
+// panic/assert 내장 함수와 컴파일러가 삽입하는 검사에서 호출하는 런타임 함수를 생성합니다.
+// "panic at <file>:<line>:<column>: <message>" 형식으로 stderr에 출력한 뒤 종료합니다.
+pub(crate) fn generate() -> Vec<String> {
+    vec![
+        r#"@__foolang_panic_prefix = linkonce_odr unnamed_addr constant [9 x i8] c"panic at ""#
+            .into(),
+        r#"@__foolang_panic_colon = linkonce_odr unnamed_addr constant [1 x i8] c":""#.into(),
+        r#"@__foolang_panic_separator = linkonce_odr unnamed_addr constant [2 x i8] c": ""#.into(),
+        r#"@__foolang_panic_newline = linkonce_odr unnamed_addr constant [1 x i8] c"\0A""#.into(),
+        format!(
+            "define linkonce_odr void @__foolang_panic({0} %message, {0} %file, i64 %line, i64 %column) noreturn nounwind {{",
+            STRING_TYPE
+        ),
+        "entry:".into(),
+        "  %number = alloca [24 x i8]".into(),
+        "  %number_pointer = getelementptr [24 x i8], [24 x i8]* %number, i64 0, i64 0".into(),
+        "  %prefix = getelementptr [9 x i8], [9 x i8]* @__foolang_panic_prefix, i64 0, i64 0".into(),
+        "  %colon = getelementptr [1 x i8], [1 x i8]* @__foolang_panic_colon, i64 0, i64 0".into(),
+        "  %separator = getelementptr [2 x i8], [2 x i8]* @__foolang_panic_separator, i64 0, i64 0"
+            .into(),
+        "  %newline = getelementptr [1 x i8], [1 x i8]* @__foolang_panic_newline, i64 0, i64 0"
+            .into(),
+        format!("  %file_pointer = extractvalue {} %file, 0", STRING_TYPE),
+        format!("  %file_length = extractvalue {} %file, 1", STRING_TYPE),
+        format!("  %message_pointer = extractvalue {} %message, 0", STRING_TYPE),
+        format!("  %message_length = extractvalue {} %message, 1", STRING_TYPE),
+        write("%prefix", "9"),
+        write("%file_pointer", "%file_length"),
+        write("%colon", "1"),
+        "  %line_length = call i64 @__foolang_format_i64(i8* %number_pointer, i64 %line)".into(),
+        write("%number_pointer", "%line_length"),
+        write("%colon", "1"),
+        "  %column_length = call i64 @__foolang_format_i64(i8* %number_pointer, i64 %column)"
+            .into(),
+        write("%number_pointer", "%column_length"),
+        write("%separator", "2"),
+        write("%message_pointer", "%message_length"),
+        write("%newline", "1"),
+        format!("  call void @__foolang_exit(i64 {})", PANIC_EXIT_CODE),
+        "  unreachable".into(),
+        "}".into(),
+        format!(
+            "define linkonce_odr void @__foolang_assert(i1 %condition, {0} %message, {0} %file, i64 %line, i64 %column) nounwind {{",
+            STRING_TYPE
+        ),
+        "entry:".into(),
+        "  br i1 %condition, label %passed, label %failed".into(),
+        "failed:".into(),
+        format!(
+            "  call void @__foolang_panic({0} %message, {0} %file, i64 %line, i64 %column)",
+            STRING_TYPE
+        ),
+        "  unreachable".into(),
+        "passed:".into(),
+        "  ret void".into(),
+        "}".into(),
+    ]
+}
+
+fn write(pointer: &str, length: &str) -> String {
+    format!(
+        "  call void @__foolang_write(i64 {}, i8* {}, i64 {})",
+        STDERR, pointer, length
+    )
+}
@@ -0,0 +1,73 @@
+use super::{
+    args,
+    syscall::{self, SYS_EXIT},
+};
+
+// codegen이 생성하는 프로그램 진입점의 이름입니다. 인자를 받지 않고 종료 코드를 i64로 반환합니다.
+pub const ENTRY_FUNCTION: &str = "__foolang_main";
+
+// 프로세스 시작과 종료를 담당하는 런타임 코드를 생성합니다.
+// no_std일 경우 libc의 crt1 대신 직접 _start를 정의하고, main의 반환값으로 exit 시스템 콜을 호출합니다.
+// _start는 entry일 때만 정의합니다.
+pub(crate) fn generate(no_std: bool, entry: bool) -> Vec<String> {
+    let mut codes = vec![];
+
+    if no_std && entry {
+        // 커널이 넘겨준 스택에서 argc, argv를 꺼내고 16바이트로 정렬한 뒤 __foolang_start를 호출합니다.
+        codes.append(&mut vec![
+            r#"module asm ".globl _start""#.into(),
+            r#"module asm "_start:""#.into(),
+            r#"module asm "    xor %rbp, %rbp""#.into(),
+            r#"module asm "    mov (%rsp), %rdi""#.into(),
+            r#"module asm "    lea 8(%rsp), %rsi""#.into(),
+            r#"module asm "    and $-16, %rsp""#.into(),
+            r#"module asm "    call __foolang_start""#.into(),
+            r#"module asm "    hlt""#.into(),
+            "define void @__foolang_start(i64 %argc, i8** %argv) noreturn nounwind {".into(),
+            "entry:".into(),
+            "  %argc32 = trunc i64 %argc to i32".into(),
+            "  %status = call i32 @main(i32 %argc32, i8** %argv)".into(),
+            "  %code = sext i32 %status to i64".into(),
+            "  call void @__foolang_exit(i64 %code)".into(),
+            "  unreachable".into(),
+            "}".into(),
+        ]);
+    }
+
+    if no_std {
+        codes.append(&mut vec![
+            "define linkonce_odr void @__foolang_exit(i64 %code) noreturn nounwind {".into(),
+            "entry:".into(),
+            syscall::call("%ignored", SYS_EXIT, &["%code"]),
+            "  unreachable".into(),
+            "}".into(),
+        ]);
+    } else {
+        codes.append(&mut vec![
+            "declare void @exit(i32) noreturn nounwind".into(),
+            "define linkonce_odr void @__foolang_exit(i64 %code) noreturn nounwind {".into(),
+            "entry:".into(),
+            "  %code32 = trunc i64 %code to i32".into(),
+            "  call void @exit(i32 %code32)".into(),
+            "  unreachable".into(),
+            "}".into(),
+        ]);
+    }
+
+    codes
+}
+
+// C ABI의 main을 정의하고 프로그램 진입점을 호출합니다.
+// 진입점의 반환값은 libc로 링크하면 main의 반환값으로, no_std에서는 __foolang_start를 거쳐 exit 시스템 콜의 인자로 전달됩니다.
+// 프로세스 종료 코드는 하위 8비트만 남으므로 -1은 255가 됩니다.
+pub(crate) fn generate_entry() -> Vec<String> {
+    vec![
+        "define i32 @main(i32 %argc, i8** %argv) nounwind {".into(),
+        "entry:".into(),
+        args::initialize(),
+        format!("  %status = call i64 @{}()", ENTRY_FUNCTION),
+        "  %status32 = trunc i64 %status to i32".into(),
+        "  ret i32 %status32".into(),
+        "}".into(),
+    ]
+}
@@ -0,0 +1,305 @@
+use super::io::STDOUT;
+
+// 런타임에서 사용하는 문자열 표현입니다. (데이터 포인터, 바이트 길이)
+// 널 종료를 가정하지 않으므로 문자열 중간에 0이 있어도 그대로 다룹니다.
+pub const STRING_TYPE: &str = "{ i8*, i64 }";
+
+// 문자열 길이, 연결, 비교, 출력 런타임 함수를 생성합니다.
+pub(crate) fn generate(no_std: bool) -> Vec<String> {
+    let mut codes = vec![];
+
+    codes.append(&mut generate_from_cstr(no_std));
+    codes.append(&mut generate_copy());
+    codes.append(&mut generate_length());
+    codes.append(&mut generate_concat());
+    codes.append(&mut generate_compare());
+
+    if no_std {
+        codes.append(&mut generate_syscall_print());
+    } else {
+        codes.append(&mut generate_libc_print());
+    }
+
+    codes
+}
+
+// 여러 모듈에서 같은 내용으로 만드는 문자열 리터럴 상수의 이름입니다. 내용의 길이와 FNV-1a 해시로 정합니다.
+pub(crate) fn shared_literal_name(text: &str) -> String {
+    let hash = text.bytes().fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0000_0100_0000_01b3)
+    });
+
+    format!("__foolang.str.{}.{:016x}", text.len(), hash)
+}
+
+// 문자열 리터럴을 담는 전역 상수를 생성합니다. 모듈마다 생긴 같은 이름의 상수를 링커가 comdat 그룹 하나로 합칩니다.
+// 그래서 여러 파일에서 같은 문자열을 써도 실행 파일의 .rodata에는 한 번만 들어갑니다.
+pub(crate) fn shared_literal_global(name: &str, text: &str) -> Vec<String> {
+    vec![
+        format!("${} = comdat any", name),
+        format!(
+            "@{} = linkonce_odr unnamed_addr constant [{} x i8] c\"{}\", comdat",
+            name,
+            text.len(),
+            escape(text)
+        ),
+    ]
+}
+
+// 문자열 리터럴 전역 상수를 가리키는 문자열 상수 표현식입니다. (타입 제외)
+pub(crate) fn literal_constant(name: &str, text: &str) -> String {
+    format!(
+        "{{ i8* getelementptr inbounds ([{length} x i8], [{length} x i8]* @{}, i64 0, i64 0), i64 {length} }}",
+        name,
+        length = text.len()
+    )
+}
+
+// LLVM-IR 문자열 상수 문법에 맞게 출력 가능한 ASCII 이외의 바이트를 \XX로 바꿉니다.
+pub(crate) fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for byte in text.bytes() {
+        match byte {
+            b'"' | b'\\' => escaped.push_str(&format!("\\{:02X}", byte)),
+            0x20..=0x7E => escaped.push(byte as char),
+            _ => escaped.push_str(&format!("\\{:02X}", byte)),
+        }
+    }
+
+    escaped
+}
+
+// 널 종료 문자열을 런타임 문자열로 변환합니다. (main의 argv 등)
+fn generate_from_cstr(no_std: bool) -> Vec<String> {
+    let mut codes = vec![];
+
+    let strlen = if no_std {
+        "__foolang_strlen"
+    } else {
+        codes.push("declare i64 @strlen(i8*) nounwind".into());
+        "strlen"
+    };
+
+    codes.append(&mut vec![
+        format!(
+            "define linkonce_odr {} @__foolang_string_from_cstr(i8* %text) nounwind {{",
+            STRING_TYPE
+        ),
+        "entry:".into(),
+        format!("  %length = call i64 @{}(i8* %text)", strlen),
+        format!(
+            "  %with_pointer = insertvalue {} undef, i8* %text, 0",
+            STRING_TYPE
+        ),
+        format!(
+            "  %string = insertvalue {} %with_pointer, i64 %length, 1",
+            STRING_TYPE
+        ),
+        format!("  ret {} %string", STRING_TYPE),
+        "}".into(),
+    ]);
+
+    codes
+}
+
+// libc의 memcpy 없이 바이트 단위로 복사합니다.
+fn generate_copy() -> Vec<String> {
+    vec![
+        "define linkonce_odr void @__foolang_copy(i8* %destination, i8* %source, i64 %length) nounwind {"
+            .into(),
+        "entry:".into(),
+        "  %index = alloca i64".into(),
+        "  store i64 0, i64* %index".into(),
+        "  br label %check".into(),
+        "check:".into(),
+        "  %i = load i64, i64* %index".into(),
+        "  %more = icmp ult i64 %i, %length".into(),
+        "  br i1 %more, label %body, label %exit".into(),
+        "body:".into(),
+        "  %from = getelementptr i8, i8* %source, i64 %i".into(),
+        "  %to = getelementptr i8, i8* %destination, i64 %i".into(),
+        "  %byte = load i8, i8* %from".into(),
+        "  store i8 %byte, i8* %to".into(),
+        "  %next_i = add i64 %i, 1".into(),
+        "  store i64 %next_i, i64* %index".into(),
+        "  br label %check".into(),
+        "exit:".into(),
+        "  ret void".into(),
+        "}".into(),
+    ]
+}
+
+// 문자열의 바이트 길이입니다. (len)
+fn generate_length() -> Vec<String> {
+    vec![
+        format!(
+            "define linkonce_odr i64 @__foolang_string_length({} %value) nounwind {{",
+            STRING_TYPE
+        ),
+        "entry:".into(),
+        format!("  %length = extractvalue {} %value, 1", STRING_TYPE),
+        "  ret i64 %length".into(),
+        "}".into(),
+    ]
+}
+
+// 두 문자열을 이어붙인 새 문자열을 힙에 할당해서 반환합니다.
+fn generate_concat() -> Vec<String> {
+    vec![
+        format!(
+            "define linkonce_odr {0} @__foolang_string_concat({0} %left, {0} %right) nounwind {{",
+            STRING_TYPE
+        ),
+        "entry:".into(),
+        format!("  %left_pointer = extractvalue {} %left, 0", STRING_TYPE),
+        format!("  %left_length = extractvalue {} %left, 1", STRING_TYPE),
+        format!("  %right_pointer = extractvalue {} %right, 0", STRING_TYPE),
+        format!("  %right_length = extractvalue {} %right, 1", STRING_TYPE),
+        "  %length = add i64 %left_length, %right_length".into(),
+        "  %buffer = call i8* @__foolang_alloc(i64 %length)".into(),
+        "  call void @__foolang_copy(i8* %buffer, i8* %left_pointer, i64 %left_length)".into(),
+        "  %middle = getelementptr i8, i8* %buffer, i64 %left_length".into(),
+        "  call void @__foolang_copy(i8* %middle, i8* %right_pointer, i64 %right_length)".into(),
+        format!(
+            "  %with_pointer = insertvalue {} undef, i8* %buffer, 0",
+            STRING_TYPE
+        ),
+        format!(
+            "  %string = insertvalue {} %with_pointer, i64 %length, 1",
+            STRING_TYPE
+        ),
+        format!("  ret {} %string", STRING_TYPE),
+        "}".into(),
+    ]
+}
+
+// 바이트 단위 사전순 비교입니다. left가 작으면 -1, 같으면 0, 크면 1을 반환합니다.
+fn generate_compare() -> Vec<String> {
+    vec![
+        format!(
+            "define linkonce_odr i64 @__foolang_string_compare({0} %left, {0} %right) nounwind {{",
+            STRING_TYPE
+        ),
+        "entry:".into(),
+        format!("  %left_pointer = extractvalue {} %left, 0", STRING_TYPE),
+        format!("  %left_length = extractvalue {} %left, 1", STRING_TYPE),
+        format!("  %right_pointer = extractvalue {} %right, 0", STRING_TYPE),
+        format!("  %right_length = extractvalue {} %right, 1", STRING_TYPE),
+        "  %left_shorter = icmp ult i64 %left_length, %right_length".into(),
+        "  %common = select i1 %left_shorter, i64 %left_length, i64 %right_length".into(),
+        "  %index = alloca i64".into(),
+        "  store i64 0, i64* %index".into(),
+        "  br label %check".into(),
+        "check:".into(),
+        "  %i = load i64, i64* %index".into(),
+        "  %more = icmp ult i64 %i, %common".into(),
+        "  br i1 %more, label %body, label %lengths".into(),
+        "body:".into(),
+        "  %left_at = getelementptr i8, i8* %left_pointer, i64 %i".into(),
+        "  %right_at = getelementptr i8, i8* %right_pointer, i64 %i".into(),
+        "  %left_byte = load i8, i8* %left_at".into(),
+        "  %right_byte = load i8, i8* %right_at".into(),
+        "  %same = icmp eq i8 %left_byte, %right_byte".into(),
+        "  br i1 %same, label %next, label %differ".into(),
+        "next:".into(),
+        "  %next_i = add i64 %i, 1".into(),
+        "  store i64 %next_i, i64* %index".into(),
+        "  br label %check".into(),
+        "differ:".into(),
+        "  %byte_less = icmp ult i8 %left_byte, %right_byte".into(),
+        "  %byte_order = select i1 %byte_less, i64 -1, i64 1".into(),
+        "  ret i64 %byte_order".into(),
+        // 공통 부분이 같으면 짧은 쪽이 앞섭니다.
+        "lengths:".into(),
+        "  %same_length = icmp eq i64 %left_length, %right_length".into(),
+        "  %length_order = select i1 %left_shorter, i64 -1, i64 1".into(),
+        "  %order = select i1 %same_length, i64 0, i64 %length_order".into(),
+        "  ret i64 %order".into(),
+        "}".into(),
+        format!(
+            "define linkonce_odr i1 @__foolang_string_equal({0} %left, {0} %right) nounwind {{",
+            STRING_TYPE
+        ),
+        "entry:".into(),
+        format!(
+            "  %order = call i64 @__foolang_string_compare({0} %left, {0} %right)",
+            STRING_TYPE
+        ),
+        "  %equal = icmp eq i64 %order, 0".into(),
+        "  ret i1 %equal".into(),
+        "}".into(),
+    ]
+}
+
+// 길이를 알고 있으므로 printf의 %.*s로 출력합니다.
+fn generate_libc_print() -> Vec<String> {
+    let mut codes = vec![
+        r#"@__foolang_printf_string = linkonce_odr unnamed_addr constant [5 x i8] c"%.*s\00""#
+            .into(),
+        r#"@__foolang_printf_string_line = linkonce_odr unnamed_addr constant [6 x i8] c"%.*s\0A\00""#
+            .into(),
+    ];
+
+    for (name, format, format_length) in [
+        ("print_string", "printf_string", 5),
+        ("println_string", "printf_string_line", 6),
+    ] {
+        codes.append(&mut vec![
+            format!(
+                "define linkonce_odr void @__foolang_{}({} %value) nounwind {{",
+                name, STRING_TYPE
+            ),
+            "entry:".into(),
+            format!("  %pointer = extractvalue {} %value, 0", STRING_TYPE),
+            format!("  %length = extractvalue {} %value, 1", STRING_TYPE),
+            "  %length32 = trunc i64 %length to i32".into(),
+            format!(
+                "  %format = getelementptr [{length} x i8], [{length} x i8]* @__foolang_{}, i64 0, i64 0",
+                format,
+                length = format_length
+            ),
+            "  %ignored = call i32 (i8*, ...) @printf(i8* %format, i32 %length32, i8* %pointer)"
+                .into(),
+            "  ret void".into(),
+            "}".into(),
+        ]);
+    }
+
+    codes
+}
+
+// write 시스템 콜로 길이만큼 그대로 출력합니다.
+fn generate_syscall_print() -> Vec<String> {
+    vec![
+        format!(
+            "define linkonce_odr void @__foolang_print_string({} %value) nounwind {{",
+            STRING_TYPE
+        ),
+        "entry:".into(),
+        format!("  %pointer = extractvalue {} %value, 0", STRING_TYPE),
+        format!("  %length = extractvalue {} %value, 1", STRING_TYPE),
+        format!(
+            "  call void @__foolang_write(i64 {}, i8* %pointer, i64 %length)",
+            STDOUT
+        ),
+        "  ret void".into(),
+        "}".into(),
+        format!(
+            "define linkonce_odr void @__foolang_println_string({} %value) nounwind {{",
+            STRING_TYPE
+        ),
+        "entry:".into(),
+        format!(
+            "  call void @__foolang_print_string({} %value)",
+            STRING_TYPE
+        ),
+        "  %newline = getelementptr [1 x i8], [1 x i8]* @__foolang_newline, i64 0, i64 0".into(),
+        format!(
+            "  call void @__foolang_write(i64 {}, i8* %newline, i64 1)",
+            STDOUT
+        ),
+        "  ret void".into(),
+        "}".into(),
+    ]
+}
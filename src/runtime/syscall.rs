@@ -0,0 +1,31 @@
+// linux x86_64 시스템 콜 번호
+pub const SYS_READ: i64 = 0;
+pub const SYS_WRITE: i64 = 1;
+pub const SYS_MMAP: i64 = 9;
+pub const SYS_EXIT: i64 = 60;
+
+// 모든 시스템 콜은 6개 인자를 받는 이 함수를 통해 호출합니다. 쓰지 않는 인자는 0으로 채웁니다.
+pub(crate) fn generate() -> Vec<String> {
+    vec![
+        "define linkonce_odr i64 @__foolang_syscall(i64 %number, i64 %a1, i64 %a2, i64 %a3, i64 %a4, i64 %a5, i64 %a6) nounwind {".into(),
+        "entry:".into(),
+        r#"  %result = call i64 asm sideeffect "syscall", "={rax},{rax},{rdi},{rsi},{rdx},{r10},{r8},{r9},~{rcx},~{r11},~{memory}"(i64 %number, i64 %a1, i64 %a2, i64 %a3, i64 %a4, i64 %a5, i64 %a6)"#.into(),
+        "  ret i64 %result".into(),
+        "}".into(),
+    ]
+}
+
+// 시스템 콜 호출 한 줄을 생성합니다.
+pub(crate) fn call(result: &str, number: i64, arguments: &[&str]) -> String {
+    let mut operands = vec![format!("i64 {}", number)];
+
+    for index in 0..6 {
+        operands.push(format!("i64 {}", arguments.get(index).unwrap_or(&"0")));
+    }
+
+    format!(
+        "  {} = call i64 @__foolang_syscall({})",
+        result,
+        operands.join(", ")
+    )
+}
@@ -0,0 +1,61 @@
+#![cfg(test)]
+
+use crate::{
+    runtime::builtin::Builtin,
+    utils::testing::{run_llvm_ir, runtime_program},
+};
+
+// 인자 개수와 모든 인자, 범위를 벗어난 인자를 차례로 출력합니다.
+fn print_arguments(no_std: bool) {
+    let codes = runtime_program(
+        no_std,
+        &[],
+        &[
+            "  %count = call i64 @__foolang_args_count()",
+            "  call void @__foolang_println_i64(i64 %count)",
+            "  %index = alloca i64",
+            "  store i64 1, i64* %index",
+            "  br label %check",
+            "check:",
+            "  %i = load i64, i64* %index",
+            "  %more = icmp ule i64 %i, %count",
+            "  br i1 %more, label %body, label %exit",
+            "body:",
+            "  %argument = call { i8*, i64 } @__foolang_arg(i64 %i)",
+            "  call void @__foolang_println_string({ i8*, i64 } %argument)",
+            "  %next_i = add i64 %i, 1",
+            "  store i64 %next_i, i64* %index",
+            "  br label %check",
+            "exit:",
+            "  ret i32 0",
+        ],
+    );
+
+    if let Some(output) = run_llvm_ir(&codes, no_std, &["first", "두번째", ""]) {
+        assert_eq!(
+            String::from_utf8_lossy(&output.stdout),
+            "4\nfirst\n두번째\n\n\n"
+        );
+    }
+}
+
+#[test]
+pub fn arguments_libc() {
+    print_arguments(false);
+}
+
+#[test]
+pub fn arguments_no_std() {
+    print_arguments(true);
+}
+
+#[test]
+pub fn builtin_lookup() {
+    assert_eq!(Builtin::from_name("args_count"), Some(Builtin::ArgsCount));
+    assert_eq!(
+        Builtin::ArgsCount.runtime_function(""),
+        Some("__foolang_args_count")
+    );
+    assert_eq!(Builtin::Arg.runtime_function("i64"), Some("__foolang_arg"));
+    assert_eq!(Builtin::Arg.runtime_function("i8*"), None);
+}
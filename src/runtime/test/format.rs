@@ -0,0 +1,81 @@
+#![cfg(test)]
+
+use crate::utils::testing::{run_llvm_ir, runtime_program};
+
+// to_string 계열 함수의 결과를 한 줄씩 출력합니다.
+fn to_strings(no_std: bool) {
+    let values = [
+        ("i64_to_string", "i64", "-1234"),
+        ("i64_to_string", "i64", "-9223372036854775808"),
+        ("u64_to_string", "i64", "-1"),
+        ("hex_to_string", "i64", "0"),
+        ("hex_to_string", "i64", "48879"),
+        ("hex_to_string", "i64", "-1"),
+        ("f64_to_string", "double", "0.0"),
+        ("f64_to_string", "double", "-0.0"),
+        ("f64_to_string", "double", "3.25"),
+        ("f64_to_string", "double", "-2.5e-1"),
+        ("f64_to_string", "double", "1.0e-7"),
+        ("f64_to_string", "double", "9.9999999e0"),
+        ("f64_to_string", "double", "123456789.125"),
+        ("f64_to_string", "double", "1.5e20"),
+        ("f64_to_string", "double", "0x7FF0000000000000"),
+        ("f64_to_string", "double", "0xFFF0000000000000"),
+        ("f64_to_string", "double", "0x7FF8000000000000"),
+    ];
+
+    let mut body = vec![];
+    for (index, (function, parameter, value)) in values.iter().enumerate() {
+        body.push(format!(
+            "  %string{} = call {{ i8*, i64 }} @__foolang_{}({} {})",
+            index, function, parameter, value
+        ));
+        body.push(format!(
+            "  call void @__foolang_println_string({{ i8*, i64 }} %string{})",
+            index
+        ));
+    }
+    body.push("  call void @__foolang_print_f64(double 1.5)".into());
+    body.push("  call void @__foolang_println_f64(double -0.125)".into());
+    body.push("  ret i32 0".into());
+    let body: Vec<&str> = body.iter().map(String::as_str).collect();
+
+    let codes = runtime_program(no_std, &[], &body);
+
+    if let Some(output) = run_llvm_ir(&codes, no_std, &[]) {
+        assert_eq!(
+            String::from_utf8_lossy(&output.stdout),
+            [
+                "-1234",
+                "-9223372036854775808",
+                "18446744073709551615",
+                "0",
+                "beef",
+                "ffffffffffffffff",
+                "0.000000",
+                "-0.000000",
+                "3.250000",
+                "-0.250000",
+                "0.000000",
+                "10.000000",
+                "123456789.125000",
+                "1.500000e20",
+                "inf",
+                "-inf",
+                "NaN",
+                "1.500000-0.125000\n",
+            ]
+            .join("\n")
+        );
+    }
+}
+
+#[test]
+pub fn to_string_libc() {
+    to_strings(false);
+}
+
+#[test]
+pub fn to_string_no_std() {
+    to_strings(true);
+}
@@ -0,0 +1,75 @@
+#![cfg(test)]
+
+use crate::utils::testing::{run_llvm_ir_with_input, runtime_program};
+
+// EOF까지 한 줄씩 읽어서 "[길이]내용" 형식으로 출력합니다.
+fn echo_lines(no_std: bool) {
+    let codes = runtime_program(
+        no_std,
+        &[],
+        &[
+            "  br label %next_line",
+            "next_line:",
+            "  %line = call { i8*, i64 } @__foolang_read_line()",
+            "  %pointer = extractvalue { i8*, i64 } %line, 0",
+            "  %end = icmp eq i8* %pointer, null",
+            "  br i1 %end, label %exit, label %print",
+            "print:",
+            "  %length = extractvalue { i8*, i64 } %line, 1",
+            "  call void @__foolang_print_i64(i64 %length)",
+            "  call void @__foolang_println_string({ i8*, i64 } %line)",
+            "  br label %next_line",
+            "exit:",
+            "  ret i32 0",
+        ],
+    );
+
+    let long_line = "x".repeat(200);
+    let input = format!("hello\n\n한글 줄\n{}\nno newline", long_line);
+
+    if let Some(output) = run_llvm_ir_with_input(&codes, no_std, &[], input.as_bytes()) {
+        assert_eq!(
+            String::from_utf8_lossy(&output.stdout),
+            format!("5hello\n0\n10한글 줄\n200{}\n10no newline\n", long_line)
+        );
+    }
+}
+
+fn read_bytes(no_std: bool) {
+    let codes = runtime_program(
+        no_std,
+        &[],
+        &[
+            "  %buffer = alloca [16 x i8]",
+            "  %pointer = getelementptr [16 x i8], [16 x i8]* %buffer, i64 0, i64 0",
+            "  %count = call i64 @__foolang_read(i64 0, i8* %pointer, i64 4)",
+            "  call void @__foolang_println_i64(i64 %count)",
+            "  call void @__foolang_write(i64 1, i8* %pointer, i64 %count)",
+            "  ret i32 0",
+        ],
+    );
+
+    if let Some(output) = run_llvm_ir_with_input(&codes, no_std, &[], b"abcdefg") {
+        assert_eq!(String::from_utf8_lossy(&output.stdout), "4\nabcd");
+    }
+}
+
+#[test]
+pub fn read_line_libc() {
+    echo_lines(false);
+}
+
+#[test]
+pub fn read_line_no_std() {
+    echo_lines(true);
+}
+
+#[test]
+pub fn read_libc() {
+    read_bytes(false);
+}
+
+#[test]
+pub fn read_no_std() {
+    read_bytes(true);
+}
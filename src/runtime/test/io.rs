@@ -0,0 +1,121 @@
+#![cfg(test)]
+
+use crate::{
+    runtime::builtin::Builtin,
+    utils::testing::{run_llvm_ir, runtime_program},
+};
+
+const HELLO: &str = r#"@hello = private unnamed_addr constant [6 x i8] c"hello\00""#;
+
+fn hello_world(no_std: bool) {
+    let codes = runtime_program(
+        no_std,
+        &[HELLO],
+        &[
+            "  %hello = getelementptr [6 x i8], [6 x i8]* @hello, i64 0, i64 0",
+            "  call void @__foolang_print_str(i8* %hello)",
+            "  call void @__foolang_println_str(i8* %hello)",
+            "  ret i32 0",
+        ],
+    );
+
+    if let Some(output) = run_llvm_ir(&codes, no_std, &[]) {
+        assert_eq!(String::from_utf8_lossy(&output.stdout), "hellohello\n");
+    }
+}
+
+fn integers(no_std: bool) {
+    let codes = runtime_program(
+        no_std,
+        &[],
+        &[
+            "  call void @__foolang_println_i64(i64 0)",
+            "  call void @__foolang_println_i64(i64 1234567890)",
+            "  call void @__foolang_println_i64(i64 -42)",
+            "  call void @__foolang_println_i64(i64 9223372036854775807)",
+            "  call void @__foolang_println_i64(i64 -9223372036854775808)",
+            "  call void @__foolang_print_i64(i64 7)",
+            "  call void @__foolang_println_bool(i1 true)",
+            "  call void @__foolang_print_bool(i1 false)",
+            "  ret i32 0",
+        ],
+    );
+
+    if let Some(output) = run_llvm_ir(&codes, no_std, &[]) {
+        assert_eq!(
+            String::from_utf8_lossy(&output.stdout),
+            "0\n1234567890\n-42\n9223372036854775807\n-9223372036854775808\n7true\nfalse"
+        );
+    }
+}
+
+// 1~4바이트 UTF-8 인코딩의 경계값
+fn chars(no_std: bool) {
+    let codes = runtime_program(
+        no_std,
+        &[],
+        &[
+            "  call void @__foolang_print_char(i32 65)",
+            "  call void @__foolang_println_char(i32 127)",
+            "  call void @__foolang_println_char(i32 233)",
+            "  call void @__foolang_println_char(i32 2047)",
+            "  call void @__foolang_println_char(i32 44032)",
+            "  call void @__foolang_println_char(i32 65535)",
+            "  call void @__foolang_println_char(i32 128512)",
+            "  call void @__foolang_println_char(i32 1114111)",
+            "  ret i32 0",
+        ],
+    );
+
+    if let Some(output) = run_llvm_ir(&codes, no_std, &[]) {
+        assert_eq!(
+            String::from_utf8_lossy(&output.stdout),
+            "A\u{7f}\né\n\u{7ff}\n가\n\u{ffff}\n😀\n\u{10ffff}\n"
+        );
+    }
+}
+
+#[test]
+pub fn hello_world_libc() {
+    hello_world(false);
+}
+
+#[test]
+pub fn hello_world_no_std() {
+    hello_world(true);
+}
+
+#[test]
+pub fn print_integers_libc() {
+    integers(false);
+}
+
+#[test]
+pub fn print_integers_no_std() {
+    integers(true);
+}
+
+#[test]
+pub fn print_chars_libc() {
+    chars(false);
+}
+
+#[test]
+pub fn print_chars_no_std() {
+    chars(true);
+}
+
+#[test]
+pub fn builtin_lookup() {
+    assert_eq!(Builtin::from_name("println"), Some(Builtin::Println));
+    assert_eq!(Builtin::from_name("foo"), None);
+    assert_eq!(
+        Builtin::Print.runtime_function("i64"),
+        Some("__foolang_print_i64")
+    );
+    assert_eq!(
+        Builtin::Println.runtime_function("i32"),
+        Some("__foolang_println_char")
+    );
+    assert_eq!(Builtin::Println.runtime_function("float"), None);
+}
@@ -0,0 +1,77 @@
+#![cfg(test)]
+
+use crate::utils::testing::{run_llvm_ir, runtime_program};
+
+// 할당받은 영역에 값을 쓰고 다시 읽어서 출력합니다.
+fn store_and_load(no_std: bool) {
+    let codes = runtime_program(
+        no_std,
+        &[],
+        &[
+            "  %raw = call i8* @__foolang_alloc(i64 24)",
+            "  %values = bitcast i8* %raw to i64*",
+            "  %second = getelementptr i64, i64* %values, i64 2",
+            "  store i64 1234, i64* %second",
+            "  %other = call i8* @__foolang_alloc(i64 8)",
+            "  %loaded = load i64, i64* %second",
+            "  call void @__foolang_println_i64(i64 %loaded)",
+            "  %raw_address = ptrtoint i8* %raw to i64",
+            "  %other_address = ptrtoint i8* %other to i64",
+            "  %alignment = and i64 %other_address, 15",
+            "  call void @__foolang_println_i64(i64 %alignment)",
+            "  %distinct = icmp ne i64 %raw_address, %other_address",
+            "  call void @__foolang_println_bool(i1 %distinct)",
+            "  call void @__foolang_free(i8* %other)",
+            "  call void @__foolang_free(i8* %raw)",
+            "  ret i32 0",
+        ],
+    );
+
+    if let Some(output) = run_llvm_ir(&codes, no_std, &[]) {
+        assert_eq!(String::from_utf8_lossy(&output.stdout), "1234\n0\ntrue\n");
+    }
+}
+
+#[test]
+pub fn alloc_libc() {
+    store_and_load(false);
+}
+
+#[test]
+pub fn alloc_no_std() {
+    store_and_load(true);
+}
+
+#[test]
+pub fn alloc_no_std_grows() {
+    // 1MiB 청크를 여러 번 넘기고, 청크보다 큰 할당도 처리하는지 확인합니다.
+    let codes = runtime_program(
+        true,
+        &[],
+        &[
+            "  %counter = alloca i64",
+            "  store i64 0, i64* %counter",
+            "  br label %loop",
+            "loop:",
+            "  %i = load i64, i64* %counter",
+            "  %block = call i8* @__foolang_alloc(i64 100000)",
+            "  %last = getelementptr i8, i8* %block, i64 99999",
+            "  store i8 1, i8* %last",
+            "  %next = add i64 %i, 1",
+            "  store i64 %next, i64* %counter",
+            "  %again = icmp ult i64 %next, 50",
+            "  br i1 %again, label %loop, label %large",
+            "large:",
+            "  %huge = call i8* @__foolang_alloc(i64 3000000)",
+            "  %huge_last = getelementptr i8, i8* %huge, i64 2999999",
+            "  store i8 7, i8* %huge_last",
+            "  %value = load i8, i8* %huge_last",
+            "  %status = zext i8 %value to i32",
+            "  ret i32 %status",
+        ],
+    );
+
+    if let Some(output) = run_llvm_ir(&codes, true, &[]) {
+        assert_eq!(output.status.code(), Some(7));
+    }
+}
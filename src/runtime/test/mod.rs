@@ -0,0 +1,8 @@
+pub(crate) mod args;
+pub(crate) mod format;
+pub(crate) mod input;
+pub(crate) mod io;
+pub(crate) mod memory;
+pub(crate) mod panic;
+pub(crate) mod startup;
+pub(crate) mod string;
@@ -0,0 +1,76 @@
+#![cfg(test)]
+
+use crate::{
+    runtime::{panic::PANIC_EXIT_CODE, string::STRING_TYPE},
+    utils::testing::{run_llvm_ir, runtime_program},
+};
+
+use super::string::{literal_global, literal_value};
+
+// condition이 false인 assert를 호출하고, 그 뒤의 출력은 실행되지 않아야 합니다.
+fn failed_assert(no_std: bool) {
+    let globals = [
+        literal_global("message", "index out of bounds"),
+        literal_global("file", "main.foo"),
+    ];
+    let globals: Vec<&str> = globals.iter().map(String::as_str).collect();
+
+    let message = literal_value("message", "index out of bounds");
+    let file = literal_value("file", "main.foo");
+
+    let body = [
+        "  call void @__foolang_print_i64(i64 1)".to_owned(),
+        format!(
+            "  call void @__foolang_assert(i1 true, {}, {}, i64 3, i64 5)",
+            message, file
+        ),
+        format!(
+            "  call void @__foolang_assert(i1 false, {}, {}, i64 12, i64 7)",
+            message, file
+        ),
+        "  call void @__foolang_print_i64(i64 2)".into(),
+        "  ret i32 0".into(),
+    ];
+    let body: Vec<&str> = body.iter().map(String::as_str).collect();
+
+    let codes = runtime_program(no_std, &globals, &body);
+
+    if let Some(output) = run_llvm_ir(&codes, no_std, &[]) {
+        assert_eq!(output.status.code(), Some(PANIC_EXIT_CODE as i32));
+        assert_eq!(String::from_utf8_lossy(&output.stdout), "1");
+        assert_eq!(
+            String::from_utf8_lossy(&output.stderr),
+            "panic at main.foo:12:7: index out of bounds\n"
+        );
+    }
+}
+
+#[test]
+pub fn assert_libc() {
+    failed_assert(false);
+}
+
+#[test]
+pub fn assert_no_std() {
+    failed_assert(true);
+}
+
+#[test]
+pub fn panic_empty_message() {
+    let codes = runtime_program(
+        true,
+        &[],
+        &[
+            &format!(
+                "  call void @__foolang_panic({0} zeroinitializer, {0} zeroinitializer, i64 0, i64 0)",
+                STRING_TYPE
+            ),
+            "  unreachable",
+        ],
+    );
+
+    if let Some(output) = run_llvm_ir(&codes, true, &[]) {
+        assert_eq!(output.status.code(), Some(PANIC_EXIT_CODE as i32));
+        assert_eq!(String::from_utf8_lossy(&output.stderr), "panic at :0:0: \n");
+    }
+}
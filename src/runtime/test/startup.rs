@@ -0,0 +1,108 @@
+#![cfg(test)]
+
+use crate::{
+    codegen::CodeGenerator,
+    runtime::{
+        startup::{generate_entry, ENTRY_FUNCTION},
+        Runtime,
+    },
+    utils::testing::{run_llvm_ir, runtime_program},
+};
+
+#[test]
+pub fn no_std_defines_start() {
+    let mut runtime = Runtime::new();
+    runtime.set_no_std(true);
+
+    let codes = runtime.generate();
+
+    assert!(codes.contains(&r#"module asm ".globl _start""#.to_owned()));
+    assert!(!codes.iter().any(|line| line.contains("@exit(")));
+}
+
+#[test]
+pub fn std_uses_libc_exit() {
+    let codes = Runtime::new().generate();
+
+    assert!(!codes.iter().any(|line| line.contains("_start")));
+    assert!(codes.contains(&"declare void @exit(i32) noreturn nounwind".to_owned()));
+}
+
+#[test]
+pub fn no_std_exit_status_from_main() {
+    let codes = runtime_program(true, &[], &["  ret i32 7"]);
+
+    if let Some(output) = run_llvm_ir(&codes, true, &[]) {
+        assert_eq!(output.status.code(), Some(7));
+    }
+}
+
+#[test]
+pub fn no_std_exit_call() {
+    let codes = runtime_program(
+        true,
+        &[],
+        &["  call void @__foolang_exit(i64 3)", "  unreachable"],
+    );
+
+    if let Some(output) = run_llvm_ir(&codes, true, &[]) {
+        assert_eq!(output.status.code(), Some(3));
+    }
+}
+
+#[test]
+pub fn std_exit_call() {
+    let codes = runtime_program(
+        false,
+        &[],
+        &["  call void @__foolang_exit(i64 3)", "  unreachable"],
+    );
+
+    if let Some(output) = run_llvm_ir(&codes, false, &[]) {
+        assert_eq!(output.status.code(), Some(3));
+    }
+}
+
+// 진입점의 반환값이 종료 코드가 되는지 확인합니다.
+fn exit_status_from_entry(no_std: bool) {
+    for (value, expected) in [(42, 42), (300, 44), (-1, 255)] {
+        let mut runtime = Runtime::new();
+        runtime.set_no_std(no_std);
+
+        let mut codes = runtime.generate();
+        codes.append(&mut generate_entry());
+        codes.append(&mut vec![
+            format!("define i64 @{}() {{", ENTRY_FUNCTION),
+            "entry:".into(),
+            format!("  ret i64 {}", value),
+            "}".into(),
+        ]);
+
+        if let Some(output) = run_llvm_ir(&codes, no_std, &[]) {
+            assert_eq!(output.status.code(), Some(expected));
+        }
+    }
+}
+
+#[test]
+pub fn std_exit_status_from_entry() {
+    exit_status_from_entry(false);
+}
+
+#[test]
+pub fn no_std_exit_status_from_entry() {
+    exit_status_from_entry(true);
+}
+
+#[test]
+pub fn empty_program_exits_successfully() {
+    for no_std in [false, true] {
+        let mut generator = CodeGenerator::new();
+        generator.set_no_std(no_std);
+        let codes = generator.generate().unwrap();
+
+        if let Some(output) = run_llvm_ir(&codes, no_std, &[]) {
+            assert_eq!(output.status.code(), Some(0));
+        }
+    }
+}
@@ -0,0 +1,133 @@
+#![cfg(test)]
+
+use crate::{
+    runtime::string::{escape, literal_constant, STRING_TYPE},
+    utils::testing::{run_llvm_ir, runtime_program},
+};
+
+// 문자열 리터럴을 담는 전역 상수를 생성합니다. 이 모듈 안에서만 보입니다.
+// 코드 생성은 shared_literal_global을 쓰고, 런타임 함수를 직접 호출하는 테스트에서만 씁니다.
+pub(crate) fn literal_global(name: &str, text: &str) -> String {
+    format!(
+        "@{} = private unnamed_addr constant [{} x i8] c\"{}\"",
+        name,
+        text.len(),
+        escape(text)
+    )
+}
+
+// literal_global로 만든 상수를 가리키는 문자열 상수에 타입을 붙인 인자 표현입니다.
+pub(crate) fn literal_value(name: &str, text: &str) -> String {
+    format!("{} {}", STRING_TYPE, literal_constant(name, text))
+}
+
+fn string_operations(no_std: bool) {
+    let globals = [
+        literal_global("foo", "foo"),
+        literal_global("bar", "bar\n\"한글\""),
+        literal_global("food", "food"),
+    ];
+    let globals: Vec<&str> = globals.iter().map(String::as_str).collect();
+
+    let foo = literal_value("foo", "foo");
+    let bar = literal_value("bar", "bar\n\"한글\"");
+    let food = literal_value("food", "food");
+
+    let body = [
+        format!("  call void @__foolang_println_string({})", foo),
+        format!(
+            "  %joined = call {0} @__foolang_string_concat({1}, {2})",
+            STRING_TYPE, foo, bar
+        ),
+        format!(
+            "  call void @__foolang_println_string({} %joined)",
+            STRING_TYPE
+        ),
+        format!(
+            "  %length = call i64 @__foolang_string_length({} %joined)",
+            STRING_TYPE
+        ),
+        "  call void @__foolang_println_i64(i64 %length)".into(),
+        format!(
+            "  %less = call i64 @__foolang_string_compare({}, {})",
+            foo, food
+        ),
+        "  call void @__foolang_println_i64(i64 %less)".into(),
+        format!(
+            "  %greater = call i64 @__foolang_string_compare({}, {})",
+            food, bar
+        ),
+        "  call void @__foolang_println_i64(i64 %greater)".into(),
+        format!(
+            "  %equal = call i1 @__foolang_string_equal({}, {})",
+            foo, foo
+        ),
+        "  call void @__foolang_println_bool(i1 %equal)".into(),
+        format!(
+            "  %different = call i1 @__foolang_string_equal({}, {})",
+            foo, food
+        ),
+        "  call void @__foolang_print_bool(i1 %different)".into(),
+        "  ret i32 0".into(),
+    ];
+    let body: Vec<&str> = body.iter().map(String::as_str).collect();
+
+    let codes = runtime_program(no_std, &globals, &body);
+
+    if let Some(output) = run_llvm_ir(&codes, no_std, &[]) {
+        assert_eq!(
+            String::from_utf8_lossy(&output.stdout),
+            "foo\nfoobar\n\"한글\"\n15\n-1\n1\ntrue\nfalse"
+        );
+    }
+}
+
+fn from_cstr(no_std: bool) {
+    let codes = runtime_program(
+        no_std,
+        &[],
+        &[
+            "  %second = getelementptr i8*, i8** %argv, i64 1",
+            "  %argument = load i8*, i8** %second",
+            "  %string = call { i8*, i64 } @__foolang_string_from_cstr(i8* %argument)",
+            "  call void @__foolang_print_string({ i8*, i64 } %string)",
+            "  ret i32 0",
+        ],
+    );
+
+    if let Some(output) = run_llvm_ir(&codes, no_std, &["hello"]) {
+        assert_eq!(String::from_utf8_lossy(&output.stdout), "hello");
+    }
+}
+
+#[test]
+pub fn string_operations_libc() {
+    string_operations(false);
+}
+
+#[test]
+pub fn string_operations_no_std() {
+    string_operations(true);
+}
+
+#[test]
+pub fn string_from_cstr_libc() {
+    from_cstr(false);
+}
+
+#[test]
+pub fn string_from_cstr_no_std() {
+    from_cstr(true);
+}
+
+#[test]
+pub fn literal_escape() {
+    assert_eq!(
+        literal_global("text", "a\"\\\n"),
+        r#"@text = private unnamed_addr constant [4 x i8] c"a\22\5C\0A""#
+    );
+    assert_eq!(
+        literal_value("text", "é"),
+        "{ i8*, i64 } { i8* getelementptr inbounds ([2 x i8], [2 x i8]* @text, i64 0, i64 0), i64 2 }"
+    );
+}
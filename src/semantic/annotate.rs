@@ -0,0 +1,138 @@
+use crate::{
+    ast::{
+        expression::{literal::LiteralExpression, match_expression::Pattern, Expression},
+        statement::Statement,
+    },
+    codegen::value::ValueType,
+};
+
+use super::SemanticAnalyzer;
+
+impl SemanticAnalyzer {
+    // analyze에서 정한 숫자 리터럴의 타입을 AST에 적습니다.
+    // i32로 정해진 정수 리터럴은 LiteralExpression::Int32, char는 Char, 실수 타입은 Float나 Float32가 되고,
+    // f32로 정해진 실수 리터럴은 LiteralExpression::Float32가 됩니다.
+    // 리터럴은 analyze가 방문한 순서와 같은 순서로 방문해야 합니다.
+    pub fn annotate(&self, statements: &mut [Statement]) {
+        let mut next = 0;
+
+        for statement in statements {
+            self.annotate_statement(statement, &mut next);
+        }
+
+        debug_assert_eq!(next, self.literals.len());
+    }
+
+    fn annotate_statement(&self, statement: &mut Statement, next: &mut usize) {
+        match statement {
+            Statement::Expression(expression) | Statement::Return(expression, _) => {
+                self.annotate_expression(expression, next);
+            }
+            Statement::DefineVariable(variable) => {
+                if let Some(value) = &mut variable.value {
+                    self.annotate_expression(value, next);
+                }
+            }
+            Statement::DefineFunction(function) => self.annotate_block(&mut function.body, next),
+            Statement::If(statement) => {
+                self.annotate_expression(&mut statement.condition, next);
+                self.annotate_block(&mut statement.then_body, next);
+
+                if let Some(else_body) = &mut statement.else_body {
+                    self.annotate_block(else_body, next);
+                }
+            }
+            Statement::While(statement) => {
+                self.annotate_expression(&mut statement.condition, next);
+                self.annotate_block(&mut statement.body, next);
+            }
+            Statement::Loop(statement) => self.annotate_block(&mut statement.body, next),
+            Statement::For(statement) => {
+                self.annotate_expression(&mut statement.start, next);
+                self.annotate_expression(&mut statement.end, next);
+                self.annotate_block(&mut statement.body, next);
+            }
+            Statement::Assign(statement) => self.annotate_expression(&mut statement.value, next),
+            Statement::DefineStruct(_) | Statement::Break(_) | Statement::Continue(_) => {}
+        }
+    }
+
+    fn annotate_block(&self, statements: &mut [Statement], next: &mut usize) {
+        for statement in statements {
+            self.annotate_statement(statement, next);
+        }
+    }
+
+    fn annotate_expression(&self, expression: &mut Expression, next: &mut usize) {
+        match expression {
+            Expression::Literal(literal, _) => self.annotate_literal(literal, next),
+            Expression::Binary(binary) => {
+                self.annotate_expression(&mut binary.lhs, next);
+                self.annotate_expression(&mut binary.rhs, next);
+            }
+            Expression::Unary(unary) => self.annotate_expression(&mut unary.operand, next),
+            Expression::Call(call) => {
+                for argument in &mut call.arguments {
+                    self.annotate_expression(argument, next);
+                }
+            }
+            Expression::Parentheses(parentheses) => {
+                self.annotate_expression(&mut parentheses.expression, next);
+            }
+            Expression::Match(expression) => {
+                self.annotate_expression(&mut expression.subject, next);
+
+                for arm in &mut expression.arms {
+                    if let Pattern::Literal(literal) = &mut arm.pattern {
+                        self.annotate_literal(literal, next);
+                    }
+
+                    self.annotate_expression(&mut arm.body, next);
+                }
+            }
+            Expression::StructLiteral(literal) => {
+                for (_, value) in &mut literal.fields {
+                    self.annotate_expression(value, next);
+                }
+            }
+            Expression::Field(field) => self.annotate_expression(&mut field.object, next),
+            Expression::Cast(cast) => self.annotate_expression(&mut cast.value, next),
+            Expression::Variable(_) | Expression::Comment(..) => {}
+        }
+    }
+
+    fn annotate_literal(&self, literal: &mut LiteralExpression, next: &mut usize) {
+        let resolved = match literal {
+            LiteralExpression::Integer(_) | LiteralExpression::Float(_) => {
+                let variable = self.literals[*next];
+                *next += 1;
+
+                self.type_variables.resolved(variable)
+            }
+            _ => return,
+        };
+
+        match (&*literal, resolved) {
+            // -2147483648의 2147483648은 i32로 표현할 수 없지만, 비트가 같으므로 부호를 붙이면 같은 값이 됩니다.
+            (LiteralExpression::Integer(integer), Some(ValueType::Int32)) => {
+                *literal = LiteralExpression::Int32(*integer as i32)
+            }
+            // char로 정해진 리터럴은 analyze에서 유효한 코드 포인트인지 검사했습니다.
+            (LiteralExpression::Integer(integer), Some(ValueType::Char)) => {
+                if let Some(character) = u32::try_from(*integer).ok().and_then(char::from_u32) {
+                    *literal = LiteralExpression::Char(character);
+                }
+            }
+            (LiteralExpression::Integer(integer), Some(ValueType::Float)) => {
+                *literal = LiteralExpression::Float(*integer as f64)
+            }
+            (LiteralExpression::Integer(integer), Some(ValueType::Float32)) => {
+                *literal = LiteralExpression::Float32(*integer as f32)
+            }
+            (LiteralExpression::Float(float), Some(ValueType::Float32)) => {
+                *literal = LiteralExpression::Float32(*float as f32)
+            }
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,27 @@
+use crate::ast::statement::Statement;
+
+// 문장 목록을 끝까지 실행하지 않고 항상 return으로 빠져나가는지 검사합니다.
+// if는 else까지 모든 가지가 return해야 하고, break가 없는 loop는 끝나지 않으므로 return한 것으로 봅니다.
+// while과 for는 조건에 따라 한 번도 실행하지 않을 수 있으므로 본문은 보지 않습니다.
+pub fn always_returns(statements: &[Statement]) -> bool {
+    statements.iter().any(|statement| match statement {
+        Statement::Return(..) => true,
+        Statement::If(statement) => {
+            always_returns(&statement.then_body)
+                && statement.else_body.as_deref().is_some_and(always_returns)
+        }
+        Statement::Loop(statement) => !breaks(&statement.body),
+        _ => false,
+    })
+}
+
+// 이 반복문을 빠져나가는 break가 있는지 검사합니다. 안쪽 반복문의 break는 그 반복문만 빠져나갑니다.
+fn breaks(statements: &[Statement]) -> bool {
+    statements.iter().any(|statement| match statement {
+        Statement::Break(_) => true,
+        Statement::If(statement) => {
+            breaks(&statement.then_body) || statement.else_body.as_deref().is_some_and(breaks)
+        }
+        _ => false,
+    })
+}
@@ -0,0 +1,160 @@
+use crate::codegen::value::ValueType;
+
+// 의미 분석 중에 계산한 표현식의 타입입니다.
+// 접미사가 없는 숫자 리터럴은 바로 타입을 정하지 않고, 함께 쓰인 값의 타입을 보고 정합니다.
+// 정수 리터럴은 정수, char, 실수 타입이 될 수 있고, 실수 리터럴은 f64나 f32가 됩니다.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Inferred {
+    Known(ValueType),
+    // 아직 정해지지 않은 숫자 타입. TypeVariables의 타입 변수 번호입니다.
+    Number(usize),
+}
+
+impl From<ValueType> for Inferred {
+    fn from(value_type: ValueType) -> Self {
+        Self::Known(value_type)
+    }
+}
+
+// 숫자 타입 변수의 목록입니다.
+// 같은 타입이어야 하는 변수는 하나로 합치고(union-find), 한 번 정해진 타입은 바뀌지 않습니다.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct TypeVariables {
+    parent: Vec<usize>,
+    // 아래 목록은 대표 변수의 것만 의미가 있습니다.
+    resolved: Vec<Option<ValueType>>,
+    // 이 변수로 타입이 정해지는 정수 리터럴 값. 타입이 정해질 때 범위를 검사합니다.
+    literals: Vec<Vec<i64>>,
+    // 실수 리터럴이 있어서 실수 타입만 될 수 있는지 여부
+    float: Vec<bool>,
+    // 비트 연산에 쓰여서 정수 타입만 될 수 있는지 여부
+    integral: Vec<bool>,
+}
+
+impl TypeVariables {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::new();
+    }
+
+    // 정수 리터럴 하나의 타입 변수를 만듭니다.
+    pub fn fresh_integer(&mut self, literal: i64) -> usize {
+        self.fresh(vec![literal], false)
+    }
+
+    // 실수 리터럴 하나의 타입 변수를 만듭니다.
+    pub fn fresh_float(&mut self) -> usize {
+        self.fresh(vec![], true)
+    }
+
+    fn fresh(&mut self, literals: Vec<i64>, float: bool) -> usize {
+        let variable = self.parent.len();
+
+        self.parent.push(variable);
+        self.resolved.push(None);
+        self.literals.push(literals);
+        self.float.push(float);
+        self.integral.push(false);
+
+        variable
+    }
+
+    pub fn find(&self, mut variable: usize) -> usize {
+        while self.parent[variable] != variable {
+            variable = self.parent[variable];
+        }
+
+        variable
+    }
+
+    pub fn resolved(&self, variable: usize) -> Option<ValueType> {
+        self.resolved[self.find(variable)]
+    }
+
+    // 끝까지 정해지지 않았을 때의 타입입니다. 실수 리터럴이 있으면 f64, 아니면 i64입니다.
+    pub fn default_type(&self, variable: usize) -> ValueType {
+        if self.float[self.find(variable)] {
+            ValueType::Float
+        } else {
+            ValueType::Integer
+        }
+    }
+
+    // 변수가 value_type이 될 수 있는지 확인합니다. 리터럴의 범위는 bind에서 검사합니다.
+    pub fn accepts(&self, variable: usize, value_type: ValueType) -> bool {
+        let root = self.find(variable);
+
+        if self.float[root] {
+            value_type.is_float()
+        } else if self.integral[root] {
+            value_type.is_integral()
+        } else {
+            value_type.is_integral() || value_type.is_float()
+        }
+    }
+
+    // 두 변수를 합쳐도 되는지 확인합니다. 정수 리터럴은 실수 타입이 될 수 있지만,
+    // 1 + 1.5처럼 실수 리터럴과 섞어 쓰면 어느 쪽으로 맞출지 알 수 없으므로 합치지 않습니다.
+    pub fn compatible(&self, a: usize, b: usize) -> bool {
+        self.float[self.find(a)] == self.float[self.find(b)]
+    }
+
+    // 비트 연산에 쓰인 변수는 정수 타입만 될 수 있습니다. 실수 리터럴이 있으면 false입니다.
+    pub fn require_integral(&mut self, variable: usize) -> bool {
+        let root = self.find(variable);
+
+        if self.float[root] {
+            return false;
+        }
+
+        self.integral[root] = true;
+        true
+    }
+
+    // 변수의 타입을 정합니다. 그 타입으로 표현할 수 없는 리터럴이 있으면 그 값을 Err로 반환합니다.
+    pub fn bind(&mut self, variable: usize, value_type: ValueType) -> Result<(), i64> {
+        let root = self.find(variable);
+
+        if let Some(literal) = self.literals[root]
+            .iter()
+            .find(|literal| !fits(**literal, value_type))
+        {
+            return Err(*literal);
+        }
+
+        self.resolved[root] = Some(value_type);
+        self.literals[root].clear();
+
+        Ok(())
+    }
+
+    // 타입이 정해지지 않은 두 변수를 합칩니다.
+    pub fn union(&mut self, a: usize, b: usize) -> usize {
+        let (a, b) = (self.find(a), self.find(b));
+
+        if a != b {
+            self.parent[b] = a;
+            let literals = std::mem::take(&mut self.literals[b]);
+            self.literals[a].extend(literals);
+            self.float[a] |= self.float[b];
+            self.integral[a] |= self.integral[b];
+        }
+
+        a
+    }
+}
+
+fn fits(literal: i64, value_type: ValueType) -> bool {
+    match value_type {
+        ValueType::Int32 => i32::try_from(literal).is_ok(),
+        // char는 유효한 유니코드 스칼라 값이어야 합니다.
+        ValueType::Char => u32::try_from(literal)
+            .ok()
+            .and_then(char::from_u32)
+            .is_some(),
+        _ => true,
+    }
+}
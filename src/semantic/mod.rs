@@ -0,0 +1,1337 @@
+pub(crate) mod annotate;
+pub(crate) mod flow;
+pub(crate) mod inference;
+pub mod reference;
+pub(crate) mod scope;
+
+pub(crate) mod test;
+
+use std::collections::HashMap;
+
+use crate::{
+    ast::{
+        expression::{
+            binary::BinaryExpression,
+            call::CallExpression,
+            cast::CastExpression,
+            field::FieldExpression,
+            literal::LiteralExpression,
+            match_expression::{MatchExpression, Pattern},
+            struct_literal::StructLiteralExpression,
+            unary::UnaryExpression,
+            Expression,
+        },
+        operator::{binary::BinaryOperator, unary::UnaryOperator},
+        statement::{
+            assign::AssignStatement, define_function::FunctionDefinitionStatement,
+            for_statement::ForStatement, if_statement::IfStatement, Statement,
+        },
+    },
+    codegen::{function::FunctionSignature, structure::StructTable, value::ValueType},
+    error::all_error::{source_error::SourceError, AllError},
+    runtime::builtin::Builtin,
+    utils::{interner::Symbol, span::Span},
+};
+
+use self::{
+    inference::{Inferred, TypeVariables},
+    reference::{Reference, ReferenceKind, SymbolInfo},
+    scope::{Binding, Scope},
+};
+
+// 코드 생성 전에 AST의 이름과 타입을 검사합니다.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SemanticAnalyzer {
+    source_name: String,
+    // 사용자 정의 함수 이름과 시그니처
+    functions: HashMap<Symbol, FunctionSignature>,
+    // 다른 모듈에 정의된 함수 이름과 시그니처
+    external_functions: HashMap<Symbol, FunctionSignature>,
+    // 이 모듈에서 선언한 구조체
+    structs: StructTable,
+    // 안쪽 스코프가 뒤에 옵니다. 첫 번째는 최상위 스코프입니다.
+    scopes: Vec<Scope>,
+    // 현재 함수 안에서 break, continue를 감싸고 있는 반복문의 개수
+    loop_depth: usize,
+    // 분석 중인 함수의 반환 타입. 최상위 코드는 i64(종료 코드)를 반환합니다.
+    return_type: ValueType,
+    // 컴파일은 계속할 수 있지만 사용자에게 알려야 하는 문제
+    warnings: Vec<SourceError>,
+    // 정수 리터럴과 타입을 생략한 변수의 타입 변수
+    type_variables: TypeVariables,
+    // 방문한 순서대로 정수 리터럴마다 만든 타입 변수
+    literals: Vec<usize>,
+    // 이 모듈에서 정의한 함수의 이름 위치와 매개변수 이름
+    function_definitions: HashMap<Symbol, (Span, Vec<Symbol>)>,
+    // 분석하면서 만난 변수, 함수 이름. 오류가 나면 오류가 난 곳까지만 기록됩니다.
+    references: Vec<Reference>,
+}
+
+impl SemanticAnalyzer {
+    pub fn new() -> Self {
+        Self {
+            source_name: "main.foo".to_owned(),
+            functions: HashMap::new(),
+            external_functions: HashMap::new(),
+            structs: StructTable::new(),
+            scopes: vec![],
+            loop_depth: 0,
+            return_type: ValueType::Integer,
+            warnings: vec![],
+            type_variables: TypeVariables::new(),
+            literals: vec![],
+            function_definitions: HashMap::new(),
+            references: vec![],
+        }
+    }
+
+    // 오류 메시지에 표시할 소스 파일 이름입니다.
+    pub fn set_source_name(&mut self, source_name: &str) {
+        self.source_name = source_name.to_owned();
+    }
+
+    pub fn set_external_functions(
+        &mut self,
+        external_functions: HashMap<Symbol, FunctionSignature>,
+    ) {
+        self.external_functions = external_functions;
+    }
+
+    // 마지막으로 analyze한 코드에서 발견한 경고입니다.
+    pub fn warnings(&self) -> &[SourceError] {
+        &self.warnings
+    }
+
+    // 마지막으로 analyze한 코드의 변수, 함수 이름과 그 정의, 타입입니다.
+    // 끝까지 정해지지 않은 정수 타입은 코드 생성과 같이 i64로 표시합니다.
+    pub fn symbols(&self) -> Vec<SymbolInfo> {
+        self.references
+            .iter()
+            .map(|reference| {
+                let detail = match reference.kind {
+                    ReferenceKind::Variable {
+                        value_type,
+                        mutable,
+                    } => {
+                        let value_type = match self.normalize(value_type) {
+                            Inferred::Known(value_type) => value_type,
+                            Inferred::Number(variable) => {
+                                self.type_variables.default_type(variable)
+                            }
+                        };
+
+                        format!(
+                            "{}{}: {}",
+                            if mutable { "mut " } else { "" },
+                            reference.name,
+                            value_type.name()
+                        )
+                    }
+                    ReferenceKind::Function => self.function_detail(reference.name),
+                };
+
+                SymbolInfo {
+                    name: reference.name.to_string(),
+                    span: reference.span,
+                    definition: reference.definition,
+                    detail,
+                }
+            })
+            .collect()
+    }
+
+    // fn 이름(매개변수: 타입, ...) -> 반환 타입
+    // 다른 모듈의 함수는 매개변수 이름을 모르므로 타입만 표시합니다.
+    fn function_detail(&self, name: Symbol) -> String {
+        let signature = &self.functions[&name];
+        let names = self
+            .function_definitions
+            .get(&name)
+            .map(|(_, names)| names.as_slice())
+            .unwrap_or_default();
+
+        let parameters = signature
+            .parameters
+            .iter()
+            .enumerate()
+            .map(|(index, value_type)| match names.get(index) {
+                Some(parameter) => format!("{}: {}", parameter, value_type.name()),
+                None => value_type.name().into_owned(),
+            })
+            .collect::<Vec<_>>();
+
+        format!(
+            "fn {}({}) -> {}",
+            name,
+            parameters.join(", "),
+            signature.return_type.name()
+        )
+    }
+
+    fn reference(
+        &mut self,
+        name: Symbol,
+        span: Span,
+        definition: Option<Span>,
+        kind: ReferenceKind,
+    ) {
+        self.references.push(Reference {
+            name,
+            span,
+            definition,
+            kind,
+        });
+    }
+
+    fn variable_reference(&mut self, name: Symbol, span: Span, binding: Binding) {
+        self.reference(
+            name,
+            span,
+            Some(binding.span),
+            ReferenceKind::Variable {
+                value_type: binding.value_type,
+                mutable: binding.mutable,
+            },
+        );
+    }
+}
+
+impl Default for SemanticAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SemanticAnalyzer {
+    pub fn analyze(&mut self, statements: &[Statement]) -> Result<(), AllError> {
+        self.functions = self.external_functions.clone();
+        self.warnings.clear();
+        self.type_variables.clear();
+        self.literals.clear();
+        self.function_definitions.clear();
+        self.references.clear();
+
+        // 구조체는 함수 시그니처와 변수 타입에 쓰이므로 가장 먼저 수집합니다.
+        self.structs = StructTable::from_statements(statements)
+            .map_err(|error| AllError::SemanticError(error.with_file(self.source_name.as_str())))?;
+
+        // 선언보다 앞에서 호출할 수 있도록 함수 목록을 먼저 수집합니다.
+        for statement in statements {
+            if let Statement::DefineFunction(function) = statement {
+                self.define_function(function)?;
+            }
+        }
+
+        self.scopes = vec![Scope::new()];
+
+        for statement in statements {
+            self.analyze_statement(statement)?;
+        }
+
+        Ok(())
+    }
+
+    // uid는 오류 코드(S0001 등)의 번호입니다. 경고는 같은 번호에 W를 붙입니다.
+    fn error_at(&self, uid: i32, span: Span, message: String) -> SourceError {
+        SourceError::new(uid, message)
+            .with_file(self.source_name.as_str())
+            .with_span(span)
+    }
+
+    fn define_function(&mut self, function: &FunctionDefinitionStatement) -> Result<(), AllError> {
+        let signature = self
+            .structs
+            .signature(function)
+            .map_err(|error| AllError::SemanticError(error.with_file(self.source_name.as_str())))?;
+        let previous = self.functions.insert(function.name, signature);
+        self.function_definitions.insert(
+            function.name,
+            (
+                function.span,
+                function
+                    .parameters
+                    .iter()
+                    .map(|parameter| parameter.name)
+                    .collect(),
+            ),
+        );
+
+        if previous.is_some() && !self.external_functions.contains_key(&function.name) {
+            return Err(AllError::SemanticError(self.error_at(
+                1,
+                function.span,
+                format!("function {} is already defined", function.name),
+            )));
+        }
+
+        Ok(())
+    }
+
+    fn analyze_statement(&mut self, statement: &Statement) -> Result<(), AllError> {
+        match statement {
+            Statement::Expression(expression) => {
+                self.expression_type(expression)?;
+            }
+            Statement::DefineVariable(variable) => {
+                let expression = if let Some(expression) = &variable.value {
+                    expression
+                } else {
+                    return Err(AllError::SemanticError(self.error_at(
+                        2,
+                        variable.span,
+                        format!("variable {} must be initialized", variable.name),
+                    )));
+                };
+
+                // 타입을 생략하면 초기값의 타입이 변수의 타입입니다.
+                let value_type = match variable.type_name {
+                    Some(type_name) => {
+                        let annotated = self.structs.resolve_type(type_name).ok_or_else(|| {
+                            AllError::SemanticError(self.error_at(
+                                3,
+                                variable.span,
+                                format!(
+                                    "unknown type {} for variable {}",
+                                    type_name, variable.name
+                                ),
+                            ))
+                        })?;
+                        let value_type = self.expect_type(expression, annotated, variable.span)?;
+
+                        if value_type != annotated {
+                            return Err(AllError::SemanticError(self.error_at(
+                                4,
+                                variable.span,
+                                format!(
+                                    "cannot assign {} to variable {} of type {}",
+                                    value_type.name(),
+                                    variable.name,
+                                    annotated.name()
+                                ),
+                            )));
+                        }
+
+                        Inferred::Known(annotated)
+                    }
+                    None => self.infer(expression)?,
+                };
+
+                if self.normalize(value_type) == Inferred::Known(ValueType::Void) {
+                    return Err(AllError::SemanticError(self.error_at(
+                        5,
+                        variable.span,
+                        format!("cannot assign void to variable {}", variable.name),
+                    )));
+                }
+
+                let scope = self.scopes.last_mut().expect("scope stack is empty");
+                if !scope.define(variable.name, value_type, variable.mutable, variable.span) {
+                    return Err(AllError::SemanticError(
+                        self.error_at(
+                            6,
+                            variable.span,
+                            format!(
+                                "variable {} is already declared in this scope",
+                                variable.name
+                            ),
+                        )
+                        .with_help("use a different name"),
+                    ));
+                }
+
+                self.reference(
+                    variable.name,
+                    variable.span,
+                    Some(variable.span),
+                    ReferenceKind::Variable {
+                        value_type,
+                        mutable: variable.mutable,
+                    },
+                );
+            }
+            Statement::DefineFunction(function) => {
+                self.analyze_function(function)?;
+            }
+            // 구조체는 analyze를 시작할 때 수집했습니다.
+            Statement::DefineStruct(definition) => {
+                if self.scopes.len() > 1 {
+                    return Err(AllError::SemanticError(self.error_at(
+                        7,
+                        definition.span,
+                        format!(
+                            "struct {} must be declared at the top level",
+                            definition.name
+                        ),
+                    )));
+                }
+            }
+            Statement::Return(expression, span) => {
+                let span = span.to(expression.span());
+                let value_type = self.expect_type(expression, self.return_type, span)?;
+
+                if value_type != self.return_type {
+                    let message = if self.return_type == ValueType::Integer {
+                        format!(
+                            "return value must be an integer, found {}",
+                            value_type.name()
+                        )
+                    } else {
+                        format!(
+                            "mismatched return type: expected {}, found {}",
+                            self.return_type.name(),
+                            value_type.name()
+                        )
+                    };
+
+                    return Err(AllError::SemanticError(self.error_at(8, span, message)));
+                }
+            }
+            Statement::If(statement) => {
+                self.analyze_if(statement)?;
+            }
+            Statement::While(statement) => {
+                let condition = self.expression_type(&statement.condition)?;
+
+                if condition != ValueType::Boolean {
+                    return Err(AllError::SemanticError(self.error_at(
+                        9,
+                        statement.condition.span(),
+                        format!(
+                            "while condition must be a boolean, found {}",
+                            condition.name()
+                        ),
+                    )));
+                }
+
+                self.analyze_loop_body(&statement.body)?;
+            }
+            Statement::Loop(statement) => {
+                self.analyze_loop_body(&statement.body)?;
+            }
+            Statement::For(statement) => {
+                self.analyze_for(statement)?;
+            }
+            Statement::Break(span) => {
+                self.check_in_loop(*span, "break")?;
+            }
+            Statement::Continue(span) => {
+                self.check_in_loop(*span, "continue")?;
+            }
+            Statement::Assign(statement) => {
+                self.analyze_assign(statement)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    // 함수 안에서는 최상위 변수와 매개변수, 함수 본문의 변수만 보입니다.
+    // 매개변수와 본문은 서로 다른 스코프이므로 본문에서 매개변수를 다시 선언해서 가릴 수 있습니다.
+    fn analyze_function(&mut self, function: &FunctionDefinitionStatement) -> Result<(), AllError> {
+        if self.scopes.len() > 1 {
+            self.define_function(function)?;
+        }
+
+        let signature = self.functions[&function.name].clone();
+        self.reference(
+            function.name,
+            function.span,
+            Some(function.span),
+            ReferenceKind::Function,
+        );
+
+        let mut parameters = Scope::new();
+        for (parameter, value_type) in function.parameters.iter().zip(&signature.parameters) {
+            if !parameters.define(parameter.name, (*value_type).into(), false, parameter.span) {
+                return Err(AllError::SemanticError(self.error_at(
+                    10,
+                    function.span,
+                    format!(
+                        "parameter {} is declared more than once in function {}",
+                        parameter.name, function.name
+                    ),
+                )));
+            }
+
+            self.variable_reference(
+                parameter.name,
+                parameter.span,
+                parameters.get(parameter.name).unwrap(),
+            );
+        }
+
+        let globals = self.scopes[0].clone();
+        let outer = std::mem::replace(&mut self.scopes, vec![globals, parameters, Scope::new()]);
+        // 함수 밖의 반복문은 함수 안에서 break할 수 없습니다.
+        let loop_depth = std::mem::replace(&mut self.loop_depth, 0);
+        let return_type = std::mem::replace(&mut self.return_type, signature.return_type);
+
+        let mut result = Ok(());
+        for statement in &function.body {
+            result = self.analyze_statement(statement);
+
+            if result.is_err() {
+                break;
+            }
+        }
+
+        self.scopes = outer;
+        self.loop_depth = loop_depth;
+        self.return_type = return_type;
+        result?;
+
+        // 반환값을 만들어 낼 수 없으므로, 본문이 return 없이 끝날 수 있으면 오류입니다.
+        if !flow::always_returns(&function.body) {
+            return Err(AllError::SemanticError(
+                self.error_at(
+                    11,
+                    function.span,
+                    format!("missing return in function {}", function.name),
+                )
+                .with_help(format!(
+                    "function {} must return {} on every path",
+                    function.name,
+                    signature.return_type.name()
+                )),
+            ));
+        }
+
+        Ok(())
+    }
+
+    fn analyze_if(&mut self, statement: &IfStatement) -> Result<(), AllError> {
+        let condition = self.expression_type(&statement.condition)?;
+
+        if condition != ValueType::Boolean {
+            return Err(AllError::SemanticError(self.error_at(
+                12,
+                statement.condition.span(),
+                format!("if condition must be a boolean, found {}", condition.name()),
+            )));
+        }
+
+        self.analyze_block(&statement.then_body)?;
+
+        if let Some(else_body) = &statement.else_body {
+            self.analyze_block(else_body)?;
+        }
+
+        Ok(())
+    }
+
+    // 반복 변수는 본문을 감싸는 스코프에 선언되며 다시 대입할 수 없습니다.
+    // 두 경계는 같은 정수 타입(또는 char)이어야 하고, 그 타입이 반복 변수의 타입입니다.
+    fn analyze_for(&mut self, statement: &ForStatement) -> Result<(), AllError> {
+        let start = self.infer(&statement.start)?;
+        let end = self.infer(&statement.end)?;
+
+        let value_type = match self.unify(start, end, statement.span)? {
+            Some(bound) => self.settle(bound),
+            None => ValueType::Void,
+        };
+
+        if !value_type.is_integral() {
+            let (start, end) = (self.settle(start), self.settle(end));
+
+            return Err(AllError::SemanticError(self.error_at(
+                13,
+                statement.span,
+                format!(
+                    "range bounds must be integers, found {} and {}",
+                    start.name(),
+                    end.name()
+                ),
+            )));
+        }
+
+        let mut scope = Scope::new();
+        scope.define(statement.variable, value_type.into(), false, statement.span);
+        self.variable_reference(
+            statement.variable,
+            statement.span,
+            scope.get(statement.variable).unwrap(),
+        );
+
+        self.scopes.push(scope);
+        let result = self.analyze_loop_body(&statement.body);
+        self.scopes.pop();
+
+        result
+    }
+
+    fn check_in_loop(&self, span: Span, keyword: &str) -> Result<(), AllError> {
+        if self.loop_depth == 0 {
+            return Err(AllError::SemanticError(self.error_at(
+                14,
+                span,
+                format!("{} outside of a loop", keyword),
+            )));
+        }
+
+        Ok(())
+    }
+
+    fn analyze_loop_body(&mut self, body: &[Statement]) -> Result<(), AllError> {
+        self.loop_depth += 1;
+        let result = self.analyze_block(body);
+        self.loop_depth -= 1;
+
+        result
+    }
+
+    // mut로 선언한 변수에 같은 타입의 값만 대입할 수 있습니다.
+    fn analyze_assign(&mut self, statement: &AssignStatement) -> Result<(), AllError> {
+        let value_type = self.infer(&statement.stored_value())?;
+
+        let binding = self.lookup(statement.name).ok_or_else(|| {
+            AllError::SemanticError(
+                self.error_at(
+                    15,
+                    statement.span,
+                    format!("undefined variable {}", statement.name),
+                )
+                .with_help(format!(
+                    "declare it with `mut {} = ...` before assigning to it",
+                    statement.name
+                )),
+            )
+        })?;
+        self.variable_reference(statement.name, statement.span, binding);
+
+        if !binding.mutable {
+            return Err(AllError::SemanticError(
+                self.error_at(
+                    16,
+                    statement.span,
+                    format!(
+                        "cannot assign twice to immutable variable {}",
+                        statement.name
+                    ),
+                )
+                .with_help(format!(
+                    "declare it with `mut {} = ...` to make it mutable",
+                    statement.name
+                )),
+            ));
+        }
+
+        if self
+            .unify(binding.value_type, value_type, statement.span)?
+            .is_none()
+        {
+            let (value_type, variable_type) =
+                (self.settle(value_type), self.settle(binding.value_type));
+
+            return Err(AllError::SemanticError(self.error_at(
+                4,
+                statement.span,
+                format!(
+                    "cannot assign {} to variable {} of type {}",
+                    value_type.name(),
+                    statement.name,
+                    variable_type.name()
+                ),
+            )));
+        }
+
+        Ok(())
+    }
+
+    // 블록마다 새 스코프를 만듭니다. 블록 안의 변수는 바깥의 같은 이름을 가릴 수 있습니다.
+    fn analyze_block(&mut self, statements: &[Statement]) -> Result<(), AllError> {
+        self.scopes.push(Scope::new());
+
+        let mut result = Ok(());
+        for statement in statements {
+            result = self.analyze_statement(statement);
+
+            if result.is_err() {
+                break;
+            }
+        }
+
+        self.scopes.pop();
+        result
+    }
+
+    fn lookup(&self, name: Symbol) -> Option<Binding> {
+        self.scopes.iter().rev().find_map(|scope| scope.get(name))
+    }
+
+    // 표현식의 타입을 계산합니다. 타입 규칙은 codegen이 만드는 명령어와 같습니다.
+    // 다른 값과 함께 쓰이지 않아 타입이 정해지지 않은 정수는 i64가 됩니다.
+    fn expression_type(&mut self, expression: &Expression) -> Result<ValueType, AllError> {
+        let inferred = self.infer(expression)?;
+
+        Ok(self.settle(inferred))
+    }
+
+    // expected 타입이어야 하는 표현식입니다. 정수 리터럴은 expected 타입이 되고,
+    // 타입이 맞지 않으면 표현식의 실제 타입을 반환합니다.
+    fn expect_type(
+        &mut self,
+        expression: &Expression,
+        expected: ValueType,
+        span: Span,
+    ) -> Result<ValueType, AllError> {
+        let inferred = self.infer(expression)?;
+
+        if self.unify(inferred, expected.into(), span)?.is_some() {
+            Ok(expected)
+        } else {
+            Ok(self.settle(inferred))
+        }
+    }
+
+    // 타입이 정해진 타입 변수는 그 타입으로 바꿉니다.
+    fn normalize(&self, inferred: Inferred) -> Inferred {
+        match inferred {
+            Inferred::Number(variable) => self
+                .type_variables
+                .resolved(variable)
+                .map_or(inferred, Inferred::Known),
+            known => known,
+        }
+    }
+
+    fn settle(&mut self, inferred: Inferred) -> ValueType {
+        match self.normalize(inferred) {
+            Inferred::Known(value_type) => value_type,
+            Inferred::Number(variable) => {
+                let value_type = self.type_variables.default_type(variable);
+                self.type_variables
+                    .bind(variable, value_type)
+                    .expect("every literal fits in i64 and f64");
+
+                value_type
+            }
+        }
+    }
+
+    // 두 타입이 같아지도록 타입 변수를 정합니다. 같아질 수 없으면 None입니다.
+    fn unify(
+        &mut self,
+        a: Inferred,
+        b: Inferred,
+        span: Span,
+    ) -> Result<Option<Inferred>, AllError> {
+        match (self.normalize(a), self.normalize(b)) {
+            (Inferred::Known(a), Inferred::Known(b)) => Ok((a == b).then_some(Inferred::Known(a))),
+            (Inferred::Known(value_type), Inferred::Number(variable))
+            | (Inferred::Number(variable), Inferred::Known(value_type)) => {
+                if !self.type_variables.accepts(variable, value_type) {
+                    return Ok(None);
+                }
+
+                self.type_variables
+                    .bind(variable, value_type)
+                    .map_err(|literal| {
+                        AllError::SemanticError(self.error_at(
+                            17,
+                            span,
+                            format!(
+                                "literal {} is out of range for {}",
+                                literal,
+                                value_type.name()
+                            ),
+                        ))
+                    })?;
+
+                Ok(Some(Inferred::Known(value_type)))
+            }
+            (Inferred::Number(a), Inferred::Number(b)) => {
+                if !self.type_variables.compatible(a, b) {
+                    return Ok(None);
+                }
+
+                Ok(Some(Inferred::Number(self.type_variables.union(a, b))))
+            }
+        }
+    }
+
+    fn infer(&mut self, expression: &Expression) -> Result<Inferred, AllError> {
+        match expression {
+            Expression::Literal(literal, _) => Ok(self.literal_type(literal)),
+            Expression::Variable(variable) => {
+                let binding = self.lookup(variable.name).ok_or_else(|| {
+                    AllError::SemanticError(
+                        self.error_at(
+                            15,
+                            variable.span,
+                            format!("undefined variable {}", variable.name),
+                        )
+                        .with_help(format!(
+                            "declare it with `let {} = ...` before using it",
+                            variable.name
+                        )),
+                    )
+                })?;
+                self.variable_reference(variable.name, variable.span, binding);
+
+                Ok(self.normalize(binding.value_type))
+            }
+            Expression::Binary(binary) => self.binary_type(binary),
+            Expression::Unary(unary) => self.unary_type(unary),
+            Expression::Call(call) => self.call_type(call),
+            Expression::Parentheses(parentheses) => self.infer(&parentheses.expression),
+            Expression::Match(expression) => self.match_type(expression),
+            Expression::StructLiteral(literal) => self.struct_literal_type(literal),
+            Expression::Field(field) => self.field_type(field).map(Inferred::Known),
+            Expression::Cast(cast) => self.cast_type(cast).map(Inferred::Known),
+            Expression::Comment(..) => Ok(ValueType::Void.into()),
+        }
+    }
+
+    fn literal_type(&mut self, literal: &LiteralExpression) -> Inferred {
+        match literal {
+            LiteralExpression::Integer(integer) => self.integer_literal(*integer),
+            LiteralExpression::Int32(_) => ValueType::Int32.into(),
+            LiteralExpression::Int64(_) => ValueType::Integer.into(),
+            LiteralExpression::Float(_) => self.float_literal(),
+            LiteralExpression::Float32(_) => ValueType::Float32.into(),
+            LiteralExpression::Boolean(_) => ValueType::Boolean.into(),
+            LiteralExpression::String(_) => ValueType::String.into(),
+            LiteralExpression::Char(_) => ValueType::Char.into(),
+        }
+    }
+
+    // 정수 리터럴마다 새 타입 변수를 만듭니다. value는 범위 검사에 쓰는 리터럴의 값입니다.
+    fn integer_literal(&mut self, value: i64) -> Inferred {
+        let variable = self.type_variables.fresh_integer(value);
+        self.literals.push(variable);
+
+        Inferred::Number(variable)
+    }
+
+    // 접미사가 없는 실수 리터럴도 새 타입 변수를 만들어서 f64인지 f32인지 정합니다.
+    fn float_literal(&mut self) -> Inferred {
+        let variable = self.type_variables.fresh_float();
+        self.literals.push(variable);
+
+        Inferred::Number(variable)
+    }
+
+    // 모든 필드를 한 번씩, 선언한 타입의 값으로 초기화해야 합니다.
+    fn struct_literal_type(
+        &mut self,
+        literal: &StructLiteralExpression,
+    ) -> Result<Inferred, AllError> {
+        let struct_type = if let Some(struct_type) = self.structs.get(literal.name) {
+            struct_type.clone()
+        } else {
+            return Err(AllError::SemanticError(self.error_at(
+                18,
+                literal.span,
+                format!("undefined struct {}", literal.name),
+            )));
+        };
+
+        for (index, (name, value)) in literal.fields.iter().enumerate() {
+            let value_type = self.infer(value)?;
+
+            let field_type = if let Some((_, field_type)) = struct_type.field(*name) {
+                field_type
+            } else {
+                return Err(AllError::SemanticError(self.error_at(
+                    19,
+                    literal.span,
+                    format!("struct {} has no field named {}", literal.name, name),
+                )));
+            };
+
+            if literal.fields[..index]
+                .iter()
+                .any(|(field, _)| field == name)
+            {
+                return Err(AllError::SemanticError(self.error_at(
+                    20,
+                    literal.span,
+                    format!("field {} is specified more than once", name),
+                )));
+            }
+
+            if self
+                .unify(value_type, field_type.into(), literal.span)?
+                .is_none()
+            {
+                let value_type = self.settle(value_type);
+
+                return Err(AllError::SemanticError(self.error_at(
+                    21,
+                    literal.span,
+                    format!(
+                        "mismatched type for field {} of struct {}: expected {}, found {}",
+                        name,
+                        literal.name,
+                        field_type.name(),
+                        value_type.name()
+                    ),
+                )));
+            }
+        }
+
+        let missing = struct_type
+            .fields
+            .iter()
+            .filter(|(field, _)| !literal.fields.iter().any(|(name, _)| name == field))
+            .map(|(field, _)| field.to_string())
+            .collect::<Vec<_>>();
+
+        if !missing.is_empty() {
+            return Err(AllError::SemanticError(self.error_at(
+                22,
+                literal.span,
+                format!(
+                    "missing fields {} in initializer of struct {}",
+                    missing.join(", "),
+                    literal.name
+                ),
+            )));
+        }
+
+        Ok(ValueType::Struct(literal.name).into())
+    }
+
+    fn field_type(&mut self, field: &FieldExpression) -> Result<ValueType, AllError> {
+        let object = self.expression_type(&field.object)?;
+
+        let field_type = match object {
+            ValueType::Struct(name) => self
+                .structs
+                .get(name)
+                .and_then(|struct_type| struct_type.field(field.field))
+                .map(|(_, field_type)| field_type),
+            _ => None,
+        };
+
+        field_type.ok_or_else(|| {
+            AllError::SemanticError(self.error_at(
+                23,
+                field.span,
+                format!("no field {} on type {}", field.field, object.name()),
+            ))
+        })
+    }
+
+    // 정수, 실수, bool, char 사이에서만 바꿀 수 있습니다. 바꿀 값의 정수 리터럴은 i64입니다.
+    fn cast_type(&mut self, cast: &CastExpression) -> Result<ValueType, AllError> {
+        let value_type = self.expression_type(&cast.value)?;
+
+        let target = self.structs.resolve_type(cast.type_name).ok_or_else(|| {
+            AllError::SemanticError(self.error_at(
+                24,
+                cast.span,
+                format!("unknown type {}", cast.type_name),
+            ))
+        })?;
+
+        if value_type.cast_instruction(target).is_none() {
+            return Err(AllError::SemanticError(
+                self.error_at(
+                    25,
+                    cast.span,
+                    format!("cannot cast {} as {}", value_type.name(), cast.type_name),
+                )
+                .with_help("only integers, floats, booleans and chars can be cast with `as`"),
+            ));
+        }
+
+        Ok(target)
+    }
+
+    // 갈래의 식은 모두 같은 타입이어야 하고, 그 타입이 match 전체의 타입입니다.
+    // 값으로 쓰는 match는 모든 경우를 다뤄야 하고, 값이 없는 match는 빠진 경우가 있으면 경고만 합니다.
+    fn match_type(&mut self, expression: &MatchExpression) -> Result<Inferred, AllError> {
+        let subject = self.expression_type(&expression.subject)?;
+
+        if !subject.is_integral() && subject != ValueType::Boolean {
+            return Err(AllError::SemanticError(
+                self.error_at(
+                    26,
+                    expression.span,
+                    format!("cannot match on {}", subject.name()),
+                )
+                .with_help("match supports integer, char and boolean values"),
+            ));
+        }
+
+        let mut result_type: Option<Inferred> = None;
+
+        for (index, arm) in expression.arms.iter().enumerate() {
+            if let Pattern::Literal(literal) = &arm.pattern {
+                let pattern_type = self.literal_type(literal);
+
+                if self
+                    .unify(pattern_type, subject.into(), arm.span)?
+                    .is_none()
+                {
+                    let pattern_type = self.settle(pattern_type);
+
+                    return Err(AllError::SemanticError(self.error_at(
+                        27,
+                        arm.span,
+                        format!(
+                            "mismatched pattern type: expected {}, found {}",
+                            subject.name(),
+                            pattern_type.name()
+                        ),
+                    )));
+                }
+            }
+
+            if !expression.is_reachable(index) {
+                let warning = self.error_at(39, arm.span, "unreachable pattern".to_owned());
+                self.warnings.push(warning);
+            }
+
+            let body_type = self.infer(&arm.body)?;
+
+            result_type = match result_type {
+                Some(previous) => match self.unify(previous, body_type, arm.span)? {
+                    Some(unified) => Some(unified),
+                    None => {
+                        let (previous, body_type) = (self.settle(previous), self.settle(body_type));
+
+                        return Err(AllError::SemanticError(self.error_at(
+                            28,
+                            arm.span,
+                            format!(
+                                "match arms have different types: {} and {}",
+                                previous.name(),
+                                body_type.name()
+                            ),
+                        )));
+                    }
+                },
+                None => Some(body_type),
+            };
+        }
+
+        let result_type = result_type.unwrap_or(ValueType::Void.into());
+
+        if let Some(missing) = Self::missing_patterns(expression, subject) {
+            let error = self
+                .error_at(
+                    29,
+                    expression.span,
+                    format!("non-exhaustive match: {} not covered", missing),
+                )
+                .with_help("add a `_ => ...` arm");
+
+            if self.normalize(result_type) != Inferred::Known(ValueType::Void) {
+                return Err(AllError::SemanticError(error));
+            }
+
+            self.warnings.push(error);
+        }
+
+        Ok(result_type)
+    }
+
+    // 어떤 갈래와도 일치하지 않는 값을 설명합니다. 모든 경우를 다루면 None입니다.
+    fn missing_patterns(expression: &MatchExpression, subject: ValueType) -> Option<String> {
+        if expression.has_wildcard() {
+            return None;
+        }
+
+        if subject.is_integral() {
+            return Some(format!("`{}` values", subject.name()));
+        }
+
+        let missing = [true, false]
+            .into_iter()
+            .filter(|boolean| {
+                !expression.arms.iter().any(|arm| {
+                    arm.pattern == Pattern::Literal(LiteralExpression::Boolean(*boolean))
+                })
+            })
+            .map(|boolean| format!("`{}`", boolean))
+            .collect::<Vec<_>>();
+
+        if missing.is_empty() {
+            None
+        } else {
+            Some(missing.join(" and "))
+        }
+    }
+
+    // 두 피연산자는 같은 타입이어야 합니다. 한쪽이 정수 리터럴이면 다른 쪽의 정수 타입을 따릅니다.
+    fn binary_type(&mut self, binary: &BinaryExpression) -> Result<Inferred, AllError> {
+        let lhs = self.infer(&binary.lhs)?;
+        let rhs = self.infer(&binary.rhs)?;
+
+        let operand = if let Some(operand) = self.unify(lhs, rhs, binary.span)? {
+            operand
+        } else {
+            let (lhs, rhs) = (self.settle(lhs), self.settle(rhs));
+
+            return Err(AllError::SemanticError(
+                self.error_at(
+                    30,
+                    binary.span,
+                    format!(
+                        "mismatched operand types for {:?}: {} and {}",
+                        binary.operator,
+                        lhs.name(),
+                        rhs.name()
+                    ),
+                )
+                .with_help(lhs.mismatch_help(rhs)),
+            ));
+        };
+
+        // 타입이 정해지지 않은 숫자는 기본 타입(i64, f64)의 연산 규칙을 따르고,
+        // 비트 연산에 쓰인 정수 리터럴은 실수 타입이 될 수 없습니다.
+        let value_type = match operand {
+            Inferred::Known(value_type) => value_type,
+            Inferred::Number(variable) => {
+                if binary.operator.is_bitwise() {
+                    self.type_variables.require_integral(variable);
+                }
+
+                self.type_variables.default_type(variable)
+            }
+        };
+
+        use BinaryOperator::*;
+        let result_type = match (value_type, &binary.operator) {
+            (
+                _,
+                Add | Subtract | Multiply | Divide | Modulo | BitwiseAnd | BitwiseOr | BitwiseXor
+                | LeftShift | RightShift,
+            ) if value_type.is_integer() => Some(operand),
+            (_, Add | Subtract | Multiply | Divide | Modulo) if value_type.is_float() => {
+                Some(operand)
+            }
+            (ValueType::Char, Add | Subtract) => Some(operand),
+            (
+                ValueType::Integer
+                | ValueType::Int32
+                | ValueType::Float
+                | ValueType::Float32
+                | ValueType::String
+                | ValueType::Char,
+                Equal | NotEqual | LessThan | LessThanOrEqual | GreaterThan | GreaterThanOrEqual,
+            ) => Some(ValueType::Boolean.into()),
+            (ValueType::String, Add) => Some(operand),
+            (
+                ValueType::Boolean,
+                And | Or | BitwiseAnd | BitwiseOr | BitwiseXor | Equal | NotEqual,
+            ) => Some(operand),
+            _ => None,
+        };
+
+        result_type.ok_or_else(|| {
+            AllError::SemanticError(self.error_at(
+                31,
+                binary.span,
+                format!(
+                    "unsupported operator {:?} for {}",
+                    binary.operator,
+                    value_type.name()
+                ),
+            ))
+        })
+    }
+
+    fn unary_type(&mut self, unary: &UnaryExpression) -> Result<Inferred, AllError> {
+        // -2147483648처럼 부호를 붙여야 i32 범위에 들어가는 리터럴이 있으므로, 음수 리터럴은 부호를 붙인 값으로 검사합니다.
+        let operand = match (&unary.operator, unary.operand.as_ref()) {
+            (UnaryOperator::Minus, Expression::Literal(LiteralExpression::Integer(integer), _)) => {
+                self.integer_literal(integer.wrapping_neg())
+            }
+            _ => self.infer(&unary.operand)?,
+        };
+
+        let value_type = match operand {
+            Inferred::Known(value_type) => value_type,
+            Inferred::Number(variable) => {
+                if unary.operator == UnaryOperator::BitwiseNot {
+                    self.type_variables.require_integral(variable);
+                }
+
+                self.type_variables.default_type(variable)
+            }
+        };
+
+        match (&unary.operator, value_type) {
+            (UnaryOperator::Plus | UnaryOperator::Minus, _) if value_type.is_float() => Ok(operand),
+            (UnaryOperator::Not, ValueType::Boolean) => Ok(operand),
+            (UnaryOperator::Plus | UnaryOperator::Minus | UnaryOperator::BitwiseNot, _)
+                if value_type.is_integer() =>
+            {
+                Ok(operand)
+            }
+            (operator, value_type) => Err(AllError::SemanticError(self.error_at(
+                32,
+                unary.span,
+                format!(
+                    "unsupported unary operator {:?} for {}",
+                    operator,
+                    value_type.name()
+                ),
+            ))),
+        }
+    }
+
+    fn call_type(&mut self, call: &CallExpression) -> Result<Inferred, AllError> {
+        let arguments = call
+            .arguments
+            .iter()
+            .map(|argument| self.infer(argument))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if let Some(builtin) = Builtin::from_name(&call.function_name.text()) {
+            let arguments = arguments
+                .into_iter()
+                .map(|argument| self.settle(argument))
+                .collect();
+
+            return self
+                .builtin_call_type(call, builtin, arguments)
+                .map(Inferred::Known);
+        }
+
+        let signature = if let Some(signature) = self.functions.get(&call.function_name) {
+            signature.clone()
+        } else {
+            return Err(AllError::SemanticError(self.error_at(
+                33,
+                call.span,
+                format!("undefined function {}", call.function_name),
+            )));
+        };
+
+        // 다른 파일의 구조체는 이 모듈에서 크기와 필드를 알 수 없으므로 주고받을 수 없습니다.
+        if self.external_functions.contains_key(&call.function_name) {
+            if let Some(name) = self.structs.foreign_struct(&signature) {
+                return Err(AllError::SemanticError(
+                    self.error_at(
+                        34,
+                        call.span,
+                        format!(
+                            "function {} uses struct {} from another file",
+                            call.function_name, name
+                        ),
+                    )
+                    .with_help("define functions that take or return this struct in the same file"),
+                ));
+            }
+        }
+
+        let definition = self
+            .function_definitions
+            .get(&call.function_name)
+            .map(|(span, _)| *span);
+        self.reference(
+            call.function_name,
+            call.span,
+            definition,
+            ReferenceKind::Function,
+        );
+
+        if signature.parameters.len() != arguments.len() {
+            return Err(AllError::SemanticError(self.error_at(
+                35,
+                call.span,
+                format!(
+                    "function {} takes {} arguments but {} were given",
+                    call.function_name,
+                    signature.parameters.len(),
+                    arguments.len()
+                ),
+            )));
+        }
+
+        for (parameter, argument) in signature.parameters.iter().zip(arguments) {
+            if self
+                .unify(argument, (*parameter).into(), call.span)?
+                .is_some()
+            {
+                continue;
+            }
+
+            let argument = self.settle(argument);
+            let message = if *parameter == ValueType::Integer {
+                format!(
+                    "function {} takes integer arguments, found {}",
+                    call.function_name,
+                    argument.name()
+                )
+            } else {
+                format!(
+                    "mismatched argument type for function {}: expected {}, found {}",
+                    call.function_name,
+                    parameter.name(),
+                    argument.name()
+                )
+            };
+
+            return Err(AllError::SemanticError(
+                self.error_at(36, call.span, message),
+            ));
+        }
+
+        Ok(signature.return_type.into())
+    }
+
+    // 내장 함수는 첫 번째 인자의 타입으로 런타임 함수를 고릅니다.
+    // 런타임 함수는 i64와 double만 받으므로 i32, f32 인자는 i64, f64로 넓혀서 전달합니다.
+    fn builtin_call_type(
+        &self,
+        call: &CallExpression,
+        builtin: Builtin,
+        arguments: Vec<ValueType>,
+    ) -> Result<ValueType, AllError> {
+        let arguments = arguments
+            .into_iter()
+            .map(|argument| match argument {
+                ValueType::Int32 => ValueType::Integer,
+                ValueType::Float32 => ValueType::Float,
+                argument => argument,
+            })
+            .collect::<Vec<_>>();
+
+        let argument_type = arguments
+            .first()
+            .map(|argument| argument.llvm_type())
+            .unwrap_or_default();
+
+        if builtin.runtime_function(&argument_type).is_none() {
+            return Err(AllError::SemanticError(self.error_at(
+                37,
+                call.span,
+                format!(
+                    "{} does not accept arguments of type ({})",
+                    call.function_name,
+                    arguments
+                        .iter()
+                        .map(|argument| argument.name())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+            )));
+        }
+
+        let parameter_types = builtin.parameter_types(&argument_type);
+        let argument_types = arguments
+            .iter()
+            .map(|argument| argument.llvm_type())
+            .collect::<Vec<_>>();
+
+        if parameter_types != argument_types {
+            return Err(AllError::SemanticError(self.error_at(
+                38,
+                call.span,
+                format!(
+                    "{} expects arguments ({}), found ({})",
+                    call.function_name,
+                    parameter_types.join(", "),
+                    argument_types.join(", ")
+                ),
+            )));
+        }
+
+        Ok(ValueType::from_llvm_type(builtin.return_type()).unwrap_or(ValueType::Void))
+    }
+}
@@ -0,0 +1,39 @@
+use crate::utils::{interner::Symbol, span::Span};
+
+use super::inference::Inferred;
+
+// 소스코드에서 변수나 함수 이름이 쓰인 곳 하나입니다. 정의도 자기 자신을 가리키는 참조로 기록합니다.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Reference {
+    pub name: Symbol,
+    pub span: Span,
+    // 이름을 정의한 곳. 다른 모듈의 함수처럼 알 수 없으면 None입니다.
+    pub definition: Option<Span>,
+    pub kind: ReferenceKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum ReferenceKind {
+    Variable { value_type: Inferred, mutable: bool },
+    Function,
+}
+
+// 편집기에 보여줄 참조 정보입니다. (정의로 이동, 타입 보기)
+#[derive(Debug, Clone, PartialEq)]
+// 분석이 끝난 뒤에도 쓰므로 이름은 Symbol이 아닌 String으로 가집니다.
+pub struct SymbolInfo {
+    pub name: String,
+    pub span: Span,
+    pub definition: Option<Span>,
+    // `a: i64`, `fn add(a: i64, b: i64) -> i64` 형태의 설명
+    pub detail: String,
+}
+
+impl SymbolInfo {
+    // line, column(1부터 시작, 문자 단위)이 이 이름 위에 있는지 검사합니다.
+    pub fn contains(&self, line: usize, column: usize) -> bool {
+        self.span.line == line
+            && self.span.column <= column
+            && column < self.span.column + self.span.length.max(1)
+    }
+}
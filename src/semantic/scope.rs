@@ -0,0 +1,53 @@
+use std::collections::HashMap;
+
+use crate::utils::{interner::Symbol, span::Span};
+
+use super::inference::Inferred;
+
+// 선언된 변수 하나의 정보입니다.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Binding {
+    pub value_type: Inferred,
+    pub mutable: bool,
+    // 변수 이름을 선언한 위치
+    pub span: Span,
+}
+
+// 블록 하나에서 선언한 변수와 그 타입입니다.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Scope {
+    variables: HashMap<Symbol, Binding>,
+}
+
+impl Scope {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // 같은 스코프에 이미 있는 이름이면 선언하지 않고 false를 반환합니다.
+    pub fn define(
+        &mut self,
+        name: Symbol,
+        value_type: Inferred,
+        mutable: bool,
+        span: Span,
+    ) -> bool {
+        if self.variables.contains_key(&name) {
+            return false;
+        }
+
+        self.variables.insert(
+            name,
+            Binding {
+                value_type,
+                mutable,
+                span,
+            },
+        );
+        true
+    }
+
+    pub fn get(&self, name: Symbol) -> Option<Binding> {
+        self.variables.get(&name).copied()
+    }
+}
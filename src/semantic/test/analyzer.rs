@@ -0,0 +1,648 @@
+#![cfg(test)]
+
+use crate::{
+    ast::{
+        expression::{
+            binary::BinaryExpression, literal::LiteralExpression, variable::VariableExpression,
+            Expression,
+        },
+        operator::binary::BinaryOperator,
+        statement::{
+            define_function::FunctionDefinitionStatement,
+            define_variable::VariableDefinitionStatement, Statement,
+        },
+    },
+    compiler::parse,
+    error::all_error::AllError,
+    semantic::SemanticAnalyzer,
+};
+
+fn analyze(statements: &[Statement]) -> Result<(), AllError> {
+    SemanticAnalyzer::new().analyze(statements)
+}
+
+// 의미 분석 오류의 메시지와 위치(줄, 열)를 반환합니다.
+fn error(source: &str) -> (String, usize, usize) {
+    match analyze(&parse(source).unwrap()) {
+        Err(AllError::SemanticError(error)) => (error.message, error.span.line, error.span.column),
+        result => panic!("expected a semantic error, found {:?}", result),
+    }
+}
+
+fn variable(name: &str) -> Expression {
+    VariableExpression {
+        name: name.into(),
+        span: Default::default(),
+    }
+    .into()
+}
+
+fn define(name: &str, value: Expression) -> Statement {
+    VariableDefinitionStatement {
+        mutable: false,
+        name: name.into(),
+        value: Some(value),
+        type_name: None,
+        span: Default::default(),
+    }
+    .into()
+}
+
+fn function(name: &str, parameters: &[&str], body: Vec<Statement>) -> Statement {
+    Statement::DefineFunction(FunctionDefinitionStatement {
+        name: name.into(),
+        parameters: parameters.iter().map(|name| (*name).into()).collect(),
+        return_type: None,
+        body,
+        span: Default::default(),
+    })
+}
+
+#[test]
+pub fn valid_program() {
+    let statements = parse(concat!(
+        "let a = 1 + 2\n",
+        "let b = to_string(a) + \"!\"\n",
+        "let c = 1.5 < 2.0\n",
+        "println(b)\n",
+        "println(len(b) == 4)\n",
+    ))
+    .unwrap();
+
+    assert!(analyze(&statements).is_ok());
+}
+
+#[test]
+pub fn use_before_define() {
+    assert_eq!(
+        error("let a = b\nlet b = 1"),
+        ("undefined variable b".to_owned(), 1, 9)
+    );
+    assert_eq!(
+        error("println(foo(1))"),
+        ("undefined function foo".to_owned(), 1, 9)
+    );
+}
+
+#[test]
+pub fn redeclaration() {
+    assert_eq!(
+        error("let a = 1\nlet a = 2"),
+        (
+            "variable a is already declared in this scope".to_owned(),
+            2,
+            5
+        )
+    );
+
+    assert!(analyze(&[
+        function("foo", &[], vec![]),
+        function("foo", &["a"], vec![]),
+    ])
+    .is_err());
+
+    assert!(analyze(&[function("foo", &["a", "a"], vec![])]).is_err());
+}
+
+#[test]
+pub fn type_mismatch() {
+    assert_eq!(
+        error("let a = 1\nlet b = a + 1.5").0,
+        "mismatched operand types for Add: i64 and f64"
+    );
+    assert_eq!(
+        error("let a = println(1)").0,
+        "cannot assign void to variable a"
+    );
+    assert_eq!(
+        error("let a = \"foo\" * \"bar\"").0,
+        "unsupported operator Multiply for String"
+    );
+    assert_eq!(
+        error("assert(1, \"message\")").0,
+        "assert does not accept arguments of type (i64, String)"
+    );
+}
+
+// 함수 본문은 최상위 변수와 매개변수를 볼 수 있고, 매개변수는 본문에서 다시 선언해서 가릴 수 있습니다.
+#[test]
+pub fn function_scopes() {
+    let add = |lhs: Expression, rhs: Expression| -> Expression {
+        BinaryExpression {
+            lhs: Box::new(lhs),
+            rhs: Box::new(rhs),
+            operator: BinaryOperator::Add,
+            span: Default::default(),
+        }
+        .into()
+    };
+
+    let statements = vec![
+        define("base", LiteralExpression::Integer(1).into()),
+        function(
+            "foo",
+            &["a"],
+            vec![
+                define("a", add(variable("a"), variable("base"))),
+                Statement::Return(variable("a"), Default::default()),
+            ],
+        ),
+    ];
+    assert!(analyze(&statements).is_ok());
+
+    // 함수 안의 지역 변수는 함수 밖에서 보이지 않습니다.
+    let statements = vec![
+        function(
+            "foo",
+            &[],
+            vec![define("local", LiteralExpression::Integer(1).into())],
+        ),
+        define("a", variable("local")),
+    ];
+    assert!(matches!(
+        analyze(&statements),
+        Err(AllError::SemanticError(_))
+    ));
+
+    // 반환값은 정수여야 합니다.
+    let statements = vec![function(
+        "foo",
+        &[],
+        vec![Statement::Return(
+            LiteralExpression::Boolean(true).into(),
+            Default::default(),
+        )],
+    )];
+    assert!(matches!(
+        analyze(&statements),
+        Err(AllError::SemanticError(_))
+    ));
+}
+
+#[test]
+pub fn function_signatures() {
+    assert_eq!(
+        error("fn foo() { }\nfn foo(a) { }"),
+        ("function foo is already defined".to_owned(), 2, 4)
+    );
+    assert_eq!(
+        error("fn foo() -> Q {\n    return 1\n}"),
+        ("unknown return type Q for function foo".to_owned(), 1, 4)
+    );
+    assert_eq!(
+        error("fn foo() -> bool {\n    return 1\n}").0,
+        "mismatched return type: expected bool, found i64"
+    );
+    assert!(analyze(
+        &parse(concat!(
+            "fn foo(a: bool, b: f64, c: f32, d: String) -> String {\n",
+            "    return d\n",
+            "}\n",
+            "let s = foo(true, 1.5, 2.5f32, \"a\")",
+        ))
+        .unwrap()
+    )
+    .is_ok());
+    assert!(analyze(&parse("fn foo(a) -> i64 { return a }").unwrap()).is_ok());
+}
+
+// 반환값을 대신 만들어 주지 않으므로, 모든 경로가 return으로 끝나야 합니다.
+#[test]
+pub fn missing_return() {
+    for source in [
+        "fn foo() -> i64 { }",
+        "fn foo(a) { if a > 0 { return 1 } }",
+        "fn foo(a) {\n    while a > 0 {\n        return 1\n    }\n}",
+        "fn foo() -> i64 {\n    loop {\n        break\n    }\n}",
+    ] {
+        assert_eq!(
+            error(source),
+            ("missing return in function foo".to_owned(), 1, 4),
+            "{}",
+            source
+        );
+    }
+
+    for source in [
+        "fn foo(a) -> i64 {\n    if a > 0 {\n        return 1\n    } else {\n        return 2\n    }\n}",
+        "fn foo(a) -> i64 {\n    if a > 0 {\n        return 1\n    } else if a < 0 {\n        return 2\n    } else {\n        return 0\n    }\n}",
+        "fn foo() -> i64 {\n    loop {\n        loop {\n            break\n        }\n        return 1\n    }\n}",
+        "fn foo() -> i64 {\n    loop { }\n}",
+    ] {
+        assert!(analyze(&parse(source).unwrap()).is_ok(), "{}", source);
+    }
+}
+
+// 경고의 메시지와 위치(줄, 열)를 반환합니다.
+fn warnings(source: &str) -> Vec<(String, usize, usize)> {
+    let mut analyzer = SemanticAnalyzer::new();
+    analyzer.analyze(&parse(source).unwrap()).unwrap();
+
+    analyzer
+        .warnings()
+        .iter()
+        .map(|warning| {
+            (
+                warning.message.clone(),
+                warning.span.line,
+                warning.span.column,
+            )
+        })
+        .collect()
+}
+
+#[test]
+pub fn match_exhaustiveness() {
+    assert_eq!(
+        warnings("match 1 {\n    1 => println(1)\n    1 => println(2)\n}"),
+        vec![
+            ("unreachable pattern".to_owned(), 3, 5),
+            (
+                "non-exhaustive match: `i64` values not covered".to_owned(),
+                1,
+                1
+            ),
+        ]
+    );
+    assert_eq!(
+        warnings("match true { _ => println(1), false => println(2) }"),
+        vec![("unreachable pattern".to_owned(), 1, 31)]
+    );
+    assert_eq!(
+        warnings("match 1 > 2 { }"),
+        vec![(
+            "non-exhaustive match: `true` and `false` not covered".to_owned(),
+            1,
+            1
+        )]
+    );
+    assert!(warnings("let a = match 1 > 2 { true => 1, false => 0 }").is_empty());
+    assert!(warnings("let a = match 3 { 1 => 1, _ => 0 }").is_empty());
+
+    // 값으로 쓰는 match는 모든 경우를 다뤄야 합니다.
+    assert_eq!(
+        error("let a = match 3 { 1 => 1, 2 => 0 }"),
+        (
+            "non-exhaustive match: `i64` values not covered".to_owned(),
+            1,
+            9
+        )
+    );
+}
+
+#[test]
+pub fn match_types() {
+    assert_eq!(
+        error("match 1 { true => println(1) }").0,
+        "mismatched pattern type: expected i64, found bool"
+    );
+    assert_eq!(
+        error("let a = match 1 { 1 => 1, _ => \"other\" }").0,
+        "match arms have different types: i64 and String"
+    );
+    assert_eq!(
+        error("match \"a\" { _ => println(1) }").0,
+        "cannot match on String"
+    );
+}
+
+#[test]
+pub fn struct_declarations() {
+    assert!(analyze(
+        &parse("struct Line { from: Point, to: Point }\nstruct Point { x: i64, y: f64 }").unwrap()
+    )
+    .is_ok());
+    assert_eq!(
+        error("struct A { x: i64 }\nstruct A { y: i64 }"),
+        ("struct A is already defined".to_owned(), 2, 8)
+    );
+    assert_eq!(
+        error("struct A { x: i64, x: bool }"),
+        (
+            "field x is declared more than once in struct A".to_owned(),
+            1,
+            20
+        )
+    );
+    assert_eq!(
+        error("struct A { x: u8 }"),
+        ("unknown type u8 for field x of struct A".to_owned(), 1, 12)
+    );
+    assert_eq!(
+        error("struct A { b: B }\nstruct B { a: A }").0,
+        "recursive struct A has infinite size"
+    );
+    assert_eq!(
+        error("fn foo() {\n    struct A { }\n}").0,
+        "struct A must be declared at the top level"
+    );
+}
+
+#[test]
+pub fn struct_values() {
+    let source = |body: &str| format!("struct P {{ x: i64, y: bool }}\n{}", body);
+
+    assert!(
+        analyze(&parse(&source("let p = P { y: true, x: 1 }\nlet a = p.x + 1")).unwrap()).is_ok()
+    );
+    assert_eq!(
+        error(&source("let p = Q { x: 1 }")),
+        ("undefined struct Q".to_owned(), 2, 9)
+    );
+    assert_eq!(
+        error(&source("let p = P { x: 1 }")).0,
+        "missing fields y in initializer of struct P"
+    );
+    assert_eq!(
+        error(&source("let p = P { x: 1, y: true, z: 2 }")).0,
+        "struct P has no field named z"
+    );
+    assert_eq!(
+        error(&source("let p = P { x: 1, x: 2, y: true }")).0,
+        "field x is specified more than once"
+    );
+    assert_eq!(
+        error(&source("let p = P { x: true, y: true }")).0,
+        "mismatched type for field x of struct P: expected i64, found bool"
+    );
+    assert_eq!(
+        error(&source("let p = P { x: 1, y: true }\nlet a = p.z")),
+        ("no field z on type P".to_owned(), 3, 11)
+    );
+    assert_eq!(
+        error(&source("let a = 1\nlet b = a.x")).0,
+        "no field x on type i64"
+    );
+}
+
+#[test]
+pub fn struct_functions() {
+    let source = |body: &str| format!("struct P {{ x: i64 }}\n{}", body);
+
+    assert!(analyze(
+        &parse(&source(
+            "fn get(p: P, n) -> i64 { return p.x + n }\nfn make() -> P { return P { x: 1 } }\nlet a = get(make(), 2)"
+        ))
+        .unwrap()
+    )
+    .is_ok());
+    assert_eq!(
+        error(&source(
+            "fn get(p: P) -> i64 { return p.x }\nlet a = get(1)"
+        ))
+        .0,
+        "mismatched argument type for function get: expected P, found i64"
+    );
+    assert_eq!(
+        error(&source("fn make() -> P { return 1 }")).0,
+        "mismatched return type: expected P, found i64"
+    );
+    assert_eq!(
+        error(&source("fn foo(a: Q) { }")).0,
+        "unknown type Q for parameter a of function foo"
+    );
+    assert_eq!(
+        error(&source("let p = P { x: 1 }\nprintln(p + p)")).0,
+        "unsupported operator Add for P"
+    );
+}
+
+// 정수 리터럴은 함께 쓰인 값의 타입을 따르고, 아무것도 정하지 않으면 i64입니다.
+#[test]
+pub fn type_inference() {
+    let source = concat!(
+        "struct P { x: i32 }\n",
+        "fn half(n: i32) -> i32 { return n / 2 }\n",
+        "let a: i32 = 1\n",
+        "let b = 2\n",
+        "let c = a + b * 3\n",
+        "let d = half(b)\n",
+        "let e = 4\n",
+        "let f = P { x: -2147483648 }\n",
+        "for i in 0..a { }\n",
+        "let g = match d { 0 => 1, _ => 2 }\n",
+        "let h: i32 = g\n",
+    );
+
+    let mut statements = parse(source).unwrap();
+    let mut analyzer = SemanticAnalyzer::new();
+    analyzer.analyze(&statements).unwrap();
+    analyzer.annotate(&mut statements);
+
+    let literals = format!("{:?}", statements);
+    assert_eq!(literals.matches("Int32(").count(), 9);
+    assert!(literals.contains("Int32(-2147483648)"));
+    assert!(literals.contains("Integer(4)"));
+
+    assert_eq!(
+        error("let a: i32 = 1\nlet b = 2\nlet c = a + b\nlet d: i64 = b"),
+        (
+            "cannot assign i32 to variable d of type i64".to_owned(),
+            4,
+            5
+        )
+    );
+    assert_eq!(
+        error("let a: i32 = 3000000000"),
+        (
+            "literal 3000000000 is out of range for i32".to_owned(),
+            1,
+            5
+        )
+    );
+    assert_eq!(
+        error("let a: i32 = 1\nlet b = a + 2147483648").0,
+        "literal 2147483648 is out of range for i32"
+    );
+    assert_eq!(
+        error("let a: i32 = 1\nlet b = 1\nlet c = a < b + 1.5").0,
+        "mismatched operand types for Add: i64 and f64"
+    );
+    assert_eq!(
+        error("let a: u8 = 1"),
+        ("unknown type u8 for variable a".to_owned(), 1, 5)
+    );
+    assert_eq!(
+        error("let a: i32 = 1\nfor i in a..10 { }\nlet b: i64 = 5\nfor j in a..b { }").0,
+        "range bounds must be integers, found i32 and i64"
+    );
+    assert_eq!(
+        error("let a: i32 = 1\nmatch a { 1 => println(1) }\nlet b = match a { 1 => 1 }").0,
+        "non-exhaustive match: `i32` values not covered"
+    );
+}
+
+#[test]
+pub fn casts() {
+    assert!(analyze(
+        &parse(concat!(
+            "let a: i32 = 1\n",
+            "let b = a as i64 + 2\n",
+            "let c = b as f64 / 2.0\n",
+            "let d = (c as i32) + a\n",
+            "let e = (1 > 2) as i64 + 1 as i64\n",
+            "let f = 1.5 as f64\n",
+        ))
+        .unwrap()
+    )
+    .is_ok());
+    assert_eq!(
+        error("let a = 1 as bool"),
+        ("cannot cast i64 as bool".to_owned(), 1, 11)
+    );
+    assert_eq!(error("let a = \"1\" as i64").0, "cannot cast String as i64");
+    assert_eq!(error("let a = 1 as u8").0, "unknown type u8");
+    assert_eq!(
+        error("struct P { x: i64 }\nlet p = P { x: 1 }\nlet a = p as P").0,
+        "cannot cast P as P"
+    );
+    assert_eq!(
+        error("let a: i32 = 1\nlet b = a as i64 + a").0,
+        "mismatched operand types for Add: i64 and i32"
+    );
+}
+
+#[test]
+pub fn suffixed_literals() {
+    assert!(analyze(
+        &parse(concat!(
+            "let a = 10i32 + 1\n",
+            "let b: i32 = a * 2\n",
+            "let c = 0x10i64 - 1\n",
+            "let d = 2.5f32 * -1.0f32\n",
+            "let e = d as f64 + 1.0\n",
+            "println(d)\n",
+        ))
+        .unwrap()
+    )
+    .is_ok());
+    assert_eq!(
+        error("let a: i32 = 1i64").0,
+        "cannot assign i64 to variable a of type i32"
+    );
+    assert_eq!(
+        error("let a = 1.0f32 + 1i32").0,
+        "mismatched operand types for Add: f32 and i32"
+    );
+    assert_eq!(
+        error("let a = 1i32 + 1i64").0,
+        "mismatched operand types for Add: i32 and i64"
+    );
+}
+
+#[test]
+pub fn chars() {
+    assert!(analyze(
+        &parse(concat!(
+            "fn next(c: char) -> char {\n",
+            "    return c + 1\n",
+            "}\n",
+            "let a = next('a') - 1\n",
+            "let b = a < 'z' && a != 'b'\n",
+            "let c: char = 48\n",
+            "let d = a as i64 + 1\n",
+            "let e = (d as char) as i32\n",
+            "for x in 'a'..'e' { }\n",
+            "let f = match a { 'a' => 1, 98 => 2, _ => 0 }\n",
+        ))
+        .unwrap()
+    )
+    .is_ok());
+    assert_eq!(
+        error("let a = 'a' * 2"),
+        ("unsupported operator Multiply for char".to_owned(), 1, 13)
+    );
+    assert_eq!(
+        error("let a = -'a'"),
+        ("unsupported unary operator Minus for char".to_owned(), 1, 9)
+    );
+    assert_eq!(
+        error("let a = 'a' + 'b' as i64").0,
+        "mismatched operand types for Add: char and i64"
+    );
+    assert_eq!(
+        error("let a: char = 55296").0,
+        "literal 55296 is out of range for char"
+    );
+    assert_eq!(error("let a = 'a' as f64").0, "cannot cast char as f64");
+}
+
+// 조건식과 반환값의 타입 오류는 그 식의 위치를 가리킵니다.
+#[test]
+pub fn condition_and_return_positions() {
+    assert_eq!(
+        error("let a = 1\nif a { }"),
+        ("if condition must be a boolean, found i64".to_owned(), 2, 4)
+    );
+    assert_eq!(
+        error("while 1 { }"),
+        (
+            "while condition must be a boolean, found i64".to_owned(),
+            1,
+            7
+        )
+    );
+    assert_eq!(
+        error("fn f() -> bool {\n    return 3.5\n}"),
+        (
+            "mismatched return type: expected bool, found f64".to_owned(),
+            2,
+            5
+        )
+    );
+}
+
+// 접미사가 없는 실수 리터럴은 정수 리터럴처럼 적은 타입이나 함께 쓰인 값의 타입을 따릅니다.
+// 정수 리터럴도 실수 타입이 필요한 자리에서는 실수가 됩니다.
+#[test]
+pub fn float_literal_inference() {
+    assert!(analyze(
+        &parse(concat!(
+            "let a: f32 = 1.5\n",
+            "let b: f64 = 1\n",
+            "let c = 1.0f32 + 1.0\n",
+            "let d = 2.5\n",
+            "let e: f32 = d * 2.0\n",
+            "fn half(x: f32) -> f32 {\n",
+            "    return x / 2\n",
+            "}\n",
+            "let f = half(3)\n",
+        ))
+        .unwrap()
+    )
+    .is_ok());
+    assert_eq!(
+        error("let a: i32 = 1.5"),
+        (
+            "cannot assign f64 to variable a of type i32".to_owned(),
+            1,
+            5
+        )
+    );
+    assert_eq!(
+        error("let a = 1 << 2\nlet b: f64 = a").0,
+        "cannot assign i64 to variable b of type f64"
+    );
+}
+
+// 타입이 다른 피연산자에는 문자열일 때만 to_string()을, 숫자끼리는 as 변환을 권합니다.
+#[test]
+pub fn mismatch_help() {
+    let help = |source: &str| match analyze(&parse(source).unwrap()) {
+        Err(AllError::SemanticError(error)) => error.help.unwrap_or_default(),
+        result => panic!("expected a semantic error, found {:?}", result),
+    };
+
+    assert_eq!(
+        help("let a = 1i32 + 1i64"),
+        "both operands must have the same type; convert one of them with `as i32` or `as i64`"
+    );
+    assert_eq!(
+        help("let a = \"a\" + 1"),
+        "both operands must have the same type; use to_string() to build strings"
+    );
+    assert_eq!(
+        help("let a = true + 1"),
+        "both operands must have the same type"
+    );
+}
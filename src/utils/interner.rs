@@ -0,0 +1,108 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+// 식별자, 심볼 이름 등을 가리키는 가벼운 핸들입니다.
+// 같은 문자열은 항상 같은 Symbol로 인터닝되므로 비교와 해싱이 O(1)입니다.
+// 인터닝한 문자열은 세션(session)이 끝날 때 해제되므로 이름에만 씁니다. 문자열 리터럴과 주석의 내용은 토큰과 AST가 직접 소유합니다.
+// Symbol은 만든 세션 안에서만 쓸 수 있습니다. 세션 밖으로 가지고 나갈 이름은 String으로 바꿉니다.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Symbol(u32);
+
+#[derive(Debug, Default)]
+pub struct Interner {
+    symbols: HashMap<Rc<str>, Symbol>,
+    strings: Vec<Rc<str>>,
+}
+
+impl Interner {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn intern(&mut self, text: &str) -> Symbol {
+        if let Some(symbol) = self.symbols.get(text) {
+            return *symbol;
+        }
+
+        let text: Rc<str> = Rc::from(text);
+        let symbol = Symbol(self.strings.len() as u32);
+
+        self.strings.push(text.clone());
+        self.symbols.insert(text, symbol);
+
+        symbol
+    }
+
+    pub fn resolve(&self, symbol: Symbol) -> Rc<str> {
+        self.strings[symbol.0 as usize].clone()
+    }
+
+    // 인터닝하지 않고, 이미 인터닝된 문자열이면 그 Symbol을 반환합니다.
+    pub fn get(&self, text: &str) -> Option<Symbol> {
+        self.symbols.get(text).copied()
+    }
+}
+
+thread_local! {
+    // 현재 세션의 인터너입니다. 컴파일은 한 스레드에서 하므로 잠그지 않습니다.
+    static INTERNER: RefCell<Interner> = RefCell::new(Interner::new());
+}
+
+// f를 새 인터너로 실행하고, 끝나면 그동안 인터닝한 문자열을 해제하고 이전 인터너로 돌아갑니다.
+// Language Server나 REPL처럼 오래 실행되면서 소스코드를 여러 번 분석할 때, 분석마다 세션을 나눠서 메모리가 계속 늘지 않게 합니다.
+pub fn session<R>(f: impl FnOnce() -> R) -> R {
+    // f가 패닉해도 이전 인터너로 돌아갑니다.
+    struct Restore(Option<Interner>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            if let Some(outer) = self.0.take() {
+                INTERNER.with(|interner| interner.replace(outer));
+            }
+        }
+    }
+
+    let _restore = Restore(Some(
+        INTERNER.with(|interner| interner.replace(Interner::new())),
+    ));
+
+    f()
+}
+
+impl Symbol {
+    pub fn intern(text: &str) -> Self {
+        INTERNER.with(|interner| interner.borrow_mut().intern(text))
+    }
+
+    // 인터닝한 문자열입니다. 문자열을 복사하지 않고 참조 카운트만 늘립니다.
+    pub fn text(&self) -> Rc<str> {
+        INTERNER.with(|interner| interner.borrow().resolve(*self))
+    }
+
+    pub fn get(text: &str) -> Option<Self> {
+        INTERNER.with(|interner| interner.borrow().get(text))
+    }
+}
+
+impl From<&str> for Symbol {
+    fn from(text: &str) -> Self {
+        Self::intern(text)
+    }
+}
+
+impl From<String> for Symbol {
+    fn from(text: String) -> Self {
+        Self::intern(&text)
+    }
+}
+
+impl std::fmt::Debug for Symbol {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(formatter, "{:?}", self.text())
+    }
+}
+
+impl std::fmt::Display for Symbol {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(formatter, "{}", self.text())
+    }
+}
@@ -0,0 +1,351 @@
+use std::fmt::{Display, Formatter};
+
+// 진단 출력과 LSP 메시지에 쓰는 작은 JSON 값입니다.
+// 객체는 필드 순서를 유지하므로 같은 값은 항상 같은 문자열이 됩니다.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Json {
+    Null,
+    Boolean(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+impl Json {
+    // {"key": value, ...} 형태의 객체를 만듭니다.
+    pub fn object<const N: usize>(fields: [(&str, Json); N]) -> Self {
+        Self::Object(
+            fields
+                .into_iter()
+                .map(|(key, value)| (key.to_owned(), value))
+                .collect(),
+        )
+    }
+
+    // 객체의 필드입니다. 객체가 아니거나 필드가 없으면 None입니다.
+    pub fn get(&self, key: &str) -> Option<&Json> {
+        match self {
+            Self::Object(fields) => fields
+                .iter()
+                .find(|(field, _)| field == key)
+                .map(|(_, value)| value),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String(text) => Some(text),
+            _ => None,
+        }
+    }
+
+    pub fn as_u64(&self) -> Option<u64> {
+        match self {
+            Self::Number(number) if *number >= 0.0 && number.fract() == 0.0 => Some(*number as u64),
+            _ => None,
+        }
+    }
+
+    pub fn as_array(&self) -> Option<&[Json]> {
+        match self {
+            Self::Array(values) => Some(values),
+            _ => None,
+        }
+    }
+
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut parser = JsonParser {
+            chars: text.chars().collect(),
+            position: 0,
+        };
+
+        let value = parser.value()?;
+        parser.skip_whitespace();
+
+        if parser.position != parser.chars.len() {
+            return Err(format!(
+                "unexpected character {:?} at {}",
+                parser.chars[parser.position], parser.position
+            ));
+        }
+
+        Ok(value)
+    }
+}
+
+impl From<&str> for Json {
+    fn from(text: &str) -> Self {
+        Self::String(text.to_owned())
+    }
+}
+
+impl From<String> for Json {
+    fn from(text: String) -> Self {
+        Self::String(text)
+    }
+}
+
+impl From<usize> for Json {
+    fn from(number: usize) -> Self {
+        Self::Number(number as f64)
+    }
+}
+
+impl From<bool> for Json {
+    fn from(value: bool) -> Self {
+        Self::Boolean(value)
+    }
+}
+
+impl<T: Into<Json>> From<Option<T>> for Json {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(Self::Null, Into::into)
+    }
+}
+
+// 공백 없이 한 줄로 출력합니다.
+impl Display for Json {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Null => write!(f, "null"),
+            Self::Boolean(value) => write!(f, "{}", value),
+            Self::Number(number) if number.fract() == 0.0 && number.abs() < 1e15 => {
+                write!(f, "{}", *number as i64)
+            }
+            Self::Number(number) => write!(f, "{}", number),
+            Self::String(text) => write!(f, "{}", json_string(text)),
+            Self::Array(values) => {
+                write!(f, "[")?;
+
+                for (index, value) in values.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{}", value)?;
+                }
+
+                write!(f, "]")
+            }
+            Self::Object(fields) => {
+                write!(f, "{{")?;
+
+                for (index, (key, value)) in fields.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{}:{}", json_string(key), value)?;
+                }
+
+                write!(f, "}}")
+            }
+        }
+    }
+}
+
+// 따옴표로 감싸고 JSON 문자열에 쓸 수 없는 문자를 이스케이프합니다.
+pub fn json_string(text: &str) -> String {
+    let mut json = String::with_capacity(text.len() + 2);
+    json.push('"');
+
+    for c in text.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if (c as u32) < 0x20 => json.push_str(&format!("\\u{:04x}", c as u32)),
+            c => json.push(c),
+        }
+    }
+
+    json.push('"');
+    json
+}
+
+struct JsonParser {
+    chars: Vec<char>,
+    position: usize,
+}
+
+impl JsonParser {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.position).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(self.peek(), Some(' ' | '\t' | '\n' | '\r')) {
+            self.position += 1;
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), String> {
+        self.skip_whitespace();
+
+        match self.peek() {
+            Some(c) if c == expected => {
+                self.position += 1;
+                Ok(())
+            }
+            c => Err(format!(
+                "expected {:?}, found {:?} at {}",
+                expected, c, self.position
+            )),
+        }
+    }
+
+    fn keyword(&mut self, keyword: &str, value: Json) -> Result<Json, String> {
+        let end = self.position + keyword.len();
+
+        if self.chars.len() >= end
+            && self.chars[self.position..end]
+                .iter()
+                .copied()
+                .eq(keyword.chars())
+        {
+            self.position = end;
+            Ok(value)
+        } else {
+            Err(format!("invalid literal at {}", self.position))
+        }
+    }
+
+    fn value(&mut self) -> Result<Json, String> {
+        self.skip_whitespace();
+
+        match self.peek() {
+            Some('n') => self.keyword("null", Json::Null),
+            Some('t') => self.keyword("true", Json::Boolean(true)),
+            Some('f') => self.keyword("false", Json::Boolean(false)),
+            Some('"') => self.string().map(Json::String),
+            Some('[') => {
+                self.position += 1;
+                let mut values = vec![];
+
+                self.skip_whitespace();
+                if self.peek() == Some(']') {
+                    self.position += 1;
+                    return Ok(Json::Array(values));
+                }
+
+                loop {
+                    values.push(self.value()?);
+                    self.skip_whitespace();
+
+                    match self.peek() {
+                        Some(',') => self.position += 1,
+                        Some(']') => {
+                            self.position += 1;
+                            return Ok(Json::Array(values));
+                        }
+                        c => return Err(format!("expected ',' or ']', found {:?}", c)),
+                    }
+                }
+            }
+            Some('{') => {
+                self.position += 1;
+                let mut fields = vec![];
+
+                self.skip_whitespace();
+                if self.peek() == Some('}') {
+                    self.position += 1;
+                    return Ok(Json::Object(fields));
+                }
+
+                loop {
+                    self.skip_whitespace();
+                    let key = self.string()?;
+                    self.expect(':')?;
+                    fields.push((key, self.value()?));
+                    self.skip_whitespace();
+
+                    match self.peek() {
+                        Some(',') => self.position += 1,
+                        Some('}') => {
+                            self.position += 1;
+                            return Ok(Json::Object(fields));
+                        }
+                        c => return Err(format!("expected ',' or '}}', found {:?}", c)),
+                    }
+                }
+            }
+            Some(c) if c == '-' || c.is_ascii_digit() => {
+                let start = self.position;
+
+                while matches!(self.peek(), Some(c) if c.is_ascii_digit() || "+-.eE".contains(c)) {
+                    self.position += 1;
+                }
+
+                let text = self.chars[start..self.position].iter().collect::<String>();
+                text.parse()
+                    .map(Json::Number)
+                    .map_err(|_| format!("invalid number {}", text))
+            }
+            c => Err(format!("unexpected {:?} at {}", c, self.position)),
+        }
+    }
+
+    fn string(&mut self) -> Result<String, String> {
+        if self.peek() != Some('"') {
+            return Err(format!("expected string at {}", self.position));
+        }
+        self.position += 1;
+
+        let mut text = String::new();
+
+        loop {
+            let c = self.peek().ok_or("unterminated string")?;
+            self.position += 1;
+
+            match c {
+                '"' => return Ok(text),
+                '\\' => {
+                    let escape = self.peek().ok_or("unterminated string")?;
+                    self.position += 1;
+
+                    match escape {
+                        '"' | '\\' | '/' => text.push(escape),
+                        'n' => text.push('\n'),
+                        't' => text.push('\t'),
+                        'r' => text.push('\r'),
+                        'b' => text.push('\u{8}'),
+                        'f' => text.push('\u{c}'),
+                        'u' => {
+                            let high = self.hex4()?;
+
+                            // UTF-16 대리 쌍은 두 개의 \u를 합쳐서 문자 하나가 됩니다.
+                            let code = if (0xD800..0xDC00).contains(&high)
+                                && self.chars.get(self.position..self.position + 2)
+                                    == Some(&['\\', 'u'])
+                            {
+                                self.position += 2;
+                                let low = self.hex4()?;
+                                0x10000 + ((high - 0xD800) << 10) + (low.wrapping_sub(0xDC00))
+                            } else {
+                                high
+                            };
+
+                            text.push(char::from_u32(code).unwrap_or(char::REPLACEMENT_CHARACTER));
+                        }
+                        c => return Err(format!("unknown escape \\{}", c)),
+                    }
+                }
+                c => text.push(c),
+            }
+        }
+    }
+
+    fn hex4(&mut self) -> Result<u32, String> {
+        let digits = self
+            .chars
+            .get(self.position..self.position + 4)
+            .ok_or("unterminated \\u escape")?
+            .iter()
+            .collect::<String>();
+        self.position += 4;
+
+        u32::from_str_radix(&digits, 16).map_err(|_| format!("invalid \\u escape {}", digits))
+    }
+}
@@ -1,14 +1,166 @@
-#![allow(dead_code)]
-use colored::Colorize;
+use std::{
+    sync::{
+        atomic::{AtomicBool, AtomicU8, Ordering},
+        Mutex,
+    },
+    time::Instant,
+};
 
+use colored::{ColoredString, Colorize};
+
+use super::json::Json;
+
+// 로그의 중요도입니다. 설정한 단계보다 자세한 로그는 출력하지 않습니다.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl Level {
+    // -v의 개수에 따른 단계입니다. 기본은 경고까지 출력합니다.
+    pub fn from_verbosity(count: u8) -> Self {
+        match count {
+            0 => Self::Warn,
+            1 => Self::Info,
+            2 => Self::Debug,
+            _ => Self::Trace,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Error => "error",
+            Self::Warn => "warn",
+            Self::Info => "info",
+            Self::Debug => "debug",
+            Self::Trace => "trace",
+        }
+    }
+
+    fn prefix(&self) -> ColoredString {
+        match self {
+            Self::Error => "!![ERROR]".red(),
+            Self::Warn => "??[WARN]".yellow(),
+            Self::Info => "@@[INFO]".green(),
+            Self::Debug => "##[DEBUG]".blue(),
+            Self::Trace => "..[TRACE]".dimmed(),
+        }
+    }
+
+    fn from_u8(value: u8) -> Self {
+        match value {
+            0 => Self::Error,
+            1 => Self::Warn,
+            2 => Self::Info,
+            3 => Self::Debug,
+            _ => Self::Trace,
+        }
+    }
+}
+
+static LEVEL: AtomicU8 = AtomicU8::new(Level::Warn as u8);
+
+// start_trace 이후의 로그를 단계와 관계없이 모아 둡니다.
+static TRACE: Mutex<Option<Trace>> = Mutex::new(None);
+// 로그를 모으는 중인지입니다. 로그마다 TRACE를 잠그지 않고 확인합니다.
+static TRACING: AtomicBool = AtomicBool::new(false);
+
+struct Trace {
+    start: Instant,
+    events: Vec<Json>,
+}
+
+// 컴파일 단계(stage)별로 남기는 로그입니다. 설정한 단계까지 표준 에러로 출력하고,
+// --trace-file로 로그를 모으는 중이라면 단계와 관계없이 모두 모읍니다.
+// 자세한 로그는 만드는 비용이 크므로, 호출하기 전에 enabled로 확인합니다.
 pub struct Logger {}
 
 impl Logger {
-    pub fn error(text: impl Into<String>) {
-        println!("{}", format!("!![ERROR] {}", text.into()).red());
+    pub fn set_level(level: Level) {
+        LEVEL.store(level as u8, Ordering::Relaxed);
+    }
+
+    pub fn level() -> Level {
+        Level::from_u8(LEVEL.load(Ordering::Relaxed))
+    }
+
+    // 출력하거나 모을 로그인지 확인합니다.
+    pub fn enabled(level: Level) -> bool {
+        Self::printed(level) || TRACING.load(Ordering::Relaxed)
     }
 
-    pub fn info(text: impl Into<String>) {
-        println!("{}", format!("@@[INFO] {}", text.into()).green());
+    fn printed(level: Level) -> bool {
+        level <= Self::level()
+    }
+
+    pub fn log(level: Level, stage: &str, text: impl Into<String>) {
+        if !Self::enabled(level) {
+            return;
+        }
+
+        let text = text.into();
+        if Self::printed(level) {
+            eprintln!("{} {}: {}", level.prefix(), stage, text);
+        }
+
+        if let Some(trace) = TRACE.lock().unwrap().as_mut() {
+            let time = trace.start.elapsed().as_micros() as usize;
+
+            trace.events.push(Json::object([
+                ("name", text.into()),
+                ("cat", stage.into()),
+                ("ph", "i".into()),
+                ("s", "g".into()),
+                ("ts", time.into()),
+                ("pid", 0usize.into()),
+                ("tid", 0usize.into()),
+                ("args", Json::object([("level", level.as_str().into())])),
+            ]));
+        }
+    }
+
+    pub fn error(stage: &str, text: impl Into<String>) {
+        Self::log(Level::Error, stage, text);
+    }
+
+    pub fn warn(stage: &str, text: impl Into<String>) {
+        Self::log(Level::Warn, stage, text);
+    }
+
+    pub fn info(stage: &str, text: impl Into<String>) {
+        Self::log(Level::Info, stage, text);
+    }
+
+    pub fn debug(stage: &str, text: impl Into<String>) {
+        Self::log(Level::Debug, stage, text);
+    }
+
+    pub fn trace(stage: &str, text: impl Into<String>) {
+        Self::log(Level::Trace, stage, text);
+    }
+
+    // 이후의 로그를 출력 단계와 관계없이 시간과 함께 모읍니다. (--trace-file)
+    pub fn start_trace() {
+        *TRACE.lock().unwrap() = Some(Trace {
+            start: Instant::now(),
+            events: vec![],
+        });
+        TRACING.store(true, Ordering::Relaxed);
+    }
+
+    // 모은 로그를 Chrome trace event 형식으로 반환하고 수집을 멈춥니다.
+    // chrome://tracing이나 Perfetto에서 열 수 있습니다.
+    pub fn finish_trace() -> Option<Json> {
+        TRACING.store(false, Ordering::Relaxed);
+
+        TRACE
+            .lock()
+            .unwrap()
+            .take()
+            .map(|trace| Json::object([("traceEvents", Json::Array(trace.events))]))
     }
 }
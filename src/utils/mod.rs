@@ -1 +1,8 @@
-pub(crate) mod logger;
+pub(crate) mod interner;
+pub mod json;
+pub mod logger;
+pub mod span;
+pub(crate) mod testing;
+pub mod timings;
+
+pub(crate) mod test;
@@ -0,0 +1,100 @@
+use std::fmt::{Display, Formatter};
+
+// 소스코드에서 토큰이나 표현식이 시작하는 위치와 길이입니다.
+// line, column은 1부터 시작하고, column과 length는 바이트가 아닌 문자 단위입니다.
+// 코드로 직접 만든 AST처럼 위치를 알 수 없으면 line이 0입니다.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Span {
+    pub line: usize,
+    pub column: usize,
+    pub length: usize,
+}
+
+impl Span {
+    pub fn new(line: usize, column: usize, length: usize) -> Self {
+        Self {
+            line,
+            column,
+            length,
+        }
+    }
+
+    pub fn is_unknown(&self) -> bool {
+        self.line == 0
+    }
+
+    // self부터 end까지의 위치입니다. 두 위치가 다른 줄에 있으면 길이를 알 수 없으므로 self를 반환합니다.
+    pub fn to(&self, end: Span) -> Span {
+        if self.line != end.line || end.column < self.column {
+            return *self;
+        }
+
+        Span::new(
+            self.line,
+            self.column,
+            end.column + end.length - self.column,
+        )
+    }
+}
+
+impl Display for Span {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
+
+// 바이트 위치를 줄, 열로 바꾸기 위해 각 줄의 시작 위치를 미리 계산해 둡니다.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LineIndex {
+    line_starts: Vec<usize>,
+}
+
+impl LineIndex {
+    pub fn new(source: &str) -> Self {
+        let mut line_starts = vec![0];
+        line_starts.extend(source.match_indices('\n').map(|(index, _)| index + 1));
+
+        Self { line_starts }
+    }
+
+    // source[start..end] 범위의 Span입니다.
+    pub fn span(&self, source: &str, start: usize, end: usize) -> Span {
+        let line = match self.line_starts.binary_search(&start) {
+            Ok(line) => line,
+            Err(next_line) => next_line - 1,
+        };
+        let line_start = self.line_starts[line];
+
+        Span::new(
+            line + 1,
+            source[line_start..start].chars().count() + 1,
+            source[start..end].chars().count(),
+        )
+    }
+
+    // 1부터 시작하는 줄 번호의 내용입니다. 줄바꿈 문자는 포함하지 않습니다.
+    pub fn line<'s>(&self, source: &'s str, line: usize) -> Option<&'s str> {
+        let start = *self.line_starts.get(line.checked_sub(1)?)?;
+        let text = source.get(start..)?;
+
+        Some(text.split('\n').next().unwrap_or_default())
+    }
+
+    // span 범위의 원본 텍스트입니다. span과 source가 맞지 않으면 None입니다.
+    pub fn slice<'s>(&self, source: &'s str, span: Span) -> Option<&'s str> {
+        let line = self.line(source, span.line)?;
+        let start =
+            self.line_starts[span.line - 1] + char_offset(line, span.column.checked_sub(1)?)?;
+        let rest = source.get(start..)?;
+
+        Some(&rest[..char_offset(rest, span.length)?])
+    }
+}
+
+// text 앞의 count 글자의 바이트 길이입니다.
+fn char_offset(text: &str, count: usize) -> Option<usize> {
+    text.char_indices()
+        .map(|(index, _)| index)
+        .chain([text.len()])
+        .nth(count)
+}
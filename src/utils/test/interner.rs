@@ -0,0 +1,74 @@
+#![cfg(test)]
+
+use crate::{
+    codegen::value::ValueType,
+    utils::interner::{session, Interner, Symbol},
+};
+
+#[test]
+pub fn intern_same_string() {
+    let mut interner = Interner::new();
+
+    let foo = interner.intern("foo");
+    let bar = interner.intern("bar");
+
+    assert_eq!(foo, interner.intern("foo"));
+    assert_ne!(foo, bar);
+}
+
+#[test]
+pub fn resolve() {
+    let mut interner = Interner::new();
+
+    let foo = interner.intern("foo");
+
+    assert_eq!(&*interner.resolve(foo), "foo");
+}
+
+#[test]
+pub fn global_symbol() {
+    let symbol = Symbol::intern("global_symbol");
+
+    assert_eq!(symbol, Symbol::from("global_symbol".to_owned()));
+    assert_eq!(&*symbol.text(), "global_symbol");
+    assert_eq!(format!("{:?}", symbol), "\"global_symbol\"");
+}
+
+#[test]
+pub fn get_does_not_intern() {
+    let mut interner = Interner::new();
+
+    assert_eq!(interner.get("foo"), None);
+
+    let foo = interner.intern("foo");
+
+    assert_eq!(interner.get("foo"), Some(foo));
+    assert_eq!(interner.get("bar"), None);
+}
+
+// 세션에서 인터닝한 문자열은 세션이 끝나면 해제되고, 이전 인터너로 돌아갑니다.
+#[test]
+pub fn session_releases_strings() {
+    let outer = Symbol::intern("outer_symbol");
+
+    let text = session(|| {
+        assert_eq!(Symbol::get("outer_symbol"), None);
+
+        Symbol::intern("session_symbol").text()
+    });
+
+    assert_eq!(&*text, "session_symbol");
+    assert_eq!(Symbol::get("session_symbol"), None);
+    assert_eq!(Symbol::get("outer_symbol"), Some(outer));
+}
+
+// 구조체의 LLVM 타입 이름은 인터닝하지 않습니다.
+#[test]
+pub fn llvm_type_does_not_intern() {
+    session(|| {
+        let point = ValueType::Struct(Symbol::intern("Point"));
+
+        assert_eq!(point.llvm_type(), "%foolang.Point");
+        assert_eq!(Symbol::get("%foolang.Point"), None);
+    });
+}
@@ -0,0 +1,29 @@
+#![cfg(test)]
+
+use crate::utils::json::Json;
+
+#[test]
+pub fn parse_and_print() {
+    let text = r#"{"a":[1,-2.5,true,false,null],"b":{"c":"d\"\n\u00e9\ud83d\ude00"},"e":[]}"#;
+    let json = Json::parse(text).unwrap();
+
+    assert_eq!(
+        json.get("b").unwrap().get("c").unwrap().as_str(),
+        Some("d\"\né😀")
+    );
+    assert_eq!(
+        json.to_string(),
+        r#"{"a":[1,-2.5,true,false,null],"b":{"c":"d\"\né😀"},"e":[]}"#
+    );
+    assert_eq!(
+        Json::parse(" { \"a\" : 1 }\n").unwrap(),
+        Json::object([("a", 1usize.into())])
+    );
+}
+
+#[test]
+pub fn parse_errors() {
+    for text in ["", "{", "[1,]", "{\"a\" 1}", "nul", "\"a", "1 2", "\"\\x\""] {
+        assert!(Json::parse(text).is_err(), "{}", text);
+    }
+}
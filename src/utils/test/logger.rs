@@ -0,0 +1,63 @@
+#![cfg(test)]
+
+use crate::utils::{
+    json::Json,
+    logger::{Level, Logger},
+};
+
+#[test]
+pub fn levels_and_trace() {
+    assert_eq!(Level::from_verbosity(0), Level::Warn);
+    assert_eq!(Level::from_verbosity(1), Level::Info);
+    assert_eq!(Level::from_verbosity(2), Level::Debug);
+    assert_eq!(Level::from_verbosity(5), Level::Trace);
+    assert!(Level::Error < Level::Trace);
+
+    Logger::set_level(Level::Debug);
+    assert!(Logger::enabled(Level::Debug));
+    assert!(!Logger::enabled(Level::Trace));
+
+    // 출력하지 않는 단계의 로그도 모읍니다.
+    Logger::start_trace();
+    assert!(Logger::enabled(Level::Trace));
+    Logger::debug("logger-test", "recorded");
+    Logger::trace("logger-test", "not printed");
+    let trace = Logger::finish_trace().unwrap();
+    Logger::set_level(Level::Warn);
+    assert!(!Logger::enabled(Level::Trace));
+
+    // 다른 테스트가 동시에 남긴 로그는 제외합니다.
+    let events = trace
+        .get("traceEvents")
+        .and_then(Json::as_array)
+        .unwrap()
+        .iter()
+        .filter(|event| event.get("cat").and_then(Json::as_str) == Some("logger-test"))
+        .collect::<Vec<_>>();
+
+    assert_eq!(events.len(), 2);
+    assert_eq!(
+        events[0].get("name").and_then(Json::as_str),
+        Some("recorded")
+    );
+    assert_eq!(
+        events[1].get("name").and_then(Json::as_str),
+        Some("not printed")
+    );
+    assert_eq!(
+        events[1]
+            .get("args")
+            .and_then(|args| args.get("level"))
+            .and_then(Json::as_str),
+        Some("trace")
+    );
+    assert_eq!(events[0].get("ph").and_then(Json::as_str), Some("i"));
+    assert_eq!(
+        events[0]
+            .get("args")
+            .and_then(|args| args.get("level"))
+            .and_then(Json::as_str),
+        Some("debug")
+    );
+    assert!(Logger::finish_trace().is_none());
+}
@@ -0,0 +1,5 @@
+pub(crate) mod interner;
+pub(crate) mod json;
+pub(crate) mod logger;
+pub(crate) mod span;
+pub(crate) mod timings;
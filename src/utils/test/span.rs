@@ -0,0 +1,74 @@
+#![cfg(test)]
+
+use crate::{
+    lexer::tokenizer::Tokenizer,
+    utils::span::{LineIndex, Span},
+};
+
+fn positions(text: &str) -> Vec<(usize, usize, usize)> {
+    Tokenizer::new(text)
+        .map(|token| {
+            let span = token.unwrap().span;
+            (span.line, span.column, span.length)
+        })
+        .collect()
+}
+
+#[test]
+pub fn token_spans() {
+    assert_eq!(
+        positions("let foo = 10\n  println(\"한글\")"),
+        vec![
+            (1, 1, 3),
+            (1, 5, 3),
+            (1, 9, 1),
+            (1, 11, 2),
+            (2, 3, 7),
+            (2, 10, 1),
+            (2, 11, 4),
+            (2, 15, 1),
+        ]
+    );
+}
+
+#[test]
+pub fn identifier_before_whitespace() {
+    assert_eq!(positions("foo \nbar"), vec![(1, 1, 3), (2, 1, 3)]);
+}
+
+#[test]
+pub fn lexer_error_position() {
+    let error = Tokenizer::string_to_tokens("let a = 1\nlet b = 1.2.3").unwrap_err();
+
+    assert!(error.to_string().contains("2:9: "));
+}
+
+#[test]
+pub fn spans_are_compared_by_position() {
+    assert_ne!(Span::new(1, 2, 3), Span::default());
+    assert_eq!(Span::new(1, 2, 3), Span::new(1, 2, 3));
+    assert!(Span::default().is_unknown());
+    assert_eq!(Span::new(3, 14, 1).to_string(), "3:14");
+}
+
+// 토큰의 위치로 원본 텍스트를 다시 찾을 수 있습니다.
+#[test]
+pub fn slice_by_span() {
+    let source = "\"한\" = r\"a\nb\"\n\n  0x1F";
+    let index = LineIndex::new(source);
+
+    let texts = Tokenizer::new(source)
+        .map(|token| index.slice(source, token.unwrap().span).unwrap())
+        .collect::<Vec<_>>();
+
+    assert_eq!(texts, vec!["\"한\"", "=", "r\"a\nb\"", "0x1F"]);
+    assert_eq!(index.line(source, 3), Some(""));
+    assert_eq!(index.line(source, 4), Some("  0x1F"));
+    assert_eq!(index.line(source, 5), None);
+    assert_eq!(index.slice(source, Span::default()), None);
+
+    let minus = Span::new(4, 1, 1);
+    let literal = Span::new(4, 3, 4);
+    assert_eq!(minus.to(literal).length, 6);
+    assert_eq!(minus.to(Span::new(5, 1, 1)).length, 1);
+}
@@ -0,0 +1,74 @@
+#![cfg(test)]
+
+use std::time::Duration;
+
+use crate::utils::{
+    json::Json,
+    timings::{StageTiming, TimingReport, Timings},
+};
+
+#[test]
+pub fn measure_stages() {
+    assert_eq!(Timings::measure("timings-test", || 1 + 1), 2);
+
+    Timings::start();
+    let value = Timings::measure("timings-test-a", || "a");
+    Timings::measure("timings-test-b", || {});
+    Timings::measure("timings-test-a", || {});
+    let report = Timings::finish().unwrap();
+
+    assert_eq!(value, "a");
+    assert!(Timings::finish().is_none());
+
+    // 다른 테스트가 동시에 측정한 단계는 제외합니다.
+    let stages = report
+        .stages
+        .iter()
+        .filter(|timing| timing.stage.starts_with("timings-test"))
+        .map(|timing| (timing.stage.as_str(), timing.count))
+        .collect::<Vec<_>>();
+    assert_eq!(stages, vec![("timings-test-a", 2), ("timings-test-b", 1)]);
+}
+
+#[test]
+pub fn report_formats() {
+    let report = TimingReport {
+        stages: vec![
+            StageTiming {
+                stage: "parse".to_owned(),
+                count: 1,
+                total: Duration::from_millis(25),
+            },
+            StageTiming {
+                stage: "<llc>".to_owned(),
+                count: 2,
+                total: Duration::from_millis(50),
+            },
+        ],
+        total: Duration::from_millis(100),
+    };
+
+    assert_eq!(
+        report.render(),
+        concat!(
+            "stage  count   time (ms)       %\n",
+            "parse      1      25.000   25.0%\n",
+            "<llc>      2      50.000   50.0%\n",
+            "other             25.000   25.0%\n",
+            "total            100.000  100.0%\n",
+        )
+    );
+
+    let json = Json::parse(&report.to_json().to_string()).unwrap();
+    assert_eq!(json.get("total_ms").and_then(Json::as_u64), Some(100));
+    assert_eq!(json.get("other_ms").and_then(Json::as_u64), Some(25));
+    let stages = json.get("stages").and_then(Json::as_array).unwrap();
+    assert_eq!(stages[1].get("stage").and_then(Json::as_str), Some("<llc>"));
+    assert_eq!(stages[1].get("count").and_then(Json::as_u64), Some(2));
+    assert_eq!(stages[1].get("time_ms").and_then(Json::as_u64), Some(50));
+
+    let html = report.to_html();
+    assert!(html.contains("<td>&lt;llc&gt;</td><td>2</td><td>50.000</td>"));
+    assert!(html.contains("style=\"width: 25.0%\""));
+    assert!(html.contains("<td>other</td><td></td><td>25.000</td>"));
+}
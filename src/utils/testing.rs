@@ -0,0 +1,240 @@
+#![cfg(test)]
+
+use std::{
+    io::Write,
+    path::{Path, PathBuf},
+    process::{Command, Output, Stdio},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use clap::ValueEnum;
+
+use crate::{
+    ast::statement::{clear_spans, Statement},
+    builder::{emit::Emit, target::Target, toolchain::Toolchain, Builder},
+    compiler::{compile_source, lower_to_ir, parse},
+    error::all_error::AllError,
+    runtime::{args, Runtime},
+};
+
+// 테스트마다 겹치지 않는 임시 디렉터리를 만듭니다.
+pub(crate) fn temporary_directory() -> PathBuf {
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+    let directory = std::env::temp_dir().join(format!(
+        "foolang-test-{}-{}",
+        std::process::id(),
+        COUNTER.fetch_add(1, Ordering::SeqCst)
+    ));
+    std::fs::create_dir_all(&directory).unwrap();
+
+    directory
+}
+
+// 소스코드를 linux-amd64 오브젝트 파일로 컴파일하고, 그 경로로 f를 호출합니다. 오브젝트 파일은 호출이 끝나면 지웁니다.
+// llc를 찾을 수 없는 환경에서는 None을 반환하므로 테스트에서는 검증을 건너뜁니다.
+pub(crate) fn with_object<R>(source: &str, no_std: bool, f: impl FnOnce(&Path) -> R) -> Option<R> {
+    if !Toolchain::new().is_available() {
+        return None;
+    }
+
+    let directory = temporary_directory();
+
+    let mut builder = Builder::new();
+    builder.set_filenames(lower_to_ir(parse(source).unwrap(), no_std).unwrap());
+    builder.set_emit(Emit::Object);
+    builder.set_no_std(no_std);
+    builder.set_target(Target::LinuxAmd64);
+    builder.set_source_path(directory.join("main.foo"));
+
+    let object_path = builder.build().unwrap();
+    let result = f(Path::new(&object_path));
+
+    std::fs::remove_dir_all(&directory).ok();
+
+    Some(result)
+}
+
+// objdump의 출력입니다. objdump가 없거나 실패하면 None입니다. (FOOLANG_OBJDUMP로 다른 objdump를 쓸 수 있습니다.)
+pub(crate) fn objdump(path: &Path, arguments: &[&str]) -> Option<String> {
+    let objdump = std::env::var("FOOLANG_OBJDUMP").unwrap_or_else(|_| "objdump".into());
+
+    Command::new(&objdump)
+        .args(arguments)
+        .arg(path)
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .map(|output| String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+// 소스코드를 파싱하고 위치를 지운 AST를 반환합니다. 테스트에서 기대하는 AST는 위치 없이 만들기 때문입니다.
+pub(crate) fn parse_without_spans(source: &str) -> Result<Vec<Statement>, AllError> {
+    let mut statements = parse(source)?;
+    clear_spans(&mut statements);
+
+    Ok(statements)
+}
+
+// LLVM-IR 코드를 컴파일, 링크하고 실행한 결과를 반환합니다.
+// llc를 찾을 수 없는 환경에서는 None을 반환하므로 테스트에서는 검증을 건너뜁니다.
+pub(crate) fn run_llvm_ir(codes: &[String], no_std: bool, arguments: &[&str]) -> Option<Output> {
+    run_llvm_ir_with_input(codes, no_std, arguments, &[])
+}
+
+// run_llvm_ir와 같지만 input을 stdin으로 넘겨서 실행합니다.
+pub(crate) fn run_llvm_ir_with_input(
+    codes: &[String],
+    no_std: bool,
+    arguments: &[&str],
+    input: &[u8],
+) -> Option<Output> {
+    let toolchain = Toolchain::new();
+
+    if !toolchain.is_available() {
+        return None;
+    }
+
+    let directory = temporary_directory();
+    let ir_path = directory.join("main.ll");
+    let object_path = directory.join("main.o");
+    let executable_path = directory.join("main");
+
+    std::fs::write(&ir_path, codes.join("\n")).unwrap();
+    toolchain.compile_object(&ir_path, &object_path).unwrap();
+    toolchain
+        .link_executable(&[object_path], &executable_path, no_std)
+        .unwrap();
+
+    let mut child = Command::new(&executable_path)
+        .args(arguments)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child.stdin.take().unwrap().write_all(input).unwrap();
+    let output = child.wait_with_output().unwrap();
+
+    std::fs::remove_dir_all(&directory).ok();
+
+    Some(output)
+}
+
+// 런타임 코드와 함께 globals, main 본문으로 이루어진 프로그램을 만듭니다.
+// codegen이 생성하는 main과 마찬가지로 본문 앞에서 프로그램 인자를 저장합니다.
+pub(crate) fn runtime_program(no_std: bool, globals: &[&str], main_body: &[&str]) -> Vec<String> {
+    let mut runtime = Runtime::new();
+    runtime.set_no_std(no_std);
+
+    let mut codes = runtime.generate();
+    codes.extend(globals.iter().map(|line| line.to_string()));
+    codes.push("define i32 @main(i32 %argc, i8** %argv) {".into());
+    codes.push("entry:".into());
+    codes.push(args::initialize());
+    codes.extend(main_body.iter().map(|line| line.to_string()));
+    codes.push("}".into());
+
+    codes
+}
+
+// 모든 target에서 같은 방법으로 검사하는 컴파일 테스트입니다.
+pub(crate) struct TestCase {
+    pub name: &'static str,
+    pub source: &'static str,
+    // 실행했을 때의 표준 출력
+    pub stdout: &'static str,
+}
+
+// 모든 target용 오브젝트 파일을 만들어 ELF 헤더를 검사하고,
+// 현재 플랫폼과 같은 target에서만 링크해서 실행한 결과를 비교합니다.
+// llc가 없으면 검사하지 않습니다.
+pub(crate) fn run_test_cases(cases: &[TestCase]) {
+    if !Toolchain::new().is_available() {
+        return;
+    }
+
+    for case in cases {
+        for target in Target::value_variants() {
+            let object = compile_source(case.source, *target)
+                .unwrap_or_else(|error| panic!("{} ({:?}): {}", case.name, target, error));
+
+            // ELF64 little endian relocatable
+            assert_eq!(&object[..4], b"\x7fELF", "{}", case.name);
+            assert_eq!(object[4], 2, "{}", case.name);
+            assert_eq!(object[5], 1, "{}", case.name);
+            assert_eq!(
+                u16::from_le_bytes([object[16], object[17]]),
+                1,
+                "{}",
+                case.name
+            );
+            assert_eq!(
+                u16::from_le_bytes([object[18], object[19]]),
+                target.elf_machine(),
+                "{} ({:?})",
+                case.name,
+                target
+            );
+
+            if Target::host() != Some(*target) {
+                continue;
+            }
+
+            let codes = lower_to_ir(parse(case.source).unwrap(), false).unwrap();
+            let output = run_llvm_ir(&codes, false, &[]).unwrap();
+            assert_eq!(
+                String::from_utf8_lossy(&output.stdout),
+                case.stdout,
+                "{}",
+                case.name
+            );
+        }
+    }
+}
+
+// 스냅샷 파일을 새로 쓰게 하는 환경변수입니다. (FOOLANG_UPDATE_SNAPSHOTS=1 cargo test)
+const UPDATE_SNAPSHOTS: &str = "FOOLANG_UPDATE_SNAPSHOTS";
+
+// actual을 저장소에 있는 스냅샷 파일(path, 저장소 기준 경로)과 비교합니다.
+// 다르면 처음으로 달라진 줄을 보여주고, 환경변수가 설정되어 있으면 비교하지 않고 파일을 새로 씁니다.
+pub(crate) fn assert_snapshot(path: &str, actual: &str) {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(path);
+
+    if std::env::var_os(UPDATE_SNAPSHOTS).is_some() {
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, actual).unwrap();
+        return;
+    }
+
+    let expected = std::fs::read_to_string(&path).unwrap_or_else(|error| {
+        panic!(
+            "{}: {}. run with {}=1 to create it",
+            path.display(),
+            error,
+            UPDATE_SNAPSHOTS
+        )
+    });
+
+    if expected == actual {
+        return;
+    }
+
+    let mut expected_lines = expected.lines();
+    let mut actual_lines = actual.lines();
+    let mut line = 1;
+
+    loop {
+        match (expected_lines.next(), actual_lines.next()) {
+            (Some(expected), Some(actual)) if expected == actual => line += 1,
+            (expected, actual) => panic!(
+                "{} differs at line {}\n- {}\n+ {}\nrun with {}=1 to update it",
+                path.display(),
+                line,
+                expected.unwrap_or("(end of file)"),
+                actual.unwrap_or("(end of file)"),
+                UPDATE_SNAPSHOTS
+            ),
+        }
+    }
+}
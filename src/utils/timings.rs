@@ -0,0 +1,261 @@
+use std::{
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use clap::ValueEnum;
+use serde::Deserialize;
+
+use super::json::Json;
+
+// 시간 측정 결과의 출력 형식입니다. (--timings)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, ValueEnum)]
+#[serde(rename_all = "kebab-case")]
+pub enum TimingsFormat {
+    // 표준 에러로 출력하는 표
+    #[default]
+    Text,
+    // foolang-timings.json
+    Json,
+    // foolang-timings.html
+    Html,
+}
+
+// start 이후에 측정한 단계별 시간입니다.
+static TIMINGS: Mutex<Option<Recording>> = Mutex::new(None);
+
+struct Recording {
+    start: Instant,
+    stages: Vec<StageTiming>,
+}
+
+// 같은 이름의 단계는 모듈이 여러 개여도 하나로 합칩니다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StageTiming {
+    pub stage: String,
+    // 측정한 횟수
+    pub count: usize,
+    pub total: Duration,
+}
+
+// 컴파일 단계(lex+parse, analyze, codegen, llc, as, link 등)마다 걸린 시간을 모읍니다.
+// start를 호출하지 않았다면 measure는 시간을 재지 않고 실행만 합니다.
+pub struct Timings {}
+
+impl Timings {
+    pub fn start() {
+        *TIMINGS.lock().unwrap() = Some(Recording {
+            start: Instant::now(),
+            stages: vec![],
+        });
+    }
+
+    pub fn measure<T>(stage: &str, f: impl FnOnce() -> T) -> T {
+        if TIMINGS.lock().unwrap().is_none() {
+            return f();
+        }
+
+        let start = Instant::now();
+        let result = f();
+        let elapsed = start.elapsed();
+
+        if let Some(recording) = TIMINGS.lock().unwrap().as_mut() {
+            match recording
+                .stages
+                .iter_mut()
+                .find(|timing| timing.stage == stage)
+            {
+                Some(timing) => {
+                    timing.count += 1;
+                    timing.total += elapsed;
+                }
+                None => recording.stages.push(StageTiming {
+                    stage: stage.to_owned(),
+                    count: 1,
+                    total: elapsed,
+                }),
+            }
+        }
+
+        result
+    }
+
+    // 측정을 멈추고 결과를 반환합니다. start를 호출하지 않았다면 None입니다.
+    pub fn finish() -> Option<TimingReport> {
+        TIMINGS
+            .lock()
+            .unwrap()
+            .take()
+            .map(|recording| TimingReport {
+                stages: recording.stages,
+                total: recording.start.elapsed(),
+            })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimingReport {
+    // 처음 측정한 순서
+    pub stages: Vec<StageTiming>,
+    // start부터 finish까지의 시간. 측정하지 않은 부분(파일 읽기 등)도 포함합니다.
+    pub total: Duration,
+}
+
+impl TimingReport {
+    // 어느 단계에도 속하지 않은 시간입니다. 단계와 합하면 total이 됩니다.
+    pub fn other(&self) -> Duration {
+        let measured = self
+            .stages
+            .iter()
+            .map(|timing| timing.total)
+            .sum::<Duration>();
+
+        self.total.saturating_sub(measured)
+    }
+
+    fn percent(&self, duration: Duration) -> f64 {
+        if self.total.is_zero() {
+            return 0.0;
+        }
+
+        duration.as_secs_f64() / self.total.as_secs_f64() * 100.0
+    }
+
+    // 단계     횟수   시간(ms)   비율
+    pub fn render(&self) -> String {
+        let width = self
+            .stages
+            .iter()
+            .map(|timing| timing.stage.len())
+            .chain(["stage".len(), "other".len(), "total".len()])
+            .max()
+            .unwrap_or_default();
+
+        let mut text = format!(
+            "{:<width$}  {:>5}  {:>10}  {:>6}\n",
+            "stage",
+            "count",
+            "time (ms)",
+            "%",
+            width = width
+        );
+
+        for timing in &self.stages {
+            text += &format!(
+                "{:<width$}  {:>5}  {:>10.3}  {:>5.1}%\n",
+                timing.stage,
+                timing.count,
+                milliseconds(timing.total),
+                self.percent(timing.total),
+                width = width
+            );
+        }
+
+        text += &format!(
+            "{:<width$}  {:>5}  {:>10.3}  {:>5.1}%\n",
+            "other",
+            "",
+            milliseconds(self.other()),
+            self.percent(self.other()),
+            width = width
+        );
+
+        text += &format!(
+            "{:<width$}  {:>5}  {:>10.3}  {:>5.1}%\n",
+            "total",
+            "",
+            milliseconds(self.total),
+            100.0,
+            width = width
+        );
+
+        text
+    }
+
+    pub fn to_json(&self) -> Json {
+        Json::object([
+            ("total_ms", Json::Number(milliseconds(self.total))),
+            ("other_ms", Json::Number(milliseconds(self.other()))),
+            (
+                "stages",
+                Json::Array(
+                    self.stages
+                        .iter()
+                        .map(|timing| {
+                            Json::object([
+                                ("stage", timing.stage.as_str().into()),
+                                ("count", timing.count.into()),
+                                ("time_ms", Json::Number(milliseconds(timing.total))),
+                            ])
+                        })
+                        .collect(),
+                ),
+            ),
+        ])
+    }
+
+    // 단계마다 전체 시간에 대한 비율만큼의 막대를 그립니다.
+    pub fn to_html(&self) -> String {
+        let mut rows = String::new();
+
+        for timing in &self.stages {
+            rows += &format!(
+                concat!(
+                    "<tr><td>{}</td><td>{}</td><td>{:.3}</td>",
+                    "<td><div class=\"bar\" style=\"width: {:.1}%\"></div></td></tr>\n"
+                ),
+                escape_html(&timing.stage),
+                timing.count,
+                milliseconds(timing.total),
+                self.percent(timing.total)
+            );
+        }
+
+        rows += &format!(
+            concat!(
+                "<tr><td>other</td><td></td><td>{:.3}</td>",
+                "<td><div class=\"bar\" style=\"width: {:.1}%\"></div></td></tr>\n"
+            ),
+            milliseconds(self.other()),
+            self.percent(self.other())
+        );
+
+        format!(
+            concat!(
+                "<!DOCTYPE html>\n",
+                "<html>\n",
+                "<head>\n",
+                "<meta charset=\"utf-8\">\n",
+                "<title>foolang timings</title>\n",
+                "<style>\n",
+                "table {{ border-collapse: collapse; }}\n",
+                "td, th {{ padding: 2px 8px; text-align: left; }}\n",
+                "td:last-child {{ width: 400px; }}\n",
+                ".bar {{ background: #4a90d9; height: 12px; }}\n",
+                "</style>\n",
+                "</head>\n",
+                "<body>\n",
+                "<h1>foolang timings</h1>\n",
+                "<p>total: {:.3} ms</p>\n",
+                "<table>\n",
+                "<tr><th>stage</th><th>count</th><th>time (ms)</th><th></th></tr>\n",
+                "{}",
+                "</table>\n",
+                "</body>\n",
+                "</html>\n"
+            ),
+            milliseconds(self.total),
+            rows
+        )
+    }
+}
+
+fn milliseconds(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}